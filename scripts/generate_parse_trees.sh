@@ -1,6 +1,9 @@
 pushd tools/generate_parse_trees > /dev/null || exit
 
-cargo run > "../../src/parsing/parse_tree.rs"
+cargo run -- parse_tree > "../../src/parsing/parse_tree.rs"
+cargo run -- visitor > "../../src/parsing/visitor.rs"
+cargo run -- folder > "../../src/parsing/folder.rs"
+cargo run -- json > "../../src/parsing/parse_tree_json.rs"
 
 popd > /dev/null
 
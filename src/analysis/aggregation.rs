@@ -0,0 +1,648 @@
+//! Aggregation analysis: checks the use of aggregate functions, GROUP BY
+//! and HAVING in a query specification, following Presto's rules.
+//!
+//! A query specification is aggregated when it has a GROUP BY or HAVING
+//! clause, or calls an aggregate function outside of a window. The select
+//! items, HAVING and ORDER BY of an aggregated query may only reference the
+//! columns of its FROM clause within aggregate calls, or within expressions
+//! which appear in the GROUP BY. GROUP BY ordinals refer to the select items.
+//!
+//! Aggregations may not be nested, nor contain window functions, and WHERE
+//! and GROUP BY may not contain aggregations, window functions or GROUPING
+//! operations.
+//!
+//! The checks run after resolution, which binds the column references and
+//! function calls they rely on.
+use super::functions::FunctionKind;
+use super::identifier_name;
+use super::list_elements;
+use super::resolver::{ColumnTarget, Fields, Resolution};
+use crate::formatting::format_tree;
+use crate::lexing::TokenKind;
+use crate::parsing::{parse_tree, visit_pre_order, ParseTree};
+use crate::utils::syntax_error::{
+    ERROR_CANNOT_HAVE_AGGREGATIONS, ERROR_INVALID_GROUPING, ERROR_INVALID_ORDINAL,
+    ERROR_MUST_BE_AGGREGATE_OR_GROUP, ERROR_NESTED_AGGREGATION, ERROR_NESTED_WINDOW,
+};
+use crate::utils::{Message, SyntaxError, TextRange};
+
+/// Checks the aggregations of a query specification.
+///
+/// sort_items are the items of the ORDER BY of the query specification;
+/// select_fields are the output fields of each of its select items, and
+/// local_columns the columns of the relations of its FROM clause.
+pub fn check_query_specification(
+    query_specification: &parse_tree::QuerySpecification,
+    sort_items: Option<&ParseTree>,
+    select_fields: &[Fields],
+    local_columns: &[ColumnTarget],
+    resolution: &Resolution,
+) -> Vec<SyntaxError> {
+    let mut checker = Checker {
+        resolution,
+        local_columns,
+        grouped_expressions: Vec::new(),
+        grouped_columns: Vec::new(),
+        errors: Vec::new(),
+    };
+    checker.check_no_calls(
+        &query_specification.where_predicate,
+        ERROR_CANNOT_HAVE_AGGREGATIONS,
+        "WHERE clause cannot contain aggregations, window functions or grouping operations",
+        |_| true,
+    );
+
+    let select_items = list_elements(&query_specification.select_items);
+    for expression in grouping_expressions(&query_specification.group_by) {
+        match ordinal(expression) {
+            Some(ordinal) => checker.add_ordinal(ordinal, expression, &select_items, select_fields),
+            None => {
+                checker.check_no_calls(
+                    expression,
+                    ERROR_CANNOT_HAVE_AGGREGATIONS,
+                    "GROUP BY clause cannot contain aggregations, window functions or grouping operations",
+                    |_| true,
+                );
+                checker.add_grouped(expression);
+            }
+        }
+    }
+    checker.check_no_calls(
+        &query_specification.having_predicate,
+        ERROR_CANNOT_HAVE_AGGREGATIONS,
+        "HAVING clause cannot contain window functions",
+        |call| matches!(call, Call::Window(_)),
+    );
+
+    // The expressions which are evaluated after grouping, with the prefix
+    // of the message reporting their ungrouped columns.
+    let mut expressions = Vec::new();
+    for select_item in &select_items {
+        if let ParseTree::SelectItem(select_item) = select_item {
+            expressions.push((&*select_item.expression, ""));
+        }
+    }
+    if !query_specification.having_predicate.is_empty() {
+        expressions.push((&*query_specification.having_predicate, ""));
+    }
+    for sort_item in sort_items.map(list_elements).unwrap_or_default() {
+        if let ParseTree::SortItem(sort_item) = sort_item {
+            expressions.push((&*sort_item.expression, "ORDER BY expression "));
+        }
+    }
+
+    for (expression, _) in &expressions {
+        checker.check_nesting(expression);
+    }
+    let is_aggregated = !query_specification.group_by.is_empty()
+        || !query_specification.having_predicate.is_empty()
+        || expressions.iter().any(|(expression, _)| {
+            checker
+                .calls(expression)
+                .iter()
+                .any(|(_, call)| matches!(call, Call::Aggregate(_)))
+        });
+    if is_aggregated {
+        for (expression, prefix) in &expressions {
+            checker.check_grouped(expression, prefix);
+        }
+        for (select_item, fields) in select_items.iter().zip(select_fields) {
+            if let (ParseTree::SelectItem(_), _) | (_, None) = (select_item, fields) {
+                continue;
+            }
+            for field in fields.iter().flatten() {
+                if checker.is_ungrouped_column(&field.target) {
+                    checker.error(
+                        ERROR_MUST_BE_AGGREGATE_OR_GROUP,
+                        select_item.get_range(),
+                        format!(
+                            "Column '{}' selected by {} must be an aggregate expression or appear in GROUP BY clause",
+                            field.name.as_deref().unwrap_or_default(),
+                            expression_text(select_item)
+                        ),
+                    );
+                }
+            }
+        }
+    } else {
+        for (expression, _) in &expressions {
+            checker.check_no_calls(
+                expression,
+                ERROR_INVALID_GROUPING,
+                "A GROUPING() operation can only be used with a corresponding GROUPING SET/CUBE/ROLLUP/GROUP BY clause",
+                |call| matches!(call, Call::Grouping),
+            );
+        }
+    }
+    checker.errors
+}
+
+/// A call which aggregation analysis treats specially.
+#[derive(Clone, Debug)]
+enum Call {
+    /// A call of an aggregate function without OVER.
+    Aggregate(String),
+    /// A call with OVER, or of a window function.
+    Window(String),
+    /// A GROUPING operation.
+    Grouping,
+}
+
+struct Checker<'r> {
+    resolution: &'r Resolution,
+    local_columns: &'r [ColumnTarget],
+    /// The canonical tokens of the GROUP BY expressions.
+    grouped_expressions: Vec<Vec<String>>,
+    /// The columns which are GROUP BY expressions.
+    grouped_columns: Vec<ColumnTarget>,
+    errors: Vec<SyntaxError>,
+}
+
+impl<'r> Checker<'r> {
+    fn error(&mut self, error_code: i32, range: TextRange, message: String) {
+        self.errors.push(SyntaxError::from_message(
+            error_code,
+            Message::new(range, message),
+        ));
+    }
+
+    fn call(&self, tree: &ParseTree) -> Option<Call> {
+        match tree {
+            ParseTree::FunctionCall(function_call) => {
+                let range = tree.get_range();
+                let binding = self
+                    .resolution
+                    .functions
+                    .iter()
+                    .find(|binding| binding.range == range)?;
+                let name = binding.name.clone();
+                match binding.kind {
+                    _ if !function_call.over_opt.is_empty() => Some(Call::Window(name)),
+                    FunctionKind::Aggregate => Some(Call::Aggregate(name)),
+                    FunctionKind::Window => Some(Call::Window(name)),
+                    FunctionKind::Scalar => None,
+                }
+            }
+            ParseTree::Grouping(_) => Some(Call::Grouping),
+            _ => None,
+        }
+    }
+
+    /// The outermost calls in tree, outside of subqueries.
+    fn calls<'t, 'a>(&self, tree: &'t ParseTree<'a>) -> Vec<(&'t ParseTree<'a>, Call)> {
+        let mut result = Vec::new();
+        self.find_calls(tree, &mut result);
+        result
+    }
+
+    fn find_calls<'t, 'a>(
+        &self,
+        tree: &'t ParseTree<'a>,
+        result: &mut Vec<(&'t ParseTree<'a>, Call)>,
+    ) {
+        if tree.is_query() {
+            return;
+        }
+        match self.call(tree) {
+            Some(call) => result.push((tree, call)),
+            None => {
+                for child in tree.children() {
+                    self.find_calls(child, result);
+                }
+            }
+        }
+    }
+
+    /// The outermost calls within the arguments and clauses of a call.
+    fn inner_calls<'t, 'a>(&self, call: &'t ParseTree<'a>) -> Vec<(&'t ParseTree<'a>, Call)> {
+        let mut result = Vec::new();
+        for child in call.children() {
+            self.find_calls(child, &mut result);
+        }
+        result
+    }
+
+    /// Reports the calls in tree which are selected by is_invalid.
+    fn check_no_calls(
+        &mut self,
+        tree: &ParseTree,
+        error_code: i32,
+        message: &str,
+        is_invalid: fn(&Call) -> bool,
+    ) {
+        let calls: Vec<&ParseTree> = self
+            .calls(tree)
+            .into_iter()
+            .filter(|(_, call)| is_invalid(call))
+            .map(|(call, _)| call)
+            .collect();
+        if let Some(first) = calls.first() {
+            self.error(
+                error_code,
+                first.get_range(),
+                format!("{}: {}", message, expression_list(&calls)),
+            );
+        }
+    }
+
+    /// Reports aggregations and window functions nested in aggregations,
+    /// and window functions nested in window functions.
+    fn check_nesting(&mut self, tree: &ParseTree) {
+        for (call_tree, call) in self.calls(tree) {
+            let inner_calls = self.inner_calls(call_tree);
+            let nested = |is_nested: fn(&Call) -> bool| -> Vec<&ParseTree> {
+                inner_calls
+                    .iter()
+                    .filter(|(_, call)| is_nested(call))
+                    .map(|(tree, _)| *tree)
+                    .collect()
+            };
+            match &call {
+                Call::Aggregate(name) => {
+                    let aggregations = nested(|call| matches!(call, Call::Aggregate(_)));
+                    let windows = nested(|call| matches!(call, Call::Window(_)));
+                    if let Some(first) = aggregations.first() {
+                        self.error(
+                            ERROR_NESTED_AGGREGATION,
+                            first.get_range(),
+                            format!(
+                                "Cannot nest aggregations inside aggregation '{}': {}",
+                                name,
+                                expression_list(&aggregations)
+                            ),
+                        );
+                    }
+                    if let Some(first) = windows.first() {
+                        self.error(
+                            ERROR_NESTED_WINDOW,
+                            first.get_range(),
+                            format!(
+                                "Cannot nest window functions inside aggregation '{}': {}",
+                                name,
+                                expression_list(&windows)
+                            ),
+                        );
+                    }
+                }
+                Call::Window(name) => {
+                    let windows = nested(|call| matches!(call, Call::Window(_)));
+                    if let Some(first) = windows.first() {
+                        self.error(
+                            ERROR_NESTED_WINDOW,
+                            first.get_range(),
+                            format!(
+                                "Cannot nest window functions inside window function '{}': {}",
+                                name,
+                                expression_list(&windows)
+                            ),
+                        );
+                    }
+                    // Windows may aggregate the groups of an aggregated query.
+                    for (inner, call) in &inner_calls {
+                        if let Call::Aggregate(_) = call {
+                            self.check_nesting(inner);
+                        }
+                    }
+                }
+                Call::Grouping => (),
+            }
+        }
+    }
+
+    /// Adds a GROUP BY expression.
+    fn add_grouped(&mut self, expression: &ParseTree) {
+        let expression = unparenthesized(expression);
+        if let Some(target) = self.column(expression) {
+            self.grouped_columns.push(target.clone());
+        }
+        self.grouped_expressions.push(canonical_tokens(expression));
+    }
+
+    /// Adds the select item which a GROUP BY ordinal refers to.
+    fn add_ordinal(
+        &mut self,
+        ordinal: usize,
+        expression: &ParseTree,
+        select_items: &[&ParseTree],
+        select_fields: &[Fields],
+    ) {
+        let mut position = 0;
+        for (select_item, fields) in select_items.iter().zip(select_fields) {
+            if ordinal == 0 {
+                break;
+            }
+            match (select_item, fields) {
+                (ParseTree::SelectItem(select_item), _) if position + 1 == ordinal => {
+                    self.check_no_calls(
+                        &select_item.expression,
+                        ERROR_CANNOT_HAVE_AGGREGATIONS,
+                        &format!(
+                            "GROUP BY position {} cannot reference an aggregation, window function or grouping operation",
+                            ordinal
+                        ),
+                        |_| true,
+                    );
+                    self.add_grouped(&select_item.expression);
+                    return;
+                }
+                (ParseTree::SelectItem(_), _) => position += 1,
+                (_, Some(fields)) if ordinal <= position + fields.len() => {
+                    let target = fields[ordinal - position - 1].target.clone();
+                    self.grouped_columns.push(target);
+                    return;
+                }
+                (_, Some(fields)) => position += fields.len(),
+                // The columns of * are unknown.
+                (_, None) => return,
+            }
+        }
+        self.error(
+            ERROR_INVALID_ORDINAL,
+            expression.get_range(),
+            format!("GROUP BY position {} is not in select list", ordinal),
+        );
+    }
+
+    /// The target of a column reference.
+    fn column(&self, tree: &ParseTree) -> Option<&'r ColumnTarget> {
+        match tree {
+            ParseTree::Identifier(_) | ParseTree::Dereference(_) => {
+                self.resolution.column(tree.get_range())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_grouped(&self, tree: &ParseTree) -> bool {
+        let tree = unparenthesized(tree);
+        self.column(tree)
+            .is_some_and(|target| self.grouped_columns.contains(target))
+            || self.grouped_expressions.contains(&canonical_tokens(tree))
+    }
+
+    /// Is target a column of the FROM clause which is not grouped.
+    /// Columns of outer queries are constant within the query.
+    fn is_ungrouped_column(&self, target: &ColumnTarget) -> bool {
+        self.local_columns.contains(target) && !self.grouped_columns.contains(target)
+    }
+
+    /// Reports the references to ungrouped columns in an expression of an
+    /// aggregated query.
+    fn check_grouped(&mut self, tree: &ParseTree, prefix: &str) {
+        if tree.is_query() || self.is_grouped(tree) {
+            return;
+        }
+        match self.call(tree) {
+            Some(Call::Aggregate(_)) => return,
+            Some(Call::Grouping) => {
+                if let ParseTree::Grouping(grouping) = tree {
+                    for argument in list_elements(&grouping.groups) {
+                        if !self.is_grouped(argument) {
+                            self.error(
+                                ERROR_INVALID_GROUPING,
+                                argument.get_range(),
+                                "The arguments to GROUPING() must be expressions referenced by the GROUP BY at the associated query level".to_string(),
+                            );
+                        }
+                    }
+                }
+                return;
+            }
+            Some(Call::Window(_)) | None => (),
+        }
+        match self.column(tree) {
+            Some(target) => {
+                if self.is_ungrouped_column(target) {
+                    self.error(
+                        ERROR_MUST_BE_AGGREGATE_OR_GROUP,
+                        tree.get_range(),
+                        format!(
+                            "{}'{}' must be an aggregate expression or appear in GROUP BY clause",
+                            prefix,
+                            expression_text(tree)
+                        ),
+                    );
+                }
+            }
+            None => {
+                for child in tree.children() {
+                    self.check_grouped(child, prefix);
+                }
+            }
+        }
+    }
+}
+
+/// The expressions of the grouping elements of a GROUP BY.
+fn grouping_expressions<'t, 'a>(group_by: &'t ParseTree<'a>) -> Vec<&'t ParseTree<'a>> {
+    let grouping_elements = match group_by {
+        ParseTree::GroupBy(group_by) => list_elements(&group_by.grouping_elements),
+        _ => Vec::new(),
+    };
+    let mut result = Vec::new();
+    for grouping_element in grouping_elements {
+        match grouping_element {
+            ParseTree::GroupingSet(grouping_set) => {
+                result.extend(grouping_set_expressions(&grouping_set.elements))
+            }
+            ParseTree::Rollup(rollup) => result.extend(list_elements(&rollup.expressions)),
+            ParseTree::Cube(cube) => result.extend(list_elements(&cube.expressions)),
+            ParseTree::GroupingSets(grouping_sets) => {
+                for grouping_set in list_elements(&grouping_sets.grouping_sets) {
+                    match grouping_set {
+                        ParseTree::GroupingSet(grouping_set) => {
+                            result.extend(grouping_set_expressions(&grouping_set.elements))
+                        }
+                        grouping_set => result.push(grouping_set),
+                    }
+                }
+            }
+            grouping_element => result.push(grouping_element),
+        }
+    }
+    result
+}
+
+/// The expressions of a grouping set: () has none, and (a, b) parses as a
+/// row constructor.
+fn grouping_set_expressions<'t, 'a>(elements: &'t ParseTree<'a>) -> Vec<&'t ParseTree<'a>> {
+    match elements {
+        ParseTree::EmptyGroupingSet(_) => Vec::new(),
+        ParseTree::RowConstructor(row_constructor) => list_elements(&row_constructor.elements),
+        elements => vec![elements],
+    }
+}
+
+/// The value of an integer literal GROUP BY item, which refers to a select
+/// item by its position.
+fn ordinal(tree: &ParseTree) -> Option<usize> {
+    match tree {
+        ParseTree::Literal(literal) => match &*literal.value {
+            ParseTree::Token(token) if token.token.kind == TokenKind::Integer => {
+                Some(token.token.value.parse().unwrap_or(usize::MAX))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn unparenthesized<'t, 'a>(tree: &'t ParseTree<'a>) -> &'t ParseTree<'a> {
+    match tree {
+        ParseTree::ParenthesizedExpression(parenthesized) => unparenthesized(&parenthesized.value),
+        tree => tree,
+    }
+}
+
+/// The tokens of a tree, with identifiers in canonical form and keywords
+/// in lower case, so that equal expressions have equal tokens.
+fn canonical_tokens(tree: &ParseTree) -> Vec<String> {
+    let mut result = Vec::new();
+    visit_pre_order(tree, &mut |tree| {
+        if let ParseTree::Token(token) = tree {
+            let token = &token.token;
+            result.push(match token.kind {
+                TokenKind::Identifier
+                | TokenKind::QuotedIdentifier
+                | TokenKind::BackquotedIdentifier => identifier_name(token),
+                kind if kind.is_keyword() => token.value.to_lowercase(),
+                _ => token.value.to_string(),
+            });
+        }
+    });
+    result
+}
+
+/// The text of an expression, on one line.
+fn expression_text(tree: &ParseTree) -> String {
+    format_tree(tree)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn expression_list(trees: &[&ParseTree]) -> String {
+    let texts: Vec<String> = trees.iter().map(|tree| expression_text(tree)).collect();
+    format!("[{}]", texts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{ddl, resolve, MemoryCatalog, Session};
+    use crate::parsing::parse_statement;
+
+    fn error_codes(text: &str) -> Vec<i32> {
+        let session = Session::new("hive", "web");
+        let mut catalog = MemoryCatalog::new();
+        ddl::load_script(
+            &mut catalog,
+            &session,
+            "create table orders (id bigint, status varchar, total double, created date)",
+        );
+        let (tree, errors) = parse_statement(text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        resolve(&tree, &catalog, &session)
+            .errors
+            .iter()
+            .map(|error| error.error_code)
+            .collect()
+    }
+
+    #[test]
+    fn valid_aggregations() {
+        for text in &[
+            "select status, count(*) from orders group by status",
+            "select status, count(*) from orders group by 1",
+            "select upper(status), sum(total) from orders group by upper(status)",
+            "select status from orders group by status having sum(total) > 10",
+            "select count(*) from orders order by count(*)",
+            "select status, rank() over (order by sum(total)) from orders group by status",
+            "select year(created), count(*) from orders group by rollup (year(created))",
+            "select grouping(status), count(*) from orders group by cube (status)",
+            "select * from orders group by id, status, total, created",
+        ] {
+            assert!(error_codes(text).is_empty(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn must_be_aggregate_or_group() {
+        assert_eq!(
+            error_codes("select status from orders group by id"),
+            vec![ERROR_MUST_BE_AGGREGATE_OR_GROUP]
+        );
+        assert_eq!(
+            error_codes("select status, count(*) from orders"),
+            vec![ERROR_MUST_BE_AGGREGATE_OR_GROUP]
+        );
+        assert_eq!(
+            error_codes("select count(*) from orders group by status having total > 1"),
+            vec![ERROR_MUST_BE_AGGREGATE_OR_GROUP]
+        );
+        assert_eq!(
+            error_codes("select status from orders group by status order by total"),
+            vec![ERROR_MUST_BE_AGGREGATE_OR_GROUP]
+        );
+    }
+
+    #[test]
+    fn nested_aggregation() {
+        assert_eq!(
+            error_codes("select sum(count(id)) from orders"),
+            vec![ERROR_NESTED_AGGREGATION]
+        );
+    }
+
+    #[test]
+    fn nested_window() {
+        assert_eq!(
+            error_codes("select sum(rank() over ()) from orders"),
+            vec![ERROR_NESTED_WINDOW]
+        );
+    }
+
+    #[test]
+    fn cannot_have_aggregations() {
+        assert_eq!(
+            error_codes("select id from orders where sum(total) > 1"),
+            vec![ERROR_CANNOT_HAVE_AGGREGATIONS]
+        );
+        assert_eq!(
+            error_codes("select count(*) from orders group by sum(total)"),
+            vec![ERROR_CANNOT_HAVE_AGGREGATIONS]
+        );
+        assert_eq!(
+            error_codes("select sum(total) from orders group by 1"),
+            vec![ERROR_CANNOT_HAVE_AGGREGATIONS]
+        );
+        assert_eq!(
+            error_codes("select status from orders group by status having rank() over () > 1"),
+            vec![ERROR_CANNOT_HAVE_AGGREGATIONS]
+        );
+    }
+
+    #[test]
+    fn invalid_ordinal() {
+        assert_eq!(
+            error_codes("select count(*) from orders group by 2"),
+            vec![ERROR_INVALID_ORDINAL]
+        );
+        assert_eq!(
+            error_codes("select count(*) from orders group by 0"),
+            vec![ERROR_INVALID_ORDINAL]
+        );
+    }
+
+    #[test]
+    fn invalid_grouping() {
+        assert_eq!(
+            error_codes("select grouping(id) from orders"),
+            vec![ERROR_INVALID_GROUPING]
+        );
+        assert_eq!(
+            error_codes("select grouping(id) from orders group by status"),
+            vec![ERROR_INVALID_GROUPING]
+        );
+    }
+}
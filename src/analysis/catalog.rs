@@ -0,0 +1,335 @@
+//! The schema model: catalogs contain schemas, which contain tables,
+//! which contain typed columns.
+//!
+//! All names are stored in their canonical, lower case form.
+use super::functions::FunctionRegistry;
+use super::types::{parse_data_type, DataType};
+use crate::utils::Json;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The fully qualified name of a table: catalog.schema.table.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QualifiedTableName {
+    pub catalog: String,
+    pub schema: String,
+    pub table: String,
+}
+
+impl QualifiedTableName {
+    pub fn new(catalog: &str, schema: &str, table: &str) -> QualifiedTableName {
+        QualifiedTableName {
+            catalog: catalog.to_string(),
+            schema: schema.to_string(),
+            table: table.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for QualifiedTableName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.catalog, self.schema, self.table)
+    }
+}
+
+/// A column of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Column {
+    pub name: String,
+    pub data_type: DataType,
+    pub not_null: bool,
+    pub comment: Option<String>,
+}
+
+impl Column {
+    pub fn new(name: &str, data_type: DataType) -> Column {
+        Column {
+            name: name.to_string(),
+            data_type,
+            not_null: false,
+            comment: None,
+        }
+    }
+}
+
+/// A table or view.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Table {
+    pub name: QualifiedTableName,
+    pub columns: Vec<Column>,
+    pub comment: Option<String>,
+    /// The WITH properties of the table; values are the source text of the
+    /// property expressions.
+    pub properties: Vec<(String, String)>,
+    /// The source text of the query of a view; None for tables.
+    pub view_query: Option<String>,
+}
+
+impl Table {
+    pub fn new(name: QualifiedTableName, columns: Vec<Column>) -> Table {
+        Table {
+            name,
+            columns,
+            comment: None,
+            properties: Vec::new(),
+            view_query: None,
+        }
+    }
+
+    pub fn is_view(&self) -> bool {
+        self.view_query.is_some()
+    }
+
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+/// A source of table definitions.
+pub trait Catalog {
+    fn catalog_names(&self) -> Vec<String>;
+
+    fn schema_names(&self, catalog: &str) -> Vec<String>;
+
+    fn table_names(&self, catalog: &str, schema: &str) -> Vec<String>;
+
+    fn table(&self, name: &QualifiedTableName) -> Option<&Table>;
+
+    /// The functions which queries may call.
+    fn functions(&self) -> &FunctionRegistry;
+}
+
+/// The catalog and schema which qualify partially qualified table names.
+#[derive(Clone, Debug)]
+pub struct Session {
+    pub catalog: String,
+    pub schema: String,
+}
+
+impl Session {
+    pub fn new(catalog: &str, schema: &str) -> Session {
+        Session {
+            catalog: catalog.to_string(),
+            schema: schema.to_string(),
+        }
+    }
+
+    /// Qualifies a table name with 1 to 3 parts.
+    /// Returns None for names with more than 3 parts.
+    pub fn qualify(&self, parts: &[String]) -> Option<QualifiedTableName> {
+        match parts {
+            [table] => Some(QualifiedTableName::new(&self.catalog, &self.schema, table)),
+            [schema, table] => Some(QualifiedTableName::new(&self.catalog, schema, table)),
+            [catalog, schema, table] => Some(QualifiedTableName::new(catalog, schema, table)),
+            _ => None,
+        }
+    }
+}
+
+/// A Catalog held in memory, with the built in functions.
+#[derive(Clone, Debug)]
+pub struct MemoryCatalog {
+    catalogs: BTreeMap<String, BTreeMap<String, BTreeMap<String, Table>>>,
+    functions: FunctionRegistry,
+}
+
+impl Default for MemoryCatalog {
+    fn default() -> MemoryCatalog {
+        MemoryCatalog {
+            catalogs: BTreeMap::new(),
+            functions: FunctionRegistry::built_in(),
+        }
+    }
+}
+
+impl MemoryCatalog {
+    pub fn new() -> MemoryCatalog {
+        MemoryCatalog::default()
+    }
+
+    /// The registry to add custom functions to.
+    pub fn functions_mut(&mut self) -> &mut FunctionRegistry {
+        &mut self.functions
+    }
+
+    /// Adds a table, replacing any existing table with the same name.
+    pub fn add_table(&mut self, table: Table) {
+        self.catalogs
+            .entry(table.name.catalog.clone())
+            .or_default()
+            .entry(table.name.schema.clone())
+            .or_default()
+            .insert(table.name.table.clone(), table);
+    }
+
+    /// Adds an empty schema, so that it is listed by schema_names().
+    pub fn add_schema(&mut self, catalog: &str, schema: &str) {
+        self.catalogs
+            .entry(catalog.to_string())
+            .or_default()
+            .entry(schema.to_string())
+            .or_default();
+    }
+
+    /// Adds the schemas and tables of other, replacing existing tables.
+    pub fn add_catalog(&mut self, other: MemoryCatalog) {
+        for (catalog, schemas) in other.catalogs {
+            for (schema, tables) in schemas {
+                self.add_schema(&catalog, &schema);
+                for table in tables.into_values() {
+                    self.add_table(table);
+                }
+            }
+        }
+    }
+
+    /// Loads a catalog from JSON of the form:
+    ///
+    ///   {"catalog": {"schema": {"table": [{"name": "id", "type": "bigint"}, ...]}}}
+    ///
+    /// Names are converted to lower case. Types are parsed, and invalid
+    /// types are errors naming their column.
+    pub fn from_json(text: &str) -> Result<MemoryCatalog, String> {
+        let json = Json::parse(text)?;
+        let mut result = MemoryCatalog::new();
+        for (catalog, schemas) in members(&json, "catalogs")? {
+            for (schema, tables) in members(schemas, catalog)? {
+                result.add_schema(&catalog.to_lowercase(), &schema.to_lowercase());
+                for (table, columns) in members(tables, schema)? {
+                    let name = QualifiedTableName::new(
+                        &catalog.to_lowercase(),
+                        &schema.to_lowercase(),
+                        &table.to_lowercase(),
+                    );
+                    let columns = columns
+                        .as_array()
+                        .ok_or_else(|| format!("Expected an array of columns for {}", name))?
+                        .iter()
+                        .map(|column| {
+                            match (
+                                column.get("name").and_then(Json::as_str),
+                                column.get("type").and_then(Json::as_str),
+                            ) {
+                                (Some(column_name), Some(data_type)) => {
+                                    let column_name = column_name.to_lowercase();
+                                    let data_type =
+                                        parse_data_type(data_type).map_err(|errors| {
+                                            format!(
+                                                "Invalid type '{}' for column {} of {}: {}",
+                                                data_type,
+                                                column_name,
+                                                name,
+                                                errors[0].messages[0].message
+                                            )
+                                        })?;
+                                    Ok(Column::new(&column_name, data_type))
+                                }
+                                _ => Err(format!(
+                                    "Expected a column with a name and type in {}",
+                                    name
+                                )),
+                            }
+                        })
+                        .collect::<Result<Vec<_>, String>>()?;
+                    result.add_table(Table::new(name, columns));
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn members<'j>(json: &'j Json, context: &str) -> Result<&'j Vec<(String, Json)>, String> {
+    json.as_object()
+        .ok_or_else(|| format!("Expected an object for {}", context))
+}
+
+impl Catalog for MemoryCatalog {
+    fn catalog_names(&self) -> Vec<String> {
+        self.catalogs.keys().cloned().collect()
+    }
+
+    fn schema_names(&self, catalog: &str) -> Vec<String> {
+        self.catalogs
+            .get(catalog)
+            .map(|schemas| schemas.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn table_names(&self, catalog: &str, schema: &str) -> Vec<String> {
+        self.catalogs
+            .get(catalog)
+            .and_then(|schemas| schemas.get(schema))
+            .map(|tables| tables.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    fn table(&self, name: &QualifiedTableName) -> Option<&Table> {
+        self.catalogs
+            .get(&name.catalog)
+            .and_then(|schemas| schemas.get(&name.schema))
+            .and_then(|tables| tables.get(&name.table))
+    }
+
+    fn functions(&self) -> &FunctionRegistry {
+        &self.functions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_json_parses_column_types() {
+        let catalog = MemoryCatalog::from_json(
+            r#"{"Hive": {"web": {"Users": [{"name": "ID", "type": "BIGINT"},
+                {"name": "tags", "type": "array(varchar(10))"}]}}}"#,
+        )
+        .unwrap();
+        let table = catalog
+            .table(&QualifiedTableName::new("hive", "web", "users"))
+            .unwrap();
+        assert_eq!(table.column("id").unwrap().data_type, DataType::BigInt);
+        assert_eq!(
+            table.column("tags").unwrap().data_type,
+            DataType::Array(Box::new(DataType::Varchar(Some(10))))
+        );
+    }
+
+    #[test]
+    fn from_json_rejects_invalid_types() {
+        let error = MemoryCatalog::from_json(
+            r#"{"hive": {"web": {"orders": [{"name": "id", "type": "bigintt"}]}}}"#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Invalid type 'bigintt' for column id of hive.web.orders: Unknown type: bigintt"
+        );
+        let error = MemoryCatalog::from_json(
+            r#"{"hive": {"web": {"orders": [{"name": "total", "type": "decimal(40, 2)"}]}}}"#,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("column total of hive.web.orders"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn from_json_accepts_other_presto_types() {
+        let catalog = MemoryCatalog::from_json(
+            r#"{"hive": {"web": {"t": [{"name": "h", "type": "hyperloglog"}]}}}"#,
+        )
+        .unwrap();
+        let table = catalog
+            .table(&QualifiedTableName::new("hive", "web", "t"))
+            .unwrap();
+        assert_eq!(
+            table.column("h").unwrap().data_type,
+            DataType::Other("hyperloglog".to_string())
+        );
+    }
+}
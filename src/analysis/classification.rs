@@ -0,0 +1,237 @@
+//! Statement classification, for routing and authorizing statements by
+//! kind.
+//!
+//! Only queries, CREATE TABLE, CREATE TABLE AS SELECT, CREATE VIEW, CREATE
+//! ROLE, INSERT and DELETE are parsed yet. classify_text classifies other
+//! statements, such as SHOW, SET SESSION or EXPLAIN, by their leading words.
+//! EXPLAIN ANALYZE runs its statement, so it is classified as that
+//! statement, while EXPLAIN is Explain.
+use crate::lexing::{Lexer, TokenKind};
+use crate::parsing::{parse_statement, ParseTree};
+use crate::utils::SourceText;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatementClass {
+    /// SELECT, VALUES and TABLE queries, including WITH.
+    Query,
+    /// INSERT and DELETE.
+    Dml,
+    /// CREATE, DROP and ALTER of schemas, tables and views, including
+    /// CREATE TABLE AS SELECT.
+    Ddl,
+    /// GRANT, REVOKE and role statements.
+    AccessControl,
+    /// SET SESSION, RESET SESSION, USE and transaction statements.
+    SessionControl,
+    /// SHOW and DESCRIBE.
+    Metadata,
+    /// EXPLAIN without ANALYZE, which plans its statement without running it.
+    Explain,
+    /// Statements which are neither parsed nor recognized by their leading
+    /// words, such as queries with syntax errors.
+    Unknown,
+}
+
+impl StatementClass {
+    /// Can running the statement modify tables or the catalog? Unknown
+    /// statements may.
+    pub fn can_modify_data(self) -> bool {
+        match self {
+            StatementClass::Dml
+            | StatementClass::Ddl
+            | StatementClass::AccessControl
+            | StatementClass::Unknown => true,
+            StatementClass::Query
+            | StatementClass::SessionControl
+            | StatementClass::Metadata
+            | StatementClass::Explain => false,
+        }
+    }
+}
+
+impl fmt::Display for StatementClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatementClass::Query => write!(f, "query"),
+            StatementClass::Dml => write!(f, "dml"),
+            StatementClass::Ddl => write!(f, "ddl"),
+            StatementClass::AccessControl => write!(f, "access control"),
+            StatementClass::SessionControl => write!(f, "session control"),
+            StatementClass::Metadata => write!(f, "metadata"),
+            StatementClass::Explain => write!(f, "explain"),
+            StatementClass::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// The class of a statement; tree may be an Entrypoint or a statement.
+pub fn classify(tree: &ParseTree) -> StatementClass {
+    match tree {
+        ParseTree::Entrypoint(entrypoint) => classify(&entrypoint.tree),
+        ParseTree::Query(_) => StatementClass::Query,
+        ParseTree::InsertInto(_) | ParseTree::Delete(_) => StatementClass::Dml,
+        ParseTree::CreateTable(_)
+        | ParseTree::CreateTableAsSelect(_)
+        | ParseTree::CreateView(_) => StatementClass::Ddl,
+        ParseTree::CreateRole(_) => StatementClass::AccessControl,
+        _ => StatementClass::Unknown,
+    }
+}
+
+/// The class of the statement in text. Statements which fail to parse are
+/// classified by their leading words.
+pub fn classify_text(text: &str) -> StatementClass {
+    let (tree, _) = parse_statement(text);
+    match classify(&tree) {
+        StatementClass::Unknown => classify_words(text),
+        class => class,
+    }
+}
+
+fn classify_words(text: &str) -> StatementClass {
+    let tokens: Vec<_> = Lexer::new(text)
+        .take_while(|token| token.kind != TokenKind::EndOfFile)
+        .take(3)
+        .collect();
+    let words: Vec<String> = tokens
+        .iter()
+        .map(|token| token.value.to_lowercase())
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    // The text of the statement after the first count words.
+    let rest = |count: usize| {
+        let source = SourceText::new(text);
+        &text[source.offset(tokens[count - 1].range.end)..]
+    };
+    match words.as_slice() {
+        ["explain", "analyze", "verbose"] => classify_text(rest(3)),
+        ["explain", "analyze", ..] => classify_text(rest(2)),
+        ["explain", ..] => StatementClass::Explain,
+        ["show", ..] | ["describe", ..] | ["desc", ..] => StatementClass::Metadata,
+        ["grant", ..]
+        | ["revoke", ..]
+        | ["create", "role", ..]
+        | ["drop", "role", ..]
+        | ["set", "role", ..] => StatementClass::AccessControl,
+        ["set", ..]
+        | ["reset", ..]
+        | ["use", ..]
+        | ["start", "transaction", ..]
+        | ["commit", ..]
+        | ["rollback", ..]
+        | ["prepare", ..]
+        | ["deallocate", ..] => StatementClass::SessionControl,
+        ["create", ..] | ["drop", ..] | ["alter", ..] | ["comment", ..] => StatementClass::Ddl,
+        ["update", ..] | ["merge", ..] | ["truncate", ..] => StatementClass::Dml,
+        _ => StatementClass::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query() {
+        assert_eq!(classify_text("select 1"), StatementClass::Query);
+        assert_eq!(
+            classify_text("with t as (select 1) select * from t"),
+            StatementClass::Query
+        );
+        assert_eq!(
+            classify_text("explain analyze select 1"),
+            StatementClass::Query
+        );
+    }
+
+    #[test]
+    fn dml() {
+        assert_eq!(classify_text("insert into t select 1"), StatementClass::Dml);
+        assert_eq!(classify_text("delete from t"), StatementClass::Dml);
+        assert_eq!(classify_text("update t set a = 1"), StatementClass::Dml);
+        assert_eq!(
+            classify_text("EXPLAIN ANALYZE VERBOSE delete from t"),
+            StatementClass::Dml
+        );
+    }
+
+    #[test]
+    fn ddl() {
+        assert_eq!(
+            classify_text("create table t (a bigint)"),
+            StatementClass::Ddl
+        );
+        assert_eq!(classify_text("drop table t"), StatementClass::Ddl);
+        assert_eq!(
+            classify_text("alter table t rename to u"),
+            StatementClass::Ddl
+        );
+    }
+
+    #[test]
+    fn access_control() {
+        assert_eq!(
+            classify_text("create role admin"),
+            StatementClass::AccessControl
+        );
+        assert_eq!(
+            classify_text("grant select on t to alice"),
+            StatementClass::AccessControl
+        );
+        assert_eq!(
+            classify_text("set role admin"),
+            StatementClass::AccessControl
+        );
+    }
+
+    #[test]
+    fn session_control() {
+        assert_eq!(
+            classify_text("set session query_max_run_time = '1h'"),
+            StatementClass::SessionControl
+        );
+        assert_eq!(
+            classify_text("reset session query_max_run_time"),
+            StatementClass::SessionControl
+        );
+        assert_eq!(
+            classify_text("use hive.web"),
+            StatementClass::SessionControl
+        );
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!(classify_text("show tables"), StatementClass::Metadata);
+        assert_eq!(
+            classify_text("/* comment */ SHOW CATALOGS"),
+            StatementClass::Metadata
+        );
+        assert_eq!(classify_text("describe t"), StatementClass::Metadata);
+    }
+
+    #[test]
+    fn explain() {
+        assert_eq!(classify_text("explain select 1"), StatementClass::Explain);
+        assert_eq!(
+            classify_text("explain (type distributed) delete from t"),
+            StatementClass::Explain
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(classify_text("frobnicate t"), StatementClass::Unknown);
+        assert_eq!(classify_text(""), StatementClass::Unknown);
+        assert_eq!(classify_text("explain analyze"), StatementClass::Unknown);
+    }
+
+    #[test]
+    fn modifying_classes() {
+        assert!(StatementClass::Dml.can_modify_data());
+        assert!(StatementClass::Unknown.can_modify_data());
+        assert!(!StatementClass::Explain.can_modify_data());
+        assert!(!StatementClass::Metadata.can_modify_data());
+    }
+}
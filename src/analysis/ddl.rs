@@ -0,0 +1,481 @@
+//! Building a MemoryCatalog from DDL scripts.
+//!
+//! A script is a sequence of statements separated by semicolons. CREATE
+//! TABLE, CREATE TABLE AS SELECT and CREATE VIEW statements add tables to
+//! the catalog, in order, so that later statements may refer to the
+//! tables created by earlier statements. Other statements are checked for
+//! syntax errors and otherwise ignored.
+use super::catalog::{Catalog, Column, MemoryCatalog, QualifiedTableName, Session, Table};
+use super::resolver;
+use super::types::DataType;
+use super::{list_elements, qualified_name_parts, string_value, tree_name};
+use crate::lexing::{Lexer, TokenKind};
+use crate::parsing::{parse_statement, ParseTree};
+use crate::utils::syntax_error::{
+    ERROR_DUPLICATE_COLUMN, ERROR_INVALID_TABLE_NAME, ERROR_MULTIPLE_INCLUDING_PROPERTIES,
+    ERROR_TABLE_EXISTS, ERROR_UNKNOWN_TABLE, ERROR_UNNAMED_COLUMN,
+};
+use crate::utils::{Message, Position, SourceText, SyntaxError, TextRange};
+
+/// Splits a script into statements at semicolons.
+///
+/// Uses the lexer, so that semicolons within strings, comments and quoted
+/// identifiers do not split statements. Returns the start of each statement
+/// along with its text; statements containing only whitespace and comments
+/// are dropped.
+pub fn split_statements(script: &str) -> Vec<(Position, &str)> {
+    let source = SourceText::new(script);
+    let mut result = Vec::new();
+    let mut start = Position::new(0, 0);
+    let mut is_empty = true;
+    for token in Lexer::new(script) {
+        let is_end = token.kind == TokenKind::EndOfFile;
+        if is_end || (token.kind == TokenKind::Error && token.value == ";") {
+            if !is_empty {
+                let range = TextRange::new(start, token.range.start);
+                result.push((start, source.slice(range)));
+            }
+            start = token.range.end;
+            is_empty = true;
+        } else {
+            is_empty = false;
+        }
+    }
+    result
+}
+
+/// Runs the DDL statements of a script, adding their tables and views to
+/// catalog. Returns the syntax and semantic errors of the script.
+pub fn load_script(
+    catalog: &mut MemoryCatalog,
+    session: &Session,
+    script: &str,
+) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    for (start, text) in split_statements(script) {
+        let (tree, syntax_errors) = parse_statement(text);
+        let statement_errors = if syntax_errors.is_empty() {
+            load_statement(catalog, session, &tree, text)
+        } else {
+            syntax_errors
+        };
+        errors.extend(
+            statement_errors
+                .into_iter()
+                .map(|error| offset_error(error, start)),
+        );
+    }
+    errors
+}
+
+/// Runs one DDL statement; text is the source text of the statement.
+/// Returns the errors of the statement; nothing is added if the statement
+/// has errors.
+pub fn load_statement(
+    catalog: &mut MemoryCatalog,
+    session: &Session,
+    tree: &ParseTree,
+    text: &str,
+) -> Vec<SyntaxError> {
+    let mut loader = Loader {
+        catalog: &*catalog,
+        session,
+        source: SourceText::new(text),
+        errors: Vec::new(),
+    };
+    let table = loader.statement_table(tree);
+    let mut errors = loader.errors;
+    if let Some(table) = table {
+        if errors.is_empty() {
+            catalog.add_table(table);
+        }
+    }
+    errors.sort_by_key(|error| {
+        let start = error.get_range().start;
+        (start.line, start.column)
+    });
+    errors
+}
+
+/// Moves an error in a statement starting at start to its position in the
+/// script.
+fn offset_error(mut error: SyntaxError, start: Position) -> SyntaxError {
+    for message in &mut error.messages {
+        message.range = TextRange::new(
+            offset_position(message.range.start, start),
+            offset_position(message.range.end, start),
+        );
+    }
+    error
+}
+
+fn offset_position(position: Position, start: Position) -> Position {
+    if position.line == 0 {
+        Position::new(start.line, start.column + position.column)
+    } else {
+        Position::new(start.line + position.line, position.column)
+    }
+}
+
+struct Loader<'c, 't> {
+    catalog: &'c MemoryCatalog,
+    session: &'c Session,
+    source: SourceText<'t>,
+    errors: Vec<SyntaxError>,
+}
+
+impl<'c, 't> Loader<'c, 't> {
+    fn error(&mut self, error_code: i32, range: TextRange, message: String) {
+        self.errors.push(SyntaxError::from_message(
+            error_code,
+            Message::new(range, message),
+        ));
+    }
+
+    /// The table created by a statement; None for other statements, and
+    /// for CREATE TABLE IF NOT EXISTS of an existing table.
+    fn statement_table(&mut self, tree: &ParseTree) -> Option<Table> {
+        match tree {
+            ParseTree::Entrypoint(entrypoint) => self.statement_table(&entrypoint.tree),
+            ParseTree::CreateTable(create_table) => {
+                let name = self.new_table_name(
+                    &create_table.table_name,
+                    !create_table.if_not_exists_opt.is_empty(),
+                    false,
+                )?;
+                let mut table = Table::new(name, Vec::new());
+                let mut including_properties = false;
+                for element in list_elements(&create_table.table_elements) {
+                    match element {
+                        ParseTree::ColumnDefinition(column_definition) => {
+                            let name = tree_name(&column_definition.identifier)?;
+                            let data_type = DataType::try_from_tree(&column_definition.type_)
+                                .unwrap_or_else(|errors| {
+                                    self.errors.extend(errors.into_iter().map(|mut error| {
+                                        for message in &mut error.messages {
+                                            message.message = format!(
+                                                "Invalid type for column {} of {}: {}",
+                                                name, table.name, message.message
+                                            );
+                                        }
+                                        error
+                                    }));
+                                    DataType::from_tree(&column_definition.type_)
+                                });
+                            let mut column = Column::new(&name, data_type);
+                            column.not_null = !column_definition.not_null_opt.is_empty();
+                            column.comment = self.comment(&column_definition.comment_opt);
+                            table.columns.push(column);
+                            self.check_duplicate_column(
+                                &table.columns,
+                                &column_definition.identifier,
+                            );
+                        }
+                        ParseTree::LikeClause(like_clause) => {
+                            let like_table = self.existing_table(&like_clause.name)?;
+                            for column in &like_table.columns {
+                                table.columns.push(column.clone());
+                                self.check_duplicate_column(&table.columns, &like_clause.name);
+                            }
+                            let option_type = tree_name(&like_clause.option_type_opt);
+                            if option_type.as_deref() == Some("including") {
+                                if including_properties {
+                                    self.error(
+                                        ERROR_MULTIPLE_INCLUDING_PROPERTIES,
+                                        like_clause.option_type_opt.get_range(),
+                                        "Only one LIKE clause can specify INCLUDING PROPERTIES."
+                                            .to_string(),
+                                    );
+                                }
+                                including_properties = true;
+                                table.properties = like_table.properties.clone();
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+                table.comment = self.comment(&create_table.comment_opt);
+                self.add_properties(&mut table, &create_table.with_properties_opt);
+                Some(table)
+            }
+            ParseTree::CreateTableAsSelect(create_table_as_select) => {
+                let name = self.new_table_name(
+                    &create_table_as_select.table_name,
+                    !create_table_as_select.if_not_exists_opt.is_empty(),
+                    false,
+                )?;
+                let columns = self.query_columns(tree, &create_table_as_select.table_name)?;
+                let mut table = Table::new(name, columns);
+                table.comment = self.comment(&create_table_as_select.comment_opt);
+                self.add_properties(&mut table, &create_table_as_select.with_properties_opt);
+                Some(table)
+            }
+            ParseTree::CreateView(create_view) => {
+                let name = self.new_table_name(
+                    &create_view.qualified_name,
+                    false,
+                    !create_view.replace_opt.is_empty(),
+                )?;
+                let columns = self.query_columns(tree, &create_view.qualified_name)?;
+                let mut table = Table::new(name, columns);
+                table.view_query =
+                    Some(self.source.slice(create_view.query.get_range()).to_string());
+                Some(table)
+            }
+            _ => None,
+        }
+    }
+
+    /// The name of a table to create. Reports an error if the table exists,
+    /// unless if_not_exists, in which case None is returned, or replace.
+    fn new_table_name(
+        &mut self,
+        name: &ParseTree,
+        if_not_exists: bool,
+        replace: bool,
+    ) -> Option<QualifiedTableName> {
+        let parts = qualified_name_parts(name);
+        let table_name = match self.session.qualify(&parts) {
+            Some(table_name) => table_name,
+            None => {
+                self.error(
+                    ERROR_INVALID_TABLE_NAME,
+                    name.get_range(),
+                    format!("Too many parts in table name '{}'.", parts.join(".")),
+                );
+                return None;
+            }
+        };
+        if self.catalog.table(&table_name).is_some() && !replace {
+            if !if_not_exists {
+                self.error(
+                    ERROR_TABLE_EXISTS,
+                    name.get_range(),
+                    format!("Table '{}' already exists.", table_name),
+                );
+            }
+            return None;
+        }
+        Some(table_name)
+    }
+
+    fn existing_table(&mut self, name: &ParseTree) -> Option<Table> {
+        let table_name = self.session.qualify(&qualified_name_parts(name))?;
+        match self.catalog.table(&table_name) {
+            Some(table) => Some(table.clone()),
+            None => {
+                self.error(
+                    ERROR_UNKNOWN_TABLE,
+                    name.get_range(),
+                    format!("Table '{}' does not exist.", table_name),
+                );
+                None
+            }
+        }
+    }
+
+    /// The output columns of the query of a CREATE TABLE AS SELECT or
+    /// CREATE VIEW statement, with the types inferred for the query.
+    fn query_columns(&mut self, statement: &ParseTree, name: &ParseTree) -> Option<Vec<Column>> {
+        let resolution = resolver::resolve(statement, self.catalog, self.session);
+        self.errors.extend(resolution.errors);
+        let mut columns: Vec<Column> = Vec::new();
+        for (index, field) in resolution.output?.into_iter().enumerate() {
+            match field.name.clone() {
+                Some(column_name) => {
+                    if columns.iter().any(|column| column.name == column_name) {
+                        self.error(
+                            ERROR_DUPLICATE_COLUMN,
+                            name.get_range(),
+                            format!("Column '{}' is specified more than once.", column_name),
+                        );
+                    }
+                    columns.push(Column::new(&column_name, field.data_type.clone()));
+                }
+                None => self.error(
+                    ERROR_UNNAMED_COLUMN,
+                    name.get_range(),
+                    format!("Column {} of the query has no name.", index + 1),
+                ),
+            }
+        }
+        Some(columns)
+    }
+
+    fn check_duplicate_column(&mut self, columns: &[Column], tree: &ParseTree) {
+        if let Some((last, previous)) = columns.split_last() {
+            if previous.iter().any(|column| column.name == last.name) {
+                self.error(
+                    ERROR_DUPLICATE_COLUMN,
+                    tree.get_range(),
+                    format!("Column '{}' is specified more than once.", last.name),
+                );
+            }
+        }
+    }
+
+    fn comment(&self, comment_opt: &ParseTree) -> Option<String> {
+        match comment_opt {
+            ParseTree::Comment(comment) => string_value(&comment.value),
+            _ => None,
+        }
+    }
+
+    /// Adds WITH properties to table, replacing properties of the same name.
+    fn add_properties(&self, table: &mut Table, with_properties_opt: &ParseTree) {
+        if let ParseTree::WithProperties(with_properties) = with_properties_opt {
+            for property in list_elements(&with_properties.properties) {
+                if let ParseTree::Property(property) = property {
+                    let name = match tree_name(&property.identifier) {
+                        Some(name) => name,
+                        None => continue,
+                    };
+                    let value = self.source.slice(property.value.get_range()).to_string();
+                    table.properties.retain(|(other, _)| *other != name);
+                    table.properties.push((name, value));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::syntax_error::ERROR_INVALID_TYPE;
+
+    const ORDERS: &str = "create table orders (id bigint, total decimal(10, 2))";
+
+    fn load(script: &str) -> (MemoryCatalog, Vec<i32>) {
+        let mut catalog = MemoryCatalog::new();
+        let errors = load_script(&mut catalog, &Session::new("hive", "web"), script);
+        let codes = errors.iter().map(|error| error.error_code).collect();
+        (catalog, codes)
+    }
+
+    fn error_codes(script: &str) -> Vec<i32> {
+        load(&format!("{};\n{}", ORDERS, script)).1
+    }
+
+    fn table(catalog: &MemoryCatalog, name: &str) -> Table {
+        catalog
+            .table(&QualifiedTableName::new("hive", "web", name))
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn splits_statements_at_semicolons() {
+        let statements: Vec<&str> = split_statements("select ';'; -- ;\n select \";\";;")
+            .into_iter()
+            .map(|(_, text)| text.trim())
+            .collect();
+        assert_eq!(statements, vec!["select ';'", "-- ;\n select \";\""]);
+        let (start, _) = split_statements("select 1;\nselect 2")[1];
+        assert_eq!(start, Position::new(0, 9));
+    }
+
+    #[test]
+    fn loads_tables_and_views() {
+        let (catalog, codes) = load(&format!(
+            "{};
+            create table copy (like orders, note varchar) comment 'copied';
+            create table totals as select id, total * 2 as doubled from orders;
+            create view big as select id from orders where total > 100",
+            ORDERS
+        ));
+        assert!(codes.is_empty(), "{:?}", codes);
+        let copy = table(&catalog, "copy");
+        let names: Vec<&str> = copy.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["id", "total", "note"]);
+        assert_eq!(copy.comment.as_deref(), Some("copied"));
+        let totals = table(&catalog, "totals");
+        assert_eq!(
+            totals.column("doubled").unwrap().data_type,
+            DataType::Decimal {
+                precision: 20,
+                scale: 2
+            }
+        );
+        let big = table(&catalog, "big");
+        assert!(big.is_view());
+        assert_eq!(
+            big.view_query.as_deref(),
+            Some("select id from orders where total > 100")
+        );
+    }
+
+    #[test]
+    fn unknown_table() {
+        assert_eq!(
+            error_codes("create table t (like missing)"),
+            vec![ERROR_UNKNOWN_TABLE]
+        );
+    }
+
+    #[test]
+    fn invalid_table_name() {
+        assert_eq!(
+            error_codes("create table a.b.c.d (id bigint)"),
+            vec![ERROR_INVALID_TABLE_NAME]
+        );
+    }
+
+    #[test]
+    fn unnamed_column() {
+        assert_eq!(
+            error_codes("create table t as select id + 1 from orders"),
+            vec![ERROR_UNNAMED_COLUMN]
+        );
+    }
+
+    #[test]
+    fn duplicate_column() {
+        assert_eq!(
+            error_codes("create table t (a bigint, A varchar)"),
+            vec![ERROR_DUPLICATE_COLUMN]
+        );
+        assert_eq!(
+            error_codes("create table t (id bigint, like orders)"),
+            vec![ERROR_DUPLICATE_COLUMN]
+        );
+        assert_eq!(
+            error_codes("create table t as select id, total as id from orders"),
+            vec![ERROR_DUPLICATE_COLUMN]
+        );
+    }
+
+    #[test]
+    fn table_exists() {
+        assert_eq!(
+            error_codes("create table orders (id bigint)"),
+            vec![ERROR_TABLE_EXISTS]
+        );
+        assert!(error_codes("create table if not exists orders (x bigint)").is_empty());
+        assert!(error_codes("create or replace view orders as select 1 as x").is_empty());
+    }
+
+    #[test]
+    fn multiple_including_properties() {
+        assert_eq!(
+            error_codes(
+                "create table other (x bigint);
+                create table t (like orders including properties,
+                    like other including properties)"
+            ),
+            vec![ERROR_MULTIPLE_INCLUDING_PROPERTIES]
+        );
+    }
+
+    #[test]
+    fn invalid_column_type() {
+        assert_eq!(
+            error_codes("create table t (a varchar(2147483647))"),
+            vec![ERROR_INVALID_TYPE]
+        );
+        let (catalog, _) = load("create table t (a bigint); create table u (b decimal(39))");
+        assert!(catalog
+            .table(&QualifiedTableName::new("hive", "web", "u"))
+            .is_none());
+    }
+}
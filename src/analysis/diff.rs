@@ -0,0 +1,543 @@
+//! Structural diff of two statements, for reviewing changes to long
+//! queries without the noise of a text diff.
+//!
+//! The trees are compared top down. Statements, queries, clauses and joins
+//! are descended into, so that a change is reported at the clause it
+//! affects; other nodes, such as expressions and select items, are
+//! reported as a whole. The elements of lists, such as select items, are
+//! matched by equality: elements found elsewhere in the new list have
+//! moved. The remaining ones are updated if they have the same name, such
+//! as a select item's alias, or are both unnamed, in order; others are
+//! inserted or deleted.
+use super::tree_name;
+use crate::formatting::format_tree;
+use crate::parsing::equality::{tree_hash, trees_equal, EqualityOptions};
+use crate::parsing::ParseTree;
+use crate::utils::{Json, TextRange};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::Hasher;
+
+/// The longest node text shown in summaries.
+const MAX_TEXT_LENGTH: usize = 60;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChangeKind {
+    Inserted,
+    Deleted,
+    Moved,
+    Updated,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::Inserted => write!(f, "inserted"),
+            ChangeKind::Deleted => write!(f, "deleted"),
+            ChangeKind::Moved => write!(f, "moved"),
+            ChangeKind::Updated => write!(f, "updated"),
+        }
+    }
+}
+
+/// A node of one of the trees.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiffNode {
+    pub range: TextRange,
+    /// The node's formatted text, on one line.
+    pub text: String,
+}
+
+impl DiffNode {
+    fn new(tree: &ParseTree) -> DiffNode {
+        DiffNode {
+            range: tree.get_range(),
+            text: format_tree(tree)
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("range", self.range.to_json()),
+            ("text", self.text.as_str().into()),
+        ])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    /// The role of the node, such as "select item" or "WHERE predicate".
+    pub role: String,
+    /// The node in the old tree; None for insertions.
+    pub old: Option<DiffNode>,
+    /// The node in the new tree; None for deletions.
+    pub new: Option<DiffNode>,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = |node: &Option<DiffNode>| match node {
+            Some(node) => quote(&node.text),
+            None => String::new(),
+        };
+        match self.kind {
+            ChangeKind::Inserted => write!(f, "{} {} added", self.role, text(&self.new)),
+            ChangeKind::Deleted => write!(f, "{} {} removed", self.role, text(&self.old)),
+            ChangeKind::Moved => write!(f, "{} {} moved", self.role, text(&self.new)),
+            ChangeKind::Updated => write!(
+                f,
+                "{} changed from {} to {}",
+                self.role,
+                text(&self.old),
+                text(&self.new)
+            ),
+        }
+    }
+}
+
+/// Quotes text for a summary, eliding the middle of long texts.
+fn quote(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= MAX_TEXT_LENGTH {
+        format!("`{}`", text)
+    } else {
+        let half = MAX_TEXT_LENGTH / 2;
+        let start: String = chars[..half].iter().collect();
+        let end: String = chars[chars.len() - half..].iter().collect();
+        format!("`{} ... {}`", start, end)
+    }
+}
+
+/// The changes from an old statement to a new one, in old tree order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeDiff {
+    pub changes: Vec<Change>,
+}
+
+impl TreeDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// One line per change, prefixed with its old and new line numbers.
+    pub fn summary(&self) -> String {
+        let line = |node: &Option<DiffNode>| match node {
+            Some(node) => (node.range.start.line + 1).to_string(),
+            None => "-".to_string(),
+        };
+        self.changes
+            .iter()
+            .map(|change| format!("{}:{}: {}\n", line(&change.old), line(&change.new), change))
+            .collect()
+    }
+
+    /// The changes as JSON:
+    ///
+    ///   {"changes": [{"kind": "updated", "role": "WHERE predicate",
+    ///     "summary": "...", "old": {"range": ..., "text": "..."}, "new": ...}]}
+    ///
+    /// old is null for insertions, and new for deletions.
+    pub fn to_json(&self) -> Json {
+        let node = |node: &Option<DiffNode>| node.as_ref().map_or(Json::Null, DiffNode::to_json);
+        Json::object(vec![(
+            "changes",
+            Json::array(
+                self.changes
+                    .iter()
+                    .map(|change| {
+                        Json::object(vec![
+                            ("kind", change.kind.to_string().into()),
+                            ("role", change.role.as_str().into()),
+                            ("summary", change.to_string().into()),
+                            ("old", node(&change.old)),
+                            ("new", node(&change.new)),
+                        ])
+                    })
+                    .collect(),
+            ),
+        )])
+    }
+}
+
+/// The changes from old to new; equality of nodes follows options.
+pub fn diff_trees(old: &ParseTree, new: &ParseTree, options: &EqualityOptions) -> TreeDiff {
+    let mut differ = Differ {
+        options,
+        changes: Vec::new(),
+    };
+    differ.diff("statement", old, new);
+    TreeDiff {
+        changes: differ.changes,
+    }
+}
+
+struct Differ<'o> {
+    options: &'o EqualityOptions,
+    changes: Vec<Change>,
+}
+
+impl<'o> Differ<'o> {
+    fn equal(&self, old: &ParseTree, new: &ParseTree) -> bool {
+        trees_equal(old, new, self.options)
+    }
+
+    fn hash(&self, tree: &ParseTree) -> u64 {
+        let mut state = DefaultHasher::new();
+        tree_hash(tree, self.options, &mut state);
+        state.finish()
+    }
+
+    fn change(
+        &mut self,
+        kind: ChangeKind,
+        role: &str,
+        old: Option<&ParseTree>,
+        new: Option<&ParseTree>,
+    ) {
+        self.changes.push(Change {
+            kind,
+            role: role.to_string(),
+            old: old.map(DiffNode::new),
+            new: new.map(DiffNode::new),
+        });
+    }
+
+    fn diff(&mut self, role: &str, old: &ParseTree, new: &ParseTree) {
+        if self.equal(old, new) {
+            return;
+        }
+        match (old, new) {
+            (ParseTree::Empty(_), _) => self.change(ChangeKind::Inserted, role, None, Some(new)),
+            (_, ParseTree::Empty(_)) => self.change(ChangeKind::Deleted, role, Some(old), None),
+            (ParseTree::List(old_list), ParseTree::List(new_list)) => {
+                let old_elements: Vec<&ParseTree> = old_list
+                    .elements_and_separators
+                    .iter()
+                    .map(|(element, _)| element)
+                    .collect();
+                let new_elements: Vec<&ParseTree> = new_list
+                    .elements_and_separators
+                    .iter()
+                    .map(|(element, _)| element)
+                    .collect();
+                self.diff_lists(role, &old_elements, &new_elements);
+            }
+            _ if old.kind_name() == new.kind_name() && is_container(old) => {
+                let field_names = old.field_names();
+                let old_children = old.children();
+                let new_children = new.children();
+                // Keywords which come and go with a clause, such as WHERE,
+                // are part of the clause's change.
+                let changed_fields: Vec<usize> = (0..old_children.len())
+                    .filter(|&index| !self.equal(old_children[index], new_children[index]))
+                    .collect();
+                for &index in &changed_fields {
+                    let (old_child, new_child) = (old_children[index], new_children[index]);
+                    if field_names[index] == "join_type" {
+                        self.diff_join_types(old_child, new_child);
+                        continue;
+                    }
+                    let is_keyword = |tree: &ParseTree| match tree {
+                        ParseTree::Token(token) => token.token.kind.is_keyword(),
+                        _ => false,
+                    };
+                    let is_clause_keyword = changed_fields.len() > 1
+                        && (old_child.is_empty() || new_child.is_empty())
+                        && (is_keyword(old_child) || is_keyword(new_child));
+                    if !is_clause_keyword {
+                        let role = field_role(old.kind_name(), field_names[index]);
+                        self.diff(&role, old_child, new_child);
+                    }
+                }
+            }
+            _ => self.change(ChangeKind::Updated, role, Some(old), Some(new)),
+        }
+    }
+
+    /// Join types are compared by name, so JOIN is the same as INNER JOIN
+    /// and LEFT JOIN the same as LEFT OUTER JOIN.
+    fn diff_join_types(&mut self, old: &ParseTree, new: &ParseTree) {
+        let (old_name, new_name) = (join_type_name(old), join_type_name(new));
+        if old_name != new_name {
+            self.changes.push(Change {
+                kind: ChangeKind::Updated,
+                role: "join type".to_string(),
+                old: Some(DiffNode {
+                    range: old.get_range(),
+                    text: old_name,
+                }),
+                new: Some(DiffNode {
+                    range: new.get_range(),
+                    text: new_name,
+                }),
+            });
+        }
+    }
+
+    /// Matches the elements of two lists by their longest common
+    /// subsequence, then reports the unmatched ones.
+    fn diff_lists(&mut self, list_role: &str, old: &[&ParseTree], new: &[&ParseTree]) {
+        let role = element_role(list_role);
+        // Elements are compared by hash first, as trees_equal() walks
+        // both trees and the LCS compares every pair of elements.
+        let old_hashes: Vec<u64> = old.iter().map(|tree| self.hash(tree)).collect();
+        let new_hashes: Vec<u64> = new.iter().map(|tree| self.hash(tree)).collect();
+        let same = |this: &Self, i: usize, j: usize| {
+            old_hashes[i] == new_hashes[j] && this.equal(old[i], new[j])
+        };
+        // lengths[i][j] is the length of the LCS of old[i..] and new[j..].
+        let mut lengths = vec![vec![0; new.len() + 1]; old.len() + 1];
+        for i in (0..old.len()).rev() {
+            for j in (0..new.len()).rev() {
+                lengths[i][j] = if same(self, i, j) {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+        let mut old_matched = vec![false; old.len()];
+        let mut new_matched = vec![false; new.len()];
+        let (mut i, mut j) = (0, 0);
+        while i < old.len() && j < new.len() {
+            if same(self, i, j) {
+                old_matched[i] = true;
+                new_matched[j] = true;
+                i += 1;
+                j += 1;
+            } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        for i in 0..old.len() {
+            if old_matched[i] {
+                continue;
+            }
+            let moved = (0..new.len()).find(|&j| !new_matched[j] && same(self, i, j));
+            if let Some(j) = moved {
+                old_matched[i] = true;
+                new_matched[j] = true;
+                self.change(ChangeKind::Moved, &role, Some(old[i]), Some(new[j]));
+            }
+        }
+
+        // Named elements are updated if their names match, and others in
+        // order.
+        let mut pairs = Vec::new();
+        let old_unmatched: Vec<usize> = (0..old.len()).filter(|&i| !old_matched[i]).collect();
+        for i in old_unmatched {
+            let key = element_key(old[i]);
+            let same_key = (0..new.len())
+                .find(|&j| !new_matched[j] && key.is_some() && element_key(new[j]) == key);
+            if let Some(j) = same_key {
+                old_matched[i] = true;
+                new_matched[j] = true;
+                pairs.push((i, j));
+            }
+        }
+        let old_unnamed: Vec<usize> = (0..old.len())
+            .filter(|&i| !old_matched[i] && element_key(old[i]).is_none())
+            .collect();
+        let new_unnamed: Vec<usize> = (0..new.len())
+            .filter(|&j| !new_matched[j] && element_key(new[j]).is_none())
+            .collect();
+        for (&i, &j) in old_unnamed.iter().zip(&new_unnamed) {
+            old_matched[i] = true;
+            new_matched[j] = true;
+            pairs.push((i, j));
+        }
+        pairs.sort_unstable();
+        for (i, j) in pairs {
+            self.diff(&role, old[i], new[j]);
+        }
+        for i in (0..old.len()).filter(|&i| !old_matched[i]) {
+            self.change(ChangeKind::Deleted, &role, Some(old[i]), None);
+        }
+        for j in (0..new.len()).filter(|&j| !new_matched[j]) {
+            self.change(ChangeKind::Inserted, &role, None, Some(new[j]));
+        }
+    }
+}
+
+/// The name identifying a list element across versions: the output name
+/// of a select item, or the name of a WITH query or aliased relation.
+fn element_key(tree: &ParseTree) -> Option<String> {
+    match tree {
+        ParseTree::SelectItem(select_item) => {
+            if select_item.identifier.is_empty() {
+                match &*select_item.expression {
+                    ParseTree::Identifier(identifier) => tree_name(&identifier.value),
+                    ParseTree::Dereference(dereference) => tree_name(&dereference.field_name),
+                    _ => None,
+                }
+            } else {
+                tree_name(&select_item.identifier)
+            }
+        }
+        ParseTree::NamedQuery(named_query) => tree_name(&named_query.name),
+        ParseTree::AliasedRelation(aliased_relation) => tree_name(&aliased_relation.identifier),
+        _ => None,
+    }
+}
+
+/// Is tree a statement, query, clause or join, whose changes are reported
+/// for its parts?
+fn is_container(tree: &ParseTree) -> bool {
+    matches!(
+        tree,
+        ParseTree::Entrypoint(_)
+            | ParseTree::Query(_)
+            | ParseTree::With(_)
+            | ParseTree::NamedQuery(_)
+            | ParseTree::QueryNoWith(_)
+            | ParseTree::QuerySetOperation(_)
+            | ParseTree::QuerySpecification(_)
+            | ParseTree::Subquery(_)
+            | ParseTree::SubqueryRelation(_)
+            | ParseTree::RelationOrQuery(_)
+            | ParseTree::ParenthesizedRelation(_)
+            | ParseTree::AliasedRelation(_)
+            | ParseTree::Join(_)
+            | ParseTree::CrossJoin(_)
+            | ParseTree::NaturalJoin(_)
+            | ParseTree::GroupBy(_)
+            | ParseTree::OrderBy(_)
+            | ParseTree::InsertInto(_)
+            | ParseTree::Delete(_)
+            | ParseTree::CreateTable(_)
+            | ParseTree::CreateTableAsSelect(_)
+            | ParseTree::CreateView(_)
+    )
+}
+
+/// INNER, LEFT, RIGHT or FULL; a missing join type is INNER, and OUTER
+/// is implied by the others.
+fn join_type_name(join_type: &ParseTree) -> String {
+    match join_type.get_first_token() {
+        Some(token) => token.value.to_uppercase(),
+        None => "INNER".to_string(),
+    }
+}
+
+/// The role of a child of a tree of a kind, named after the field.
+fn field_role(kind_name: &str, field_name: &str) -> String {
+    let role = match (kind_name, field_name) {
+        ("QuerySpecification", "select_items") => "select items",
+        ("QuerySpecification", "set_quantifier_opt") => "SELECT set quantifier",
+        ("QuerySpecification", "relations") => "FROM relations",
+        ("QuerySpecification", "where_predicate") => "WHERE predicate",
+        ("QuerySpecification", "group_by") => "GROUP BY",
+        ("QuerySpecification", "having_predicate") => "HAVING predicate",
+        ("GroupBy", "grouping_elements") => "grouping elements",
+        ("QueryNoWith", "order_by_opt") => "ORDER BY",
+        ("QueryNoWith", "limit_opt") => "LIMIT",
+        ("OrderBy", "sort_items") => "sort items",
+        ("With", "named_queries") => "WITH queries",
+        ("QuerySetOperation", "operator") => "set operation",
+        ("Join", "join_type") => "join type",
+        ("Join", "join_criteria") => "join criteria",
+        ("AliasedRelation", "relation_primary") => "relation",
+        ("AliasedRelation", "identifier") => "relation alias",
+        ("InsertInto", "table_name")
+        | ("Delete", "table_name")
+        | ("CreateTable", "table_name")
+        | ("CreateTableAsSelect", "table_name")
+        | ("CreateView", "qualified_name") => "table name",
+        ("Delete", "predicate") => "WHERE predicate",
+        _ => {
+            let name = field_name.trim_end_matches("_opt").trim_end_matches('_');
+            return name.replace('_', " ");
+        }
+    };
+    role.to_string()
+}
+
+/// The role of an element of a list with a role: select items have
+/// select item elements.
+fn element_role(list_role: &str) -> String {
+    match list_role.strip_suffix("ies") {
+        Some(stem) => format!("{}y", stem),
+        None => list_role.strip_suffix('s').unwrap_or(list_role).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+
+    fn changes(old: &str, new: &str, options: &EqualityOptions) -> Vec<String> {
+        let (old, _) = parse_statement(old);
+        let (new, _) = parse_statement(new);
+        let diff = diff_trees(&old, &new, options);
+        diff.changes
+            .iter()
+            .map(|change| change.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn list_elements_match_under_options() {
+        let lenient = EqualityOptions::lenient();
+        assert!(changes("select a, (b) from t", "SELECT A, b FROM t", &lenient).is_empty());
+        assert_eq!(
+            changes("select a, b, c from t", "select c, a, b from t", &lenient),
+            vec!["select item `c` moved"]
+        );
+        assert_eq!(
+            changes(
+                "select a, b from t",
+                "select a, B from t",
+                &EqualityOptions::default()
+            ),
+            vec!["select item changed from `b` to `B`"]
+        );
+    }
+
+    #[test]
+    fn join_types_compare_by_name() {
+        let options = EqualityOptions::default();
+        let join = |join_type: &str| format!("select * from a {} join b on true", join_type);
+        assert!(changes(&join(""), &join("inner"), &options).is_empty());
+        assert!(changes(&join("left"), &join("left outer"), &options).is_empty());
+        assert!(changes(&join("full outer"), &join("FULL"), &options).is_empty());
+        assert_eq!(
+            changes(&join(""), &join("left"), &options),
+            vec!["join type changed from `INNER` to `LEFT`"]
+        );
+        assert_eq!(
+            changes(&join("left outer"), &join("right"), &options),
+            vec!["join type changed from `LEFT` to `RIGHT`"]
+        );
+        assert_eq!(
+            changes(
+                "select * from a natural join b",
+                "select * from a natural full join b",
+                &options
+            ),
+            vec!["join type changed from `INNER` to `FULL`"]
+        );
+    }
+
+    #[test]
+    fn join_type_change_is_reported_with_other_changes() {
+        assert_eq!(
+            changes(
+                "select * from a join b on a.x = b.x",
+                "select * from a left join b on a.y = b.y",
+                &EqualityOptions::default()
+            ),
+            vec![
+                "join type changed from `INNER` to `LEFT`",
+                "join criteria changed from `ON a.x = b.x` to `ON a.y = b.y`"
+            ]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Query fingerprints, for grouping queries which differ only in their
+//! constants, formatting and comments.
+//!
+//! The fingerprint is computed from the parse tree: literals, the values of
+//! intervals and typed literals such as DATE '2020-01-01', and IN lists
+//! become ?, keywords are lower cased, identifiers are written as their
+//! folded names with minimal quoting, so that foo, FOO and "foo" are the
+//! same, comments are dropped, and tokens are separated by single spaces.
+//! Because string contents are never examined, strings which look like SQL
+//! do not affect the fingerprint.
+use crate::lexing::{token, TokenKind};
+use crate::parsing::ParseTree;
+use std::fmt;
+
+/// The placeholder which replaces constants.
+const PLACEHOLDER: &str = "?";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    /// The normalized SQL text.
+    pub text: String,
+    /// The 64-bit FNV-1a hash of text, which is stable across runs and
+    /// platforms.
+    pub hash: u64,
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.hash)
+    }
+}
+
+/// The fingerprint of a statement, an Entrypoint or an expression.
+pub fn fingerprint(tree: &ParseTree) -> Fingerprint {
+    let mut words = Vec::new();
+    normalize(tree, &mut words);
+    let mut text = String::new();
+    for (index, word) in words.iter().enumerate() {
+        let is_joined = index == 0
+            || matches!(word.as_str(), "," | ")" | "]" | ".")
+            || matches!(words[index - 1].as_str(), "(" | "[" | ".");
+        if !is_joined {
+            text.push(' ');
+        }
+        text.push_str(word);
+    }
+    let hash = fnv1a(text.as_bytes());
+    Fingerprint { text, hash }
+}
+
+/// Appends the normalized words of tree to words.
+fn normalize(tree: &ParseTree, words: &mut Vec<String>) {
+    match tree {
+        ParseTree::Token(token) => {
+            if !token.token.value.is_empty() {
+                words.push(normalize_token(&token.token));
+            }
+        }
+        ParseTree::Literal(_) => words.push(PLACEHOLDER.to_string()),
+        ParseTree::TypeConstructor(type_constructor) => {
+            normalize(&type_constructor.type_, words);
+            words.push(PLACEHOLDER.to_string());
+        }
+        ParseTree::Interval(interval) => {
+            normalize(&interval.interval, words);
+            words.push(PLACEHOLDER.to_string());
+            for tree in [&interval.from, &interval.to_kw_opt, &interval.to] {
+                normalize(tree, words);
+            }
+        }
+        ParseTree::InList(in_list) => {
+            for tree in [&in_list.value, &in_list.not_opt, &in_list.in_] {
+                normalize(tree, words);
+            }
+            words.extend(["(", PLACEHOLDER, ")"].iter().map(|word| word.to_string()));
+        }
+        tree => {
+            for child in tree.children() {
+                normalize(child, words);
+            }
+        }
+    }
+}
+
+/// Lower cases keywords, folds identifiers, and collapses the whitespace in
+/// multi word keywords such as DOUBLE PRECISION.
+fn normalize_token(token: &token::Token) -> String {
+    if let Ok(identifier) = token.identifier_value() {
+        return identifier.to_string();
+    }
+    match token.kind {
+        TokenKind::String | TokenKind::UnicodeString | TokenKind::BinaryLiteral => {
+            token.value.to_string()
+        }
+        _ => token
+            .value
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase(),
+    }
+}
+
+/// The 64-bit FNV-1a hash of bytes.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+
+    fn fingerprint_of(text: &str) -> Fingerprint {
+        let (tree, errors) = parse_statement(text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        fingerprint(&tree)
+    }
+
+    fn text(text: &str) -> String {
+        fingerprint_of(text).text
+    }
+
+    #[test]
+    fn constants_become_placeholders() {
+        assert_eq!(
+            text("select 1, 'a', 2.5, X'00', true, null from t where d > DATE '2020-01-01'"),
+            "select ?, ?, ?, ?, ?, ? from t where d > date ?"
+        );
+        assert_eq!(
+            text("select * from t where d > now() - INTERVAL '1' DAY"),
+            "select * from t where d > now () - interval ? day"
+        );
+    }
+
+    #[test]
+    fn in_lists_collapse() {
+        assert_eq!(
+            text("select * from t where a in (1, 2, 3) and b not in ('x')"),
+            "select * from t where a in (?) and b not in (?)"
+        );
+        assert_eq!(
+            fingerprint_of("select * from t where a in (1)"),
+            fingerprint_of("select * from t where a in (1, 2, 3, 4)")
+        );
+        // Subqueries are not IN lists.
+        assert_eq!(
+            text("select * from t where a in (select b from u)"),
+            "select * from t where a in (select b from u)"
+        );
+    }
+
+    #[test]
+    fn stable_across_formatting_and_case() {
+        let expected = fingerprint_of("select a, count(*) from t where b = 1 group by a");
+        for text in &[
+            "SELECT A, COUNT(*) FROM T WHERE B = 2 GROUP BY A",
+            "select\n  a, -- a\n  count( * )\nfrom \"t\"\nwhere b = 'x' /* b */\ngroup by a",
+        ] {
+            assert_eq!(fingerprint_of(text), expected, "{}", text);
+        }
+        assert_eq!(
+            expected.text,
+            "select a, count (*) from t where b = ? group by a"
+        );
+        assert_ne!(
+            fingerprint_of("select \"A\" from t"),
+            fingerprint_of("select a from t")
+        );
+        assert_ne!(
+            expected,
+            fingerprint_of("select a, count(*) from u where b = 1 group by a")
+        );
+    }
+
+    #[test]
+    fn hash_is_fnv1a_of_the_text() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        let fingerprint = fingerprint_of("select 1");
+        assert_eq!(fingerprint.hash, fnv1a(b"select ?"));
+        assert_eq!(
+            fingerprint.to_string(),
+            format!("{:016x}", fingerprint.hash)
+        );
+    }
+}
@@ -0,0 +1,839 @@
+//! Function signatures, and the registry of built in and custom functions.
+//!
+//! Signatures are written as text, in the notation of the Presto
+//! documentation extended with type variables:
+//!
+//!     transform<T, U>(array(T), function(T, U)) -> array(U)
+//!     max<T: orderable>(T) -> T
+//!     concat(varchar...) -> varchar
+//!
+//! function(A, B, R) is the type of a lambda with parameters A and B which
+//! returns R. A trailing ... makes the last argument variadic: it may be
+//! repeated one or more times. Type variables may be constrained to be
+//! comparable, orderable, numeric or decimal types.
+//!
+//! A call binds to the signature of its function which needs the fewest
+//! implicit coercions of its arguments.
+use super::list_elements;
+use super::tree_name;
+use super::types::DataType;
+use crate::parsing::{parse_type, ParseTree};
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FunctionKind {
+    Scalar,
+    Aggregate,
+    /// A function which is only valid with an OVER clause, such as rank.
+    Window,
+}
+
+impl fmt::Display for FunctionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FunctionKind::Scalar => write!(f, "scalar"),
+            FunctionKind::Aggregate => write!(f, "aggregate"),
+            FunctionKind::Window => write!(f, "window"),
+        }
+    }
+}
+
+/// A restriction on the types a type variable binds to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    Any,
+    Comparable,
+    Orderable,
+    Numeric,
+    Decimal,
+}
+
+impl Constraint {
+    fn from_name(name: &str) -> Option<Constraint> {
+        match name {
+            "comparable" => Some(Constraint::Comparable),
+            "orderable" => Some(Constraint::Orderable),
+            "numeric" => Some(Constraint::Numeric),
+            "decimal" => Some(Constraint::Decimal),
+            _ => None,
+        }
+    }
+
+    fn is_satisfied_by(self, data_type: &DataType) -> bool {
+        data_type.is_unknown()
+            || match self {
+                Constraint::Any => true,
+                Constraint::Comparable => data_type.is_comparable(),
+                Constraint::Orderable => data_type.is_orderable(),
+                Constraint::Numeric => data_type.is_numeric(),
+                Constraint::Decimal => matches!(data_type, DataType::Decimal { .. }),
+            }
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::Any => Ok(()),
+            Constraint::Comparable => write!(f, "comparable"),
+            Constraint::Orderable => write!(f, "orderable"),
+            Constraint::Numeric => write!(f, "numeric"),
+            Constraint::Decimal => write!(f, "decimal"),
+        }
+    }
+}
+
+/// The type of an argument or result of a function, which may contain type
+/// variables.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TypeSignature {
+    Type(DataType),
+    Variable(String),
+    Array(Box<TypeSignature>),
+    Map(Box<TypeSignature>, Box<TypeSignature>),
+    /// A lambda, with its parameter types and result type.
+    Function(Vec<TypeSignature>, Box<TypeSignature>),
+}
+
+impl fmt::Display for TypeSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeSignature::Type(data_type) => write!(f, "{}", data_type),
+            TypeSignature::Variable(name) => write!(f, "{}", name),
+            TypeSignature::Array(element) => write!(f, "array({})", element),
+            TypeSignature::Map(key, value) => write!(f, "map({}, {})", key, value),
+            TypeSignature::Function(parameters, result) => {
+                write!(f, "function(")?;
+                for parameter in parameters {
+                    write!(f, "{}, ", parameter)?;
+                }
+                write!(f, "{})", result)
+            }
+        }
+    }
+}
+
+impl TypeSignature {
+    /// The signature of a type tree; identifiers naming variables are
+    /// type variables.
+    fn from_tree(tree: &ParseTree, variables: &[(String, Constraint)]) -> TypeSignature {
+        let variable = |tree: &ParseTree| {
+            let name = tree_name(tree)?;
+            variables
+                .iter()
+                .find(|(variable, _)| variable.to_lowercase() == name)
+                .map(|(variable, _)| TypeSignature::Variable(variable.clone()))
+        };
+        match tree {
+            ParseTree::Identifier(_) => {
+                variable(tree).unwrap_or_else(|| TypeSignature::Type(DataType::from_tree(tree)))
+            }
+            ParseTree::NamedType(named_type) => {
+                let parameters: Vec<TypeSignature> = list_elements(&named_type.type_parameters)
+                    .into_iter()
+                    .map(|parameter| TypeSignature::from_tree(parameter, variables))
+                    .collect();
+                let name = tree_name(&named_type.name);
+                match (name.as_deref(), parameters.as_slice()) {
+                    (_, []) => variable(&named_type.name)
+                        .unwrap_or_else(|| TypeSignature::Type(DataType::from_tree(tree))),
+                    (Some("array"), [element]) => TypeSignature::Array(Box::new(element.clone())),
+                    (Some("map"), [key, value]) => {
+                        TypeSignature::Map(Box::new(key.clone()), Box::new(value.clone()))
+                    }
+                    (Some("function"), [parameters @ .., result]) => {
+                        TypeSignature::Function(parameters.to_vec(), Box::new(result.clone()))
+                    }
+                    _ => TypeSignature::Type(DataType::from_tree(tree)),
+                }
+            }
+            ParseTree::ArrayType(array_type) => TypeSignature::Array(Box::new(
+                TypeSignature::from_tree(&array_type.element_type, variables),
+            )),
+            ParseTree::ArrayTypeSuffix(array_type_suffix) => TypeSignature::Array(Box::new(
+                TypeSignature::from_tree(&array_type_suffix.base_type, variables),
+            )),
+            ParseTree::MapType(map_type) => TypeSignature::Map(
+                Box::new(TypeSignature::from_tree(&map_type.key_type, variables)),
+                Box::new(TypeSignature::from_tree(&map_type.value_type, variables)),
+            ),
+            _ => TypeSignature::Type(DataType::from_tree(tree)),
+        }
+    }
+
+    /// The type with the variables replaced by their bindings; unbound
+    /// variables are unknown.
+    pub fn bind(&self, bindings: &Bindings) -> DataType {
+        match self {
+            TypeSignature::Type(data_type) => data_type.clone(),
+            TypeSignature::Variable(name) => bindings
+                .types
+                .get(name)
+                .cloned()
+                .unwrap_or(DataType::Unknown),
+            TypeSignature::Array(element) => DataType::Array(Box::new(element.bind(bindings))),
+            TypeSignature::Map(key, value) => {
+                DataType::Map(Box::new(key.bind(bindings)), Box::new(value.bind(bindings)))
+            }
+            TypeSignature::Function(..) => DataType::Unknown,
+        }
+    }
+}
+
+/// The type of an argument of a call, for binding it to a signature.
+#[derive(Clone, Debug)]
+pub enum ArgumentType {
+    Value(DataType),
+    /// A lambda with a number of parameters. Its type depends on the types
+    /// of its parameters, so it is known only after binding.
+    Lambda(usize),
+}
+
+impl fmt::Display for ArgumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentType::Value(data_type) => write!(f, "{}", data_type),
+            ArgumentType::Lambda(_) => write!(f, "lambda"),
+        }
+    }
+}
+
+/// The types bound to the type variables of a signature by a call.
+#[derive(Clone, Debug, Default)]
+pub struct Bindings {
+    pub types: BTreeMap<String, DataType>,
+    /// The number of implicit coercions needed.
+    pub cost: usize,
+}
+
+impl Bindings {
+    /// Binds the variables in signature to the corresponding parts of
+    /// data_type. Returns false if data_type does not fit the signature.
+    pub fn bind(&mut self, signature: &TypeSignature, data_type: &DataType) -> bool {
+        match (signature, data_type) {
+            (_, DataType::Unknown) => true,
+            (TypeSignature::Type(expected), data_type) => {
+                if data_type == expected || data_type.is_string() && expected.is_string() {
+                    true
+                } else if data_type.can_coerce_to(expected) {
+                    self.cost += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+            (TypeSignature::Variable(name), data_type) => match self.types.get(name) {
+                Some(bound) if bound == data_type => true,
+                Some(bound) => match DataType::common_super_type(bound, data_type) {
+                    Some(super_type) => {
+                        self.types.insert(name.clone(), super_type);
+                        self.cost += 1;
+                        true
+                    }
+                    None => false,
+                },
+                None => {
+                    self.types.insert(name.clone(), data_type.clone());
+                    true
+                }
+            },
+            (TypeSignature::Array(element), DataType::Array(data_type)) => {
+                self.bind(element, data_type)
+            }
+            (TypeSignature::Map(key, value), DataType::Map(key_type, value_type)) => {
+                self.bind(key, key_type) && self.bind(value, value_type)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FunctionSignature {
+    pub name: String,
+    pub kind: FunctionKind,
+    pub variables: Vec<(String, Constraint)>,
+    pub arguments: Vec<TypeSignature>,
+    /// Whether the last argument may be repeated.
+    pub variadic: bool,
+    pub return_type: TypeSignature,
+}
+
+impl fmt::Display for FunctionSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.variables.is_empty() {
+            let variables: Vec<String> = self
+                .variables
+                .iter()
+                .map(|(name, constraint)| match constraint {
+                    Constraint::Any => name.clone(),
+                    constraint => format!("{}: {}", name, constraint),
+                })
+                .collect();
+            write!(f, "<{}>", variables.join(", "))?;
+        }
+        let arguments: Vec<String> = self
+            .arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect();
+        write!(f, "({}", arguments.join(", "))?;
+        if self.variadic {
+            write!(f, "...")?;
+        }
+        write!(f, ") -> {}", self.return_type)
+    }
+}
+
+impl FunctionSignature {
+    /// Parses a signature such as "transform<T, U>(array(T), function(T, U)) -> array(U)".
+    pub fn parse(kind: FunctionKind, text: &str) -> Result<FunctionSignature, String> {
+        let error = |message: &str| format!("Invalid signature '{}': {}.", text, message);
+        let (head, return_type) = text
+            .rsplit_once("->")
+            .ok_or_else(|| error("expected -> and a return type"))?;
+        let open = head.find('(').ok_or_else(|| error("expected arguments"))?;
+        let arguments = head[open..].trim();
+        if !arguments.ends_with(')') {
+            return Err(error("expected ) after the arguments"));
+        }
+        let arguments = &arguments[1..arguments.len() - 1];
+        let (name, variables) = match head[..open].split_once('<') {
+            Some((name, variables)) => (
+                name,
+                variables
+                    .trim()
+                    .strip_suffix('>')
+                    .ok_or_else(|| error("expected > after the type variables"))?,
+            ),
+            None => (&head[..open], ""),
+        };
+        let name = name.trim().to_lowercase();
+        if name.is_empty() {
+            return Err(error("expected a name"));
+        }
+
+        let mut parsed_variables = Vec::new();
+        for variable in split_top_level(variables) {
+            let (variable, constraint) = match variable.split_once(':') {
+                Some((variable, constraint)) => (
+                    variable.trim(),
+                    Constraint::from_name(constraint.trim()).ok_or_else(|| {
+                        error(&format!("unknown constraint '{}'", constraint.trim()))
+                    })?,
+                ),
+                None => (variable, Constraint::Any),
+            };
+            parsed_variables.push((variable.to_string(), constraint));
+        }
+
+        let mut parsed_arguments = Vec::new();
+        let mut variadic = false;
+        for argument in split_top_level(arguments) {
+            if variadic {
+                return Err(error("only the last argument may be variadic"));
+            }
+            let argument = match argument.strip_suffix("...") {
+                Some(argument) => {
+                    variadic = true;
+                    argument
+                }
+                None => argument,
+            };
+            parsed_arguments.push(
+                parse_type_signature(argument, &parsed_variables).ok_or_else(|| {
+                    error(&format!("invalid argument type '{}'", argument.trim()))
+                })?,
+            );
+        }
+        let return_type = parse_type_signature(return_type, &parsed_variables)
+            .ok_or_else(|| error("invalid return type"))?;
+        Ok(FunctionSignature {
+            name,
+            kind,
+            variables: parsed_variables,
+            arguments: parsed_arguments,
+            variadic,
+            return_type,
+        })
+    }
+
+    /// The argument signatures for a call with argument_count arguments;
+    /// None if the signature does not take that many arguments.
+    pub fn argument_signatures(&self, argument_count: usize) -> Option<Vec<&TypeSignature>> {
+        let fixed = self.arguments.len();
+        if self.variadic && argument_count >= fixed {
+            Some(
+                (0..argument_count)
+                    .map(|index| &self.arguments[index.min(fixed - 1)])
+                    .collect(),
+            )
+        } else if argument_count == fixed {
+            Some(self.arguments.iter().collect())
+        } else {
+            None
+        }
+    }
+
+    /// Binds the type variables to the types of the arguments of a call.
+    /// Lambda arguments only need a lambda of the right arity; they are
+    /// bound later, with bind_lambda. None if the call does not fit.
+    pub fn bind(&self, arguments: &[ArgumentType]) -> Option<Bindings> {
+        let signatures = self.argument_signatures(arguments.len())?;
+        let mut bindings = Bindings::default();
+        for (signature, argument) in signatures.iter().zip(arguments) {
+            let fits = match (signature, argument) {
+                (TypeSignature::Function(parameters, _), ArgumentType::Lambda(count)) => {
+                    parameters.len() == *count
+                }
+                (TypeSignature::Function(..), ArgumentType::Value(data_type)) => {
+                    data_type.is_unknown()
+                }
+                (_, ArgumentType::Lambda(_)) => false,
+                (signature, ArgumentType::Value(data_type)) => bindings.bind(signature, data_type),
+            };
+            if !fits {
+                return None;
+            }
+        }
+        if self.satisfies_constraints(&bindings) {
+            Some(bindings)
+        } else {
+            None
+        }
+    }
+
+    fn satisfies_constraints(&self, bindings: &Bindings) -> bool {
+        self.variables.iter().all(|(name, constraint)| {
+            bindings
+                .types
+                .get(name)
+                .is_none_or(|data_type| constraint.is_satisfied_by(data_type))
+        })
+    }
+}
+
+/// Parses the type signature in text, with the given type variables.
+fn parse_type_signature(text: &str, variables: &[(String, Constraint)]) -> Option<TypeSignature> {
+    let (tree, errors) = parse_type(text);
+    if !errors.is_empty() {
+        return None;
+    }
+    match &tree {
+        ParseTree::Entrypoint(entrypoint) => {
+            Some(TypeSignature::from_tree(&entrypoint.tree, variables))
+        }
+        tree => Some(TypeSignature::from_tree(tree, variables)),
+    }
+}
+
+/// Splits text at the commas which are not within parentheses; none for
+/// blank text.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '(' | '<' => depth += 1,
+            ')' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(text[start..index].trim());
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    if !text[start..].trim().is_empty() {
+        parts.push(text[start..].trim());
+    }
+    parts
+}
+
+/// The functions which may be called, by name.
+#[derive(Clone, Debug, Default)]
+pub struct FunctionRegistry {
+    functions: BTreeMap<String, Vec<FunctionSignature>>,
+}
+
+impl FunctionRegistry {
+    /// An empty registry.
+    pub fn new() -> FunctionRegistry {
+        FunctionRegistry::default()
+    }
+
+    /// A registry of Presto's built in functions.
+    pub fn built_in() -> FunctionRegistry {
+        let mut registry = FunctionRegistry::new();
+        for (kind, signatures) in &[
+            (FunctionKind::Scalar, SCALAR_FUNCTIONS),
+            (FunctionKind::Aggregate, AGGREGATE_FUNCTIONS),
+            (FunctionKind::Window, WINDOW_FUNCTIONS),
+        ] {
+            for text in *signatures {
+                registry.register_text(*kind, text).unwrap();
+            }
+        }
+        for field in DATE_TIME_FIELDS {
+            for argument in &["timestamp", "timestamp with time zone", "date"] {
+                registry
+                    .register_text(
+                        FunctionKind::Scalar,
+                        &format!("{}({}) -> bigint", field, argument),
+                    )
+                    .unwrap();
+            }
+        }
+        registry
+    }
+
+    /// Adds a signature. Functions may have several signatures, but all
+    /// must be of the same kind, except that aggregate functions may have
+    /// window signatures.
+    pub fn register(&mut self, signature: FunctionSignature) {
+        self.functions
+            .entry(signature.name.clone())
+            .or_default()
+            .push(signature);
+    }
+
+    /// Adds a signature written as text; see FunctionSignature::parse.
+    pub fn register_text(&mut self, kind: FunctionKind, text: &str) -> Result<(), String> {
+        self.register(FunctionSignature::parse(kind, text)?);
+        Ok(())
+    }
+
+    /// The signatures of a function; empty for unknown functions.
+    pub fn signatures(&self, name: &str) -> &[FunctionSignature] {
+        self.functions
+            .get(name)
+            .map_or(&[], |signatures| signatures.as_slice())
+    }
+
+    /// The kind of a function; None for unknown functions.
+    pub fn kind(&self, name: &str) -> Option<FunctionKind> {
+        self.signatures(name)
+            .first()
+            .map(|signature| signature.kind)
+    }
+
+    /// The signature which the arguments of a call bind to with the fewest
+    /// coercions, along with the bindings of its type variables.
+    pub fn resolve(
+        &self,
+        name: &str,
+        arguments: &[ArgumentType],
+    ) -> Option<(&FunctionSignature, Bindings)> {
+        self.signatures(name)
+            .iter()
+            .filter_map(|signature| Some((signature, signature.bind(arguments)?)))
+            .min_by_key(|(_, bindings)| bindings.cost)
+    }
+}
+
+/// The functions which extract a field of a date or time.
+const DATE_TIME_FIELDS: &[&str] = &[
+    "year",
+    "quarter",
+    "month",
+    "week",
+    "week_of_year",
+    "day",
+    "day_of_month",
+    "day_of_week",
+    "dow",
+    "day_of_year",
+    "doy",
+    "year_of_week",
+    "yow",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+];
+
+const SCALAR_FUNCTIONS: &[&str] = &[
+    // Conditional and general functions.
+    "coalesce<T>(T...) -> T",
+    "if<T>(boolean, T) -> T",
+    "if<T>(boolean, T, T) -> T",
+    "nullif<T: comparable>(T, T) -> T",
+    "try<T>(T) -> T",
+    "typeof<T>(T) -> varchar",
+    "uuid() -> uuid",
+    "greatest<T: orderable>(T...) -> T",
+    "least<T: orderable>(T...) -> T",
+    // Mathematical functions.
+    "abs<T: numeric>(T) -> T",
+    "sign<T: numeric>(T) -> T",
+    "ceil<T: numeric>(T) -> T",
+    "ceiling<T: numeric>(T) -> T",
+    "floor<T: numeric>(T) -> T",
+    "round<T: numeric>(T) -> T",
+    "round<T: numeric>(T, integer) -> T",
+    "truncate(double) -> double",
+    "truncate<T: decimal>(T) -> T",
+    "mod<T: numeric>(T, T) -> T",
+    "sqrt(double) -> double",
+    "cbrt(double) -> double",
+    "exp(double) -> double",
+    "ln(double) -> double",
+    "log2(double) -> double",
+    "log10(double) -> double",
+    "log(double, double) -> double",
+    "power(double, double) -> double",
+    "pow(double, double) -> double",
+    "degrees(double) -> double",
+    "radians(double) -> double",
+    "sin(double) -> double",
+    "cos(double) -> double",
+    "tan(double) -> double",
+    "asin(double) -> double",
+    "acos(double) -> double",
+    "atan(double) -> double",
+    "atan2(double, double) -> double",
+    "sinh(double) -> double",
+    "cosh(double) -> double",
+    "tanh(double) -> double",
+    "pi() -> double",
+    "e() -> double",
+    "infinity() -> double",
+    "nan() -> double",
+    "is_nan(double) -> boolean",
+    "is_finite(double) -> boolean",
+    "is_infinite(double) -> boolean",
+    "rand() -> double",
+    "rand(bigint) -> bigint",
+    "random() -> double",
+    "random(bigint) -> bigint",
+    "width_bucket(double, double, double, bigint) -> bigint",
+    "to_base(bigint, bigint) -> varchar",
+    "from_base(varchar, bigint) -> bigint",
+    "bitwise_and(bigint, bigint) -> bigint",
+    "bitwise_or(bigint, bigint) -> bigint",
+    "bitwise_xor(bigint, bigint) -> bigint",
+    "bitwise_not(bigint) -> bigint",
+    // String functions.
+    "length(varchar) -> bigint",
+    "lower(varchar) -> varchar",
+    "upper(varchar) -> varchar",
+    "trim(varchar) -> varchar",
+    "ltrim(varchar) -> varchar",
+    "rtrim(varchar) -> varchar",
+    "reverse(varchar) -> varchar",
+    "concat(varchar...) -> varchar",
+    "substr(varchar, bigint) -> varchar",
+    "substr(varchar, bigint, bigint) -> varchar",
+    "replace(varchar, varchar) -> varchar",
+    "replace(varchar, varchar, varchar) -> varchar",
+    "lpad(varchar, bigint, varchar) -> varchar",
+    "rpad(varchar, bigint, varchar) -> varchar",
+    "split(varchar, varchar) -> array(varchar)",
+    "split(varchar, varchar, bigint) -> array(varchar)",
+    "split_part(varchar, varchar, bigint) -> varchar",
+    "split_to_map(varchar, varchar, varchar) -> map(varchar, varchar)",
+    "strpos(varchar, varchar) -> bigint",
+    "strpos(varchar, varchar, bigint) -> bigint",
+    "starts_with(varchar, varchar) -> boolean",
+    "ends_with(varchar, varchar) -> boolean",
+    "chr(bigint) -> varchar",
+    "codepoint(varchar) -> integer",
+    "levenshtein_distance(varchar, varchar) -> bigint",
+    "hamming_distance(varchar, varchar) -> bigint",
+    "normalize(varchar) -> varchar",
+    "to_utf8(varchar) -> varbinary",
+    "from_utf8(varbinary) -> varchar",
+    "regexp_like(varchar, varchar) -> boolean",
+    "regexp_extract(varchar, varchar) -> varchar",
+    "regexp_extract(varchar, varchar, bigint) -> varchar",
+    "regexp_extract_all(varchar, varchar) -> array(varchar)",
+    "regexp_extract_all(varchar, varchar, bigint) -> array(varchar)",
+    "regexp_replace(varchar, varchar) -> varchar",
+    "regexp_replace(varchar, varchar, varchar) -> varchar",
+    "regexp_split(varchar, varchar) -> array(varchar)",
+    "url_extract_host(varchar) -> varchar",
+    "url_extract_path(varchar) -> varchar",
+    "url_extract_parameter(varchar, varchar) -> varchar",
+    "url_encode(varchar) -> varchar",
+    "url_decode(varchar) -> varchar",
+    // Binary functions.
+    "length(varbinary) -> bigint",
+    "concat(varbinary...) -> varbinary",
+    "to_hex(varbinary) -> varchar",
+    "from_hex(varchar) -> varbinary",
+    "to_base64(varbinary) -> varchar",
+    "from_base64(varchar) -> varbinary",
+    "md5(varbinary) -> varbinary",
+    "sha1(varbinary) -> varbinary",
+    "sha256(varbinary) -> varbinary",
+    "sha512(varbinary) -> varbinary",
+    "xxhash64(varbinary) -> varbinary",
+    // JSON functions.
+    "json_parse(varchar) -> json",
+    "json_format(json) -> varchar",
+    "json_extract(json, varchar) -> json",
+    "json_extract(varchar, varchar) -> json",
+    "json_extract_scalar(json, varchar) -> varchar",
+    "json_extract_scalar(varchar, varchar) -> varchar",
+    "json_array_length(json) -> bigint",
+    "json_array_length(varchar) -> bigint",
+    "json_array_contains<T>(json, T) -> boolean",
+    "json_size(json, varchar) -> bigint",
+    "is_json_scalar(json) -> boolean",
+    // Date and time functions.
+    "now() -> timestamp with time zone",
+    "current_timezone() -> varchar",
+    "date(varchar) -> date",
+    "date(timestamp) -> date",
+    "date(timestamp with time zone) -> date",
+    "from_unixtime(double) -> timestamp",
+    "from_unixtime(double, varchar) -> timestamp with time zone",
+    "to_unixtime(timestamp) -> double",
+    "to_unixtime(timestamp with time zone) -> double",
+    "from_iso8601_timestamp(varchar) -> timestamp with time zone",
+    "from_iso8601_date(varchar) -> date",
+    "to_iso8601(date) -> varchar",
+    "to_iso8601(timestamp) -> varchar",
+    "to_iso8601(timestamp with time zone) -> varchar",
+    "to_milliseconds(interval day to second) -> bigint",
+    "date_trunc(varchar, date) -> date",
+    "date_trunc(varchar, timestamp) -> timestamp",
+    "date_trunc(varchar, timestamp with time zone) -> timestamp with time zone",
+    "date_add(varchar, bigint, date) -> date",
+    "date_add(varchar, bigint, timestamp) -> timestamp",
+    "date_add(varchar, bigint, timestamp with time zone) -> timestamp with time zone",
+    "date_diff(varchar, date, date) -> bigint",
+    "date_diff(varchar, timestamp, timestamp) -> bigint",
+    "date_diff(varchar, timestamp with time zone, timestamp with time zone) -> bigint",
+    "date_format(timestamp, varchar) -> varchar",
+    "date_format(timestamp with time zone, varchar) -> varchar",
+    "date_parse(varchar, varchar) -> timestamp",
+    "format_datetime(timestamp, varchar) -> varchar",
+    "format_datetime(timestamp with time zone, varchar) -> varchar",
+    "parse_datetime(varchar, varchar) -> timestamp with time zone",
+    "last_day_of_month(date) -> date",
+    "last_day_of_month(timestamp) -> date",
+    // Array functions.
+    "cardinality<T>(array(T)) -> bigint",
+    "element_at<T>(array(T), bigint) -> T",
+    "contains<T: comparable>(array(T), T) -> boolean",
+    "array_position<T: comparable>(array(T), T) -> bigint",
+    "array_distinct<T: comparable>(array(T)) -> array(T)",
+    "array_sort<T: orderable>(array(T)) -> array(T)",
+    "array_sort<T>(array(T), function(T, T, integer)) -> array(T)",
+    "array_max<T: orderable>(array(T)) -> T",
+    "array_min<T: orderable>(array(T)) -> T",
+    "array_join<T>(array(T), varchar) -> varchar",
+    "array_join<T>(array(T), varchar, varchar) -> varchar",
+    "array_remove<T: comparable>(array(T), T) -> array(T)",
+    "array_except<T: comparable>(array(T), array(T)) -> array(T)",
+    "array_intersect<T: comparable>(array(T), array(T)) -> array(T)",
+    "array_union<T: comparable>(array(T), array(T)) -> array(T)",
+    "arrays_overlap<T: comparable>(array(T), array(T)) -> boolean",
+    "concat<T>(array(T)...) -> array(T)",
+    "reverse<T>(array(T)) -> array(T)",
+    "shuffle<T>(array(T)) -> array(T)",
+    "slice<T>(array(T), bigint, bigint) -> array(T)",
+    "flatten<T>(array(array(T))) -> array(T)",
+    "repeat<T>(T, bigint) -> array(T)",
+    "sequence(bigint, bigint) -> array(bigint)",
+    "sequence(bigint, bigint, bigint) -> array(bigint)",
+    "sequence(date, date) -> array(date)",
+    "sequence(date, date, interval day to second) -> array(date)",
+    "sequence(date, date, interval year to month) -> array(date)",
+    "sequence(timestamp, timestamp, interval day to second) -> array(timestamp)",
+    "sequence(timestamp, timestamp, interval year to month) -> array(timestamp)",
+    "filter<T>(array(T), function(T, boolean)) -> array(T)",
+    "transform<T, U>(array(T), function(T, U)) -> array(U)",
+    "reduce<T, S, R>(array(T), S, function(S, T, S), function(S, R)) -> R",
+    "zip_with<T, U, R>(array(T), array(U), function(T, U, R)) -> array(R)",
+    "any_match<T>(array(T), function(T, boolean)) -> boolean",
+    "all_match<T>(array(T), function(T, boolean)) -> boolean",
+    "none_match<T>(array(T), function(T, boolean)) -> boolean",
+    // Map functions.
+    "map<K, V>() -> map(K, V)",
+    "map<K: comparable, V>(array(K), array(V)) -> map(K, V)",
+    "cardinality<K, V>(map(K, V)) -> bigint",
+    "element_at<K, V>(map(K, V), K) -> V",
+    "map_keys<K, V>(map(K, V)) -> array(K)",
+    "map_values<K, V>(map(K, V)) -> array(V)",
+    "map_concat<K, V>(map(K, V)...) -> map(K, V)",
+    "map_filter<K, V>(map(K, V), function(K, V, boolean)) -> map(K, V)",
+    "transform_keys<K, V, R>(map(K, V), function(K, V, R)) -> map(R, V)",
+    "transform_values<K, V, R>(map(K, V), function(K, V, R)) -> map(K, R)",
+    "map_zip_with<K, V, U, R>(map(K, V), map(K, U), function(K, V, U, R)) -> map(K, R)",
+];
+
+const AGGREGATE_FUNCTIONS: &[&str] = &[
+    "count() -> bigint",
+    "count<T>(T) -> bigint",
+    "count_if(boolean) -> bigint",
+    "sum(bigint) -> bigint",
+    "sum(real) -> real",
+    "sum(double) -> double",
+    "sum<T: decimal>(T) -> T",
+    "sum(interval day to second) -> interval day to second",
+    "sum(interval year to month) -> interval year to month",
+    "avg(bigint) -> double",
+    "avg(real) -> real",
+    "avg(double) -> double",
+    "avg<T: decimal>(T) -> T",
+    "avg(interval day to second) -> interval day to second",
+    "avg(interval year to month) -> interval year to month",
+    "min<T: orderable>(T) -> T",
+    "min<T: orderable>(T, bigint) -> array(T)",
+    "max<T: orderable>(T) -> T",
+    "max<T: orderable>(T, bigint) -> array(T)",
+    "min_by<T, U: orderable>(T, U) -> T",
+    "min_by<T, U: orderable>(T, U, bigint) -> array(T)",
+    "max_by<T, U: orderable>(T, U) -> T",
+    "max_by<T, U: orderable>(T, U, bigint) -> array(T)",
+    "arbitrary<T>(T) -> T",
+    "any_value<T>(T) -> T",
+    "array_agg<T>(T) -> array(T)",
+    "map_agg<K: comparable, V>(K, V) -> map(K, V)",
+    "multimap_agg<K: comparable, V>(K, V) -> map(K, array(V))",
+    "histogram<T: comparable>(T) -> map(T, bigint)",
+    "approx_distinct<T>(T) -> bigint",
+    "approx_distinct<T>(T, double) -> bigint",
+    "approx_percentile(bigint, double) -> bigint",
+    "approx_percentile(double, double) -> double",
+    "approx_percentile(bigint, array(double)) -> array(bigint)",
+    "approx_percentile(double, array(double)) -> array(double)",
+    "bool_and(boolean) -> boolean",
+    "bool_or(boolean) -> boolean",
+    "every(boolean) -> boolean",
+    "checksum<T>(T) -> varbinary",
+    "geometric_mean(double) -> double",
+    "stddev(double) -> double",
+    "stddev_samp(double) -> double",
+    "stddev_pop(double) -> double",
+    "variance(double) -> double",
+    "var_samp(double) -> double",
+    "var_pop(double) -> double",
+    "corr(double, double) -> double",
+    "covar_samp(double, double) -> double",
+    "covar_pop(double, double) -> double",
+    "regr_slope(double, double) -> double",
+    "regr_intercept(double, double) -> double",
+];
+
+const WINDOW_FUNCTIONS: &[&str] = &[
+    "row_number() -> bigint",
+    "rank() -> bigint",
+    "dense_rank() -> bigint",
+    "percent_rank() -> double",
+    "cume_dist() -> double",
+    "ntile(bigint) -> bigint",
+    "first_value<T>(T) -> T",
+    "last_value<T>(T) -> T",
+    "nth_value<T>(T, bigint) -> T",
+    "lag<T>(T) -> T",
+    "lag<T>(T, bigint) -> T",
+    "lag<T>(T, bigint, T) -> T",
+    "lead<T>(T) -> T",
+    "lead<T>(T, bigint) -> T",
+    "lead<T>(T, bigint, T) -> T",
+];
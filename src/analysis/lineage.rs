@@ -0,0 +1,330 @@
+//! Column lineage: the catalog table columns which each column written by
+//! INSERT INTO ... SELECT, CREATE TABLE AS SELECT or CREATE VIEW derives
+//! from, and how.
+//!
+//! Lineage is computed during resolution, as part of the fields of each
+//! relation and query, so it follows CTEs, subqueries, joins, UNNEST and
+//! set operations the same way column references do.
+use super::catalog::{Catalog, QualifiedTableName, Session};
+use super::resolver::resolve;
+use super::{identifier_list, qualified_name_parts};
+use crate::parsing::ParseTree;
+use crate::utils::Json;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// How a column derives from its sources. Derivations are ordered, so that
+/// the derivation of an expression is the greatest of its parts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Derivation {
+    /// A copy of a single source column.
+    Direct,
+    /// Computed from its sources by scalar expressions.
+    #[default]
+    Transformation,
+    /// Computed from its sources by aggregate functions.
+    Aggregation,
+}
+
+impl fmt::Display for Derivation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Derivation::Direct => write!(f, "direct"),
+            Derivation::Transformation => write!(f, "transformation"),
+            Derivation::Aggregation => write!(f, "aggregation"),
+        }
+    }
+}
+
+/// A column of a catalog table.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SourceColumn {
+    pub table: QualifiedTableName,
+    pub column: String,
+}
+
+impl fmt::Display for SourceColumn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.table, self.column)
+    }
+}
+
+/// The lineage of a column or expression. Constants have no sources.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Lineage {
+    pub sources: BTreeSet<SourceColumn>,
+    pub derivation: Derivation,
+}
+
+impl Lineage {
+    /// The lineage of a column of a catalog table.
+    pub fn column(table: &QualifiedTableName, column: &str) -> Lineage {
+        Lineage {
+            sources: vec![SourceColumn {
+                table: table.clone(),
+                column: column.to_string(),
+            }]
+            .into_iter()
+            .collect(),
+            derivation: Derivation::Direct,
+        }
+    }
+
+    /// Adds the sources of other, and makes the derivation at least as
+    /// strong as other's.
+    pub fn merge(&mut self, other: &Lineage) {
+        self.sources.extend(other.sources.iter().cloned());
+        self.derivation = self.derivation.max(other.derivation);
+    }
+}
+
+/// A column written by a statement, with its lineage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLineage {
+    pub name: String,
+    pub lineage: Lineage,
+}
+
+/// The lineage graph of a statement: an edge from each source column to
+/// each column of the target table which derives from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableLineage {
+    pub target: QualifiedTableName,
+    pub columns: Vec<ColumnLineage>,
+}
+
+impl TableLineage {
+    /// The graph as JSON:
+    ///
+    ///   {"target": "c.s.t", "columns": [{"name": "x", "derivation": "direct",
+    ///     "sources": [{"table": "c.s.u", "column": "y"}]}]}
+    pub fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("target", self.target.to_string().into()),
+            (
+                "columns",
+                Json::array(
+                    self.columns
+                        .iter()
+                        .map(|column| {
+                            Json::object(vec![
+                                ("name", column.name.as_str().into()),
+                                ("derivation", column.lineage.derivation.to_string().into()),
+                                (
+                                    "sources",
+                                    Json::array(
+                                        column
+                                            .lineage
+                                            .sources
+                                            .iter()
+                                            .map(|source| {
+                                                Json::object(vec![
+                                                    ("table", source.table.to_string().into()),
+                                                    ("column", source.column.as_str().into()),
+                                                ])
+                                            })
+                                            .collect(),
+                                    ),
+                                ),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+}
+
+/// The column lineage of an INSERT INTO, CREATE TABLE AS SELECT or CREATE
+/// VIEW statement; None for other statements, and for queries whose
+/// columns cannot be resolved.
+///
+/// INSERT INTO without a column list writes the columns of the target
+/// table in order; when the table is unknown, the columns are named after
+/// the query's columns.
+pub fn lineage(tree: &ParseTree, catalog: &dyn Catalog, session: &Session) -> Option<TableLineage> {
+    let statement = match tree {
+        ParseTree::Entrypoint(entrypoint) => &*entrypoint.tree,
+        tree => tree,
+    };
+    let (table_name, column_names) = match statement {
+        ParseTree::InsertInto(insert_into) => {
+            let table_name = session.qualify(&qualified_name_parts(&insert_into.table_name))?;
+            let column_names = match &*insert_into.column_aliases_opt {
+                ParseTree::Empty(_) => catalog.table(&table_name).map(|table| {
+                    table
+                        .columns
+                        .iter()
+                        .map(|column| column.name.clone())
+                        .collect()
+                }),
+                column_aliases => Some(identifier_list(column_aliases)),
+            };
+            (table_name, column_names)
+        }
+        ParseTree::CreateTableAsSelect(create_table_as_select) => (
+            session.qualify(&qualified_name_parts(&create_table_as_select.table_name))?,
+            None,
+        ),
+        ParseTree::CreateView(create_view) => (
+            session.qualify(&qualified_name_parts(&create_view.qualified_name))?,
+            None,
+        ),
+        _ => return None,
+    };
+    let output = resolve(tree, catalog, session).output?;
+    let columns = output
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let name = column_names
+                .as_ref()
+                .and_then(|names| names.get(index).cloned())
+                .or(field.name)
+                .unwrap_or_else(|| format!("_col{}", index));
+            ColumnLineage {
+                name,
+                lineage: field.lineage,
+            }
+        })
+        .collect();
+    Some(TableLineage {
+        target: table_name,
+        columns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{ddl, MemoryCatalog};
+    use crate::parsing::parse_statement;
+
+    /// Each column written by the statement, as
+    /// "name: derivation <- table.column, ...".
+    fn lineages(text: &str) -> Vec<String> {
+        let session = Session::new("hive", "web");
+        let mut catalog = MemoryCatalog::new();
+        ddl::load_script(
+            &mut catalog,
+            &session,
+            "create table orders (id bigint, customer_id bigint, total double, tags array(varchar));
+             create table customers (id bigint, name varchar)",
+        );
+        let (tree, errors) = parse_statement(text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        lineage(&tree, &catalog, &session)
+            .expect("lineage")
+            .columns
+            .iter()
+            .map(|column| {
+                let sources: Vec<String> = column
+                    .lineage
+                    .sources
+                    .iter()
+                    .map(|source| source.to_string())
+                    .collect();
+                format!(
+                    "{}: {} <- {}",
+                    column.name,
+                    column.lineage.derivation,
+                    sources.join(", ")
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn derivations() {
+        assert_eq!(
+            lineages(
+                "create table t as
+                 select id, total * 2 as doubled, 1 as one, o.customer_id + o.id as sum
+                 from orders o"
+            ),
+            vec![
+                "id: direct <- hive.web.orders.id",
+                "doubled: transformation <- hive.web.orders.total",
+                "one: transformation <- ",
+                "sum: transformation <- hive.web.orders.customer_id, hive.web.orders.id",
+            ]
+        );
+        assert_eq!(
+            lineages(
+                "create view v as
+                 select c.name, sum(o.total) as spent, count(*) as n
+                 from orders o join customers c on o.customer_id = c.id
+                 group by c.name"
+            ),
+            vec![
+                "name: direct <- hive.web.customers.name",
+                "spent: aggregation <- hive.web.orders.total",
+                "n: aggregation <- ",
+            ]
+        );
+    }
+
+    #[test]
+    fn insert_names_columns_after_the_target() {
+        assert_eq!(
+            lineages(
+                "insert into customers select customer_id, cast(total as varchar) from orders"
+            ),
+            vec![
+                "id: direct <- hive.web.orders.customer_id",
+                "name: transformation <- hive.web.orders.total",
+            ]
+        );
+        assert_eq!(
+            lineages("insert into customers (name) select cast(id as varchar) from orders"),
+            vec!["name: transformation <- hive.web.orders.id"]
+        );
+    }
+
+    #[test]
+    fn union_merges_sources() {
+        assert_eq!(
+            lineages(
+                "create table ids as
+                 select id from orders union all select id from customers"
+            ),
+            vec!["id: direct <- hive.web.customers.id, hive.web.orders.id"]
+        );
+    }
+
+    #[test]
+    fn through_ctes_and_subqueries() {
+        assert_eq!(
+            lineages(
+                "create table t as
+                 with totals as (select customer_id, sum(total) as spent from orders group by 1)
+                 select name, spent
+                 from customers join (select * from totals) s on customers.id = s.customer_id"
+            ),
+            vec![
+                "name: direct <- hive.web.customers.name",
+                "spent: aggregation <- hive.web.orders.total",
+            ]
+        );
+    }
+
+    #[test]
+    fn unnest() {
+        assert_eq!(
+            lineages(
+                "create table t as select id, tag from orders cross join unnest(tags) as u (tag)"
+            ),
+            vec![
+                "id: direct <- hive.web.orders.id",
+                "tag: transformation <- hive.web.orders.tags",
+            ]
+        );
+    }
+
+    #[test]
+    fn other_statements_have_no_lineage() {
+        let session = Session::new("hive", "web");
+        let (tree, _) = parse_statement("select * from orders");
+        assert_eq!(lineage(&tree, &MemoryCatalog::new(), &session), None);
+    }
+}
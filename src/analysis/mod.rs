@@ -0,0 +1,107 @@
+//! Semantic analysis of parse trees.
+//!
+//! Analyses take a parse tree without syntax errors, along with a Catalog
+//! describing the tables it may reference, and report their findings as
+//! SyntaxErrors with error codes in the 300-399 range.
+use crate::lexing::{token, TokenKind};
+use crate::parsing::ParseTree;
+
+pub mod aggregation;
+pub mod catalog;
+pub mod classification;
+pub mod ddl;
+pub mod diff;
+pub mod fingerprint;
+pub mod functions;
+pub mod lineage;
+pub mod references;
+pub mod resolver;
+pub mod types;
+pub mod windows;
+
+pub use self::catalog::{Catalog, MemoryCatalog, Session};
+pub use self::classification::{classify, classify_text};
+pub use self::fingerprint::fingerprint;
+pub use self::functions::FunctionKind;
+pub use self::resolver::resolve;
+
+/// The canonical name of an identifier token.
+///
+/// Presto's connectors store catalog, schema, table and column names in
+/// lower case, so catalog lookups lower case delimited identifiers too,
+/// unlike Identifier equality.
+pub fn identifier_name(token: &token::Token) -> String {
+    match token.identifier_value() {
+        Ok(identifier) => identifier.name.to_lowercase(),
+        Err(_) => token.value.to_lowercase(),
+    }
+}
+
+/// The canonical name of a tree which is an identifier token; None for
+/// other trees.
+pub fn tree_name(tree: &ParseTree) -> Option<String> {
+    match tree {
+        ParseTree::Token(token) => Some(identifier_name(&token.token)),
+        ParseTree::Identifier(identifier) => tree_name(&identifier.value),
+        _ => None,
+    }
+}
+
+/// The value of a string literal.
+pub fn string_value(tree: &ParseTree) -> Option<String> {
+    match tree {
+        ParseTree::Literal(literal) => string_value(&literal.value),
+        ParseTree::Token(token)
+            if matches!(
+                token.token.kind,
+                TokenKind::String | TokenKind::UnicodeString
+            ) =>
+        {
+            token.token.string_value().ok()
+        }
+        ParseTree::Token(token) => Some(token.token.value.to_string()),
+        ParseTree::UnicodeString(unicode_string) => unicode_string.decode().ok(),
+        _ => None,
+    }
+}
+
+/// The canonical names of the parts of a QualifiedName tree.
+pub fn qualified_name_parts(tree: &ParseTree) -> Vec<String> {
+    match tree {
+        ParseTree::QualifiedName(qualified_name) => match &*qualified_name.names {
+            ParseTree::List(names) => names
+                .elements_and_separators
+                .iter()
+                .filter_map(|(name, _)| tree_name(name))
+                .collect(),
+            names => tree_name(names).into_iter().collect(),
+        },
+        _ => tree_name(tree).into_iter().collect(),
+    }
+}
+
+/// The canonical names in a list of identifiers, such as column aliases.
+pub fn identifier_list(tree: &ParseTree) -> Vec<String> {
+    match tree {
+        ParseTree::List(list) => list
+            .elements_and_separators
+            .iter()
+            .filter_map(|(element, _)| tree_name(element))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// The elements of a List tree; a single tree for other trees, and none
+/// for Empty trees.
+pub fn list_elements<'t, 'a>(tree: &'t ParseTree<'a>) -> Vec<&'t ParseTree<'a>> {
+    match tree {
+        ParseTree::List(list) => list
+            .elements_and_separators
+            .iter()
+            .map(|(element, _)| element)
+            .collect(),
+        ParseTree::Empty(_) => Vec::new(),
+        _ => vec![tree],
+    }
+}
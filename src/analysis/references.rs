@@ -0,0 +1,438 @@
+//! Table references: the tables a statement reads and writes, for access
+//! control.
+//!
+//! References are found from the parse tree alone: unqualified names are
+//! qualified with the session's catalog and schema, and names of WITH
+//! queries in scope are not table references.
+//!
+//! DROP TABLE, DROP VIEW and ALTER TABLE are not parsed yet; from_text
+//! finds their tables from the statement's leading words. References of
+//! statements which are not understood are marked incomplete, and should
+//! be treated as accessing unknown tables.
+use super::catalog::{QualifiedTableName, Session};
+use super::{identifier_name, list_elements, qualified_name_parts, tree_name};
+use crate::lexing::{token, Lexer, TokenKind};
+use crate::parsing::{parse_statement, visit_pre_order, ParseTree};
+use crate::utils::{Json, TextRange};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// How a statement accesses a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AccessMode {
+    Read,
+    Insert,
+    Delete,
+    /// CREATE TABLE, CREATE TABLE AS SELECT and CREATE VIEW, and the new
+    /// name of ALTER TABLE ... RENAME TO.
+    Create,
+    /// DROP TABLE and DROP VIEW.
+    Drop,
+    /// ALTER TABLE.
+    Alter,
+}
+
+impl AccessMode {
+    pub fn is_write(self) -> bool {
+        self != AccessMode::Read
+    }
+}
+
+impl fmt::Display for AccessMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessMode::Read => write!(f, "read"),
+            AccessMode::Insert => write!(f, "insert"),
+            AccessMode::Delete => write!(f, "delete"),
+            AccessMode::Create => write!(f, "create"),
+            AccessMode::Drop => write!(f, "drop"),
+            AccessMode::Alter => write!(f, "alter"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableReference {
+    pub table: QualifiedTableName,
+    /// The range of the table's name.
+    pub range: TextRange,
+    pub mode: AccessMode,
+}
+
+/// The table references of a statement, in source order.
+#[derive(Clone, Debug, Default)]
+pub struct TableReferences {
+    pub references: Vec<TableReference>,
+    /// False when the statement was not fully understood, such as a
+    /// statement with syntax errors; it may access other tables.
+    pub is_complete: bool,
+}
+
+impl TableReferences {
+    /// The table references of a statement, an Entrypoint or a query.
+    /// Names with more than three parts are not references. Trees
+    /// containing errors are incomplete.
+    pub fn new(tree: &ParseTree, session: &Session) -> TableReferences {
+        let mut collector = Collector {
+            session,
+            references: Vec::new(),
+        };
+        collector.statement(tree);
+        let mut references = collector.references;
+        references
+            .sort_by_key(|reference| (reference.range.start.line, reference.range.start.column));
+        let mut is_complete = true;
+        visit_pre_order(tree, &mut |tree| is_complete &= !tree.is_error());
+        TableReferences {
+            references,
+            is_complete,
+        }
+    }
+
+    /// The table references of the statement in text. Statements which do
+    /// not parse are recognized by their leading words if they are DROP
+    /// TABLE, DROP VIEW or ALTER TABLE, and are incomplete otherwise.
+    pub fn from_text(text: &str, session: &Session) -> TableReferences {
+        let (tree, errors) = parse_statement(text);
+        if errors.is_empty() {
+            return TableReferences::new(&tree, session);
+        }
+        match leading_words_references(text, session) {
+            Some(references) => TableReferences {
+                references,
+                is_complete: true,
+            },
+            None => TableReferences {
+                is_complete: false,
+                ..TableReferences::new(&tree, session)
+            },
+        }
+    }
+
+    /// The tables which are read.
+    pub fn reads(&self) -> BTreeSet<&QualifiedTableName> {
+        self.tables(|mode| !mode.is_write())
+    }
+
+    /// The tables which are inserted into, deleted from, created, dropped
+    /// or altered.
+    pub fn writes(&self) -> BTreeSet<&QualifiedTableName> {
+        self.tables(AccessMode::is_write)
+    }
+
+    fn tables(&self, predicate: impl Fn(AccessMode) -> bool) -> BTreeSet<&QualifiedTableName> {
+        self.references
+            .iter()
+            .filter(|reference| predicate(reference.mode))
+            .map(|reference| &reference.table)
+            .collect()
+    }
+
+    /// The references as JSON:
+    ///
+    ///   {"reads": ["c.s.u"], "writes": ["c.s.t"], "complete": true,
+    ///     "references": [{"table": "c.s.t", "mode": "insert", "range": ...},
+    ///     ...]}
+    pub fn to_json(&self) -> Json {
+        let names = |tables: BTreeSet<&QualifiedTableName>| {
+            Json::array(
+                tables
+                    .into_iter()
+                    .map(|table| Json::from(table.to_string()))
+                    .collect(),
+            )
+        };
+        Json::object(vec![
+            ("reads", names(self.reads())),
+            ("writes", names(self.writes())),
+            ("complete", self.is_complete.into()),
+            (
+                "references",
+                Json::array(
+                    self.references
+                        .iter()
+                        .map(|reference| {
+                            Json::object(vec![
+                                ("table", reference.table.to_string().into()),
+                                ("mode", reference.mode.to_string().into()),
+                                ("range", reference.range.to_json()),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+}
+
+struct Collector<'s> {
+    session: &'s Session,
+    references: Vec<TableReference>,
+}
+
+impl<'s> Collector<'s> {
+    fn statement(&mut self, tree: &ParseTree) {
+        match tree {
+            ParseTree::Entrypoint(entrypoint) => self.statement(&entrypoint.tree),
+            ParseTree::InsertInto(insert_into) => {
+                self.reference(&insert_into.table_name, &[], AccessMode::Insert);
+                self.tree(&insert_into.query, &[]);
+            }
+            ParseTree::Delete(delete) => {
+                self.reference(&delete.table_name, &[], AccessMode::Delete);
+                self.tree(&delete.predicate, &[]);
+            }
+            ParseTree::CreateTable(create_table) => {
+                self.reference(&create_table.table_name, &[], AccessMode::Create);
+                self.tree(&create_table.table_elements, &[]);
+            }
+            ParseTree::CreateTableAsSelect(create_table_as_select) => {
+                self.reference(&create_table_as_select.table_name, &[], AccessMode::Create);
+                self.tree(&create_table_as_select.query, &[]);
+            }
+            ParseTree::CreateView(create_view) => {
+                self.reference(&create_view.qualified_name, &[], AccessMode::Create);
+                self.tree(&create_view.query, &[]);
+            }
+            tree => self.tree(tree, &[]),
+        }
+    }
+
+    /// Collects the tables read by tree; ctes are the names of the WITH
+    /// queries in scope.
+    fn tree(&mut self, tree: &ParseTree, ctes: &[String]) {
+        match tree {
+            ParseTree::Query(query) => {
+                let mut ctes = ctes.to_vec();
+                if let ParseTree::With(with) = &*query.with {
+                    let is_recursive = !with.recursive.is_empty();
+                    for named_query in list_elements(&with.named_queries) {
+                        if let ParseTree::NamedQuery(named_query) = named_query {
+                            // A WITH query is visible to the following ones,
+                            // and to itself if it is recursive.
+                            let name = tree_name(&named_query.name);
+                            if is_recursive {
+                                ctes.extend(name.clone());
+                            }
+                            self.tree(&named_query.query, &ctes);
+                            if !is_recursive {
+                                ctes.extend(name);
+                            }
+                        }
+                    }
+                }
+                self.tree(&query.query_no_with, &ctes);
+            }
+            ParseTree::TableName(table_name) => {
+                self.reference(&table_name.name, ctes, AccessMode::Read)
+            }
+            ParseTree::Table(table) => {
+                self.reference(&table.qualified_name, ctes, AccessMode::Read)
+            }
+            ParseTree::LikeClause(like_clause) => {
+                self.reference(&like_clause.name, &[], AccessMode::Read)
+            }
+            tree => {
+                for child in tree.children() {
+                    self.tree(child, ctes);
+                }
+            }
+        }
+    }
+
+    fn reference(&mut self, name: &ParseTree, ctes: &[String], mode: AccessMode) {
+        let parts = qualified_name_parts(name);
+        if let [part] = parts.as_slice() {
+            if ctes.contains(part) {
+                return;
+            }
+        }
+        if let Some(table) = self.session.qualify(&parts) {
+            self.references.push(TableReference {
+                table,
+                range: name.get_range(),
+                mode,
+            });
+        }
+    }
+}
+
+/// The references of a DROP TABLE, DROP VIEW or ALTER TABLE statement from
+/// its leading words; None for other statements, and for names which cannot
+/// be read.
+fn leading_words_references(text: &str, session: &Session) -> Option<Vec<TableReference>> {
+    let tokens: Vec<token::Token> = Lexer::new(text)
+        .take_while(|token| token.kind != TokenKind::EndOfFile)
+        .collect();
+    let word = |index: usize| {
+        tokens
+            .get(index)
+            .map_or(String::new(), |token| token.value.to_lowercase())
+    };
+    let (mode, mut index) = match (word(0).as_str(), word(1).as_str()) {
+        ("drop", "table") | ("drop", "view") => (AccessMode::Drop, 2),
+        ("alter", "table") => (AccessMode::Alter, 2),
+        _ => return None,
+    };
+    if word(index) == "if" && word(index + 1) == "exists" {
+        index += 2;
+    }
+    let (table, range, index) = token_name(&tokens, index, session)?;
+    let mut references = vec![TableReference { table, range, mode }];
+    if mode == AccessMode::Alter && word(index) == "rename" && word(index + 1) == "to" {
+        let (table, range, _) = token_name(&tokens, index + 2, session)?;
+        references.push(TableReference {
+            table,
+            range,
+            mode: AccessMode::Create,
+        });
+    }
+    Some(references)
+}
+
+/// The table named by the tokens starting at index, with its range and the
+/// index of the token after the name.
+fn token_name(
+    tokens: &[token::Token],
+    mut index: usize,
+    session: &Session,
+) -> Option<(QualifiedTableName, TextRange, usize)> {
+    let start = tokens.get(index)?.range.start;
+    let mut parts = Vec::new();
+    loop {
+        let token = tokens.get(index)?;
+        let is_name = token.kind.is_keyword() || token.identifier_value().is_ok();
+        if !is_name {
+            return None;
+        }
+        parts.push(identifier_name(token));
+        index += 1;
+        match tokens.get(index) {
+            Some(period) if period.kind == TokenKind::Period => index += 1,
+            _ => break,
+        }
+    }
+    let range = TextRange::new(start, tokens[index - 1].range.end);
+    Some((session.qualify(&parts)?, range, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn references(text: &str) -> TableReferences {
+        TableReferences::from_text(text, &Session::new("hive", "web"))
+    }
+
+    fn names(tables: BTreeSet<&QualifiedTableName>) -> Vec<String> {
+        tables.into_iter().map(|table| table.to_string()).collect()
+    }
+
+    fn modes(text: &str) -> Vec<(String, AccessMode)> {
+        let references = references(text);
+        assert!(references.is_complete, "{}", text);
+        references
+            .references
+            .into_iter()
+            .map(|reference| (reference.table.to_string(), reference.mode))
+            .collect()
+    }
+
+    #[test]
+    fn reads_and_writes() {
+        let references = references(
+            "insert into sales.t select * from u join other.s.v on true \
+             where exists (select 1 from w)",
+        );
+        assert!(references.is_complete);
+        assert_eq!(names(references.writes()), vec!["hive.sales.t"]);
+        assert_eq!(
+            names(references.reads()),
+            vec!["hive.web.u", "hive.web.w", "other.s.v"]
+        );
+        assert_eq!(
+            modes("delete from t where a in (select a from u)"),
+            vec![
+                ("hive.web.t".to_string(), AccessMode::Delete),
+                ("hive.web.u".to_string(), AccessMode::Read),
+            ]
+        );
+        assert_eq!(
+            modes("create table t (like u)"),
+            vec![
+                ("hive.web.t".to_string(), AccessMode::Create),
+                ("hive.web.u".to_string(), AccessMode::Read),
+            ]
+        );
+        assert_eq!(
+            modes("create view v as table u"),
+            vec![
+                ("hive.web.v".to_string(), AccessMode::Create),
+                ("hive.web.u".to_string(), AccessMode::Read),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_queries_are_not_tables() {
+        assert_eq!(
+            modes("with a as (select * from t), b as (select * from a) select * from a, b, c"),
+            vec![
+                ("hive.web.t".to_string(), AccessMode::Read),
+                ("hive.web.c".to_string(), AccessMode::Read),
+            ]
+        );
+        // Without RECURSIVE, a WITH query does not see itself.
+        assert_eq!(
+            modes("with a as (select * from a) select * from a"),
+            vec![("hive.web.a".to_string(), AccessMode::Read)]
+        );
+    }
+
+    #[test]
+    fn drop_and_alter() {
+        assert_eq!(
+            modes("DROP TABLE secret"),
+            vec![("hive.web.secret".to_string(), AccessMode::Drop)]
+        );
+        assert_eq!(
+            modes("drop view if exists other.\"Report\""),
+            vec![("hive.other.report".to_string(), AccessMode::Drop)]
+        );
+        assert_eq!(
+            modes("alter table t add column c bigint"),
+            vec![("hive.web.t".to_string(), AccessMode::Alter)]
+        );
+        assert_eq!(
+            modes("alter table if exists c.s.t rename to u"),
+            vec![
+                ("c.s.t".to_string(), AccessMode::Alter),
+                ("hive.web.u".to_string(), AccessMode::Create),
+            ]
+        );
+        let references = references("drop table secret");
+        assert_eq!(names(references.writes()), vec!["hive.web.secret"]);
+        assert!(references.reads().is_empty());
+        let range = references.references[0].range;
+        assert_eq!((range.start.column, range.end.column), (11, 17));
+    }
+
+    #[test]
+    fn statements_not_understood_are_incomplete() {
+        for text in &[
+            "drop table",
+            "drop table a.b.c.d",
+            "drop schema s",
+            "update t set a = 1",
+            "select * from t where",
+        ] {
+            assert!(!references(text).is_complete, "{}", text);
+        }
+        // The parse tree of an unparsed statement is an error.
+        let session = Session::new("hive", "web");
+        let (tree, _) = parse_statement("drop table secret");
+        let references = TableReferences::new(&tree, &session);
+        assert!(references.references.is_empty());
+        assert!(!references.is_complete);
+    }
+}
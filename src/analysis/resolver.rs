@@ -0,0 +1,2550 @@
+//! Name resolution: binds the relation and column references of a
+//! statement to the objects they refer to.
+//!
+//! Relations are bound to catalog tables or to CTEs (With queries).
+//! Columns are bound to catalog table columns where possible; columns
+//! computed by subqueries, CTEs, UNNEST and VALUES are bound to the
+//! derived relation which computes them.
+//!
+//! Scopes follow Presto's rules: a With clause makes its queries visible
+//! to the following queries of the clause and to the body of the query;
+//! a FROM clause makes its relations visible to the rest of its query
+//! specification, and to subqueries within it, which may be correlated;
+//! UNNEST and LATERAL see the relations to their left in the FROM clause;
+//! ORDER BY sees the output columns of its query before the input columns.
+//!
+//! While resolving, the type of every expression is inferred, following
+//! Presto's implicit coercion rules, and type errors are reported: operators
+//! applied to the wrong types, non-boolean predicates, and lists of values
+//! which have no common type.
+use super::aggregation;
+use super::catalog::{Catalog, QualifiedTableName, Session};
+use super::functions::{ArgumentType, FunctionKind, TypeSignature};
+use super::lineage::{Derivation, Lineage};
+use super::types::{self, DataType, RowField};
+use super::windows;
+use super::{identifier_list, list_elements, qualified_name_parts, string_value, tree_name};
+use crate::lexing::TokenKind;
+use crate::parsing::{parse_tree, ParseTree};
+use crate::utils::syntax_error::{
+    ERROR_AMBIGUOUS_COLUMN, ERROR_COLUMN_ALIAS_COUNT, ERROR_DUPLICATE_WITH_QUERY,
+    ERROR_INCOMPATIBLE_TYPES, ERROR_INVALID_ARGUMENTS, ERROR_INVALID_FUNCTION_CALL,
+    ERROR_INVALID_SUBSCRIPT, ERROR_INVALID_TABLE_NAME, ERROR_NOT_BOOLEAN, ERROR_TYPE_MISMATCH,
+    ERROR_UNKNOWN_COLUMN, ERROR_UNKNOWN_FIELD, ERROR_UNKNOWN_FUNCTION, ERROR_UNKNOWN_RELATION,
+    ERROR_UNKNOWN_TABLE,
+};
+use crate::utils::{Message, SyntaxError, TextRange};
+use std::fmt;
+
+/// What a relation reference refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RelationTarget {
+    Table(QualifiedTableName),
+    /// A With query; range is the range of the NamedQuery.
+    Cte {
+        name: String,
+        range: TextRange,
+    },
+}
+
+/// What a column reference refers to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ColumnTarget {
+    Table {
+        table: QualifiedTableName,
+        column: String,
+    },
+    /// A column computed by a subquery, CTE, UNNEST or VALUES.
+    /// relation is the name of the CTE or the alias of the relation.
+    Derived {
+        relation: Option<String>,
+        column: Option<String>,
+        index: usize,
+    },
+    LambdaParameter {
+        name: String,
+    },
+    /// An output column of the enclosing query, referenced from ORDER BY.
+    Output {
+        index: usize,
+    },
+}
+
+impl fmt::Display for RelationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelationTarget::Table(table) => write!(f, "table {}", table),
+            RelationTarget::Cte { name, range } => write!(f, "with query {} at {}", name, range),
+        }
+    }
+}
+
+impl fmt::Display for ColumnTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnTarget::Table { table, column } => write!(f, "column {}.{}", table, column),
+            ColumnTarget::Derived {
+                relation,
+                column,
+                index,
+            } => write!(
+                f,
+                "derived column {} of {}",
+                column.as_deref().unwrap_or(&format!("#{}", index + 1)),
+                relation.as_deref().unwrap_or("a subquery")
+            ),
+            ColumnTarget::LambdaParameter { name } => write!(f, "lambda parameter {}", name),
+            ColumnTarget::Output { index } => write!(f, "output column #{}", index + 1),
+        }
+    }
+}
+
+/// A column of a relation, or an output column of a query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Field {
+    /// None for unnamed columns, such as expressions without an alias.
+    pub name: Option<String>,
+    pub target: ColumnTarget,
+    pub data_type: DataType,
+    /// The catalog table columns the column derives from.
+    pub lineage: Lineage,
+}
+
+/// The columns of a relation or query; None when they cannot be known,
+/// for example when a table does not exist.
+pub type Fields = Option<Vec<Field>>;
+
+#[derive(Clone, Debug)]
+pub struct RelationBinding {
+    /// The range of the name of the relation.
+    pub range: TextRange,
+    pub target: RelationTarget,
+}
+
+#[derive(Clone, Debug)]
+pub struct ColumnBinding {
+    /// The range of the Identifier or Dereference referencing the column.
+    pub range: TextRange,
+    pub target: ColumnTarget,
+}
+
+/// A call of a registered function.
+#[derive(Clone, Debug)]
+pub struct FunctionBinding {
+    /// The range of the FunctionCall.
+    pub range: TextRange,
+    pub name: String,
+    pub kind: FunctionKind,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExpressionType {
+    /// The range of the expression.
+    pub range: TextRange,
+    pub data_type: DataType,
+}
+
+/// The result of resolve().
+#[derive(Clone, Debug, Default)]
+pub struct Resolution {
+    pub relations: Vec<RelationBinding>,
+    pub columns: Vec<ColumnBinding>,
+    /// The output columns of the statement's query, if it has one.
+    pub output: Fields,
+    /// The types of the expressions, innermost first.
+    pub types: Vec<ExpressionType>,
+    /// The calls of registered functions.
+    pub functions: Vec<FunctionBinding>,
+    pub errors: Vec<SyntaxError>,
+}
+
+impl Resolution {
+    /// The target of the column reference with the given range.
+    pub fn column(&self, range: TextRange) -> Option<&ColumnTarget> {
+        self.columns
+            .iter()
+            .find(|binding| binding.range == range)
+            .map(|binding| &binding.target)
+    }
+
+    /// The target of the relation reference with the given range.
+    pub fn relation(&self, range: TextRange) -> Option<&RelationTarget> {
+        self.relations
+            .iter()
+            .find(|binding| binding.range == range)
+            .map(|binding| &binding.target)
+    }
+
+    /// The type of the expression with the given range. When expressions
+    /// share a range, the outermost one's type.
+    pub fn expression_type(&self, range: TextRange) -> Option<&DataType> {
+        self.types
+            .iter()
+            .rev()
+            .find(|expression_type| expression_type.range == range)
+            .map(|expression_type| &expression_type.data_type)
+    }
+}
+
+/// Resolves the names in a statement, and infers the types of its
+/// expressions.
+/// tree may be an Entrypoint, a statement or a query.
+pub fn resolve(tree: &ParseTree, catalog: &dyn Catalog, session: &Session) -> Resolution {
+    let mut resolver = Resolver {
+        catalog,
+        session,
+        resolution: Resolution::default(),
+        lineages: Vec::new(),
+    };
+    resolver.resolve_statement(tree, &Scope::root());
+    let mut resolution = resolver.resolution;
+    resolution.errors.sort_by_key(|error| {
+        let range = error.get_range();
+        (range.start.line, range.start.column)
+    });
+    resolution
+}
+
+/// A relation visible in a scope.
+#[derive(Clone, Debug)]
+struct ScopeRelation {
+    /// The names which may qualify references to the relation's columns.
+    /// A table t in schema s of catalog c has [t], [s, t] and [c, s, t];
+    /// an aliased relation has only its alias. Unaliased subqueries have none.
+    qualifiers: Vec<Vec<String>>,
+    fields: Fields,
+}
+
+impl ScopeRelation {
+    fn is_qualified_by(&self, qualifier: &[String]) -> bool {
+        self.qualifiers.iter().any(|name| name == qualifier)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Cte {
+    name: String,
+    range: TextRange,
+    fields: Fields,
+}
+
+struct Scope<'p> {
+    parent: Option<&'p Scope<'p>>,
+    ctes: Vec<Cte>,
+    relations: Vec<ScopeRelation>,
+    lambda_parameters: Vec<(String, DataType)>,
+    /// Columns joined with USING, which are not ambiguous.
+    using_columns: Vec<String>,
+    /// The output columns, for ORDER BY.
+    outputs: Option<Vec<Field>>,
+}
+
+impl<'p> Scope<'p> {
+    fn root() -> Scope<'static> {
+        Scope {
+            parent: None,
+            ctes: Vec::new(),
+            relations: Vec::new(),
+            lambda_parameters: Vec::new(),
+            using_columns: Vec::new(),
+            outputs: None,
+        }
+    }
+
+    fn child(parent: &'p Scope<'p>) -> Scope<'p> {
+        Scope {
+            parent: Some(parent),
+            ..Scope::root()
+        }
+    }
+
+    fn find_cte(&self, name: &str) -> Option<&Cte> {
+        match self.ctes.iter().rev().find(|cte| cte.name == name) {
+            Some(cte) => Some(cte),
+            None => self.parent.and_then(|parent| parent.find_cte(name)),
+        }
+    }
+
+    /// Is qualifier the name of a relation in this scope or an outer scope.
+    fn has_relation(&self, qualifier: &[String]) -> bool {
+        self.relations
+            .iter()
+            .any(|relation| relation.is_qualified_by(qualifier))
+            || self
+                .parent
+                .is_some_and(|parent| parent.has_relation(qualifier))
+    }
+
+    fn lookup(&self, qualifier: &[String], name: &str) -> Lookup {
+        if qualifier.is_empty() {
+            let parameter = self
+                .lambda_parameters
+                .iter()
+                .find(|(parameter, _)| parameter == name);
+            if let Some((_, data_type)) = parameter {
+                return Lookup::Found(Field {
+                    name: Some(name.to_string()),
+                    target: ColumnTarget::LambdaParameter {
+                        name: name.to_string(),
+                    },
+                    data_type: data_type.clone(),
+                    lineage: Lineage::default(),
+                });
+            }
+            if let Some(outputs) = &self.outputs {
+                let index = outputs
+                    .iter()
+                    .position(|output| output.name.as_deref() == Some(name));
+                if let Some(index) = index {
+                    return Lookup::Found(Field {
+                        target: ColumnTarget::Output { index },
+                        ..outputs[index].clone()
+                    });
+                }
+            }
+        }
+
+        let mut matches = Vec::new();
+        let mut relation_matched = false;
+        let mut maybe = false;
+        for relation in &self.relations {
+            if !qualifier.is_empty() && !relation.is_qualified_by(qualifier) {
+                continue;
+            }
+            relation_matched = true;
+            match &relation.fields {
+                Some(fields) => matches.extend(
+                    fields
+                        .iter()
+                        .filter(|field| field.name.as_deref() == Some(name))
+                        .cloned(),
+                ),
+                None => maybe = true,
+            }
+        }
+        match matches.len() {
+            1 => Lookup::Found(matches.remove(0)),
+            0 if maybe => Lookup::Unknown,
+            0 if relation_matched && !qualifier.is_empty() => Lookup::NotFound,
+            0 => match self.parent {
+                Some(parent) => parent.lookup(qualifier, name),
+                None => Lookup::NotFound,
+            },
+            _ => {
+                if qualifier.is_empty() && self.using_columns.iter().any(|column| column == name) {
+                    Lookup::Found(matches.remove(0))
+                } else {
+                    Lookup::Ambiguous
+                }
+            }
+        }
+    }
+
+    /// All fields of all relations, for SELECT *.
+    fn all_fields(&self) -> Fields {
+        let mut result = Vec::new();
+        for relation in &self.relations {
+            result.extend(relation.fields.as_ref()?.iter().cloned());
+        }
+        Some(result)
+    }
+}
+
+enum Lookup {
+    Found(Field),
+    /// The column may belong to a relation whose columns are unknown.
+    Unknown,
+    Ambiguous,
+    NotFound,
+}
+
+/// Names the computed columns of a query after the relation they become.
+fn name_derived_fields(fields: Fields, relation: &str) -> Fields {
+    fields.map(|fields| {
+        fields
+            .into_iter()
+            .map(|field| match field.target {
+                ColumnTarget::Derived {
+                    relation: None,
+                    column,
+                    index,
+                } => Field {
+                    name: field.name,
+                    target: ColumnTarget::Derived {
+                        relation: Some(relation.to_string()),
+                        column,
+                        index,
+                    },
+                    data_type: field.data_type,
+                    lineage: field.lineage,
+                },
+                _ => field,
+            })
+            .collect()
+    })
+}
+
+/// The fields of a derived relation, from the names, types and lineages of
+/// its columns.
+fn derived_fields(
+    columns: Vec<(Option<String>, DataType, Lineage)>,
+    relation: Option<&str>,
+) -> Vec<Field> {
+    columns
+        .into_iter()
+        .enumerate()
+        .map(|(index, (name, data_type, lineage))| Field {
+            name: name.clone(),
+            target: ColumnTarget::Derived {
+                relation: relation.map(|relation| relation.to_string()),
+                column: name,
+                index,
+            },
+            data_type,
+            lineage,
+        })
+        .collect()
+}
+
+/// The columns produced by unnesting a value of a type: arrays of rows
+/// produce a column per field, maps a key and a value column.
+fn unnest_column_types(data_type: &DataType) -> Vec<DataType> {
+    match data_type {
+        DataType::Array(element) => match &**element {
+            DataType::Row(fields) => fields.iter().map(|field| field.data_type.clone()).collect(),
+            element => vec![element.clone()],
+        },
+        DataType::Map(key, value) => vec![(**key).clone(), (**value).clone()],
+        _ => vec![DataType::Unknown],
+    }
+}
+
+/// The distinct types in a list, for error messages.
+fn type_list(types: &[DataType]) -> String {
+    let mut names: Vec<String> = Vec::new();
+    for data_type in types {
+        let name = data_type.to_string();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names.join(", ")
+}
+
+/// The parts of a reference like a.b.c, along with the tree ending at
+/// each part. None if the reference does not start with an identifier.
+fn dereference_parts<'t, 'a>(tree: &'t ParseTree<'a>) -> Option<Vec<(String, &'t ParseTree<'a>)>> {
+    match tree {
+        ParseTree::Identifier(identifier) => Some(vec![(tree_name(&identifier.value)?, tree)]),
+        ParseTree::Dereference(dereference) => {
+            let mut parts = dereference_parts(&dereference.object)?;
+            parts.push((tree_name(&dereference.field_name)?, tree));
+            Some(parts)
+        }
+        _ => None,
+    }
+}
+
+struct Resolver<'c> {
+    catalog: &'c dyn Catalog,
+    session: &'c Session,
+    resolution: Resolution,
+    /// The lineages of the resolved column references and scalar
+    /// subqueries, by range.
+    lineages: Vec<(TextRange, Lineage)>,
+}
+
+impl<'c> Resolver<'c> {
+    fn error(&mut self, error_code: i32, range: TextRange, message: String) {
+        self.resolution.errors.push(SyntaxError::from_message(
+            error_code,
+            Message::new(range, message),
+        ));
+    }
+
+    fn bind_column(&mut self, range: TextRange, target: ColumnTarget) {
+        self.resolution
+            .columns
+            .push(ColumnBinding { range, target });
+    }
+
+    /// Reports a predicate which is not a boolean.
+    /// clause describes the predicate, as in "WHERE clause".
+    fn check_boolean(&mut self, tree: &ParseTree, data_type: &DataType, clause: &str) {
+        if !data_type.is_boolean() {
+            self.error(
+                ERROR_NOT_BOOLEAN,
+                tree.get_range(),
+                format!(
+                    "{} must evaluate to a boolean: actual type {}",
+                    clause, data_type
+                ),
+            );
+        }
+    }
+
+    /// Resolves a predicate, which must be a boolean if present.
+    fn resolve_predicate(&mut self, tree: &ParseTree, scope: &Scope, clause: &str) {
+        if !tree.is_empty() {
+            let data_type = self.resolve_expression(tree, scope);
+            self.check_boolean(tree, &data_type, clause);
+        }
+    }
+
+    fn resolve_statement(&mut self, tree: &ParseTree, scope: &Scope) {
+        match tree {
+            ParseTree::Entrypoint(entrypoint) => self.resolve_statement(&entrypoint.tree, scope),
+            ParseTree::Query(_) | ParseTree::QueryNoWith(_) => {
+                self.resolution.output = self.resolve_query(tree, scope);
+            }
+            ParseTree::CreateView(create_view) => {
+                self.resolution.output = self.resolve_query(&create_view.query, scope);
+            }
+            ParseTree::CreateTableAsSelect(create_table_as_select) => {
+                let output = self.resolve_query(&create_table_as_select.query, scope);
+                let aliases = identifier_list(&create_table_as_select.column_aliases_opt);
+                self.resolution.output = if aliases.is_empty() {
+                    output
+                } else {
+                    self.apply_column_aliases(output, &aliases, &create_table_as_select.table_name)
+                };
+            }
+            ParseTree::CreateTable(create_table) => {
+                for element in list_elements(&create_table.table_elements) {
+                    if let ParseTree::LikeClause(like_clause) = element {
+                        self.resolve_table_name(&like_clause.name, scope);
+                    }
+                }
+            }
+            ParseTree::InsertInto(insert_into) => {
+                let fields = self.resolve_table_name(&insert_into.table_name, scope);
+                let output = self.resolve_query(&insert_into.query, scope);
+                if let Some(fields) = fields {
+                    let aliases = list_elements(&insert_into.column_aliases_opt);
+                    let mut targets = Vec::new();
+                    for alias in &aliases {
+                        let name = match tree_name(alias) {
+                            Some(name) => name,
+                            None => continue,
+                        };
+                        match fields
+                            .iter()
+                            .find(|field| field.name.as_ref() == Some(&name))
+                        {
+                            Some(field) => targets.push(field.data_type.clone()),
+                            None => self.error(
+                                ERROR_UNKNOWN_COLUMN,
+                                alias.get_range(),
+                                format!("Column '{}' does not exist in the target table.", name),
+                            ),
+                        }
+                    }
+                    if aliases.is_empty() {
+                        targets = fields.into_iter().map(|field| field.data_type).collect();
+                    }
+                    let is_complete = aliases.is_empty() || targets.len() == aliases.len();
+                    if let (Some(output), true) = (&output, is_complete) {
+                        self.check_insert_types(&targets, output, &insert_into.query);
+                    }
+                }
+                self.resolution.output = output;
+            }
+            ParseTree::Delete(delete) => {
+                let relation = self.table_relation(&delete.table_name, scope);
+                let mut delete_scope = Scope::child(scope);
+                delete_scope.relations.push(relation);
+                self.resolve_predicate(&delete.predicate, &delete_scope, "WHERE clause");
+            }
+            _ => (),
+        }
+    }
+
+    /// Reports an INSERT whose query's columns do not coerce to the types
+    /// of the columns inserted into.
+    fn check_insert_types(&mut self, targets: &[DataType], output: &[Field], query: &ParseTree) {
+        let query_types: Vec<DataType> =
+            output.iter().map(|field| field.data_type.clone()).collect();
+        if targets.len() != query_types.len()
+            || query_types
+                .iter()
+                .zip(targets)
+                .any(|(from, to)| !from.can_coerce_to(to))
+        {
+            let names = |types: &[DataType]| {
+                types
+                    .iter()
+                    .map(|data_type| data_type.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            self.error(
+                ERROR_TYPE_MISMATCH,
+                query.get_range(),
+                format!(
+                    "Insert query has mismatched column types: Table: [{}], Query: [{}]",
+                    names(targets),
+                    names(&query_types)
+                ),
+            );
+        }
+    }
+
+    /// Resolves a Query, QueryNoWith or query term; returns its output columns.
+    fn resolve_query(&mut self, tree: &ParseTree, scope: &Scope) -> Fields {
+        match tree {
+            ParseTree::Query(query) => {
+                if query.with.is_empty() {
+                    self.resolve_query(&query.query_no_with, scope)
+                } else {
+                    let mut with_scope = Scope::child(scope);
+                    if let ParseTree::With(with) = &*query.with {
+                        for named_query in list_elements(&with.named_queries) {
+                            let cte = self.resolve_named_query(named_query, &with_scope);
+                            if let Some(cte) = cte {
+                                if with_scope.ctes.iter().any(|other| other.name == cte.name) {
+                                    self.error(
+                                        ERROR_DUPLICATE_WITH_QUERY,
+                                        cte.range,
+                                        format!(
+                                            "With query '{}' is defined more than once.",
+                                            cte.name
+                                        ),
+                                    );
+                                }
+                                with_scope.ctes.push(cte);
+                            }
+                        }
+                    }
+                    self.resolve_query(&query.query_no_with, &with_scope)
+                }
+            }
+            ParseTree::QueryNoWith(query_no_with) => {
+                let order_by = match &*query_no_with.order_by_opt {
+                    ParseTree::OrderBy(order_by) => Some(&*order_by.sort_items),
+                    _ => None,
+                };
+                match &*query_no_with.query_term {
+                    ParseTree::QuerySpecification(_) => {
+                        self.resolve_query_specification(&query_no_with.query_term, scope, order_by)
+                    }
+                    query_term => {
+                        let output = self.resolve_query(query_term, scope);
+                        if let Some(sort_items) = order_by {
+                            let mut order_scope = Scope::child(scope);
+                            order_scope.outputs = output.clone();
+                            if output.is_none() {
+                                order_scope.relations.push(ScopeRelation {
+                                    qualifiers: Vec::new(),
+                                    fields: None,
+                                });
+                            }
+                            self.resolve_sort_items(sort_items, &order_scope);
+                        }
+                        output
+                    }
+                }
+            }
+            ParseTree::QuerySetOperation(query_set_operation) => {
+                let left = self.resolve_query(&query_set_operation.left, scope);
+                let right = self.resolve_query(&query_set_operation.right, scope);
+                let operator = match &*query_set_operation.operator {
+                    ParseTree::Token(token) => token.token.value.to_uppercase(),
+                    _ => String::new(),
+                };
+                self.set_operation_fields(left?, right?, &operator, tree)
+            }
+            ParseTree::Subquery(subquery) => self.resolve_query(&subquery.query_no_with, scope),
+            ParseTree::RelationOrQuery(relation_or_query) => {
+                self.resolve_query(&relation_or_query.query_or_relation, scope)
+            }
+            ParseTree::QuerySpecification(_) => self.resolve_query_specification(tree, scope, None),
+            ParseTree::InlineTable(inline_table) => {
+                let mut columns: Option<Vec<DataType>> = None;
+                for row in list_elements(&inline_table.expressions) {
+                    let row_type = self.resolve_expression(row, scope);
+                    let row_types = match (row, row_type) {
+                        (ParseTree::RowConstructor(_), DataType::Row(fields))
+                        | (ParseTree::Row(_), DataType::Row(fields)) => {
+                            fields.into_iter().map(|field| field.data_type).collect()
+                        }
+                        (_, row_type) => vec![row_type],
+                    };
+                    columns = Some(match columns {
+                        None => row_types,
+                        Some(columns) => {
+                            self.merge_column_types(columns, row_types, "VALUES", row)?
+                        }
+                    });
+                }
+                Some(derived_fields(
+                    columns
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|data_type| (None, data_type, Lineage::default()))
+                        .collect(),
+                    None,
+                ))
+            }
+            ParseTree::Table(table) => self.table_relation(&table.qualified_name, scope).fields,
+            _ => None,
+        }
+    }
+
+    /// The output of UNION, INTERSECT or EXCEPT: the columns of the left
+    /// query, with the common super types of both queries' column types,
+    /// deriving from the columns of both queries.
+    fn set_operation_fields(
+        &mut self,
+        left: Vec<Field>,
+        right: Vec<Field>,
+        operator: &str,
+        tree: &ParseTree,
+    ) -> Fields {
+        let left_types = left.iter().map(|field| field.data_type.clone()).collect();
+        let right_types = right.iter().map(|field| field.data_type.clone()).collect();
+        let types = self.merge_column_types(left_types, right_types, operator, tree)?;
+        Some(
+            left.into_iter()
+                .zip(right)
+                .zip(types)
+                .map(|((mut field, right), data_type)| {
+                    field.lineage.merge(&right.lineage);
+                    Field { data_type, ..field }
+                })
+                .collect(),
+        )
+    }
+
+    /// The common super types of the columns of two rows of a set operation
+    /// or VALUES; None after reporting an error if there are none.
+    fn merge_column_types(
+        &mut self,
+        left: Vec<DataType>,
+        right: Vec<DataType>,
+        operator: &str,
+        tree: &ParseTree,
+    ) -> Option<Vec<DataType>> {
+        if left.len() != right.len() {
+            self.error(
+                ERROR_INCOMPATIBLE_TYPES,
+                tree.get_range(),
+                format!(
+                    "{} query has different number of fields: {}, {}",
+                    operator,
+                    left.len(),
+                    right.len()
+                ),
+            );
+            return None;
+        }
+        let mut result = Vec::new();
+        for (index, (left, right)) in left.iter().zip(&right).enumerate() {
+            match DataType::common_super_type(left, right) {
+                Some(data_type) => result.push(data_type),
+                None => {
+                    self.error(
+                        ERROR_INCOMPATIBLE_TYPES,
+                        tree.get_range(),
+                        format!(
+                            "Column {} in {} query has incompatible types: {}, {}",
+                            index + 1,
+                            operator,
+                            left,
+                            right
+                        ),
+                    );
+                    return None;
+                }
+            }
+        }
+        Some(result)
+    }
+
+    fn resolve_named_query(&mut self, tree: &ParseTree, scope: &Scope) -> Option<Cte> {
+        match tree {
+            ParseTree::NamedQuery(named_query) => {
+                let name = tree_name(&named_query.name)?;
+                let output = self.resolve_query(&named_query.query, scope);
+                let aliases = identifier_list(&named_query.column_aliases_opt);
+                let output = if aliases.is_empty() {
+                    output
+                } else {
+                    self.apply_column_aliases(output, &aliases, &named_query.name)
+                };
+                Some(Cte {
+                    fields: name_derived_fields(output, &name),
+                    name,
+                    range: tree.get_range(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Renames fields with column aliases.
+    fn apply_column_aliases(
+        &mut self,
+        fields: Fields,
+        aliases: &[String],
+        name: &ParseTree,
+    ) -> Fields {
+        let fields = fields?;
+        if fields.len() != aliases.len() {
+            self.error(
+                ERROR_COLUMN_ALIAS_COUNT,
+                name.get_range(),
+                format!(
+                    "Relation '{}' has {} columns, but {} column aliases.",
+                    qualified_name_parts(name).join("."),
+                    fields.len(),
+                    aliases.len()
+                ),
+            );
+            return None;
+        }
+        Some(
+            fields
+                .into_iter()
+                .zip(aliases)
+                .map(|(field, alias)| Field {
+                    name: Some(alias.clone()),
+                    target: match field.target {
+                        ColumnTarget::Derived {
+                            relation, index, ..
+                        } => ColumnTarget::Derived {
+                            relation,
+                            column: Some(alias.clone()),
+                            index,
+                        },
+                        target => target,
+                    },
+                    data_type: field.data_type,
+                    lineage: field.lineage,
+                })
+                .collect(),
+        )
+    }
+
+    fn resolve_query_specification(
+        &mut self,
+        tree: &ParseTree,
+        scope: &Scope,
+        order_by: Option<&ParseTree>,
+    ) -> Fields {
+        let query_specification = match tree {
+            ParseTree::QuerySpecification(query_specification) => query_specification,
+            _ => return None,
+        };
+        let mut from_scope = Scope::child(scope);
+        for relation in list_elements(&query_specification.relations) {
+            let mut using_columns = Vec::new();
+            let relations =
+                self.resolve_relation(relation, scope, &from_scope.relations, &mut using_columns);
+            from_scope.relations.extend(relations);
+            from_scope.using_columns.extend(using_columns);
+        }
+        self.resolve_predicate(
+            &query_specification.where_predicate,
+            &from_scope,
+            "WHERE clause",
+        );
+
+        let mut outputs = Some(Vec::new());
+        let mut select_fields = Vec::new();
+        for select_item in list_elements(&query_specification.select_items) {
+            let fields = match select_item {
+                ParseTree::SelectAll(_) => from_scope.all_fields(),
+                ParseTree::QualifiedSelectAll(qualified_select_all) => {
+                    let qualifier = qualified_name_parts(&qualified_select_all.qualifier);
+                    let relation = from_scope
+                        .relations
+                        .iter()
+                        .find(|relation| relation.is_qualified_by(&qualifier));
+                    match relation {
+                        Some(relation) => relation.fields.clone(),
+                        None => {
+                            self.error(
+                                ERROR_UNKNOWN_RELATION,
+                                qualified_select_all.qualifier.get_range(),
+                                format!("Relation '{}' does not exist.", qualifier.join(".")),
+                            );
+                            None
+                        }
+                    }
+                }
+                ParseTree::SelectItem(select_item) => {
+                    let data_type = self.resolve_expression(&select_item.expression, &from_scope);
+                    let name = if select_item.identifier.is_empty() {
+                        match &*select_item.expression {
+                            ParseTree::Identifier(identifier) => tree_name(&identifier.value),
+                            ParseTree::Dereference(dereference) => {
+                                tree_name(&dereference.field_name)
+                            }
+                            _ => None,
+                        }
+                    } else {
+                        tree_name(&select_item.identifier)
+                    };
+                    let target = match &*select_item.expression {
+                        ParseTree::Identifier(_) | ParseTree::Dereference(_) => self
+                            .resolution
+                            .column(select_item.expression.get_range())
+                            .cloned(),
+                        _ => None,
+                    };
+                    let index = outputs
+                        .as_ref()
+                        .map_or(0, |outputs: &Vec<Field>| outputs.len());
+                    let target = target.unwrap_or(ColumnTarget::Derived {
+                        relation: None,
+                        column: name.clone(),
+                        index,
+                    });
+                    let lineage = self.expression_lineage(&select_item.expression);
+                    Some(vec![Field {
+                        name,
+                        target,
+                        data_type,
+                        lineage,
+                    }])
+                }
+                _ => None,
+            };
+            select_fields.push(fields.clone());
+            outputs = match (outputs, fields) {
+                (Some(mut outputs), Some(fields)) => {
+                    outputs.extend(fields);
+                    Some(outputs)
+                }
+                _ => None,
+            };
+        }
+
+        for grouping_element in list_elements(&query_specification.group_by) {
+            self.resolve_expression(grouping_element, &from_scope);
+        }
+        self.resolve_predicate(
+            &query_specification.having_predicate,
+            &from_scope,
+            "HAVING clause",
+        );
+
+        if let Some(sort_items) = order_by {
+            let mut order_scope = Scope::child(&from_scope);
+            order_scope.outputs = outputs.clone();
+            self.resolve_sort_items(sort_items, &order_scope);
+        }
+
+        let local_columns: Vec<ColumnTarget> = from_scope
+            .relations
+            .iter()
+            .flat_map(|relation| relation.fields.iter().flatten())
+            .map(|field| field.target.clone())
+            .collect();
+        let errors = aggregation::check_query_specification(
+            query_specification,
+            order_by,
+            &select_fields,
+            &local_columns,
+            &self.resolution,
+        );
+        self.resolution.errors.extend(errors);
+        outputs
+    }
+
+    /// Resolves the sort items of an ORDER BY, which must be orderable.
+    fn resolve_sort_items(&mut self, sort_items: &ParseTree, scope: &Scope) {
+        for sort_item in list_elements(sort_items) {
+            match sort_item {
+                ParseTree::SortItem(sort_item) => {
+                    let data_type = self.resolve_expression(&sort_item.expression, scope);
+                    if !data_type.is_orderable() {
+                        self.error(
+                            ERROR_TYPE_MISMATCH,
+                            sort_item.expression.get_range(),
+                            format!(
+                                "Type {} is not orderable, and therefore cannot be used in ORDER BY",
+                                data_type
+                            ),
+                        );
+                    }
+                }
+                sort_item => {
+                    self.resolve_expression(sort_item, scope);
+                }
+            }
+        }
+    }
+
+    /// Binds a table name to a CTE or a catalog table.
+    /// Returns the relation's columns.
+    fn resolve_table_name(&mut self, name: &ParseTree, scope: &Scope) -> Fields {
+        self.table_relation(name, scope).fields
+    }
+
+    fn table_relation(&mut self, name: &ParseTree, scope: &Scope) -> ScopeRelation {
+        let parts = qualified_name_parts(name);
+        let range = name.get_range();
+        if let [cte_name] = parts.as_slice() {
+            if let Some(cte) = scope.find_cte(cte_name) {
+                self.resolution.relations.push(RelationBinding {
+                    range,
+                    target: RelationTarget::Cte {
+                        name: cte.name.clone(),
+                        range: cte.range,
+                    },
+                });
+                return ScopeRelation {
+                    qualifiers: vec![parts.clone()],
+                    fields: cte.fields.clone(),
+                };
+            }
+        }
+
+        let table_name = match self.session.qualify(&parts) {
+            Some(table_name) => table_name,
+            None => {
+                self.error(
+                    ERROR_INVALID_TABLE_NAME,
+                    range,
+                    format!("Too many parts in table name '{}'.", parts.join(".")),
+                );
+                return ScopeRelation {
+                    qualifiers: Vec::new(),
+                    fields: None,
+                };
+            }
+        };
+        let qualifiers = vec![
+            vec![table_name.table.clone()],
+            vec![table_name.schema.clone(), table_name.table.clone()],
+            vec![
+                table_name.catalog.clone(),
+                table_name.schema.clone(),
+                table_name.table.clone(),
+            ],
+        ];
+        let fields = match self.catalog.table(&table_name) {
+            Some(table) => Some(
+                table
+                    .columns
+                    .iter()
+                    .map(|column| Field {
+                        name: Some(column.name.clone()),
+                        target: ColumnTarget::Table {
+                            table: table_name.clone(),
+                            column: column.name.clone(),
+                        },
+                        data_type: column.data_type.clone(),
+                        lineage: Lineage::column(&table_name, &column.name),
+                    })
+                    .collect(),
+            ),
+            None => {
+                self.error(
+                    ERROR_UNKNOWN_TABLE,
+                    range,
+                    format!("Table '{}' does not exist.", table_name),
+                );
+                None
+            }
+        };
+        self.resolution.relations.push(RelationBinding {
+            range,
+            target: RelationTarget::Table(table_name),
+        });
+        ScopeRelation { qualifiers, fields }
+    }
+
+    /// Resolves a relation in a FROM clause.
+    ///
+    /// outer is the scope enclosing the query specification; left are the
+    /// relations to the left of this one in the FROM clause, which are
+    /// visible to UNNEST and LATERAL.
+    fn resolve_relation(
+        &mut self,
+        tree: &ParseTree,
+        outer: &Scope,
+        left: &[ScopeRelation],
+        using_columns: &mut Vec<String>,
+    ) -> Vec<ScopeRelation> {
+        match tree {
+            ParseTree::TableName(table_name) => vec![self.table_relation(&table_name.name, outer)],
+            ParseTree::AliasedRelation(aliased_relation) => {
+                let relations = self.resolve_relation(
+                    &aliased_relation.relation_primary,
+                    outer,
+                    left,
+                    using_columns,
+                );
+                let mut fields = Some(Vec::new());
+                for relation in relations {
+                    fields = match (fields, relation.fields) {
+                        (Some(mut fields), Some(relation_fields)) => {
+                            fields.extend(relation_fields);
+                            Some(fields)
+                        }
+                        _ => None,
+                    };
+                }
+                let alias = match tree_name(&aliased_relation.identifier) {
+                    Some(alias) => alias,
+                    None => return Vec::new(),
+                };
+                let fields = name_derived_fields(fields, &alias);
+                let column_aliases = identifier_list(&aliased_relation.column_aliases_opt);
+                let is_unnest = matches!(&*aliased_relation.relation_primary, ParseTree::Unnest(_));
+                let fields = if column_aliases.is_empty() {
+                    fields
+                } else if is_unnest
+                    && fields
+                        .as_ref()
+                        .is_none_or(|fields| fields.len() != column_aliases.len())
+                {
+                    // The columns of UNNEST of a value of unknown type are
+                    // only known from the aliases.
+                    let mut lineage = Lineage::default();
+                    if let ParseTree::Unnest(unnest) = &*aliased_relation.relation_primary {
+                        for expression in list_elements(&unnest.expressions) {
+                            lineage.merge(&self.unnest_lineage(expression));
+                        }
+                    }
+                    Some(derived_fields(
+                        column_aliases
+                            .into_iter()
+                            .map(|alias| (Some(alias), DataType::Unknown, lineage.clone()))
+                            .collect(),
+                        Some(&alias),
+                    ))
+                } else {
+                    self.apply_column_aliases(fields, &column_aliases, &aliased_relation.identifier)
+                };
+                vec![ScopeRelation {
+                    qualifiers: vec![vec![alias]],
+                    fields,
+                }]
+            }
+            ParseTree::SubqueryRelation(subquery_relation) => {
+                self.subquery_relation(&subquery_relation.query, outer)
+            }
+            ParseTree::RelationOrQuery(relation_or_query) => {
+                let inner = &relation_or_query.query_or_relation;
+                if inner.is_query() || inner.is_query_no_with() || inner.is_relation_or_query() {
+                    self.subquery_relation(inner, outer)
+                } else {
+                    self.resolve_relation(inner, outer, left, using_columns)
+                }
+            }
+            ParseTree::ParenthesizedRelation(parenthesized_relation) => {
+                self.resolve_relation(&parenthesized_relation.relation, outer, left, using_columns)
+            }
+            ParseTree::Lateral(lateral) => {
+                let lateral_scope = lateral_scope(outer, left, &[]);
+                let fields = self.resolve_query(&lateral.query, &lateral_scope);
+                vec![ScopeRelation {
+                    qualifiers: Vec::new(),
+                    fields,
+                }]
+            }
+            ParseTree::Unnest(unnest) => {
+                let lateral_scope = lateral_scope(outer, left, &[]);
+                let mut columns = Vec::new();
+                for expression in list_elements(&unnest.expressions) {
+                    let data_type = self.resolve_expression(expression, &lateral_scope);
+                    match data_type {
+                        DataType::Array(_) | DataType::Map(..) | DataType::Unknown => (),
+                        _ => self.error(
+                            ERROR_TYPE_MISMATCH,
+                            expression.get_range(),
+                            format!("Cannot unnest type: {}", data_type),
+                        ),
+                    }
+                    let lineage = self.unnest_lineage(expression);
+                    columns.extend(
+                        unnest_column_types(&data_type)
+                            .into_iter()
+                            .map(|data_type| (None, data_type, lineage.clone())),
+                    );
+                }
+                if !unnest.ordinality.is_empty() {
+                    columns.push((None, DataType::BigInt, Lineage::default()));
+                }
+                vec![ScopeRelation {
+                    qualifiers: Vec::new(),
+                    fields: Some(derived_fields(columns, None)),
+                }]
+            }
+            ParseTree::SampledRelation(sampled_relation) => {
+                self.resolve_expression(&sampled_relation.expression, outer);
+                self.resolve_relation(
+                    &sampled_relation.aliased_relation,
+                    outer,
+                    left,
+                    using_columns,
+                )
+            }
+            ParseTree::CrossJoin(cross_join) => self.resolve_join(
+                &cross_join.left,
+                &cross_join.right,
+                None,
+                outer,
+                left,
+                using_columns,
+            ),
+            ParseTree::NaturalJoin(natural_join) => self.resolve_join(
+                &natural_join.left,
+                &natural_join.right,
+                None,
+                outer,
+                left,
+                using_columns,
+            ),
+            ParseTree::Join(join) => self.resolve_join(
+                &join.left,
+                &join.right,
+                Some(&join.join_criteria),
+                outer,
+                left,
+                using_columns,
+            ),
+            _ => Vec::new(),
+        }
+    }
+
+    fn subquery_relation(&mut self, query: &ParseTree, outer: &Scope) -> Vec<ScopeRelation> {
+        let fields = self.resolve_query(query, outer);
+        vec![ScopeRelation {
+            qualifiers: Vec::new(),
+            fields,
+        }]
+    }
+
+    fn resolve_join(
+        &mut self,
+        left_tree: &ParseTree,
+        right_tree: &ParseTree,
+        criteria: Option<&ParseTree>,
+        outer: &Scope,
+        left: &[ScopeRelation],
+        using_columns: &mut Vec<String>,
+    ) -> Vec<ScopeRelation> {
+        let mut relations = self.resolve_relation(left_tree, outer, left, using_columns);
+        let preceding: Vec<ScopeRelation> = left.iter().chain(relations.iter()).cloned().collect();
+        let right_relations = self.resolve_relation(right_tree, outer, &preceding, using_columns);
+        relations.extend(right_relations);
+        match criteria {
+            Some(ParseTree::OnJoinCriteria(on_join_criteria)) => {
+                let join_scope = lateral_scope(outer, left, &relations);
+                self.resolve_predicate(&on_join_criteria.predicate, &join_scope, "JOIN ON clause");
+            }
+            Some(ParseTree::UsingJoinCriteria(using_join_criteria)) => {
+                using_columns.extend(identifier_list(&using_join_criteria.names));
+            }
+            _ => (),
+        }
+        relations
+    }
+
+    /// Resolves the column references within an expression, and infers its
+    /// type. The types of expression trees are recorded in the resolution.
+    fn resolve_expression(&mut self, tree: &ParseTree, scope: &Scope) -> DataType {
+        let data_type = self.expression_type(tree, scope);
+        if is_expression(tree) {
+            self.resolution.types.push(ExpressionType {
+                range: tree.get_range(),
+                data_type: data_type.clone(),
+            });
+        }
+        data_type
+    }
+
+    fn expression_type(&mut self, tree: &ParseTree, scope: &Scope) -> DataType {
+        match tree {
+            ParseTree::Identifier(identifier) => match tree_name(&identifier.value) {
+                Some(name) => self.resolve_column(tree, &[], &name, scope),
+                None => DataType::Unknown,
+            },
+            ParseTree::Dereference(dereference) => match dereference_parts(tree) {
+                Some(parts) => self.resolve_dereference(&parts, scope),
+                None => {
+                    let object_type = self.resolve_expression(&dereference.object, scope);
+                    let field_name = tree_name(&dereference.field_name).unwrap_or_default();
+                    self.row_field_type(&object_type, &field_name, tree)
+                }
+            },
+            // The parser reports invalid literals.
+            ParseTree::Literal(literal) => match &*literal.value {
+                ParseTree::Token(token) => types::literal_type(&token.token),
+                value @ ParseTree::UnicodeString(_) => self.expression_type(value, scope),
+                _ => DataType::Unknown,
+            },
+            ParseTree::UnicodeString(unicode_string) => match unicode_string.decode() {
+                Ok(value) => DataType::Varchar(Some(value.chars().count() as u32)),
+                Err(_) => DataType::Varchar(None),
+            },
+            ParseTree::TypeConstructor(type_constructor) => {
+                let value = string_value(&type_constructor.value).unwrap_or_default();
+                match &*type_constructor.type_ {
+                    ParseTree::Token(token) if token.token.kind == TokenKind::DoublePrecision => {
+                        DataType::Double
+                    }
+                    type_ => {
+                        types::type_constructor_type(&tree_name(type_).unwrap_or_default(), &value)
+                    }
+                }
+            }
+            ParseTree::Interval(interval) => {
+                types::interval_type(&tree_name(&interval.from).unwrap_or_default())
+            }
+            ParseTree::CurrentTime(_) => DataType::TimeWithTimeZone,
+            ParseTree::CurrentTimestamp(_) => DataType::TimestampWithTimeZone,
+            ParseTree::Localtime(_) => DataType::Time,
+            ParseTree::Localtimestamp(_) => DataType::Timestamp,
+            ParseTree::Cast(cast) => {
+                self.resolve_expression(&cast.value, scope);
+                self.resolve_type(&cast.type_)
+            }
+            ParseTree::TryCast(try_cast) => {
+                self.resolve_expression(&try_cast.value, scope);
+                self.resolve_type(&try_cast.type_)
+            }
+            ParseTree::ParenthesizedExpression(parenthesized_expression) => {
+                self.resolve_expression(&parenthesized_expression.value, scope)
+            }
+            ParseTree::BinaryExpression(binary_expression) => {
+                let left = self.resolve_expression(&binary_expression.left, scope);
+                let right = self.resolve_expression(&binary_expression.right, scope);
+                let operator = match &*binary_expression.operator {
+                    ParseTree::Token(token) => token.token.value.to_lowercase(),
+                    _ => String::new(),
+                };
+                self.binary_type(&operator, &left, &right, binary_expression, tree)
+            }
+            ParseTree::UnaryExpression(unary_expression) => {
+                let operand = self.resolve_expression(&unary_expression.operand, scope);
+                let operator = match &*unary_expression.operator {
+                    ParseTree::Token(token) => token.token.value.to_lowercase(),
+                    _ => String::new(),
+                };
+                if operator == "not" {
+                    self.check_boolean(
+                        &unary_expression.operand,
+                        &operand,
+                        "Value of logical NOT expression",
+                    );
+                    DataType::Boolean
+                } else if operand.is_unknown() || operand.is_numeric() || operand.is_interval() {
+                    operand
+                } else {
+                    self.error(
+                        ERROR_TYPE_MISMATCH,
+                        tree.get_range(),
+                        format!("Cannot apply operator: {}{}", operator, operand),
+                    );
+                    DataType::Unknown
+                }
+            }
+            ParseTree::QuantifiedComparison(quantified_comparison) => {
+                let operand = self.resolve_expression(&quantified_comparison.operand, scope);
+                let query = self.subquery_type(&quantified_comparison.query, scope);
+                let operator = match &*quantified_comparison.operator {
+                    ParseTree::Token(token) => token.token.value.to_string(),
+                    _ => String::new(),
+                };
+                self.check_comparison(&operator, &operand, &query, tree);
+                DataType::Boolean
+            }
+            ParseTree::NullPredicate(null_predicate) => {
+                self.resolve_expression(&null_predicate.value, scope);
+                DataType::Boolean
+            }
+            ParseTree::DistinctFrom(distinct_from) => {
+                let left = self.resolve_expression(&distinct_from.left, scope);
+                let right = self.resolve_expression(&distinct_from.right, scope);
+                self.check_comparison("IS DISTINCT FROM", &left, &right, tree);
+                DataType::Boolean
+            }
+            ParseTree::Between(between) => {
+                let operands = [
+                    self.resolve_expression(&between.value, scope),
+                    self.resolve_expression(&between.lower, scope),
+                    self.resolve_expression(&between.upper, scope),
+                ];
+                match DataType::common_super_type_of(&operands) {
+                    Some(data_type) if !data_type.is_orderable() => self.error(
+                        ERROR_TYPE_MISMATCH,
+                        tree.get_range(),
+                        format!("Type {} is not orderable", data_type),
+                    ),
+                    Some(_) => (),
+                    None => self.error(
+                        ERROR_TYPE_MISMATCH,
+                        tree.get_range(),
+                        format!(
+                            "Cannot check if {} is BETWEEN {} and {}",
+                            operands[0], operands[1], operands[2]
+                        ),
+                    ),
+                }
+                DataType::Boolean
+            }
+            ParseTree::Like(like) => {
+                for (operand, side) in &[
+                    (&like.value, "Left side of LIKE expression"),
+                    (&like.patrern, "Pattern for LIKE expression"),
+                    (&like.escape_value_opt, "Escape for LIKE expression"),
+                ] {
+                    if operand.is_empty() {
+                        continue;
+                    }
+                    let data_type = self.resolve_expression(operand, scope);
+                    if !data_type.is_string() && !data_type.is_unknown() {
+                        self.error(
+                            ERROR_TYPE_MISMATCH,
+                            operand.get_range(),
+                            format!(
+                                "{} must evaluate to a varchar (actual: {})",
+                                side, data_type
+                            ),
+                        );
+                    }
+                }
+                DataType::Boolean
+            }
+            ParseTree::InSubquery(in_subquery) => {
+                let value = self.resolve_expression(&in_subquery.value, scope);
+                let query = self.subquery_type(&in_subquery.query, scope);
+                self.check_comparison("IN", &value, &query, tree);
+                DataType::Boolean
+            }
+            ParseTree::InList(in_list) => {
+                let mut types = vec![self.resolve_expression(&in_list.value, scope)];
+                for expression in list_elements(&in_list.expressions) {
+                    types.push(self.resolve_expression(expression, scope));
+                }
+                match DataType::common_super_type_of(&types) {
+                    Some(data_type) if !data_type.is_comparable() => self.error(
+                        ERROR_TYPE_MISMATCH,
+                        tree.get_range(),
+                        format!("Type {} is not comparable", data_type),
+                    ),
+                    Some(_) => (),
+                    None => self.error(
+                        ERROR_INCOMPATIBLE_TYPES,
+                        tree.get_range(),
+                        format!(
+                            "IN value and list items must be the same type: {}",
+                            type_list(&types)
+                        ),
+                    ),
+                }
+                DataType::Boolean
+            }
+            ParseTree::AtTimeZone(at_time_zone) => {
+                let value = self.resolve_expression(&at_time_zone.value, scope);
+                self.resolve_expression(&at_time_zone.specifier, scope);
+                match value {
+                    DataType::Time | DataType::TimeWithTimeZone => DataType::TimeWithTimeZone,
+                    DataType::Timestamp | DataType::TimestampWithTimeZone => {
+                        DataType::TimestampWithTimeZone
+                    }
+                    DataType::Unknown => DataType::Unknown,
+                    value => {
+                        self.error(
+                            ERROR_TYPE_MISMATCH,
+                            at_time_zone.value.get_range(),
+                            format!(
+                                "Type of value must be a time or timestamp with or without time zone (actual {})",
+                                value
+                            ),
+                        );
+                        DataType::Unknown
+                    }
+                }
+            }
+            ParseTree::Subscript(subscript) => {
+                let operand = self.resolve_expression(&subscript.operand, scope);
+                let index = self.resolve_expression(&subscript.index, scope);
+                self.subscript_type(&operand, &index, &subscript.index, tree)
+            }
+            ParseTree::Lambda(lambda) => {
+                self.lambda_type(lambda, &[], scope);
+                DataType::Unknown
+            }
+            ParseTree::RowConstructor(row_constructor) => {
+                self.row_type(&row_constructor.elements, scope)
+            }
+            ParseTree::Row(row) => self.row_type(&row.elements, scope),
+            ParseTree::Array(array) => {
+                let types: Vec<DataType> = list_elements(&array.elements)
+                    .into_iter()
+                    .map(|element| self.resolve_expression(element, scope))
+                    .collect();
+                match DataType::common_super_type_of(&types) {
+                    Some(element) => DataType::Array(Box::new(element)),
+                    None => {
+                        self.error(
+                            ERROR_INCOMPATIBLE_TYPES,
+                            tree.get_range(),
+                            format!(
+                                "All ARRAY elements must be the same type: {}",
+                                type_list(&types)
+                            ),
+                        );
+                        DataType::Unknown
+                    }
+                }
+            }
+            ParseTree::Case(case) => self.case_type(case, tree, scope),
+            ParseTree::FunctionCall(function_call) => {
+                self.function_call_type(function_call, tree, scope)
+            }
+            ParseTree::Grouping(grouping) => {
+                self.resolve_expression(&grouping.groups, scope);
+                DataType::BigInt
+            }
+            ParseTree::Extract(extract) => {
+                let value = self.resolve_expression(&extract.value, scope);
+                match value {
+                    DataType::Date
+                    | DataType::Time
+                    | DataType::TimeWithTimeZone
+                    | DataType::Timestamp
+                    | DataType::TimestampWithTimeZone
+                    | DataType::Unknown => (),
+                    value if value.is_interval() => (),
+                    value => self.error(
+                        ERROR_TYPE_MISMATCH,
+                        extract.value.get_range(),
+                        format!(
+                            "Type of argument to extract must be DATE, TIME, TIMESTAMP, or INTERVAL (actual {})",
+                            value
+                        ),
+                    ),
+                }
+                DataType::BigInt
+            }
+            ParseTree::Substring(substring) => {
+                let value = self.resolve_expression(&substring.value, scope);
+                self.resolve_expression(&substring.from_value, scope);
+                self.resolve_expression(&substring.for_value, scope);
+                match value {
+                    DataType::Char(_) | DataType::Varchar(_) => value,
+                    _ => DataType::Varchar(None),
+                }
+            }
+            ParseTree::Position(position) => {
+                self.resolve_expression(&position.value, scope);
+                self.resolve_expression(&position.target, scope);
+                DataType::BigInt
+            }
+            ParseTree::Normalize(normalize) => {
+                self.resolve_expression(&normalize.value, scope);
+                DataType::Varchar(None)
+            }
+            ParseTree::Exists(exists) => {
+                self.resolve_query(&exists.query, scope);
+                DataType::Boolean
+            }
+            ParseTree::SubqueryExpression(subquery_expression) => {
+                self.subquery_type(&subquery_expression.query, scope)
+            }
+            ParseTree::Query(_) | ParseTree::QueryNoWith(_) => {
+                self.resolve_query(tree, scope);
+                DataType::Unknown
+            }
+            _ => {
+                for child in tree.children() {
+                    self.resolve_expression(child, scope);
+                }
+                DataType::Unknown
+            }
+        }
+    }
+
+    /// The type denoted by a type tree, after reporting invalid parameters.
+    fn resolve_type(&mut self, tree: &ParseTree) -> DataType {
+        DataType::try_from_tree(tree).unwrap_or_else(|errors| {
+            self.resolution.errors.extend(errors);
+            DataType::from_tree(tree)
+        })
+    }
+
+    /// The type of a binary operator's result, after reporting operands of
+    /// the wrong types.
+    fn binary_type(
+        &mut self,
+        operator: &str,
+        left: &DataType,
+        right: &DataType,
+        binary_expression: &parse_tree::BinaryExpression,
+        tree: &ParseTree,
+    ) -> DataType {
+        match operator {
+            "and" | "or" => {
+                self.check_boolean(
+                    &binary_expression.left,
+                    left,
+                    "Left side of logical expression",
+                );
+                self.check_boolean(
+                    &binary_expression.right,
+                    right,
+                    "Right side of logical expression",
+                );
+                DataType::Boolean
+            }
+            "=" | "<>" | "!=" | "<" | ">" | "<=" | ">=" => {
+                self.check_comparison(operator, left, right, tree);
+                DataType::Boolean
+            }
+            _ => {
+                let result = if operator == "||" {
+                    types::concat_type(left, right)
+                } else {
+                    types::arithmetic_type(operator, left, right)
+                };
+                match result {
+                    Some(result) => result,
+                    None => {
+                        self.error(
+                            ERROR_TYPE_MISMATCH,
+                            tree.get_range(),
+                            format!("Cannot apply operator: {} {} {}", left, operator, right),
+                        );
+                        DataType::Unknown
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reports a comparison of types which have no common super type, or
+    /// whose common super type cannot be compared with the operator.
+    fn check_comparison(
+        &mut self,
+        operator: &str,
+        left: &DataType,
+        right: &DataType,
+        tree: &ParseTree,
+    ) {
+        let range = tree.get_range();
+        let is_ordering = matches!(operator, "<" | ">" | "<=" | ">=");
+        match DataType::common_super_type(left, right) {
+            Some(data_type) if is_ordering && !data_type.is_orderable() => self.error(
+                ERROR_TYPE_MISMATCH,
+                range,
+                format!("Type {} is not orderable", data_type),
+            ),
+            Some(data_type) if !is_ordering && !data_type.is_comparable() => self.error(
+                ERROR_TYPE_MISMATCH,
+                range,
+                format!("Type {} is not comparable", data_type),
+            ),
+            Some(_) => (),
+            None => self.error(
+                ERROR_TYPE_MISMATCH,
+                range,
+                format!("Cannot apply operator: {} {} {}", left, operator, right),
+            ),
+        }
+    }
+
+    /// The type of the single column of a subquery used as an expression.
+    fn subquery_type(&mut self, query: &ParseTree, scope: &Scope) -> DataType {
+        match self.resolve_query(query, scope) {
+            Some(mut fields) if fields.len() == 1 => {
+                let field = fields.remove(0);
+                self.lineages.push((query.get_range(), field.lineage));
+                field.data_type
+            }
+            Some(fields) => {
+                self.error(
+                    ERROR_TYPE_MISMATCH,
+                    query.get_range(),
+                    format!(
+                        "Multiple columns returned by subquery are not yet supported. Found {}",
+                        fields.len()
+                    ),
+                );
+                DataType::Unknown
+            }
+            None => DataType::Unknown,
+        }
+    }
+
+    /// The lineage of a resolved expression: a column reference keeps the
+    /// lineage of its column; other expressions transform the columns they
+    /// reference, or aggregate them if they call an aggregate function.
+    fn expression_lineage(&self, expression: &ParseTree) -> Lineage {
+        let mut expression = expression;
+        while let ParseTree::ParenthesizedExpression(parenthesized) = expression {
+            expression = &parenthesized.value;
+        }
+        let range = expression.get_range();
+        if let Some((_, lineage)) = self.lineages.iter().find(|(other, _)| *other == range) {
+            return lineage.clone();
+        }
+        let mut lineage = Lineage::default();
+        self.collect_lineage(expression, &mut lineage);
+        lineage
+    }
+
+    /// Merges the lineages of the column references and scalar subqueries
+    /// in tree into lineage, which becomes an aggregation if tree calls an
+    /// aggregate function. Does not descend into other queries, whose
+    /// columns do not flow into the expression.
+    fn collect_lineage(&self, tree: &ParseTree, lineage: &mut Lineage) {
+        let range = tree.get_range();
+        if let Some((_, other)) = self.lineages.iter().find(|(other, _)| *other == range) {
+            lineage.merge(other);
+            return;
+        }
+        if tree.is_query() {
+            return;
+        }
+        if let ParseTree::FunctionCall(_) = tree {
+            let is_aggregate = self.resolution.functions.iter().any(|function| {
+                function.range == range && function.kind == FunctionKind::Aggregate
+            });
+            if is_aggregate {
+                lineage.derivation = Derivation::Aggregation;
+            }
+        }
+        for child in tree.children() {
+            self.collect_lineage(child, lineage);
+        }
+    }
+
+    /// The lineage of the columns produced by unnesting a resolved
+    /// expression.
+    fn unnest_lineage(&self, expression: &ParseTree) -> Lineage {
+        let mut lineage = self.expression_lineage(expression);
+        lineage.derivation = lineage.derivation.max(Derivation::Transformation);
+        lineage
+    }
+
+    /// The type of the body of a lambda whose parameters have the given
+    /// types; missing parameter types are unknown.
+    fn lambda_type(
+        &mut self,
+        lambda: &parse_tree::Lambda,
+        parameter_types: &[DataType],
+        scope: &Scope,
+    ) -> DataType {
+        let mut lambda_scope = Scope::child(scope);
+        lambda_scope.lambda_parameters = lambda_parameter_names(lambda)
+            .into_iter()
+            .enumerate()
+            .map(|(index, parameter)| {
+                let data_type = parameter_types.get(index).cloned();
+                (parameter, data_type.unwrap_or(DataType::Unknown))
+            })
+            .collect();
+        self.resolve_expression(&lambda.body, &lambda_scope)
+    }
+
+    /// Binds a function call to a signature of its function, reporting
+    /// unknown functions, arguments which fit no signature, and clauses
+    /// which are not valid for the kind of function.
+    fn function_call_type(
+        &mut self,
+        function_call: &parse_tree::FunctionCall,
+        tree: &ParseTree,
+        scope: &Scope,
+    ) -> DataType {
+        let name = qualified_name_parts(&function_call.name).join(".");
+        let argument_trees = match &*function_call.arguments {
+            // count(*)
+            ParseTree::Token(_) => Vec::new(),
+            arguments => list_elements(arguments),
+        };
+        let arguments: Vec<ArgumentType> = argument_trees
+            .iter()
+            .map(|argument| match argument {
+                ParseTree::Lambda(lambda) => {
+                    ArgumentType::Lambda(lambda_parameter_names(lambda).len())
+                }
+                argument => ArgumentType::Value(self.resolve_expression(argument, scope)),
+            })
+            .collect();
+        self.resolve_expression(&function_call.order_by_opt, scope);
+        if let ParseTree::Filter(filter) = &*function_call.filter_opt {
+            self.resolve_predicate(&filter.predicate, scope, "FILTER clause");
+        }
+        self.resolve_expression(&function_call.over_opt, scope);
+
+        let functions = self.catalog.functions();
+        let kind = match functions.kind(&name) {
+            Some(kind) => kind,
+            None => {
+                self.error(
+                    ERROR_UNKNOWN_FUNCTION,
+                    function_call.name.get_range(),
+                    format!("Function '{}' not registered.", name),
+                );
+                self.resolve_lambda_arguments(&argument_trees, scope);
+                return DataType::Unknown;
+            }
+        };
+        self.resolution.functions.push(FunctionBinding {
+            range: tree.get_range(),
+            name: name.clone(),
+            kind,
+        });
+        self.check_function_clauses(function_call, &name, kind);
+        let errors = windows::check_function_call(function_call, &name, kind, &self.resolution);
+        self.resolution.errors.extend(errors);
+
+        let (signature, mut bindings) = match functions.resolve(&name, &arguments) {
+            Some(resolved) => resolved,
+            None => {
+                let argument_types: Vec<String> = arguments
+                    .iter()
+                    .map(|argument| argument.to_string())
+                    .collect();
+                let signatures: Vec<String> = functions
+                    .signatures(&name)
+                    .iter()
+                    .map(|signature| signature.to_string())
+                    .collect();
+                self.error(
+                    ERROR_INVALID_ARGUMENTS,
+                    tree.get_range(),
+                    format!(
+                        "Unexpected parameters ({}) for function {}. Expected: {}",
+                        argument_types.join(", "),
+                        name,
+                        signatures.join(", ")
+                    ),
+                );
+                self.resolve_lambda_arguments(&argument_trees, scope);
+                return DataType::Unknown;
+            }
+        };
+        let argument_signatures = signature
+            .argument_signatures(argument_trees.len())
+            .unwrap_or_default();
+        for (argument, argument_signature) in argument_trees.iter().zip(argument_signatures) {
+            if let (ParseTree::Lambda(lambda), TypeSignature::Function(parameters, result)) =
+                (argument, argument_signature)
+            {
+                let parameter_types: Vec<DataType> = parameters
+                    .iter()
+                    .map(|parameter| parameter.bind(&bindings))
+                    .collect();
+                let body = self.lambda_type(lambda, &parameter_types, scope);
+                let expected = result.bind(&bindings);
+                if !bindings.bind(result, &body) {
+                    self.error(
+                        ERROR_TYPE_MISMATCH,
+                        lambda.body.get_range(),
+                        format!(
+                            "Lambda expression for function {} must evaluate to {} (actual: {})",
+                            name, expected, body
+                        ),
+                    );
+                }
+            }
+        }
+        signature.return_type.bind(&bindings)
+    }
+
+    /// Resolves the lambdas among the arguments of a call which could not
+    /// be bound, with parameters of unknown type.
+    fn resolve_lambda_arguments(&mut self, arguments: &[&ParseTree], scope: &Scope) {
+        for argument in arguments {
+            if let ParseTree::Lambda(lambda) = argument {
+                self.lambda_type(lambda, &[], scope);
+            }
+        }
+    }
+
+    /// Reports DISTINCT, ORDER BY and FILTER on functions which are not
+    /// aggregates, and OVER on scalar functions.
+    fn check_function_clauses(
+        &mut self,
+        function_call: &parse_tree::FunctionCall,
+        name: &str,
+        kind: FunctionKind,
+    ) {
+        if kind != FunctionKind::Aggregate {
+            for (clause, description) in &[
+                (&function_call.set_quantifier_opt, "DISTINCT and ALL are"),
+                (&function_call.order_by_opt, "ORDER BY is"),
+                (&function_call.filter_opt, "FILTER is"),
+            ] {
+                if !clause.is_empty() {
+                    self.error(
+                        ERROR_INVALID_FUNCTION_CALL,
+                        clause.get_range(),
+                        format!(
+                            "{} only valid for aggregate functions: {} is a {} function",
+                            description, name, kind
+                        ),
+                    );
+                }
+            }
+        }
+        if kind == FunctionKind::Scalar && !function_call.over_opt.is_empty() {
+            self.error(
+                ERROR_INVALID_FUNCTION_CALL,
+                function_call.over_opt.get_range(),
+                format!(
+                    "OVER is only valid for aggregate and window functions: {} is a scalar function",
+                    name
+                ),
+            );
+        }
+    }
+
+    /// The type of ROW(...) or (a, b, ...): a row with anonymous fields.
+    fn row_type(&mut self, elements: &ParseTree, scope: &Scope) -> DataType {
+        DataType::Row(
+            list_elements(elements)
+                .into_iter()
+                .map(|element| RowField::new(None, self.resolve_expression(element, scope)))
+                .collect(),
+        )
+    }
+
+    fn case_type(&mut self, case: &parse_tree::Case, tree: &ParseTree, scope: &Scope) -> DataType {
+        let operand = if case.value_opt.is_empty() {
+            None
+        } else {
+            Some(self.resolve_expression(&case.value_opt, scope))
+        };
+        let mut results = Vec::new();
+        for when_clause in list_elements(&case.when_clauses) {
+            if let ParseTree::WhenClause(when_clause) = when_clause {
+                match &operand {
+                    Some(operand) => {
+                        let condition = self.resolve_expression(&when_clause.condition, scope);
+                        self.check_comparison("=", operand, &condition, &when_clause.condition);
+                    }
+                    None => self.resolve_predicate(&when_clause.condition, scope, "WHEN clause"),
+                }
+                results.push(self.resolve_expression(&when_clause.result, scope));
+            }
+        }
+        if !case.default.is_empty() {
+            results.push(self.resolve_expression(&case.default, scope));
+        }
+        match DataType::common_super_type_of(&results) {
+            Some(data_type) => data_type,
+            None => {
+                self.error(
+                    ERROR_INCOMPATIBLE_TYPES,
+                    tree.get_range(),
+                    format!(
+                        "All CASE results must be the same type: {}",
+                        type_list(&results)
+                    ),
+                );
+                DataType::Unknown
+            }
+        }
+    }
+
+    /// The type of operand[index].
+    fn subscript_type(
+        &mut self,
+        operand: &DataType,
+        index: &DataType,
+        index_tree: &ParseTree,
+        tree: &ParseTree,
+    ) -> DataType {
+        match operand {
+            DataType::Array(element) => {
+                if !index.can_coerce_to(&DataType::BigInt) {
+                    self.error(
+                        ERROR_INVALID_SUBSCRIPT,
+                        index_tree.get_range(),
+                        format!("Array subscript must be an integer: actual type {}", index),
+                    );
+                }
+                (**element).clone()
+            }
+            DataType::Map(key, value) => {
+                if DataType::common_super_type(index, key).is_none() {
+                    self.error(
+                        ERROR_INVALID_SUBSCRIPT,
+                        index_tree.get_range(),
+                        format!(
+                            "Map subscript must be of the key type {}: actual type {}",
+                            key, index
+                        ),
+                    );
+                }
+                (**value).clone()
+            }
+            DataType::Row(fields) => {
+                let position = match index_tree {
+                    ParseTree::Literal(_) if index.is_integral() => {
+                        string_value(index_tree).and_then(|value| value.parse::<usize>().ok())
+                    }
+                    _ => None,
+                };
+                match position {
+                    Some(position) if position >= 1 && position <= fields.len() => {
+                        fields[position - 1].data_type.clone()
+                    }
+                    Some(position) => {
+                        self.error(
+                            ERROR_INVALID_SUBSCRIPT,
+                            index_tree.get_range(),
+                            format!(
+                                "Invalid subscript index: {}. ROW indices start at 1 and must be less than or equal to the number of fields: {}",
+                                position,
+                                fields.len()
+                            ),
+                        );
+                        DataType::Unknown
+                    }
+                    None => {
+                        self.error(
+                            ERROR_INVALID_SUBSCRIPT,
+                            index_tree.get_range(),
+                            "ROW subscript must be an integer literal".to_string(),
+                        );
+                        DataType::Unknown
+                    }
+                }
+            }
+            DataType::Unknown => DataType::Unknown,
+            operand => {
+                self.error(
+                    ERROR_INVALID_SUBSCRIPT,
+                    tree.get_range(),
+                    format!("Cannot subscript a value of type {}", operand),
+                );
+                DataType::Unknown
+            }
+        }
+    }
+
+    /// The type of the field name of a value of a row type.
+    fn row_field_type(&mut self, row: &DataType, name: &str, tree: &ParseTree) -> DataType {
+        match row {
+            DataType::Row(fields) => {
+                match fields
+                    .iter()
+                    .find(|field| field.name.as_deref() == Some(name))
+                {
+                    Some(field) => field.data_type.clone(),
+                    None => {
+                        self.error(
+                            ERROR_UNKNOWN_FIELD,
+                            tree.get_range(),
+                            format!("Field '{}' not found in type {}.", name, row),
+                        );
+                        DataType::Unknown
+                    }
+                }
+            }
+            DataType::Unknown => DataType::Unknown,
+            row => {
+                self.error(
+                    ERROR_UNKNOWN_FIELD,
+                    tree.get_range(),
+                    format!(
+                        "Expression of type {} is not a row; cannot access field '{}'.",
+                        row, name
+                    ),
+                );
+                DataType::Unknown
+            }
+        }
+    }
+
+    fn resolve_column(
+        &mut self,
+        tree: &ParseTree,
+        qualifier: &[String],
+        name: &str,
+        scope: &Scope,
+    ) -> DataType {
+        let range = tree.get_range();
+        let mut full_name = qualifier.to_vec();
+        full_name.push(name.to_string());
+        match scope.lookup(qualifier, name) {
+            Lookup::Found(field) => {
+                self.bind_column(range, field.target);
+                self.lineages.push((range, field.lineage));
+                return field.data_type;
+            }
+            Lookup::Unknown => (),
+            Lookup::Ambiguous => self.error(
+                ERROR_AMBIGUOUS_COLUMN,
+                range,
+                format!("Column '{}' is ambiguous.", full_name.join(".")),
+            ),
+            Lookup::NotFound => self.error(
+                ERROR_UNKNOWN_COLUMN,
+                range,
+                format!("Column '{}' cannot be resolved.", full_name.join(".")),
+            ),
+        }
+        DataType::Unknown
+    }
+
+    /// Resolves a.b.c: the longest prefix which names a relation qualifies
+    /// the following column; otherwise a is a column. Any remaining parts
+    /// are fields of a row.
+    fn resolve_dereference(&mut self, parts: &[(String, &ParseTree)], scope: &Scope) -> DataType {
+        let names: Vec<String> = parts.iter().map(|(name, _)| name.clone()).collect();
+        let mut column_index = 0;
+        for qualifier_length in (1..names.len().min(4)).rev() {
+            if scope.has_relation(&names[..qualifier_length]) {
+                column_index = qualifier_length;
+                break;
+            }
+        }
+        let (name, tree) = &parts[column_index];
+        let mut data_type = self.resolve_column(tree, &names[..column_index], name, scope);
+        // The type of the whole reference is recorded by resolve_expression;
+        // record the types of the references it contains.
+        let mut current = *tree;
+        for (field_name, field_tree) in &parts[column_index + 1..] {
+            self.resolution.types.push(ExpressionType {
+                range: current.get_range(),
+                data_type: data_type.clone(),
+            });
+            data_type = self.row_field_type(&data_type, field_name, field_tree);
+            current = field_tree;
+        }
+        data_type
+    }
+}
+
+/// The names of the parameters of a lambda.
+fn lambda_parameter_names(lambda: &parse_tree::Lambda) -> Vec<String> {
+    match &*lambda.parameters {
+        ParseTree::List(_) => identifier_list(&lambda.parameters),
+        parameter => tree_name(parameter).into_iter().collect(),
+    }
+}
+
+/// Whether a tree is an expression, whose type is recorded.
+fn is_expression(tree: &ParseTree) -> bool {
+    matches!(
+        tree,
+        ParseTree::BinaryExpression(_)
+            | ParseTree::UnaryExpression(_)
+            | ParseTree::QuantifiedComparison(_)
+            | ParseTree::NullPredicate(_)
+            | ParseTree::DistinctFrom(_)
+            | ParseTree::Between(_)
+            | ParseTree::Like(_)
+            | ParseTree::InSubquery(_)
+            | ParseTree::InList(_)
+            | ParseTree::AtTimeZone(_)
+            | ParseTree::Dereference(_)
+            | ParseTree::Subscript(_)
+            | ParseTree::Literal(_)
+            | ParseTree::RowConstructor(_)
+            | ParseTree::ParenthesizedExpression(_)
+            | ParseTree::Identifier(_)
+            | ParseTree::FunctionCall(_)
+            | ParseTree::UnicodeString(_)
+            | ParseTree::SubqueryExpression(_)
+            | ParseTree::Grouping(_)
+            | ParseTree::Extract(_)
+            | ParseTree::CurrentTime(_)
+            | ParseTree::CurrentTimestamp(_)
+            | ParseTree::Normalize(_)
+            | ParseTree::Localtime(_)
+            | ParseTree::Localtimestamp(_)
+            | ParseTree::Cast(_)
+            | ParseTree::Case(_)
+            | ParseTree::Exists(_)
+            | ParseTree::TypeConstructor(_)
+            | ParseTree::Array(_)
+            | ParseTree::Interval(_)
+            | ParseTree::Row(_)
+            | ParseTree::TryCast(_)
+            | ParseTree::Substring(_)
+            | ParseTree::Position(_)
+    )
+}
+
+/// A scope containing the relations to the left of a relation in a FROM
+/// clause, followed by relations.
+fn lateral_scope<'p>(
+    outer: &'p Scope<'p>,
+    left: &[ScopeRelation],
+    relations: &[ScopeRelation],
+) -> Scope<'p> {
+    let mut scope = Scope::child(outer);
+    scope.relations = left.iter().chain(relations.iter()).cloned().collect();
+    scope
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{ddl, MemoryCatalog};
+    use crate::parsing::parse_statement;
+    use crate::utils::syntax_error::{ERROR_INVALID_TABLE_NAME, ERROR_INVALID_TYPE};
+
+    const SCHEMA: &str = "
+        create table hive.web.orders (id bigint, customer_id bigint, total double,
+            status varchar(10), created date, tags array(varchar),
+            info row(name varchar, age integer));
+        create table hive.web.customers (id bigint, name varchar, region varchar);
+    ";
+
+    fn resolve_text(text: &str) -> Resolution {
+        let session = Session::new("hive", "web");
+        let mut catalog = MemoryCatalog::new();
+        let errors = ddl::load_script(&mut catalog, &session, SCHEMA);
+        assert!(errors.is_empty(), "{:?}", errors);
+        let (tree, errors) = parse_statement(text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        resolve(&tree, &catalog, &session)
+    }
+
+    fn error_codes(text: &str) -> Vec<i32> {
+        resolve_text(text)
+            .errors
+            .iter()
+            .map(|error| error.error_code)
+            .collect()
+    }
+
+    fn column_targets(text: &str) -> Vec<ColumnTarget> {
+        resolve_text(text)
+            .columns
+            .into_iter()
+            .map(|binding| binding.target)
+            .collect()
+    }
+
+    fn orders_column(column: &str) -> ColumnTarget {
+        ColumnTarget::Table {
+            table: QualifiedTableName::new("hive", "web", "orders"),
+            column: column.to_string(),
+        }
+    }
+
+    fn customers_column(column: &str) -> ColumnTarget {
+        ColumnTarget::Table {
+            table: QualifiedTableName::new("hive", "web", "customers"),
+            column: column.to_string(),
+        }
+    }
+
+    #[test]
+    fn binds_table_columns() {
+        let resolution = resolve_text("select o.id, TOTAL from web.orders o");
+        assert!(resolution.errors.is_empty(), "{:?}", resolution.errors);
+        let relations: Vec<RelationTarget> = resolution
+            .relations
+            .iter()
+            .map(|binding| binding.target.clone())
+            .collect();
+        assert_eq!(
+            relations,
+            vec![RelationTarget::Table(QualifiedTableName::new(
+                "hive", "web", "orders"
+            ))]
+        );
+        assert_eq!(
+            column_targets("select o.id, TOTAL from web.orders o"),
+            vec![orders_column("id"), orders_column("total")]
+        );
+    }
+
+    #[test]
+    fn binds_derived_and_output_columns() {
+        assert_eq!(
+            column_targets("with t (n) as (select id from orders) select n from t"),
+            vec![orders_column("id"), orders_column("id")]
+        );
+        assert_eq!(
+            column_targets("with t as (select id + 1 as n from orders) select n from t"),
+            vec![
+                orders_column("id"),
+                ColumnTarget::Derived {
+                    relation: Some("t".to_string()),
+                    column: Some("n".to_string()),
+                    index: 0,
+                },
+            ]
+        );
+        assert_eq!(
+            column_targets("select total as x from orders order by x"),
+            vec![orders_column("total"), ColumnTarget::Output { index: 0 }]
+        );
+    }
+
+    #[test]
+    fn binds_correlated_columns() {
+        // WHERE is resolved before the select items.
+        assert_eq!(
+            column_targets(
+                "select name from customers c where exists \
+                 (select 1 from orders where customer_id = c.id)"
+            ),
+            vec![
+                orders_column("customer_id"),
+                customers_column("id"),
+                customers_column("name"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_table() {
+        assert_eq!(
+            error_codes("select * from missing"),
+            vec![ERROR_UNKNOWN_TABLE]
+        );
+        assert_eq!(
+            error_codes("select * from other.orders"),
+            vec![ERROR_UNKNOWN_TABLE]
+        );
+    }
+
+    #[test]
+    fn unknown_column() {
+        assert_eq!(
+            error_codes("select nope from orders"),
+            vec![ERROR_UNKNOWN_COLUMN]
+        );
+        assert_eq!(
+            error_codes("select total from orders order by name"),
+            vec![ERROR_UNKNOWN_COLUMN]
+        );
+    }
+
+    #[test]
+    fn ambiguous_column() {
+        assert_eq!(
+            error_codes("select id from orders, customers"),
+            vec![ERROR_AMBIGUOUS_COLUMN]
+        );
+        assert!(error_codes("select orders.id from orders, customers").is_empty());
+    }
+
+    #[test]
+    fn unknown_relation() {
+        assert_eq!(
+            error_codes("select x.* from orders"),
+            vec![ERROR_UNKNOWN_RELATION]
+        );
+    }
+
+    #[test]
+    fn column_alias_count() {
+        assert_eq!(
+            error_codes("select * from (select 1, 2) t(a)"),
+            vec![ERROR_COLUMN_ALIAS_COUNT]
+        );
+        assert!(error_codes("select a, b from (select 1, 2) t(a, b)").is_empty());
+    }
+
+    #[test]
+    fn duplicate_with_query() {
+        assert_eq!(
+            error_codes("with a as (select 1), A as (select 2) select * from a"),
+            vec![ERROR_DUPLICATE_WITH_QUERY]
+        );
+    }
+
+    #[test]
+    fn invalid_table_name() {
+        assert_eq!(
+            error_codes("select * from a.b.c.d"),
+            vec![ERROR_INVALID_TABLE_NAME]
+        );
+    }
+
+    fn output_types(text: &str) -> Vec<DataType> {
+        let resolution = resolve_text(text);
+        assert!(resolution.errors.is_empty(), "{:?}", resolution.errors);
+        resolution
+            .output
+            .unwrap()
+            .into_iter()
+            .map(|field| field.data_type)
+            .collect()
+    }
+
+    #[test]
+    fn infers_types_with_coercion() {
+        let decimal = |precision, scale| DataType::Decimal { precision, scale };
+        assert_eq!(
+            output_types("select id + 1, 1 + 2, 1.5 * 2, total + 1, status || 'ab' from orders"),
+            vec![
+                DataType::BigInt,
+                DataType::Integer,
+                decimal(12, 1),
+                DataType::Double,
+                DataType::Varchar(Some(12)),
+            ]
+        );
+        assert_eq!(
+            output_types("select coalesce(created, timestamp '2020-01-01 00:00:00') from orders"),
+            vec![DataType::Timestamp]
+        );
+        assert_eq!(
+            output_types("select case when true then 1 else 2.5 end, array[1, 2.5]"),
+            vec![decimal(11, 1), DataType::Array(Box::new(decimal(11, 1)))]
+        );
+        assert_eq!(
+            output_types("select tags[1], info.name, cast(null as bigint) from orders"),
+            vec![
+                DataType::Varchar(None),
+                DataType::Varchar(None),
+                DataType::BigInt
+            ]
+        );
+        assert!(error_codes("select id from orders where id in (1, 2.5, null)").is_empty());
+    }
+
+    #[test]
+    fn type_mismatch() {
+        assert_eq!(
+            error_codes("select id + 'x' from orders"),
+            vec![ERROR_TYPE_MISMATCH]
+        );
+        assert_eq!(
+            error_codes("select id from orders where status = 1"),
+            vec![ERROR_TYPE_MISMATCH]
+        );
+        // Errors do not cascade through expressions of unknown type.
+        assert_eq!(
+            error_codes("select (id + 'x') * 2 from orders"),
+            vec![ERROR_TYPE_MISMATCH]
+        );
+    }
+
+    #[test]
+    fn not_boolean() {
+        assert_eq!(
+            error_codes("select id from orders where total"),
+            vec![ERROR_NOT_BOOLEAN]
+        );
+        assert_eq!(
+            error_codes("select 1 from orders o join customers c on o.id"),
+            vec![ERROR_NOT_BOOLEAN]
+        );
+    }
+
+    #[test]
+    fn incompatible_types() {
+        assert_eq!(
+            error_codes("select case when true then 1 else 'a' end"),
+            vec![ERROR_INCOMPATIBLE_TYPES]
+        );
+        assert_eq!(
+            error_codes("select 1 union select 'a'"),
+            vec![ERROR_INCOMPATIBLE_TYPES]
+        );
+    }
+
+    #[test]
+    fn invalid_subscript() {
+        assert_eq!(
+            error_codes("select total[1] from orders"),
+            vec![ERROR_INVALID_SUBSCRIPT]
+        );
+        assert_eq!(
+            error_codes("select tags['a'] from orders"),
+            vec![ERROR_INVALID_SUBSCRIPT]
+        );
+    }
+
+    #[test]
+    fn unknown_field() {
+        assert_eq!(
+            error_codes("select info.missing from orders"),
+            vec![ERROR_UNKNOWN_FIELD]
+        );
+    }
+
+    #[test]
+    fn invalid_type() {
+        assert_eq!(
+            error_codes("select cast(1 as foo)"),
+            vec![ERROR_INVALID_TYPE]
+        );
+        assert_eq!(
+            error_codes("select cast(total as decimal(39, 2)) from orders"),
+            vec![ERROR_INVALID_TYPE]
+        );
+    }
+
+    #[test]
+    fn binds_function_calls() {
+        let resolution =
+            resolve_text("select abs(total), sum(id) over (), rank() over () from orders");
+        assert!(resolution.errors.is_empty(), "{:?}", resolution.errors);
+        let functions: Vec<(&str, FunctionKind)> = resolution
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.kind))
+            .collect();
+        assert_eq!(
+            functions,
+            vec![
+                ("abs", FunctionKind::Scalar),
+                ("sum", FunctionKind::Aggregate),
+                ("rank", FunctionKind::Window),
+            ]
+        );
+        assert_eq!(
+            output_types("select abs(total), length(status), coalesce(id, 1) from orders"),
+            vec![DataType::Double, DataType::BigInt, DataType::BigInt]
+        );
+        assert_eq!(
+            output_types("select count(*), sum(id), avg(id), max(status) from orders"),
+            vec![
+                DataType::BigInt,
+                DataType::BigInt,
+                DataType::Double,
+                DataType::Varchar(Some(10))
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_function() {
+        assert_eq!(
+            error_codes("select nosuch(1)"),
+            vec![ERROR_UNKNOWN_FUNCTION]
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert_eq!(
+            error_codes("select abs('x')"),
+            vec![ERROR_INVALID_ARGUMENTS]
+        );
+        assert_eq!(
+            error_codes("select substr(status) from orders"),
+            vec![ERROR_INVALID_ARGUMENTS]
+        );
+    }
+
+    #[test]
+    fn invalid_function_call() {
+        assert_eq!(
+            error_codes("select abs(distinct total) from orders"),
+            vec![ERROR_INVALID_FUNCTION_CALL]
+        );
+        assert_eq!(
+            error_codes("select abs(total) filter (where id > 1) from orders"),
+            vec![ERROR_INVALID_FUNCTION_CALL]
+        );
+        assert_eq!(
+            error_codes("select abs(total) over () from orders"),
+            vec![ERROR_INVALID_FUNCTION_CALL]
+        );
+        assert!(
+            error_codes("select count(distinct id) filter (where id > 1) from orders").is_empty()
+        );
+    }
+}
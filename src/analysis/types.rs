@@ -0,0 +1,981 @@
+//! Presto data types, and the rules for implicit coercion and for the
+//! result types of operators.
+//!
+//! Types are built from the type trees produced by the parser, or parsed
+//! from text with parse_data_type. Parameters of built in types are
+//! validated with Presto's bounds, e.g. decimal precision is 1 to 38, and
+//! names which are not Presto types are errors.
+//!
+//! Unknown is the type of NULL, which coerces to every type. It is also the
+//! type of expressions whose type cannot be determined, so that errors do
+//! not cascade.
+use super::{list_elements, tree_name};
+use crate::lexing::literal::NumberValue;
+use crate::lexing::{token, TokenKind};
+use crate::parsing::{parse_type, ParseTree};
+use crate::utils::syntax_error::ERROR_INVALID_TYPE;
+use crate::utils::{text_range, Message, SyntaxError, TextRange};
+use std::convert::TryFrom;
+use std::fmt;
+
+pub const MAX_DECIMAL_PRECISION: u32 = 38;
+pub const MAX_VARCHAR_LENGTH: u32 = 2_147_483_646;
+pub const MAX_CHAR_LENGTH: u32 = 65_536;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DataType {
+    Unknown,
+    Boolean,
+    TinyInt,
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    Double,
+    Decimal {
+        precision: u32,
+        scale: u32,
+    },
+    /// varchar(n); None for unbounded varchar.
+    Varchar(Option<u32>),
+    /// char(n); char is char(1).
+    Char(u32),
+    Varbinary,
+    Date,
+    Time,
+    TimeWithTimeZone,
+    Timestamp,
+    TimestampWithTimeZone,
+    IntervalYearToMonth,
+    IntervalDayToSecond,
+    Array(Box<DataType>),
+    Map(Box<DataType>, Box<DataType>),
+    Row(Vec<RowField>),
+    Json,
+    IpAddress,
+    /// Any other type, by its canonical name, e.g. "hyperloglog".
+    Other(String),
+}
+
+/// A field of a row type; fields of anonymous rows have no name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RowField {
+    pub name: Option<String>,
+    pub data_type: DataType,
+}
+
+impl RowField {
+    pub fn new(name: Option<String>, data_type: DataType) -> RowField {
+        RowField { name, data_type }
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Unknown => write!(f, "unknown"),
+            DataType::Boolean => write!(f, "boolean"),
+            DataType::TinyInt => write!(f, "tinyint"),
+            DataType::SmallInt => write!(f, "smallint"),
+            DataType::Integer => write!(f, "integer"),
+            DataType::BigInt => write!(f, "bigint"),
+            DataType::Real => write!(f, "real"),
+            DataType::Double => write!(f, "double"),
+            DataType::Decimal { precision, scale } => {
+                write!(f, "decimal({},{})", precision, scale)
+            }
+            DataType::Varchar(None) => write!(f, "varchar"),
+            DataType::Varchar(Some(length)) => write!(f, "varchar({})", length),
+            DataType::Char(length) => write!(f, "char({})", length),
+            DataType::Varbinary => write!(f, "varbinary"),
+            DataType::Date => write!(f, "date"),
+            DataType::Time => write!(f, "time"),
+            DataType::TimeWithTimeZone => write!(f, "time with time zone"),
+            DataType::Timestamp => write!(f, "timestamp"),
+            DataType::TimestampWithTimeZone => write!(f, "timestamp with time zone"),
+            DataType::IntervalYearToMonth => write!(f, "interval year to month"),
+            DataType::IntervalDayToSecond => write!(f, "interval day to second"),
+            DataType::Array(element) => write!(f, "array({})", element),
+            DataType::Map(key, value) => write!(f, "map({}, {})", key, value),
+            DataType::Row(fields) => {
+                write!(f, "row(")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    match &field.name {
+                        Some(name) => write!(f, "{} {}", name, field.data_type)?,
+                        None => write!(f, "{}", field.data_type)?,
+                    }
+                }
+                write!(f, ")")
+            }
+            DataType::Json => write!(f, "json"),
+            DataType::IpAddress => write!(f, "ipaddress"),
+            DataType::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl DataType {
+    /// The type denoted by a type tree, as produced by the parser for
+    /// CAST and column definitions. Invalid parameters are not reported;
+    /// see try_from_tree.
+    pub fn from_tree(tree: &ParseTree) -> DataType {
+        DataType::convert(tree, &mut Vec::new())
+    }
+
+    /// The type denoted by a type tree; errors for invalid type
+    /// parameters, such as decimal(40) or varchar(x).
+    pub fn try_from_tree(tree: &ParseTree) -> Result<DataType, Vec<SyntaxError>> {
+        let mut errors = Vec::new();
+        let data_type = DataType::convert(tree, &mut errors);
+        if errors.is_empty() {
+            Ok(data_type)
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn convert(tree: &ParseTree, errors: &mut Vec<SyntaxError>) -> DataType {
+        let range = tree.get_range();
+        match tree {
+            ParseTree::Token(token) => {
+                DataType::from_name(&type_token_name(&token.token), &[], range, errors)
+            }
+            ParseTree::Identifier(_) => {
+                DataType::from_name(&tree_name(tree).unwrap_or_default(), &[], range, errors)
+            }
+            ParseTree::NamedType(named_type) => {
+                let name = match &*named_type.name {
+                    ParseTree::Token(token) => type_token_name(&token.token),
+                    name => tree_name(name).unwrap_or_default(),
+                };
+                let parameters: Vec<TypeParameter> = list_elements(&named_type.type_parameters)
+                    .into_iter()
+                    .map(|parameter| match parameter {
+                        ParseTree::Token(token) if token.token.kind == TokenKind::Integer => {
+                            TypeParameter::Number(token.token.value.parse().unwrap_or(u64::MAX))
+                        }
+                        parameter => TypeParameter::Type(DataType::convert(parameter, errors)),
+                    })
+                    .collect();
+                DataType::from_name(&name, &parameters, range, errors)
+            }
+            ParseTree::ArrayType(array_type) => DataType::Array(Box::new(DataType::convert(
+                &array_type.element_type,
+                errors,
+            ))),
+            ParseTree::ArrayTypeSuffix(array_type_suffix) => DataType::Array(Box::new(
+                DataType::convert(&array_type_suffix.base_type, errors),
+            )),
+            ParseTree::MapType(map_type) => DataType::Map(
+                Box::new(DataType::convert(&map_type.key_type, errors)),
+                Box::new(DataType::convert(&map_type.value_type, errors)),
+            ),
+            ParseTree::RowType(row_type) => DataType::Row(
+                list_elements(&row_type.element_types)
+                    .into_iter()
+                    .map(|element| match element {
+                        ParseTree::RowTypeElement(row_type_element) => RowField::new(
+                            tree_name(&row_type_element.identifier),
+                            DataType::convert(&row_type_element.type_, errors),
+                        ),
+                        element => RowField::new(None, DataType::convert(element, errors)),
+                    })
+                    .collect(),
+            ),
+            ParseTree::IntervalType(interval_type) => {
+                DataType::interval(&tree_name(&interval_type.from).unwrap_or_default())
+            }
+            _ => DataType::Unknown,
+        }
+    }
+
+    /// The type with a name, such as "bigint", and its parameters.
+    /// Invalid parameters of built in types are reported in errors.
+    fn from_name(
+        name: &str,
+        parameters: &[TypeParameter],
+        range: TextRange,
+        errors: &mut Vec<SyntaxError>,
+    ) -> DataType {
+        let mut error = |message: String| {
+            errors.push(SyntaxError::from_message(
+                ERROR_INVALID_TYPE,
+                Message::new(range, message),
+            ))
+        };
+        let mut bounded = |kind: &str, value: u64, min: u32, max: u32| {
+            if value < u64::from(min) || value > u64::from(max) {
+                error(format!(
+                    "{} must be in range [{}, {}]: {}",
+                    kind, min, max, value
+                ));
+            }
+            u32::try_from(value.clamp(u64::from(min), u64::from(max))).unwrap_or(max)
+        };
+        match (name, parameters) {
+            ("boolean", []) => DataType::Boolean,
+            ("tinyint", []) => DataType::TinyInt,
+            ("smallint", []) => DataType::SmallInt,
+            ("integer", []) | ("int", []) => DataType::Integer,
+            ("bigint", []) => DataType::BigInt,
+            ("real", []) => DataType::Real,
+            ("double", []) | ("double precision", []) => DataType::Double,
+            ("decimal", []) => DataType::Decimal {
+                precision: MAX_DECIMAL_PRECISION,
+                scale: 0,
+            },
+            ("decimal", [TypeParameter::Number(precision)]) => DataType::Decimal {
+                precision: bounded("DECIMAL precision", *precision, 1, MAX_DECIMAL_PRECISION),
+                scale: 0,
+            },
+            ("decimal", [TypeParameter::Number(precision), TypeParameter::Number(scale)]) => {
+                let precision = bounded("DECIMAL precision", *precision, 1, MAX_DECIMAL_PRECISION);
+                let scale = bounded("DECIMAL scale", *scale, 0, precision);
+                DataType::Decimal { precision, scale }
+            }
+            ("varchar", []) => DataType::Varchar(None),
+            ("varchar", [TypeParameter::Number(length)]) => DataType::Varchar(Some(bounded(
+                "VARCHAR length",
+                *length,
+                0,
+                MAX_VARCHAR_LENGTH,
+            ))),
+            ("char", []) => DataType::Char(1),
+            ("char", [TypeParameter::Number(length)]) => {
+                DataType::Char(bounded("CHAR length", *length, 1, MAX_CHAR_LENGTH))
+            }
+            ("varbinary", []) => DataType::Varbinary,
+            ("date", []) => DataType::Date,
+            ("time", []) => DataType::Time,
+            ("time with time zone", []) => DataType::TimeWithTimeZone,
+            ("timestamp", []) => DataType::Timestamp,
+            ("timestamp with time zone", []) => DataType::TimestampWithTimeZone,
+            ("json", []) => DataType::Json,
+            ("ipaddress", []) => DataType::IpAddress,
+            ("array", [TypeParameter::Type(element)]) => DataType::Array(Box::new(element.clone())),
+            ("map", [TypeParameter::Type(key), TypeParameter::Type(value)]) => {
+                DataType::Map(Box::new(key.clone()), Box::new(value.clone()))
+            }
+            _ => {
+                let parameters: Vec<String> = parameters.iter().map(|p| p.to_string()).collect();
+                let data_type = if parameters.is_empty() {
+                    DataType::Other(name.to_string())
+                } else {
+                    DataType::Other(format!("{}({})", name, parameters.join(", ")))
+                };
+                if is_built_in_type_name(name) {
+                    error(format!(
+                        "Invalid parameters for type {}: {}",
+                        name, data_type
+                    ));
+                } else if !is_other_type_name(name) {
+                    error(format!("Unknown type: {}", data_type));
+                }
+                data_type
+            }
+        }
+    }
+
+    /// The interval type whose leading field is field.
+    fn interval(field: &str) -> DataType {
+        match field {
+            "year" | "month" => DataType::IntervalYearToMonth,
+            _ => DataType::IntervalDayToSecond,
+        }
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        *self == DataType::Unknown
+    }
+
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, DataType::Boolean | DataType::Unknown)
+    }
+
+    pub fn is_integral(&self) -> bool {
+        matches!(
+            self,
+            DataType::TinyInt | DataType::SmallInt | DataType::Integer | DataType::BigInt
+        )
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integral()
+            || matches!(
+                self,
+                DataType::Real | DataType::Double | DataType::Decimal { .. }
+            )
+    }
+
+    pub fn is_string(&self) -> bool {
+        matches!(self, DataType::Varchar(_) | DataType::Char(_))
+    }
+
+    pub fn is_interval(&self) -> bool {
+        matches!(
+            self,
+            DataType::IntervalYearToMonth | DataType::IntervalDayToSecond
+        )
+    }
+
+    /// Whether values of the type may be compared with =.
+    pub fn is_comparable(&self) -> bool {
+        match self {
+            DataType::Array(element) => element.is_comparable(),
+            DataType::Map(key, value) => key.is_comparable() && value.is_comparable(),
+            DataType::Row(fields) => fields.iter().all(|field| field.data_type.is_comparable()),
+            DataType::Other(_) => false,
+            _ => true,
+        }
+    }
+
+    /// Whether values of the type may be compared with <.
+    pub fn is_orderable(&self) -> bool {
+        match self {
+            DataType::Array(element) => element.is_orderable(),
+            DataType::Row(fields) => fields.iter().all(|field| field.data_type.is_orderable()),
+            DataType::Map(..) | DataType::Json | DataType::Other(_) => false,
+            _ => true,
+        }
+    }
+
+    /// The smallest decimal type which holds all values of an integral type.
+    fn integral_as_decimal(&self) -> Option<(u32, u32)> {
+        match self {
+            DataType::TinyInt => Some((3, 0)),
+            DataType::SmallInt => Some((5, 0)),
+            DataType::Integer => Some((10, 0)),
+            DataType::BigInt => Some((19, 0)),
+            DataType::Decimal { precision, scale } => Some((*precision, *scale)),
+            _ => None,
+        }
+    }
+
+    fn integral_rank(&self) -> Option<u32> {
+        match self {
+            DataType::TinyInt => Some(1),
+            DataType::SmallInt => Some(2),
+            DataType::Integer => Some(3),
+            DataType::BigInt => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Whether a value of this type may be used where a value of type to is
+    /// expected, without an explicit CAST.
+    pub fn can_coerce_to(&self, to: &DataType) -> bool {
+        if self == to {
+            return true;
+        }
+        match (self, to) {
+            (DataType::Unknown, _) | (_, DataType::Unknown) => true,
+            (from, to) if from.is_integral() && to.is_integral() => {
+                from.integral_rank() < to.integral_rank()
+            }
+            (from, DataType::Decimal { precision, scale }) if from.is_integral() => {
+                let (from_precision, _) = from.integral_as_decimal().unwrap();
+                precision - scale >= from_precision
+            }
+            (
+                DataType::Decimal {
+                    precision: from_precision,
+                    scale: from_scale,
+                },
+                DataType::Decimal { precision, scale },
+            ) => scale >= from_scale && precision - scale >= from_precision - from_scale,
+            (from, DataType::Real) => {
+                from.is_integral() || matches!(from, DataType::Decimal { .. })
+            }
+            (from, DataType::Double) => from.is_numeric(),
+            (DataType::Varchar(_), DataType::Varchar(None)) => true,
+            (DataType::Varchar(Some(from)), DataType::Varchar(Some(to))) => from <= to,
+            (DataType::Char(from), DataType::Char(to)) => from <= to,
+            (DataType::Date, DataType::Timestamp)
+            | (DataType::Date, DataType::TimestampWithTimeZone)
+            | (DataType::Timestamp, DataType::TimestampWithTimeZone)
+            | (DataType::Time, DataType::TimeWithTimeZone) => true,
+            (DataType::Array(from), DataType::Array(to)) => from.can_coerce_to(to),
+            (DataType::Map(from_key, from_value), DataType::Map(key, value)) => {
+                from_key.can_coerce_to(key) && from_value.can_coerce_to(value)
+            }
+            (DataType::Row(from), DataType::Row(to)) => {
+                from.len() == to.len()
+                    && from
+                        .iter()
+                        .zip(to)
+                        .all(|(from, to)| from.data_type.can_coerce_to(&to.data_type))
+            }
+            _ => false,
+        }
+    }
+
+    /// The narrowest type which both types coerce to; None if there is none.
+    pub fn common_super_type(left: &DataType, right: &DataType) -> Option<DataType> {
+        if left.is_unknown() {
+            return Some(right.clone());
+        }
+        if right.is_unknown() || left.can_coerce_to(right) && right.can_coerce_to(left) {
+            return Some(left.clone());
+        }
+        match (left, right) {
+            (DataType::Decimal { .. }, _) | (_, DataType::Decimal { .. }) => {
+                if let (Some((p1, s1)), Some((p2, s2))) =
+                    (left.integral_as_decimal(), right.integral_as_decimal())
+                {
+                    let scale = s1.max(s2);
+                    let precision = (scale + (p1 - s1).max(p2 - s2)).min(MAX_DECIMAL_PRECISION);
+                    return Some(DataType::Decimal { precision, scale });
+                }
+            }
+            (DataType::Varchar(Some(left)), DataType::Varchar(Some(right))) => {
+                return Some(DataType::Varchar(Some(*left.max(right))));
+            }
+            (DataType::Char(left), DataType::Char(right)) => {
+                return Some(DataType::Char(*left.max(right)));
+            }
+            (DataType::Array(left), DataType::Array(right)) => {
+                return DataType::common_super_type(left, right)
+                    .map(|element| DataType::Array(Box::new(element)));
+            }
+            (DataType::Map(left_key, left_value), DataType::Map(right_key, right_value)) => {
+                return Some(DataType::Map(
+                    Box::new(DataType::common_super_type(left_key, right_key)?),
+                    Box::new(DataType::common_super_type(left_value, right_value)?),
+                ));
+            }
+            (DataType::Row(left), DataType::Row(right)) if left.len() == right.len() => {
+                return left
+                    .iter()
+                    .zip(right)
+                    .map(|(left, right)| {
+                        DataType::common_super_type(&left.data_type, &right.data_type)
+                            .map(|data_type| RowField::new(left.name.clone(), data_type))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(DataType::Row);
+            }
+            _ => (),
+        }
+        if left.can_coerce_to(right) {
+            Some(right.clone())
+        } else if right.can_coerce_to(left) {
+            Some(left.clone())
+        } else {
+            None
+        }
+    }
+
+    /// The common super type of all types; None if there is none.
+    pub fn common_super_type_of<'t, I>(types: I) -> Option<DataType>
+    where
+        I: IntoIterator<Item = &'t DataType>,
+    {
+        types
+            .into_iter()
+            .try_fold(DataType::Unknown, |result, data_type| {
+                DataType::common_super_type(&result, data_type)
+            })
+    }
+}
+
+/// Parses text containing a type, such as "array(varchar(10))".
+/// Errors are syntax errors, and invalid type parameters such as
+/// decimal(40, 2).
+pub fn parse_data_type(text: &str) -> Result<DataType, Vec<SyntaxError>> {
+    let (tree, errors) = parse_type(text);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    match &tree {
+        ParseTree::Entrypoint(entrypoint) => DataType::try_from_tree(&entrypoint.tree),
+        tree => DataType::try_from_tree(tree),
+    }
+}
+
+/// A parameter of a named type: a number, as in varchar(10), or a type,
+/// as in array(bigint).
+#[derive(Clone, Debug)]
+enum TypeParameter {
+    Number(u64),
+    Type(DataType),
+}
+
+impl fmt::Display for TypeParameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeParameter::Number(number) => write!(f, "{}", number),
+            TypeParameter::Type(data_type) => write!(f, "{}", data_type),
+        }
+    }
+}
+
+/// Whether name is the name of a built in type which takes parameters
+/// or none, such as varchar or bigint, rather than a custom type.
+fn is_built_in_type_name(name: &str) -> bool {
+    matches!(
+        name,
+        "boolean"
+            | "tinyint"
+            | "smallint"
+            | "integer"
+            | "int"
+            | "bigint"
+            | "real"
+            | "double"
+            | "double precision"
+            | "decimal"
+            | "varchar"
+            | "char"
+            | "varbinary"
+            | "date"
+            | "time"
+            | "time with time zone"
+            | "timestamp"
+            | "timestamp with time zone"
+            | "json"
+            | "ipaddress"
+            | "array"
+            | "map"
+    )
+}
+
+/// The names of Presto's types which are modelled as Other types.
+fn is_other_type_name(name: &str) -> bool {
+    matches!(
+        name,
+        "hyperloglog"
+            | "p4hyperloglog"
+            | "khyperloglog"
+            | "setdigest"
+            | "qdigest"
+            | "tdigest"
+            | "uuid"
+            | "ipprefix"
+            | "geometry"
+            | "sphericalgeography"
+            | "bingtile"
+            | "kdbtree"
+            | "color"
+            | "unknown"
+    )
+}
+
+/// The name of a type from its name token, with whitespace normalized,
+/// e.g. "timestamp with time zone".
+fn type_token_name(token: &token::Token) -> String {
+    token
+        .value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// The type of a literal token.
+pub fn literal_type(token: &token::Token) -> DataType {
+    match token.kind {
+        TokenKind::Integer => match token.number_value() {
+            Ok(NumberValue::Integer(value)) if i32::try_from(value).is_ok() => DataType::Integer,
+            _ => DataType::BigInt,
+        },
+        TokenKind::Decimal => decimal_literal_type(token.value),
+        TokenKind::Double => DataType::Double,
+        TokenKind::String | TokenKind::UnicodeString => match token.string_value() {
+            Ok(value) => DataType::Varchar(Some(value.chars().count() as u32)),
+            Err(_) => DataType::Varchar(None),
+        },
+        TokenKind::BinaryLiteral => DataType::Varbinary,
+        TokenKind::TRUE | TokenKind::FALSE => DataType::Boolean,
+        TokenKind::CURRENT_DATE => DataType::Date,
+        TokenKind::CURRENT_USER | TokenKind::CURRENT_PATH => DataType::Varchar(None),
+        _ => DataType::Unknown,
+    }
+}
+
+/// The type of a decimal literal such as 12.50: decimal(4,2).
+pub fn decimal_literal_type(value: &str) -> DataType {
+    let value = value.trim_start_matches(['+', '-']);
+    let (integer_part, fraction_part) = value.split_once('.').unwrap_or((value, ""));
+    let integer_digits = integer_part.trim_start_matches('0').len() as u32;
+    let scale = fraction_part.len() as u32;
+    DataType::Decimal {
+        precision: (integer_digits + scale).max(1),
+        scale,
+    }
+}
+
+/// The type of DATE '...', DECIMAL '...' and other type constructors.
+pub fn type_constructor_type(name: &str, value: &str) -> DataType {
+    match name {
+        "decimal" => decimal_literal_type(value.trim()),
+        "time" if has_time_zone(value, false) => DataType::TimeWithTimeZone,
+        "timestamp" if has_time_zone(value, true) => DataType::TimestampWithTimeZone,
+        "char" => DataType::Char((value.chars().count() as u32).max(1)),
+        _ => DataType::from_name(name, &[], text_range::NONE, &mut Vec::new()),
+    }
+}
+
+/// Whether a time or timestamp literal has a time zone, as in
+/// '2020-01-01 00:00:00 UTC' or '12:00:00+01:00'.
+fn has_time_zone(value: &str, is_timestamp: bool) -> bool {
+    let value = value.trim();
+    let time = if is_timestamp {
+        value.split_once(' ').map_or("", |(_, time)| time)
+    } else {
+        value
+    };
+    time.chars()
+        .any(|ch| ch.is_ascii_alphabetic() || ch == '+' || ch == '-')
+}
+
+/// The type of an interval literal, from its leading field.
+pub fn interval_type(field: &str) -> DataType {
+    DataType::interval(field)
+}
+
+/// The result type of an arithmetic operator: + - * / or %.
+/// None if the operator does not apply to the operand types.
+pub fn arithmetic_type(operator: &str, left: &DataType, right: &DataType) -> Option<DataType> {
+    use self::DataType::*;
+    match (left, right) {
+        (Unknown, Unknown) => Some(Unknown),
+        (Unknown, other) | (other, Unknown) => {
+            if other.is_numeric() || other.is_interval() {
+                Some(other.clone())
+            } else {
+                Some(Unknown)
+            }
+        }
+        (left, right) if left.is_integral() && right.is_integral() => {
+            DataType::common_super_type(left, right)
+        }
+        (Double, other) | (other, Double) if other.is_numeric() => Some(Double),
+        (Real, other) | (other, Real) if other.is_numeric() => Some(Real),
+        (left, right) if left.is_numeric() && right.is_numeric() => {
+            let (p1, s1) = left.integral_as_decimal()?;
+            let (p2, s2) = right.integral_as_decimal()?;
+            let (precision, scale) = match operator {
+                "+" | "-" => (1 + s1.max(s2) + (p1 - s1).max(p2 - s2), s1.max(s2)),
+                "*" => (p1 + p2, s1 + s2),
+                "/" => (p1 + s2 + s2.saturating_sub(s1), s1.max(s2)),
+                _ => ((p2 - s2).min(p1 - s1) + s1.max(s2), s1.max(s2)),
+            };
+            Some(Decimal {
+                precision: precision.min(MAX_DECIMAL_PRECISION),
+                scale: scale.min(MAX_DECIMAL_PRECISION),
+            })
+        }
+        (Date, interval) | (interval, Date) if interval.is_interval() => {
+            date_time_arithmetic(operator, left, Date)
+        }
+        (Timestamp, interval) | (interval, Timestamp) if interval.is_interval() => {
+            date_time_arithmetic(operator, left, Timestamp)
+        }
+        (TimestampWithTimeZone, interval) | (interval, TimestampWithTimeZone)
+            if interval.is_interval() =>
+        {
+            date_time_arithmetic(operator, left, TimestampWithTimeZone)
+        }
+        (Time, IntervalDayToSecond) | (IntervalDayToSecond, Time) => {
+            date_time_arithmetic(operator, left, Time)
+        }
+        (left, right) if left.is_interval() && left == right => match operator {
+            "+" | "-" => Some(left.clone()),
+            _ => None,
+        },
+        (interval, number) | (number, interval)
+            if interval.is_interval() && number.is_numeric() =>
+        {
+            match operator {
+                "*" => Some(interval.clone()),
+                "/" if left.is_interval() => Some(interval.clone()),
+                _ => None,
+            }
+        }
+        (Timestamp, Timestamp) if operator == "-" => Some(IntervalDayToSecond),
+        _ => None,
+    }
+}
+
+/// date_time + interval, interval + date_time and date_time - interval.
+fn date_time_arithmetic(operator: &str, left: &DataType, result: DataType) -> Option<DataType> {
+    match operator {
+        "+" => Some(result),
+        "-" if !left.is_interval() => Some(result),
+        _ => None,
+    }
+}
+
+/// The result type of ||: string or array concatenation.
+pub fn concat_type(left: &DataType, right: &DataType) -> Option<DataType> {
+    use self::DataType::*;
+    match (left, right) {
+        (Unknown, Unknown) => Some(Unknown),
+        (Varchar(Some(left)), Varchar(Some(right))) => Some(Varchar(
+            left.checked_add(*right)
+                .filter(|length| *length <= MAX_VARCHAR_LENGTH),
+        )),
+        (Varchar(_), Varchar(_)) | (Varchar(_), Unknown) | (Unknown, Varchar(_)) => {
+            Some(Varchar(None))
+        }
+        (Varbinary, Varbinary) | (Varbinary, Unknown) | (Unknown, Varbinary) => Some(Varbinary),
+        (Array(_), Array(_)) => DataType::common_super_type(left, right),
+        (Array(element), other) | (other, Array(element)) => {
+            DataType::common_super_type(element, other).map(|element| Array(Box::new(element)))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexing::Lexer;
+
+    fn varchar(length: u32) -> DataType {
+        DataType::Varchar(Some(length))
+    }
+
+    fn decimal(precision: u32, scale: u32) -> DataType {
+        DataType::Decimal { precision, scale }
+    }
+
+    fn array(element: DataType) -> DataType {
+        DataType::Array(Box::new(element))
+    }
+
+    fn parse(text: &str) -> DataType {
+        parse_data_type(text).unwrap()
+    }
+
+    fn invalid_type_message(text: &str) -> String {
+        let errors = parse_data_type(text).unwrap_err();
+        assert_eq!(errors.len(), 1, "{}", text);
+        assert_eq!(errors[0].error_code, ERROR_INVALID_TYPE, "{}", text);
+        errors[0].messages[0].message.clone()
+    }
+
+    fn literal(text: &str) -> DataType {
+        literal_type(&Lexer::new(text).next().unwrap())
+    }
+
+    #[test]
+    fn parse_types() {
+        assert_eq!(parse("INT"), DataType::Integer);
+        assert_eq!(parse("decimal"), decimal(38, 0));
+        assert_eq!(parse("decimal(10, 2)"), decimal(10, 2));
+        assert_eq!(parse("varchar"), DataType::Varchar(None));
+        assert_eq!(parse("char"), DataType::Char(1));
+        assert_eq!(
+            parse("timestamp with time zone"),
+            DataType::TimestampWithTimeZone
+        );
+        assert_eq!(
+            parse("map(varchar, array(bigint))"),
+            DataType::Map(
+                Box::new(DataType::Varchar(None)),
+                Box::new(array(DataType::BigInt))
+            )
+        );
+        assert_eq!(
+            parse("row(a bigint, b double)"),
+            DataType::Row(vec![
+                RowField::new(Some("a".to_string()), DataType::BigInt),
+                RowField::new(Some("b".to_string()), DataType::Double),
+            ])
+        );
+        assert_eq!(parse("uuid"), DataType::Other("uuid".to_string()));
+    }
+
+    #[test]
+    fn invalid_types() {
+        assert_eq!(invalid_type_message("foo"), "Unknown type: foo");
+        assert_eq!(
+            invalid_type_message("bigint(3)"),
+            "Invalid parameters for type bigint: bigint(3)"
+        );
+        assert_eq!(
+            invalid_type_message("decimal(39)"),
+            "DECIMAL precision must be in range [1, 38]: 39"
+        );
+        assert_eq!(
+            invalid_type_message("decimal(0, 0)"),
+            "DECIMAL precision must be in range [1, 38]: 0"
+        );
+        assert_eq!(
+            invalid_type_message("decimal(10, 11)"),
+            "DECIMAL scale must be in range [0, 10]: 11"
+        );
+        assert_eq!(
+            invalid_type_message("char(0)"),
+            "CHAR length must be in range [1, 65536]: 0"
+        );
+        assert_eq!(invalid_type_message("array(foo)"), "Unknown type: foo");
+    }
+
+    #[test]
+    fn type_parameter_bounds() {
+        assert_eq!(parse("varchar(2147483646)"), varchar(MAX_VARCHAR_LENGTH));
+        assert_eq!(parse("char(65536)"), DataType::Char(MAX_CHAR_LENGTH));
+        assert_eq!(parse("decimal(38, 38)"), decimal(38, 38));
+        assert_eq!(
+            invalid_type_message("varchar(2147483647)"),
+            "VARCHAR length must be in range [0, 2147483646]: 2147483647"
+        );
+        // Parameters which do not fit in a u64 are out of range too.
+        assert_eq!(
+            invalid_type_message("varchar(99999999999999999999)"),
+            format!(
+                "VARCHAR length must be in range [0, 2147483646]: {}",
+                u64::MAX
+            )
+        );
+    }
+
+    #[test]
+    fn coerce_numbers() {
+        use self::DataType::*;
+        assert!(TinyInt.can_coerce_to(&BigInt));
+        assert!(Integer.can_coerce_to(&BigInt));
+        assert!(!BigInt.can_coerce_to(&Integer));
+        assert!(Integer.can_coerce_to(&decimal(10, 0)));
+        assert!(Integer.can_coerce_to(&decimal(12, 2)));
+        assert!(!Integer.can_coerce_to(&decimal(10, 2)));
+        assert!(!BigInt.can_coerce_to(&decimal(18, 0)));
+        assert!(decimal(5, 2).can_coerce_to(&decimal(6, 3)));
+        assert!(!decimal(5, 2).can_coerce_to(&decimal(5, 3)));
+        assert!(!decimal(5, 2).can_coerce_to(&decimal(10, 1)));
+        assert!(BigInt.can_coerce_to(&Real));
+        assert!(decimal(38, 0).can_coerce_to(&Double));
+        assert!(!Real.can_coerce_to(&decimal(38, 0)));
+        assert!(!Double.can_coerce_to(&Real));
+        assert!(Unknown.can_coerce_to(&Integer));
+        assert!(!Boolean.can_coerce_to(&Integer));
+    }
+
+    #[test]
+    fn coerce_other_types() {
+        use self::DataType::*;
+        assert!(varchar(3).can_coerce_to(&varchar(5)));
+        assert!(!varchar(5).can_coerce_to(&varchar(3)));
+        assert!(varchar(5).can_coerce_to(&Varchar(None)));
+        assert!(!Varchar(None).can_coerce_to(&varchar(5)));
+        assert!(!varchar(1).can_coerce_to(&Char(1)));
+        assert!(Date.can_coerce_to(&Timestamp));
+        assert!(Timestamp.can_coerce_to(&TimestampWithTimeZone));
+        assert!(!Timestamp.can_coerce_to(&Date));
+        assert!(array(Integer).can_coerce_to(&array(BigInt)));
+        assert!(!array(BigInt).can_coerce_to(&array(Integer)));
+        let row = |types: Vec<DataType>| {
+            Row(types
+                .into_iter()
+                .map(|data_type| RowField::new(None, data_type))
+                .collect())
+        };
+        assert!(row(vec![Integer, varchar(1)]).can_coerce_to(&row(vec![BigInt, varchar(2)])));
+        assert!(!row(vec![Integer]).can_coerce_to(&row(vec![Integer, Integer])));
+        assert!(!varchar(1).can_coerce_to(&Integer));
+        assert!(!Json.can_coerce_to(&Varchar(None)));
+    }
+
+    #[test]
+    fn common_super_types() {
+        use self::DataType::*;
+        let common = DataType::common_super_type;
+        assert_eq!(common(&Integer, &BigInt), Some(BigInt));
+        assert_eq!(common(&Unknown, &Date), Some(Date));
+        assert_eq!(common(&Date, &Unknown), Some(Date));
+        assert_eq!(common(&Integer, &decimal(5, 2)), Some(decimal(12, 2)));
+        assert_eq!(common(&decimal(5, 2), &decimal(4, 3)), Some(decimal(6, 3)));
+        assert_eq!(common(&BigInt, &decimal(38, 30)), Some(decimal(38, 30)));
+        assert_eq!(common(&Integer, &Double), Some(Double));
+        assert_eq!(common(&varchar(3), &varchar(5)), Some(varchar(5)));
+        assert_eq!(common(&varchar(3), &Varchar(None)), Some(Varchar(None)));
+        assert_eq!(common(&Date, &Timestamp), Some(Timestamp));
+        assert_eq!(
+            common(&array(Integer), &array(decimal(5, 2))),
+            Some(array(decimal(12, 2)))
+        );
+        assert_eq!(common(&Integer, &varchar(1)), None);
+        assert_eq!(common(&array(Integer), &array(Boolean)), None);
+        assert_eq!(
+            DataType::common_super_type_of(&[Integer, Unknown, BigInt]),
+            Some(BigInt)
+        );
+        assert_eq!(DataType::common_super_type_of(&[]), Some(Unknown));
+    }
+
+    #[test]
+    fn arithmetic_types() {
+        use self::DataType::*;
+        assert_eq!(arithmetic_type("+", &Integer, &BigInt), Some(BigInt));
+        assert_eq!(arithmetic_type("+", &Integer, &Double), Some(Double));
+        assert_eq!(
+            arithmetic_type("+", &decimal(5, 2), &decimal(4, 3)),
+            Some(decimal(7, 3))
+        );
+        assert_eq!(
+            arithmetic_type("*", &decimal(5, 2), &decimal(4, 3)),
+            Some(decimal(9, 5))
+        );
+        assert_eq!(
+            arithmetic_type("-", &Date, &IntervalDayToSecond),
+            Some(Date)
+        );
+        assert_eq!(arithmetic_type("-", &IntervalDayToSecond, &Date), None);
+        assert_eq!(arithmetic_type("+", &Integer, &varchar(1)), None);
+    }
+
+    #[test]
+    fn decimal_arithmetic_is_capped() {
+        let wide = decimal(38, 10);
+        assert_eq!(arithmetic_type("+", &wide, &wide), Some(wide.clone()));
+        assert_eq!(arithmetic_type("*", &wide, &wide), Some(decimal(38, 20)));
+        assert_eq!(
+            arithmetic_type("*", &decimal(38, 38), &decimal(38, 38)),
+            Some(decimal(38, 38))
+        );
+        assert_eq!(
+            arithmetic_type("/", &decimal(38, 0), &decimal(38, 38)),
+            Some(decimal(38, 38))
+        );
+    }
+
+    #[test]
+    fn literal_types() {
+        use self::DataType::*;
+        assert_eq!(literal("2147483647"), Integer);
+        assert_eq!(literal("2147483648"), BigInt);
+        assert_eq!(literal("12.50"), decimal(4, 2));
+        assert_eq!(literal("0.5"), decimal(1, 1));
+        assert_eq!(literal("1e3"), Double);
+        assert_eq!(literal("'it''s'"), varchar(4));
+        assert_eq!(literal("true"), Boolean);
+    }
+
+    #[test]
+    fn concat_type_adds_lengths() {
+        assert_eq!(concat_type(&varchar(3), &varchar(4)), Some(varchar(7)));
+        assert_eq!(
+            concat_type(&varchar(MAX_VARCHAR_LENGTH - 1), &varchar(1)),
+            Some(varchar(MAX_VARCHAR_LENGTH))
+        );
+    }
+
+    #[test]
+    fn concat_type_overflow_is_unbounded() {
+        let big = varchar(MAX_VARCHAR_LENGTH);
+        assert_eq!(
+            concat_type(&big, &varchar(1)),
+            Some(DataType::Varchar(None))
+        );
+        assert_eq!(concat_type(&big, &big), Some(DataType::Varchar(None)));
+        assert_eq!(
+            concat_type(&varchar(u32::MAX), &varchar(u32::MAX)),
+            Some(DataType::Varchar(None))
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Window analysis: checks the OVER clauses and null treatments of function
+//! calls, following Presto's rules.
+//!
+//! Window functions, such as rank, must have an OVER clause, and only the
+//! value functions accept IGNORE NULLS or RESPECT NULLS. A window frame may
+//! not start with UNBOUNDED FOLLOWING or end with UNBOUNDED PRECEDING, nor
+//! end before it starts. ROWS offsets are integers; RANGE offsets need a
+//! single numeric or date/time ORDER BY key.
+use super::functions::FunctionKind;
+use super::list_elements;
+use super::resolver::Resolution;
+use super::tree_name;
+use super::types::DataType;
+use crate::parsing::{parse_tree, ParseTree};
+use crate::utils::syntax_error::{
+    ERROR_INVALID_NULL_TREATMENT, ERROR_INVALID_WINDOW_FRAME, ERROR_MISSING_OVER,
+};
+use crate::utils::{Message, SyntaxError, TextRange};
+use std::fmt;
+
+/// The window functions which accept IGNORE NULLS and RESPECT NULLS.
+const NULL_TREATMENT_FUNCTIONS: &[&str] =
+    &["first_value", "last_value", "nth_value", "lag", "lead"];
+
+/// Checks the window of a call of a registered function, whose arguments
+/// and OVER clause are resolved.
+pub fn check_function_call(
+    function_call: &parse_tree::FunctionCall,
+    name: &str,
+    kind: FunctionKind,
+    resolution: &Resolution,
+) -> Vec<SyntaxError> {
+    let mut errors = Vec::new();
+    let mut error = |error_code, range, message| {
+        errors.push(SyntaxError::from_message(
+            error_code,
+            Message::new(range, message),
+        ))
+    };
+    if kind == FunctionKind::Window && function_call.over_opt.is_empty() {
+        error(
+            ERROR_MISSING_OVER,
+            function_call.name.get_range(),
+            format!("Window function {} requires an OVER clause", name),
+        );
+    }
+    if !function_call.null_treatment_opt.is_empty() && !NULL_TREATMENT_FUNCTIONS.contains(&name) {
+        error(
+            ERROR_INVALID_NULL_TREATMENT,
+            function_call.null_treatment_opt.get_range(),
+            format!(
+                "Cannot specify null treatment clause for function {}; only {} accept it",
+                name,
+                NULL_TREATMENT_FUNCTIONS.join(", ")
+            ),
+        );
+    }
+    if let ParseTree::Over(over) = &*function_call.over_opt {
+        check_window_frame(over, resolution, &mut error);
+    }
+    errors
+}
+
+/// A frame bound, in frame order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Bound {
+    UnboundedPreceding,
+    Preceding,
+    CurrentRow,
+    Following,
+    UnboundedFollowing,
+}
+
+impl fmt::Display for Bound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            Bound::Preceding => write!(f, "PRECEDING"),
+            Bound::CurrentRow => write!(f, "CURRENT ROW"),
+            Bound::Following => write!(f, "FOLLOWING"),
+            Bound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl Bound {
+    fn from_tree(tree: &ParseTree) -> Option<Bound> {
+        match tree {
+            ParseTree::UnboundedFrame(unbounded_frame) => {
+                match tree_name(&unbounded_frame.bound_type)?.as_str() {
+                    "preceding" => Some(Bound::UnboundedPreceding),
+                    _ => Some(Bound::UnboundedFollowing),
+                }
+            }
+            ParseTree::CurrentRowBound(_) => Some(Bound::CurrentRow),
+            ParseTree::BoundedFrame(bounded_frame) => {
+                match tree_name(&bounded_frame.bound_type)?.as_str() {
+                    "preceding" => Some(Bound::Preceding),
+                    _ => Some(Bound::Following),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+fn check_window_frame(
+    over: &parse_tree::Over,
+    resolution: &Resolution,
+    error: &mut impl FnMut(i32, TextRange, String),
+) {
+    let window_frame = match &*over.window_frame {
+        ParseTree::WindowFrame(window_frame) => window_frame,
+        _ => return,
+    };
+    let frame_range = over.window_frame.get_range();
+    let start = Bound::from_tree(&window_frame.start);
+    // A frame without BETWEEN ends at the current row.
+    let end = if window_frame.end.is_empty() {
+        Some(Bound::CurrentRow)
+    } else {
+        Bound::from_tree(&window_frame.end)
+    };
+    match (start, end) {
+        (Some(Bound::UnboundedFollowing), _) => error(
+            ERROR_INVALID_WINDOW_FRAME,
+            window_frame.start.get_range(),
+            "Window frame start cannot be UNBOUNDED FOLLOWING".to_string(),
+        ),
+        (_, Some(Bound::UnboundedPreceding)) => error(
+            ERROR_INVALID_WINDOW_FRAME,
+            window_frame.end.get_range(),
+            "Window frame end cannot be UNBOUNDED PRECEDING".to_string(),
+        ),
+        (Some(start), Some(end)) if start > end => error(
+            ERROR_INVALID_WINDOW_FRAME,
+            frame_range,
+            format!(
+                "Window frame starting from {} cannot end with {}",
+                start, end
+            ),
+        ),
+        _ => (),
+    }
+
+    let frame_type = tree_name(&window_frame.frame_type).unwrap_or_default();
+    let offsets: Vec<&ParseTree> = [&window_frame.start, &window_frame.end]
+        .iter()
+        .filter_map(|bound| match &***bound {
+            ParseTree::BoundedFrame(bounded_frame) => Some(&*bounded_frame.bound),
+            _ => None,
+        })
+        .collect();
+    let expression_type = |offset: &ParseTree| {
+        resolution
+            .expression_type(offset.get_range())
+            .cloned()
+            .unwrap_or(DataType::Unknown)
+    };
+    if frame_type == "rows" {
+        for offset in offsets {
+            let data_type = expression_type(offset);
+            if !data_type.is_integral() && !data_type.is_unknown() {
+                error(
+                    ERROR_INVALID_WINDOW_FRAME,
+                    offset.get_range(),
+                    format!(
+                        "Window frame ROWS offset must be an integer (actual {})",
+                        data_type
+                    ),
+                );
+            }
+        }
+    } else if frame_type == "range" && !offsets.is_empty() {
+        let sort_items = match &*over.order_by_opt {
+            ParseTree::OrderBy(order_by) => list_elements(&order_by.sort_items),
+            _ => Vec::new(),
+        };
+        let sort_key = match sort_items.as_slice() {
+            [ParseTree::SortItem(sort_item)] => &*sort_item.expression,
+            [sort_item] => *sort_item,
+            _ => {
+                error(
+                    ERROR_INVALID_WINDOW_FRAME,
+                    frame_range,
+                    format!(
+                        "Window frame of type RANGE PRECEDING or FOLLOWING requires single sort item in ORDER BY (actual: {})",
+                        sort_items.len()
+                    ),
+                );
+                return;
+            }
+        };
+        let key_type = expression_type(sort_key);
+        if !key_type.is_unknown() && !key_type.is_numeric() && !is_date_time(&key_type) {
+            error(
+                ERROR_INVALID_WINDOW_FRAME,
+                sort_key.get_range(),
+                format!(
+                    "Window frame of type RANGE PRECEDING or FOLLOWING requires that sort item type be numeric, datetime or interval (actual: {})",
+                    key_type
+                ),
+            );
+        }
+        for offset in offsets {
+            let data_type = expression_type(offset);
+            if !data_type.is_unknown() && !data_type.is_numeric() && !data_type.is_interval() {
+                error(
+                    ERROR_INVALID_WINDOW_FRAME,
+                    offset.get_range(),
+                    format!(
+                        "Window frame RANGE offset must be a number or an interval (actual {})",
+                        data_type
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn is_date_time(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Date
+            | DataType::Time
+            | DataType::TimeWithTimeZone
+            | DataType::Timestamp
+            | DataType::TimestampWithTimeZone
+    ) || data_type.is_interval()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{ddl, resolve, MemoryCatalog, Session};
+    use crate::parsing::parse_statement;
+
+    /// The error codes and messages of a select item over orders.
+    fn errors(select_item: &str) -> Vec<(i32, String)> {
+        let session = Session::new("hive", "web");
+        let mut catalog = MemoryCatalog::new();
+        ddl::load_script(
+            &mut catalog,
+            &session,
+            "create table orders (id bigint, status varchar, total double, created date)",
+        );
+        let text = format!("select {} from orders", select_item);
+        let (tree, errors) = parse_statement(&text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        resolve(&tree, &catalog, &session)
+            .errors
+            .iter()
+            .map(|error| (error.error_code, error.messages[0].message.clone()))
+            .collect()
+    }
+
+    fn frame_errors(frame: &str) -> Vec<String> {
+        errors(&format!("sum(total) over (order by id {})", frame))
+            .into_iter()
+            .map(|(error_code, message)| {
+                assert_eq!(error_code, ERROR_INVALID_WINDOW_FRAME);
+                message
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_windows() {
+        for select_item in &[
+            "rank() over (partition by status order by total desc)",
+            "sum(total) over (order by id rows between 2 preceding and current row)",
+            "sum(total) over (order by id rows unbounded preceding)",
+            "sum(total) over (order by total range between 1.5 preceding and 1 following)",
+            "count(*) over (order by created range interval '1' day preceding)",
+            "lag(total) ignore nulls over (order by id)",
+            "first_value(total) respect nulls over (order by id)",
+        ] {
+            assert!(errors(select_item).is_empty(), "{}", select_item);
+        }
+    }
+
+    #[test]
+    fn frame_bounds() {
+        assert_eq!(
+            frame_errors("rows between unbounded following and current row"),
+            vec!["Window frame start cannot be UNBOUNDED FOLLOWING"]
+        );
+        assert_eq!(
+            frame_errors("rows between current row and unbounded preceding"),
+            vec!["Window frame end cannot be UNBOUNDED PRECEDING"]
+        );
+        assert_eq!(
+            frame_errors("rows between 1 following and 1 preceding"),
+            vec!["Window frame starting from FOLLOWING cannot end with PRECEDING"]
+        );
+        assert_eq!(
+            frame_errors("rows 1 following"),
+            vec!["Window frame starting from FOLLOWING cannot end with CURRENT ROW"]
+        );
+    }
+
+    #[test]
+    fn frame_offsets() {
+        assert_eq!(
+            frame_errors("rows between 1.5 preceding and current row"),
+            vec!["Window frame ROWS offset must be an integer (actual decimal(2,1))"]
+        );
+        assert_eq!(
+            frame_errors("range between 'a' preceding and current row"),
+            vec!["Window frame RANGE offset must be a number or an interval (actual varchar(1))"]
+        );
+        assert_eq!(
+            errors("sum(total) over (order by status range 1 preceding)"),
+            vec![(
+                ERROR_INVALID_WINDOW_FRAME,
+                "Window frame of type RANGE PRECEDING or FOLLOWING requires that sort item type be numeric, datetime or interval (actual: varchar)".to_string()
+            )]
+        );
+        assert_eq!(
+            errors("sum(total) over (order by id, total range 1 preceding)"),
+            vec![(
+                ERROR_INVALID_WINDOW_FRAME,
+                "Window frame of type RANGE PRECEDING or FOLLOWING requires single sort item in ORDER BY (actual: 2)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid_null_treatment() {
+        let errors = errors("sum(total) ignore nulls over ()");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, ERROR_INVALID_NULL_TREATMENT);
+    }
+
+    #[test]
+    fn missing_over() {
+        assert_eq!(
+            errors("rank()"),
+            vec![(
+                ERROR_MISSING_OVER,
+                "Window function rank requires an OVER clause".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,223 @@
+//! Checking large batches of queries, such as query logs.
+//!
+//! Each query is parsed independently. A panic while parsing one query is
+//! caught and recorded as that query's outcome, so that one bad query does
+//! not stop a batch. Outcomes are accumulated into a Summary which groups
+//! errors by error code and message.
+use crate::parsing::{parse_statement_with_options, ParseOptions};
+use crate::utils::SyntaxError;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::panic;
+
+pub mod parallel;
+
+/// The result of parsing one query.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Parsed,
+    Errors(Vec<SyntaxError>),
+    /// The parser panicked; with the panic message.
+    Panicked(String),
+}
+
+impl Outcome {
+    pub fn is_parsed(&self) -> bool {
+        matches!(self, Outcome::Parsed)
+    }
+}
+
+/// Parses a query, catching any panic. Consumed tokens are dropped, as
+/// query logs may hold very large generated queries.
+///
+/// Panics are still reported by the panic hook; use with_silent_panics()
+/// to suppress those reports while checking a batch.
+pub fn check_query(query: &str) -> Outcome {
+    let options = ParseOptions {
+        drop_consumed_tokens: true,
+        ..ParseOptions::default()
+    };
+    match panic::catch_unwind(|| parse_statement_with_options(query, &options).1) {
+        Ok(errors) => {
+            if errors.is_empty() {
+                Outcome::Parsed
+            } else {
+                Outcome::Errors(errors)
+            }
+        }
+        Err(payload) => {
+            let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                message.to_string()
+            } else if let Some(message) = payload.downcast_ref::<String>() {
+                message.clone()
+            } else {
+                "unknown panic".to_string()
+            };
+            Outcome::Panicked(message)
+        }
+    }
+}
+
+/// Runs f with the panic hook replaced by one which prints nothing.
+///
+/// The previous hook is restored when f returns or panics; a panic is then
+/// resumed. It is restored after catching the panic rather than by a Drop
+/// guard, as the hook cannot be set on a panicking thread.
+///
+/// The panic hook is process wide: while f runs, panics on all threads are
+/// silenced, and concurrent callers race to replace and restore the hook,
+/// so one may leave the other's silent hook in place.
+pub fn with_silent_panics<F: FnOnce() -> T, T>(f: F) -> T {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    panic::set_hook(previous_hook);
+    match result {
+        Ok(result) => result,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Panics are grouped under this error code.
+pub const PANIC_ERROR_CODE: i32 = 0;
+
+/// The occurrences of one error code and message.
+#[derive(Clone, Debug)]
+pub struct ErrorGroup {
+    pub error_code: i32,
+    pub message: String,
+    /// The number of occurrences; a query may contribute more than one.
+    pub count: usize,
+    /// The number of queries with this error.
+    pub query_count: usize,
+    /// The id of the first query with this error.
+    pub example: usize,
+}
+
+/// Statistics over the outcomes of a batch of queries.
+#[derive(Clone, Debug, Default)]
+pub struct Summary {
+    pub query_count: usize,
+    pub parsed_count: usize,
+    pub error_count: usize,
+    pub panic_count: usize,
+    groups: HashMap<(i32, String), ErrorGroup>,
+}
+
+impl Summary {
+    pub fn new() -> Summary {
+        Summary::default()
+    }
+
+    /// Adds the outcome of a query. id identifies the query in reports;
+    /// typically it is the line or record number of the query.
+    pub fn add(&mut self, id: usize, outcome: &Outcome) {
+        self.query_count += 1;
+        match outcome {
+            Outcome::Parsed => self.parsed_count += 1,
+            Outcome::Errors(errors) => {
+                self.error_count += 1;
+                let mut seen = Vec::new();
+                for error in errors {
+                    let message = error
+                        .messages
+                        .first()
+                        .map_or("", |message| message.message.as_str());
+                    let key = (error.error_code, message.to_string());
+                    let is_first_in_query = !seen.contains(&key);
+                    if is_first_in_query {
+                        seen.push(key.clone());
+                    }
+                    self.add_to_group(key, id, is_first_in_query);
+                }
+            }
+            Outcome::Panicked(message) => {
+                self.panic_count += 1;
+                self.add_to_group((PANIC_ERROR_CODE, message.clone()), id, true);
+            }
+        }
+    }
+
+    fn add_to_group(&mut self, key: (i32, String), id: usize, is_first_in_query: bool) {
+        let group = self
+            .groups
+            .entry(key)
+            .or_insert_with_key(|(error_code, message)| ErrorGroup {
+                error_code: *error_code,
+                message: message.clone(),
+                count: 0,
+                query_count: 0,
+                example: id,
+            });
+        group.count += 1;
+        if is_first_in_query {
+            group.query_count += 1;
+        }
+    }
+
+    /// The error groups, most frequent first.
+    pub fn groups(&self) -> Vec<&ErrorGroup> {
+        let mut result: Vec<&ErrorGroup> = self.groups.values().collect();
+        result.sort_by(|left, right| {
+            right
+                .query_count
+                .cmp(&left.query_count)
+                .then(left.error_code.cmp(&right.error_code))
+                .then(left.message.cmp(&right.message))
+        });
+        result
+    }
+
+    /// A human readable report of the counts and error groups.
+    pub fn report(&self) -> String {
+        let mut result = String::new();
+        let percent = |count: usize| {
+            if self.query_count == 0 {
+                0.0
+            } else {
+                100.0 * count as f64 / self.query_count as f64
+            }
+        };
+        let _ = writeln!(result, "queries:  {}", self.query_count);
+        let _ = writeln!(
+            result,
+            "parsed:   {} ({:.2}%)",
+            self.parsed_count,
+            percent(self.parsed_count)
+        );
+        let _ = writeln!(
+            result,
+            "errors:   {} ({:.2}%)",
+            self.error_count,
+            percent(self.error_count)
+        );
+        let _ = writeln!(
+            result,
+            "panics:   {} ({:.2}%)",
+            self.panic_count,
+            percent(self.panic_count)
+        );
+        let groups = self.groups();
+        if !groups.is_empty() {
+            let _ = writeln!(result);
+            let _ = writeln!(
+                result,
+                "{:>8} {:>8} {:>6}  message (first query)",
+                "queries", "count", "code"
+            );
+            for group in groups {
+                let code = if group.error_code == PANIC_ERROR_CODE {
+                    "panic".to_string()
+                } else {
+                    group.error_code.to_string()
+                };
+                let _ = writeln!(
+                    result,
+                    "{:>8} {:>8} {:>6}  {} ({})",
+                    group.query_count, group.count, code, group.message, group.example
+                );
+            }
+        }
+        result
+    }
+}
@@ -0,0 +1,280 @@
+//! Checking queries on a pool of worker threads.
+//!
+//! Queries are streamed from an iterator on the calling thread into a
+//! bounded queue. Workers parse queries from the queue; a collector thread
+//! restores the input order of the outcomes before passing them on.
+//!
+//! A query is only dispatched once it is within a window of the oldest
+//! query whose outcome has not been passed on, so one slow query holds back
+//! at most a window of finished outcomes, and memory use does not grow with
+//! the size of the input.
+use super::{check_query, Outcome};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::mpsc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The number of queued queries per worker.
+const QUEUE_LENGTH_PER_THREAD: usize = 64;
+
+/// The most queries which may be dispatched but not yet passed on.
+fn window_length(thread_count: usize) -> usize {
+    thread_count * QUEUE_LENGTH_PER_THREAD * 2
+}
+
+/// A query to check; id identifies the query in reports.
+#[derive(Clone, Debug)]
+pub struct Query {
+    pub id: usize,
+    pub text: String,
+}
+
+/// The amount of work done by check_parallel(), and how long it took.
+#[derive(Clone, Copy, Debug)]
+pub struct Throughput {
+    pub query_count: usize,
+    pub byte_count: usize,
+    pub thread_count: usize,
+    pub elapsed: Duration,
+}
+
+impl Throughput {
+    pub fn queries_per_second(&self) -> f64 {
+        self.query_count as f64 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    pub fn megabytes_per_second(&self) -> f64 {
+        self.byte_count as f64 / 1_000_000.0 / self.elapsed.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+impl fmt::Display for Throughput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} queries, {:.1} MB in {:.2}s on {} threads: {:.0} queries/s, {:.2} MB/s",
+            self.query_count,
+            self.byte_count as f64 / 1_000_000.0,
+            self.elapsed.as_secs_f64(),
+            self.thread_count,
+            self.queries_per_second(),
+            self.megabytes_per_second()
+        )
+    }
+}
+
+/// The number of worker threads to use by default.
+pub fn default_thread_count() -> usize {
+    thread::available_parallelism().map_or(1, |count| count.get())
+}
+
+/// Checks queries on thread_count worker threads.
+///
+/// on_outcome is called on a separate thread, once per query, in the order
+/// that queries are produced by the iterator. Panics while parsing are
+/// caught as for check_query(); use with_silent_panics() to suppress their
+/// reports.
+pub fn check_parallel<I, F>(queries: I, thread_count: usize, on_outcome: F) -> Throughput
+where
+    I: IntoIterator<Item = Query>,
+    F: FnMut(Query, Outcome) + Send,
+{
+    let thread_count = thread_count.max(1);
+    let start = Instant::now();
+    let (query_sender, query_receiver) =
+        mpsc::sync_channel::<(usize, Query)>(thread_count * QUEUE_LENGTH_PER_THREAD);
+    let query_receiver = Arc::new(Mutex::new(query_receiver));
+    // Unbounded, but holds at most a window of outcomes.
+    let (outcome_sender, outcome_receiver) = mpsc::channel::<(usize, Query, Outcome)>();
+    let window_length = window_length(thread_count);
+    let progress = Progress::default();
+
+    let (query_count, byte_count) = thread::scope(|scope| {
+        for _ in 0..thread_count {
+            let query_receiver = Arc::clone(&query_receiver);
+            let outcome_sender = outcome_sender.clone();
+            scope.spawn(move || loop {
+                // Hold the lock only while receiving, not while parsing.
+                let next = query_receiver.lock().unwrap().recv();
+                match next {
+                    Ok((sequence, query)) => {
+                        let outcome = check_query(&query.text);
+                        if outcome_sender.send((sequence, query, outcome)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            });
+        }
+        // Only the workers hold the queue, so that sending fails once they
+        // have all stopped.
+        drop(query_receiver);
+        drop(outcome_sender);
+        let collector_progress = &progress;
+        scope.spawn(move || collect_in_order(outcome_receiver, collector_progress, on_outcome));
+
+        let mut query_count = 0;
+        let mut byte_count = 0;
+        for query in queries {
+            byte_count += query.text.len();
+            if query_count >= window_length {
+                progress.wait_until_passed_on(query_count + 1 - window_length);
+            }
+            if progress.is_finished() || query_sender.send((query_count, query)).is_err() {
+                break;
+            }
+            query_count += 1;
+        }
+        drop(query_sender);
+        (query_count, byte_count)
+    });
+
+    Throughput {
+        query_count,
+        byte_count,
+        thread_count,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// The number of outcomes which collect_in_order has passed on.
+#[derive(Default)]
+struct Progress {
+    passed_on: Mutex<usize>,
+    changed: Condvar,
+}
+
+impl Progress {
+    fn set(&self, passed_on: usize) {
+        *self.passed_on.lock().unwrap() = passed_on;
+        self.changed.notify_all();
+    }
+
+    /// Whether the collector has stopped.
+    fn is_finished(&self) -> bool {
+        *self.passed_on.lock().unwrap() == usize::MAX
+    }
+
+    fn wait_until_passed_on(&self, count: usize) {
+        let mut passed_on = self.passed_on.lock().unwrap();
+        while *passed_on < count {
+            passed_on = self.changed.wait(passed_on).unwrap();
+        }
+    }
+}
+
+/// Stops the dispatcher once the collector stops, including when on_outcome
+/// panics.
+struct FinishOnDrop<'p>(&'p Progress);
+
+impl<'p> Drop for FinishOnDrop<'p> {
+    fn drop(&mut self) {
+        self.0.set(usize::MAX);
+    }
+}
+
+/// Passes outcomes to on_outcome in sequence order, recording the progress.
+/// Outcomes which arrive early wait until their predecessors arrive.
+fn collect_in_order<F>(
+    receiver: mpsc::Receiver<(usize, Query, Outcome)>,
+    progress: &Progress,
+    mut on_outcome: F,
+) where
+    F: FnMut(Query, Outcome),
+{
+    let _finish = FinishOnDrop(progress);
+    let mut next_sequence = 0;
+    let mut pending = BTreeMap::new();
+    for (sequence, query, outcome) in receiver {
+        pending.insert(sequence, (query, outcome));
+        let previous_sequence = next_sequence;
+        while let Some((query, outcome)) = pending.remove(&next_sequence) {
+            on_outcome(query, outcome);
+            next_sequence += 1;
+        }
+        if next_sequence != previous_sequence {
+            progress.set(next_sequence);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn queries(count: usize) -> Vec<Query> {
+        (0..count)
+            .map(|id| Query {
+                id,
+                text: if id % 3 == 0 {
+                    format!("select {}", id)
+                } else {
+                    "select from".to_string()
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn outcomes_are_in_input_order() {
+        let mut ids = Vec::new();
+        let throughput = check_parallel(queries(500), 4, |query, outcome| {
+            assert_eq!(outcome.is_parsed(), query.id % 3 == 0);
+            ids.push(query.id);
+        });
+        assert_eq!(throughput.query_count, 500);
+        assert_eq!(ids, (0..500).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn slow_query_bounds_queries_in_flight() {
+        let thread_count = 2;
+        let window_length = window_length(thread_count);
+        let passed_on = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        // A slow query first, so that later outcomes wait for it.
+        let slow = Query {
+            id: 0,
+            text: format!(
+                "select {}",
+                (0..50_000)
+                    .map(|i| format!("c{} + {}", i, i))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        let queries = std::iter::once(slow)
+            .chain(queries(window_length * 4).into_iter().skip(1))
+            .enumerate()
+            .map(|(index, query)| {
+                let in_flight = index - passed_on.load(Ordering::SeqCst);
+                max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                query
+            });
+        check_parallel(queries, thread_count, |_, _| {
+            passed_on.fetch_add(1, Ordering::SeqCst);
+        });
+        assert!(max_in_flight.load(Ordering::SeqCst) <= window_length + 1);
+    }
+
+    #[test]
+    fn panicking_on_outcome_stops_dispatch() {
+        let (done_sender, done_receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let result = std::panic::catch_unwind(|| {
+                check_parallel(queries(100_000), 2, |query, _| {
+                    if query.id == 10 {
+                        panic!("on_outcome failed");
+                    }
+                })
+            });
+            done_sender.send(result.is_err()).unwrap();
+        });
+        let panicked = done_receiver.recv_timeout(Duration::from_secs(30));
+        assert_eq!(panicked, Ok(true));
+    }
+}
@@ -0,0 +1,185 @@
+//! Checking the queries in a query log CSV file.
+use super::{EXIT_FAILURE, EXIT_SUCCESS, EXIT_USAGE};
+use crate::batch::parallel::{self, Query, Throughput};
+use crate::batch::{self, Outcome, Summary, PANIC_ERROR_CODE};
+use csv::{ReaderBuilder, StringRecord, Writer};
+use std::error::Error;
+use std::fs::File;
+
+/// Selects the column containing the queries.
+pub enum Column {
+    /// A 0-based index: 1 is the second column.
+    Index(usize),
+    Name(String),
+}
+
+impl Column {
+    /// Parses a 0-based column index, or else a header name.
+    pub fn parse(value: &str) -> Column {
+        match value.parse() {
+            Ok(index) => Column::Index(index),
+            Err(_) => Column::Name(value.to_string()),
+        }
+    }
+}
+
+pub struct Options {
+    pub column: Column,
+    pub has_headers: bool,
+    /// Where to write the failing queries.
+    pub failures_path: Option<String>,
+    /// The number of worker threads.
+    pub thread_count: usize,
+}
+
+/// Prints progress to stderr after this many queries.
+const PROGRESS_INTERVAL: usize = 10_000;
+
+/// Checks every query in a CSV file, continuing past failures, and prints
+/// a summary of the errors and the throughput.
+pub fn process_csv(path: &str, options: &Options) -> i32 {
+    match batch::with_silent_panics(|| check_csv(path, options)) {
+        Ok((summary, throughput)) => {
+            print!("{}", summary.report());
+            println!();
+            println!("{}", throughput);
+            if summary.parsed_count == summary.query_count {
+                EXIT_SUCCESS
+            } else {
+                EXIT_FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("{}: {}", path, error);
+            EXIT_USAGE
+        }
+    }
+}
+
+fn column_index(column: &Column, headers: Option<&StringRecord>) -> Result<usize, String> {
+    match (column, headers) {
+        (Column::Index(index), _) => Ok(*index),
+        (Column::Name(name), Some(headers)) => headers
+            .iter()
+            .position(|header| header == name)
+            .ok_or_else(|| format!("no column named '{}'", name)),
+        (Column::Name(name), None) => Err(format!(
+            "cannot select column '{}' by name without a header row",
+            name
+        )),
+    }
+}
+
+/// The failures file has one row per failing query: the line of the query
+/// in the input, the error code (0 for panics), the first error's message
+/// and position (1 based), the number of errors, and the query.
+fn write_failure(
+    writer: &mut Writer<File>,
+    line: usize,
+    outcome: &Outcome,
+    query: &str,
+) -> csv::Result<()> {
+    let (error_code, message, position, count) = match outcome {
+        Outcome::Parsed => return Ok(()),
+        Outcome::Errors(errors) => {
+            let message = &errors[0].messages[0];
+            let start = message.range.start;
+            (
+                errors[0].error_code,
+                message.message.as_str(),
+                format!("{}:{}", start.line + 1, start.column + 1),
+                errors.len(),
+            )
+        }
+        Outcome::Panicked(message) => (PANIC_ERROR_CODE, message.as_str(), String::new(), 1),
+    };
+    writer.write_record([
+        line.to_string().as_str(),
+        error_code.to_string().as_str(),
+        message,
+        position.as_str(),
+        count.to_string().as_str(),
+        query,
+    ])
+}
+
+fn check_csv(path: &str, options: &Options) -> Result<(Summary, Throughput), Box<dyn Error>> {
+    let mut reader = ReaderBuilder::new()
+        .has_headers(options.has_headers)
+        .flexible(true)
+        .from_path(path)?;
+    let headers = if options.has_headers {
+        Some(reader.headers()?.clone())
+    } else {
+        None
+    };
+    let column = column_index(&options.column, headers.as_ref())?;
+    let mut failures = match &options.failures_path {
+        Some(failures_path) => {
+            let mut writer = Writer::from_path(failures_path)?;
+            writer.write_record([
+                "line",
+                "error_code",
+                "message",
+                "position",
+                "errors",
+                "query",
+            ])?;
+            Some(writer)
+        }
+        None => None,
+    };
+
+    // Queries are read on this thread; errors stop reading.
+    let mut read_error = None;
+    let mut skipped_count = 0;
+    let queries = reader
+        .into_records()
+        .map_while(|record_result| match record_result {
+            Ok(record) => Some(record),
+            Err(error) => {
+                read_error = Some(error);
+                None
+            }
+        })
+        .filter_map(|record| match record.get(column) {
+            Some(query) => Some(Query {
+                id: record
+                    .position()
+                    .map_or(0, |position| position.line() as usize),
+                text: query.to_string(),
+            }),
+            None => {
+                skipped_count += 1;
+                None
+            }
+        });
+
+    // Outcomes are reported on the collector thread, in input order.
+    let mut summary = Summary::new();
+    let mut write_error = None;
+    let throughput = parallel::check_parallel(queries, options.thread_count, |query, outcome| {
+        summary.add(query.id, &outcome);
+        if let (Some(writer), None) = (&mut failures, &write_error) {
+            if let Err(error) = write_failure(writer, query.id, &outcome, &query.text) {
+                write_error = Some(error);
+            }
+        }
+        if summary.query_count.is_multiple_of(PROGRESS_INTERVAL) {
+            eprintln!("{} queries", summary.query_count);
+        }
+    });
+    if let Some(error) = read_error.or(write_error) {
+        return Err(error.into());
+    }
+    if let Some(writer) = &mut failures {
+        writer.flush()?;
+    }
+    if skipped_count > 0 {
+        eprintln!(
+            "skipped {} records without column {}",
+            skipped_count, column
+        );
+    }
+    Ok((summary, throughput))
+}
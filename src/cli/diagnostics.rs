@@ -0,0 +1,75 @@
+//! Rendering diagnostics for people.
+use crate::utils::{SourceText, SyntaxError};
+use std::fmt::Write;
+
+#[derive(Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    fn name(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Renders a diagnostic as:
+///
+///   file:line:column: error[code]: message
+///      |
+///   12 | SELECT a FROM
+///      |              ^
+///
+/// Lines and columns are 1 based. Any further messages are rendered
+/// as notes.
+pub fn render(
+    file_name: &str,
+    source: &SourceText,
+    severity: Severity,
+    error: &SyntaxError,
+) -> String {
+    let mut result = String::new();
+    for (index, message) in error.messages.iter().enumerate() {
+        let range = message.range;
+        let label = if index == 0 {
+            format!("{}[{}]", severity.name(), error.error_code)
+        } else {
+            "note".to_string()
+        };
+        let _ = writeln!(
+            result,
+            "{}:{}:{}: {}: {}",
+            file_name,
+            range.start.line + 1,
+            range.start.column + 1,
+            label,
+            message.message
+        );
+        if range.start.line as usize >= source.line_count() {
+            continue;
+        }
+
+        let line_number = (range.start.line + 1).to_string();
+        let gutter = " ".repeat(line_number.len());
+        let line = source.line(range.start.line);
+        let width = if range.end.line == range.start.line {
+            (range.end.column - range.start.column).max(1)
+        } else {
+            (line.chars().count() as i32 - range.start.column).max(1)
+        };
+        let _ = writeln!(result, "{} |", gutter);
+        let _ = writeln!(result, "{} | {}", line_number, line);
+        let _ = writeln!(
+            result,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(range.start.column as usize),
+            "^".repeat(width as usize)
+        );
+    }
+    result
+}
@@ -0,0 +1,159 @@
+//! Expanding command line arguments into the inputs to process.
+//!
+//! An argument is one of:
+//! - "-", which reads stdin,
+//! - a directory, which is searched recursively for files ending in .sql,
+//! - a glob pattern, where * and ? match within a path component and **
+//!   matches any number of path components,
+//! - a file name.
+//!
+//! No arguments reads stdin.
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+pub const STDIN: &str = "-";
+
+/// The name and text of an input.
+pub struct Input {
+    pub name: String,
+    pub contents: String,
+}
+
+impl Input {
+    pub fn is_stdin(&self) -> bool {
+        self.name == STDIN
+    }
+}
+
+/// Expands arguments into the names of the inputs to read.
+/// Returns an error message if an argument matches nothing.
+pub fn expand(args: &[String]) -> Result<Vec<String>, String> {
+    if args.is_empty() {
+        return Ok(vec![STDIN.to_string()]);
+    }
+    let mut result = Vec::new();
+    for arg in args {
+        let path = Path::new(arg);
+        if arg == STDIN || path.is_file() {
+            result.push(arg.clone());
+        } else if path.is_dir() {
+            let mut files = Vec::new();
+            walk(path, &mut files).map_err(|error| format!("{}: {}", arg, error))?;
+            result.extend(
+                files
+                    .into_iter()
+                    .filter(|file| file.extension().is_some_and(|extension| extension == "sql"))
+                    .map(|file| file.to_string_lossy().into_owned()),
+            );
+        } else if is_pattern(arg) {
+            let matches = expand_pattern(arg).map_err(|error| format!("{}: {}", arg, error))?;
+            if matches.is_empty() {
+                return Err(format!("{}: no matching files", arg));
+            }
+            result.extend(matches);
+        } else {
+            return Err(format!("{}: no such file or directory", arg));
+        }
+    }
+    Ok(result)
+}
+
+/// Reads an input named by expand().
+pub fn read(name: &str) -> io::Result<Input> {
+    let contents = if name == STDIN {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        contents
+    } else {
+        fs::read_to_string(name)?
+    };
+    Ok(Input {
+        name: name.to_string(),
+        contents,
+    })
+}
+
+fn is_pattern(arg: &str) -> bool {
+    arg.contains(['*', '?'])
+}
+
+/// Appends all files under directory, in sorted order.
+fn walk(directory: &Path, result: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            walk(&entry, result)?;
+        } else {
+            result.push(entry);
+        }
+    }
+    Ok(())
+}
+
+fn expand_pattern(pattern: &str) -> io::Result<Vec<String>> {
+    // Search from the longest leading directory without wildcards.
+    let components: Vec<&str> = pattern.split('/').collect();
+    let literal_count = components
+        .iter()
+        .position(|component| is_pattern(component))
+        .unwrap_or(components.len());
+    let root = components[..literal_count].join("/");
+    let root = if root.is_empty() && literal_count > 0 {
+        "/".to_string()
+    } else {
+        root
+    };
+    let root_path = if root.is_empty() { "." } else { root.as_str() };
+    if !Path::new(root_path).is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let pattern_components = &components[literal_count..];
+    let mut files = Vec::new();
+    walk(Path::new(root_path), &mut files)?;
+    Ok(files
+        .into_iter()
+        .filter_map(|file| {
+            let relative = file
+                .strip_prefix(root_path)
+                .ok()?
+                .to_string_lossy()
+                .into_owned();
+            let relative_components: Vec<&str> = relative.split('/').collect();
+            if matches_components(pattern_components, &relative_components) {
+                Some(file.to_string_lossy().into_owned())
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+fn matches_components(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| matches_components(rest, &path[skip..])),
+        Some((first, rest)) => match path.split_first() {
+            Some((path_first, path_rest)) => {
+                let pattern: Vec<char> = first.chars().collect();
+                let name: Vec<char> = path_first.chars().collect();
+                matches_component(&pattern, &name) && matches_components(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path component against a pattern containing * and ?.
+fn matches_component(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| matches_component(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && matches_component(rest, &name[1..]),
+        Some((ch, rest)) => name.first() == Some(ch) && matches_component(rest, &name[1..]),
+    }
+}
@@ -0,0 +1,714 @@
+//! The presto_rs command line tool.
+//!
+//! presto_rs <command> [options] [inputs...]
+//!
+//! Each input is a file containing one statement; see inputs.rs for how
+//! arguments are expanded into inputs. Exit codes are:
+//! - 0 on success,
+//! - 1 when an input has errors, warnings, or (with format --check)
+//!   would be reformatted,
+//! - 2 for usage errors, and inputs which cannot be read or written.
+use crate::analysis::classification::StatementClass;
+use crate::analysis::diff::diff_trees;
+use crate::analysis::references::TableReferences;
+use crate::analysis::{self, ddl, lineage, Catalog, FunctionKind, MemoryCatalog, Session};
+use crate::batch::parallel;
+use crate::formatting::{self, FormatError};
+use crate::lexing::{Lexer, LexerOptions};
+use crate::linting;
+use crate::parsing::equality::{trees_equal, EqualityOptions};
+use crate::parsing::{
+    dump, parse_statement, parse_statement_with_options, parse_tree_json, ParseOptions, ParseTree,
+};
+use crate::utils::{SourceText, SyntaxError};
+use std::fs;
+
+mod batch;
+mod diagnostics;
+mod inputs;
+
+use self::diagnostics::Severity;
+use self::inputs::Input;
+
+pub const EXIT_SUCCESS: i32 = 0;
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "\
+usage: presto_rs <command> [options] [inputs...]
+
+commands:
+  lex [--json] [--strict-identifiers]
+                                       print the tokens of each input
+  parse [--format sexp|dot|json|debug] print the parse tree of each input
+  check [--strict-identifiers]         report syntax errors
+  lint [--strict-identifiers]          report syntax errors and lint warnings
+  classify                             print the class of each statement (query,
+                                       dml, ddl, ...) and whether it can modify
+                                       data
+  compare [--ignore-keyword-case] [--ignore-identifier-case]
+          [--ignore-parentheses] <left> <right>
+                                       exit with 0 if two statements have the
+                                       same parse tree apart from whitespace
+                                       and comments, and 1 otherwise
+  diff [--json] <old> <new>            print the changed clauses, select items,
+                                       expressions, ... between two statements
+  fingerprint                          print a hash and the normalized text of
+                                       each input, with constants replaced by ?
+  analyze --catalog <catalog.json|schema.sql>... [--session <catalog.schema>]
+          [--function <signature>]... [--aggregate <signature>]...
+          [--bindings] [--types] [--lineage] [--tables]
+                                       report unknown and ambiguous names and
+                                       type and function call errors;
+                                       --function and --aggregate register
+                                       custom functions, e.g.
+                                       'slugify(varchar) -> varchar';
+                                       --bindings prints what names refer to,
+                                       --types the types of expressions,
+                                       --lineage the source columns of the
+                                       columns written by INSERT, CREATE
+                                       TABLE AS and CREATE VIEW as JSON,
+                                       --tables the tables read and written
+                                       as JSON
+  format [--check]                     format inputs in place, or with --check
+                                       list the inputs which are not formatted
+  batch [--column <index|name>] [--no-headers] [--failures <out.csv>]
+        [--threads <count>] <file.csv>
+                                       check the queries in a query log CSV file
+                                       on a pool of threads, and summarize the
+                                       errors and throughput; --column is a
+                                       0-based index (default 1, the second
+                                       column) or a header name
+
+inputs are files, directories (searched for *.sql files), glob patterns
+(*, ? and **), or - for stdin. With no inputs, stdin is read.
+--strict-identifiers rejects the @ and : which Presto allows in identifiers.";
+
+/// Runs the command line tool; returns the exit code.
+pub fn run(args: &[String]) -> i32 {
+    let (command, args) = match args.split_first() {
+        Some((command, args)) => (command.as_str(), args),
+        None => return usage_error("Missing command."),
+    };
+    match command {
+        "lex" => lex(args),
+        "parse" => parse(args),
+        "check" => check(args, false),
+        "lint" => check(args, true),
+        "classify" => classify(args),
+        "fingerprint" => fingerprint(args),
+        "compare" => compare(args),
+        "diff" => diff(args),
+        "analyze" => analyze(args),
+        "format" => format(args),
+        "batch" => run_batch(args),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            EXIT_SUCCESS
+        }
+        _ => usage_error(&format!("Unknown command '{}'.", command)),
+    }
+}
+
+fn usage_error(message: &str) -> i32 {
+    eprintln!("{}\n\n{}", message, USAGE);
+    EXIT_USAGE
+}
+
+/// Splits args into options, which start with --, and inputs.
+/// Options which take a value consume the following arg.
+fn split_options<'s>(
+    args: &'s [String],
+    options_with_values: &[&str],
+) -> (Vec<(&'s str, Option<&'s str>)>, Vec<String>) {
+    let mut options = Vec::new();
+    let mut input_args = Vec::new();
+    let mut index = 0;
+    while index < args.len() {
+        let arg = args[index].as_str();
+        if arg.starts_with("--") {
+            if options_with_values.contains(&arg) {
+                index += 1;
+                options.push((arg, args.get(index).map(|value| value.as_str())));
+            } else {
+                options.push((arg, None));
+            }
+        } else {
+            input_args.push(args[index].clone());
+        }
+        index += 1;
+    }
+    (options, input_args)
+}
+
+/// Reads each input and passes it to process.
+/// Returns the worst exit code from process, or EXIT_USAGE if an input
+/// cannot be read.
+fn for_each_input<F>(input_args: &[String], mut process: F) -> i32
+where
+    F: FnMut(&Input, bool) -> i32,
+{
+    let names = match inputs::expand(input_args) {
+        Ok(names) => names,
+        Err(message) => {
+            eprintln!("{}", message);
+            return EXIT_USAGE;
+        }
+    };
+    let is_multiple = names.len() > 1;
+    let mut exit_code = EXIT_SUCCESS;
+    for name in names {
+        let result = match inputs::read(&name) {
+            Ok(input) => process(&input, is_multiple),
+            Err(error) => {
+                eprintln!("{}: {}", name, error);
+                EXIT_USAGE
+            }
+        };
+        exit_code = exit_code.max(result);
+    }
+    exit_code
+}
+
+fn print_diagnostics(input: &Input, severity: Severity, errors: &[SyntaxError]) {
+    let source = SourceText::new(&input.contents);
+    for error in errors {
+        print!(
+            "{}",
+            diagnostics::render(&input.name, &source, severity, error)
+        );
+    }
+}
+
+fn run_batch(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &["--column", "--failures", "--threads"]);
+    let mut batch_options = batch::Options {
+        column: batch::Column::Index(1),
+        has_headers: true,
+        failures_path: None,
+        thread_count: parallel::default_thread_count(),
+    };
+    for (option, value) in options {
+        match (option, value) {
+            ("--column", Some(value)) => batch_options.column = batch::Column::parse(value),
+            ("--failures", Some(value)) => batch_options.failures_path = Some(value.to_string()),
+            ("--threads", Some(value)) => match value.parse() {
+                Ok(thread_count) if thread_count > 0 => batch_options.thread_count = thread_count,
+                _ => return usage_error("--threads expects a positive number."),
+            },
+            ("--no-headers", None) => batch_options.has_headers = false,
+            ("--column", None) | ("--failures", None) | ("--threads", None) => {
+                return usage_error(&format!("{} expects a value.", option))
+            }
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    match input_args.as_slice() {
+        [path] => batch::process_csv(path, &batch_options),
+        _ => usage_error("batch expects a single CSV file."),
+    }
+}
+
+fn lex(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    let mut json = false;
+    let mut lexer_options = LexerOptions::default();
+    for (option, _) in options {
+        match option {
+            "--json" => json = true,
+            "--strict-identifiers" => lexer_options.allow_identifier_extensions = false,
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    for_each_input(&input_args, |input, is_multiple| {
+        if is_multiple && !json {
+            println!("-- {}", input.name);
+        }
+        let mut had_error = false;
+        for token in Lexer::with_options(&input.contents, lexer_options) {
+            had_error = had_error || !token.errors.is_empty();
+            if json {
+                let mut value = token.to_json();
+                if is_multiple {
+                    value.push("file", input.name.as_str().into());
+                }
+                println!("{}", value);
+            } else {
+                println!("{} {} {:?}", token.range, token.kind, token.value);
+                for comment in &token.leading_comments {
+                    println!(
+                        "  leading {} {} {:?}",
+                        comment.range, comment.kind, comment.value
+                    );
+                }
+                for comment in &token.trailing_comments {
+                    println!(
+                        "  trailing {} {} {:?}",
+                        comment.range, comment.kind, comment.value
+                    );
+                }
+                for error in &token.errors {
+                    println!("  error {}", error);
+                }
+            }
+        }
+        if had_error {
+            EXIT_FAILURE
+        } else {
+            EXIT_SUCCESS
+        }
+    })
+}
+
+/// How parse prints trees.
+#[derive(Clone, Copy)]
+enum TreeFormat {
+    Sexp,
+    Dot,
+    Json,
+    Debug,
+}
+
+fn parse(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &["--format"]);
+    let mut tree_format = TreeFormat::Sexp;
+    for (option, value) in options {
+        tree_format = match (option, value) {
+            ("--format", Some("sexp")) => TreeFormat::Sexp,
+            ("--format", Some("dot")) => TreeFormat::Dot,
+            ("--format", Some("json")) => TreeFormat::Json,
+            ("--format", Some("debug")) => TreeFormat::Debug,
+            ("--format", _) => return usage_error("--format expects sexp, dot, json or debug."),
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        };
+    }
+    for_each_input(&input_args, |input, is_multiple| {
+        let (tree, errors) = parse_statement(&input.contents);
+        match tree_format {
+            TreeFormat::Json => {
+                // One JSON document per line; even if there are errors.
+                let mut value = parse_tree_json::parse_result_to_json(&tree, &errors);
+                if is_multiple {
+                    value.push("file", input.name.as_str().into());
+                }
+                println!("{}", value);
+            }
+            TreeFormat::Dot => {
+                // Keep the output a valid DOT file.
+                if is_multiple {
+                    println!("// {}", input.name);
+                }
+                print!("{}", dump::to_dot(&tree));
+            }
+            TreeFormat::Sexp => {
+                if is_multiple {
+                    println!("-- {}", input.name);
+                }
+                println!("{}", dump::to_sexp(&tree));
+            }
+            TreeFormat::Debug => {
+                if is_multiple {
+                    println!("-- {}", input.name);
+                }
+                println!("{:#?}", tree);
+            }
+        }
+        if errors.is_empty() {
+            EXIT_SUCCESS
+        } else {
+            // Trees are printed to stdout; keep diagnostics out of the way.
+            let source = SourceText::new(&input.contents);
+            for error in &errors {
+                eprint!(
+                    "{}",
+                    diagnostics::render(&input.name, &source, Severity::Error, error)
+                );
+            }
+            EXIT_FAILURE
+        }
+    })
+}
+
+fn check(args: &[String], lint: bool) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    let mut parse_options = ParseOptions::default();
+    for (option, _) in options {
+        match option {
+            "--strict-identifiers" => parse_options.lexer.allow_identifier_extensions = false,
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    for_each_input(&input_args, |input, _| {
+        let (tree, errors) = parse_statement_with_options(&input.contents, &parse_options);
+        print_diagnostics(input, Severity::Error, &errors);
+        // Lint warnings on trees with errors are not reliable.
+        let warnings = if lint && errors.is_empty() {
+            linting::lint(&tree)
+        } else {
+            Vec::new()
+        };
+        print_diagnostics(input, Severity::Warning, &warnings);
+        if errors.is_empty() && warnings.is_empty() {
+            EXIT_SUCCESS
+        } else {
+            EXIT_FAILURE
+        }
+    })
+}
+
+/// The session defaults to the catalog's only schema, if it has one.
+fn default_session(catalog: &MemoryCatalog) -> Option<Session> {
+    match catalog.catalog_names().as_slice() {
+        [catalog_name] => match catalog.schema_names(catalog_name).as_slice() {
+            [schema_name] => Some(Session::new(catalog_name, schema_name)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn classify(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    if let Some((option, _)) = options.first() {
+        return usage_error(&format!("Unknown option '{}'.", option));
+    }
+    for_each_input(&input_args, |input, _| {
+        let (tree, mut errors) = parse_statement(&input.contents);
+        let class = analysis::classify_text(&input.contents);
+        // Statements which are not parsed yet, such as SHOW, are classified
+        // by their leading words; their syntax errors are expected.
+        if analysis::classify(&tree) == StatementClass::Unknown && class != StatementClass::Unknown
+        {
+            errors.clear();
+        }
+        print_diagnostics(input, Severity::Error, &errors);
+        println!(
+            "{}: {} ({})",
+            input.name,
+            class,
+            if class.can_modify_data() {
+                "can modify data"
+            } else {
+                "read-only"
+            }
+        );
+        if errors.is_empty() {
+            EXIT_SUCCESS
+        } else {
+            EXIT_FAILURE
+        }
+    })
+}
+
+fn compare(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    let mut equality_options = EqualityOptions::default();
+    for (option, _) in options {
+        match option {
+            "--ignore-keyword-case" => equality_options.ignore_keyword_case = true,
+            "--ignore-identifier-case" => equality_options.ignore_identifier_case = true,
+            "--ignore-parentheses" => equality_options.ignore_redundant_parentheses = true,
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    let inputs = match read_pair("compare", &input_args) {
+        Ok(inputs) => inputs,
+        Err(exit_code) => return exit_code,
+    };
+    let trees = match parse_inputs(&inputs) {
+        Some(trees) => trees,
+        None => return EXIT_FAILURE,
+    };
+    if trees_equal(&trees[0], &trees[1], &equality_options) {
+        println!("{} and {} are equal", inputs[0].name, inputs[1].name);
+        EXIT_SUCCESS
+    } else {
+        println!("{} and {} differ", inputs[0].name, inputs[1].name);
+        EXIT_FAILURE
+    }
+}
+
+fn diff(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    let mut json = false;
+    for (option, _) in options {
+        match option {
+            "--json" => json = true,
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    let inputs = match read_pair("diff", &input_args) {
+        Ok(inputs) => inputs,
+        Err(exit_code) => return exit_code,
+    };
+    let trees = match parse_inputs(&inputs) {
+        Some(trees) => trees,
+        None => return EXIT_FAILURE,
+    };
+    let tree_diff = diff_trees(&trees[0], &trees[1], &EqualityOptions::lenient());
+    if json {
+        println!("{}", tree_diff.to_json());
+    } else {
+        print!("{}", tree_diff.summary());
+    }
+    if tree_diff.is_empty() {
+        EXIT_SUCCESS
+    } else {
+        EXIT_FAILURE
+    }
+}
+
+/// Reads the two inputs of a command which compares statements; Err is the
+/// exit code.
+fn read_pair(command: &str, input_args: &[String]) -> Result<Vec<Input>, i32> {
+    if input_args.len() != 2 {
+        return Err(usage_error(&format!("{} expects two inputs.", command)));
+    }
+    let mut inputs = Vec::new();
+    for name in input_args {
+        match inputs::read(name) {
+            Ok(input) => inputs.push(input),
+            Err(error) => {
+                eprintln!("{}: {}", name, error);
+                return Err(EXIT_USAGE);
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+/// Parses each input as a statement; None after printing the syntax errors
+/// if any input has them.
+fn parse_inputs(inputs: &[Input]) -> Option<Vec<ParseTree<'_>>> {
+    let mut trees = Vec::new();
+    let mut had_errors = false;
+    for input in inputs {
+        let (tree, errors) = parse_statement(&input.contents);
+        print_diagnostics(input, Severity::Error, &errors);
+        had_errors = had_errors || !errors.is_empty();
+        trees.push(tree);
+    }
+    if had_errors {
+        None
+    } else {
+        Some(trees)
+    }
+}
+
+fn fingerprint(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    if let Some((option, _)) = options.first() {
+        return usage_error(&format!("Unknown option '{}'.", option));
+    }
+    for_each_input(&input_args, |input, is_multiple| {
+        let (tree, errors) = parse_statement(&input.contents);
+        print_diagnostics(input, Severity::Error, &errors);
+        if !errors.is_empty() {
+            return EXIT_FAILURE;
+        }
+        let fingerprint = analysis::fingerprint(&tree);
+        if is_multiple {
+            println!("{}: {} {}", input.name, fingerprint, fingerprint.text);
+        } else {
+            println!("{} {}", fingerprint, fingerprint.text);
+        }
+        EXIT_SUCCESS
+    })
+}
+
+fn analyze(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(
+        args,
+        &["--catalog", "--session", "--function", "--aggregate"],
+    );
+    let mut catalog_paths = Vec::new();
+    let mut functions = Vec::new();
+    let mut session = None;
+    let mut print_bindings = false;
+    let mut print_types = false;
+    let mut print_lineage = false;
+    let mut print_tables = false;
+    for (option, value) in options {
+        match (option, value) {
+            ("--catalog", Some(value)) => catalog_paths.push(value),
+            ("--session", Some(value)) => match value.split_once('.') {
+                Some((catalog_name, schema_name)) => {
+                    session = Some(Session::new(
+                        &catalog_name.to_lowercase(),
+                        &schema_name.to_lowercase(),
+                    ))
+                }
+                None => return usage_error("--session expects <catalog>.<schema>."),
+            },
+            ("--function", Some(value)) => functions.push((FunctionKind::Scalar, value)),
+            ("--aggregate", Some(value)) => functions.push((FunctionKind::Aggregate, value)),
+            ("--bindings", None) => print_bindings = true,
+            ("--types", None) => print_types = true,
+            ("--lineage", None) => print_lineage = true,
+            ("--tables", None) => print_tables = true,
+            ("--catalog", None)
+            | ("--session", None)
+            | ("--function", None)
+            | ("--aggregate", None) => return usage_error(&format!("{} expects a value.", option)),
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    if catalog_paths.is_empty() {
+        return usage_error("analyze expects --catalog.");
+    }
+    if session.is_none() && catalog_paths.iter().any(|path| !path.ends_with(".json")) {
+        return usage_error("analyze expects --session with DDL catalogs.");
+    }
+    let mut catalog = MemoryCatalog::new();
+    for (kind, signature) in functions {
+        if let Err(error) = catalog.functions_mut().register_text(kind, signature) {
+            return usage_error(&error);
+        }
+    }
+    for catalog_path in catalog_paths {
+        let input = match inputs::read(catalog_path) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}: {}", catalog_path, error);
+                return EXIT_USAGE;
+            }
+        };
+        if catalog_path.ends_with(".json") {
+            match MemoryCatalog::from_json(&input.contents) {
+                Ok(json_catalog) => catalog.add_catalog(json_catalog),
+                Err(error) => {
+                    eprintln!("{}: {}", catalog_path, error);
+                    return EXIT_USAGE;
+                }
+            }
+        } else if let Some(session) = &session {
+            let errors = ddl::load_script(&mut catalog, session, &input.contents);
+            if !errors.is_empty() {
+                print_diagnostics(&input, Severity::Error, &errors);
+                return EXIT_USAGE;
+            }
+        }
+    }
+    let session = match session.or_else(|| default_session(&catalog)) {
+        Some(session) => session,
+        None => return usage_error("analyze expects --session for catalogs with several schemas."),
+    };
+    for_each_input(&input_args, |input, is_multiple| {
+        let (tree, errors) = parse_statement(&input.contents);
+        print_diagnostics(input, Severity::Error, &errors);
+        if !errors.is_empty() {
+            return EXIT_FAILURE;
+        }
+        let resolution = analysis::resolve(&tree, &catalog, &session);
+        if print_bindings {
+            let relations = resolution
+                .relations
+                .iter()
+                .map(|binding| (binding.range, binding.target.to_string()));
+            let columns = resolution
+                .columns
+                .iter()
+                .map(|binding| (binding.range, binding.target.to_string()));
+            let mut bindings: Vec<_> = relations.chain(columns).collect();
+            bindings.sort_by_key(|(range, _)| (range.start.line, range.start.column));
+            for (range, target) in bindings {
+                let text = SourceText::new(&input.contents).slice(range).to_string();
+                println!(
+                    "{}:{}:{}: {} -> {}",
+                    input.name,
+                    range.start.line + 1,
+                    range.start.column + 1,
+                    text,
+                    target
+                );
+            }
+        }
+        if print_types {
+            let mut types: Vec<_> = resolution.types.iter().collect();
+            types.sort_by_key(|expression_type| {
+                let range = expression_type.range;
+                (range.start.line, range.start.column)
+            });
+            for expression_type in types {
+                let range = expression_type.range;
+                let text = SourceText::new(&input.contents).slice(range).to_string();
+                println!(
+                    "{}:{}:{}: {} : {}",
+                    input.name,
+                    range.start.line + 1,
+                    range.start.column + 1,
+                    text,
+                    expression_type.data_type
+                );
+            }
+        }
+        if print_lineage {
+            if let Some(lineage) = lineage::lineage(&tree, &catalog, &session) {
+                let mut value = lineage.to_json();
+                if is_multiple {
+                    value.push("file", input.name.as_str().into());
+                }
+                println!("{}", value);
+            }
+        }
+        if print_tables {
+            let mut value = TableReferences::new(&tree, &session).to_json();
+            if is_multiple {
+                value.push("file", input.name.as_str().into());
+            }
+            println!("{}", value);
+        }
+        print_diagnostics(input, Severity::Error, &resolution.errors);
+        if resolution.errors.is_empty() {
+            EXIT_SUCCESS
+        } else {
+            EXIT_FAILURE
+        }
+    })
+}
+
+fn format(args: &[String]) -> i32 {
+    let (options, input_args) = split_options(args, &[]);
+    let mut check_only = false;
+    for (option, _) in options {
+        match option {
+            "--check" => check_only = true,
+            _ => return usage_error(&format!("Unknown option '{}'.", option)),
+        }
+    }
+    for_each_input(&input_args, |input, _| {
+        let formatted = match formatting::format(&input.contents) {
+            Ok(formatted) => formatted,
+            Err(FormatError::SyntaxErrors(errors)) => {
+                print_diagnostics(input, Severity::Error, &errors);
+                return EXIT_FAILURE;
+            }
+            Err(FormatError::TokensChanged) => {
+                eprintln!("{}: formatting would change the query; skipped", input.name);
+                return EXIT_FAILURE;
+            }
+        };
+        let is_formatted = formatted == input.contents;
+        if check_only {
+            if is_formatted {
+                EXIT_SUCCESS
+            } else {
+                println!("{}: not formatted", input.name);
+                EXIT_FAILURE
+            }
+        } else if input.is_stdin() {
+            print!("{}", formatted);
+            EXIT_SUCCESS
+        } else if is_formatted {
+            EXIT_SUCCESS
+        } else {
+            match fs::write(&input.name, formatted) {
+                Ok(()) => EXIT_SUCCESS,
+                Err(error) => {
+                    eprintln!("{}: {}", input.name, error);
+                    EXIT_USAGE
+                }
+            }
+        }
+    })
+}
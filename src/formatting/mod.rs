@@ -0,0 +1,329 @@
+//! Formatting of parse trees into a canonical layout.
+//!
+//! Keywords are upper cased; identifiers, literals and comments are kept
+//! as written. Each clause of a query starts a new line, joins
+//! are indented beneath their FROM clause, and parenthesized queries are
+//! indented one level deeper than the line containing the open paren.
+//! Within a line, tokens are separated by a single space where needed.
+//!
+//! Formatting never changes the meaning of a query: format() reparses its
+//! output and fails if the tokens differ from the original tokens.
+use crate::lexing::{token, Comment, CommentKind, TokenKind};
+use crate::parsing::edit::needs_space;
+use crate::parsing::visitor;
+use crate::parsing::{parse_statement, parse_tree, visit_pre_order, ParseTree, Visitor};
+use crate::utils::{Position, SyntaxError};
+use std::collections::{HashMap, HashSet};
+
+const INDENT: &str = "  ";
+
+/// The reasons that text could not be formatted.
+#[derive(Debug)]
+pub enum FormatError {
+    /// The text does not parse.
+    SyntaxErrors(Vec<SyntaxError>),
+    /// The formatted text does not contain the same tokens as the original.
+    /// This indicates a bug in the formatter.
+    TokensChanged,
+}
+
+/// Formats the text of a statement.
+pub fn format(text: &str) -> Result<String, FormatError> {
+    let (tree, errors) = parse_statement(text);
+    if !errors.is_empty() {
+        return Err(FormatError::SyntaxErrors(errors));
+    }
+    let result = format_tree(&tree);
+    let (formatted_tree, errors) = parse_statement(&result);
+    if !errors.is_empty() || !same_tokens(&tree, &formatted_tree) {
+        return Err(FormatError::TokensChanged);
+    }
+    Ok(result)
+}
+
+/// Formats a tree which has no errors.
+pub fn format_tree(tree: &ParseTree) -> String {
+    let mut formatter = Formatter {
+        result: String::new(),
+        depth: 0,
+        line_breaks: HashMap::new(),
+        keywords: HashSet::new(),
+        indent: 0,
+        previous: TokenKind::BeginningOfFile,
+        at_line_start: true,
+    };
+    formatter.visit(tree);
+    if !formatter.at_line_start {
+        formatter.result.push('\n');
+    }
+    formatter.result
+}
+
+fn tokens<'a>(tree: &'a ParseTree<'a>) -> Vec<&'a token::Token<'a>> {
+    let mut result = Vec::new();
+    visit_pre_order(tree, &mut |tree| {
+        if let ParseTree::Token(token) = tree {
+            result.push(&token.token);
+        }
+    });
+    result
+}
+
+fn same_tokens(left: &ParseTree, right: &ParseTree) -> bool {
+    let comment_count =
+        |token: &&token::Token| token.leading_comments.len() + token.trailing_comments.len();
+    let left = tokens(left);
+    let right = tokens(right);
+    left.len() == right.len()
+        && left.iter().map(comment_count).sum::<usize>()
+            == right.iter().map(comment_count).sum::<usize>()
+        && left.iter().zip(right.iter()).all(|(left, right)| {
+            left.kind == right.kind
+                && (left.value == right.value
+                    || (is_case_insensitive(left.kind)
+                        && left.value.eq_ignore_ascii_case(right.value)))
+        })
+}
+
+/// Keywords, and the non-reserved keywords which are lexed as identifiers.
+fn is_case_insensitive(kind: TokenKind) -> bool {
+    kind.is_keyword() || kind == TokenKind::Identifier
+}
+
+struct Formatter {
+    result: String,
+    // The nesting depth of parenthesized queries.
+    depth: usize,
+    // Tokens which start a new line, keyed by their start; with the
+    // indentation depth of the new line.
+    line_breaks: HashMap<Position, usize>,
+    // Non-reserved keywords to upper case, keyed by their start.
+    keywords: HashSet<Position>,
+    // The indentation depth of the current line.
+    indent: usize,
+    previous: TokenKind,
+    at_line_start: bool,
+}
+
+impl Formatter {
+    /// Starts a new line, indented to depth, at the first token of tree.
+    fn break_before(&mut self, tree: &ParseTree, depth: usize) {
+        if let Some(token) = tree.get_first_token() {
+            self.line_breaks.insert(token.range.start, depth);
+        }
+    }
+
+    fn new_line(&mut self) {
+        if !self.at_line_start {
+            self.result.push('\n');
+            self.at_line_start = true;
+        }
+    }
+
+    fn write_comment(&mut self, comment: &Comment) {
+        match comment.kind {
+            CommentKind::LineComment => {
+                if !self.at_line_start {
+                    self.result.push(' ');
+                } else {
+                    self.write_indent();
+                }
+                self.result
+                    .push_str(comment.value.trim_end_matches(['\n', '\r']));
+                self.result.push('\n');
+                self.at_line_start = true;
+            }
+            CommentKind::DelimitedComment => {
+                if self.at_line_start {
+                    self.write_indent();
+                } else {
+                    self.result.push(' ');
+                }
+                self.result.push_str(comment.value);
+                self.at_line_start = false;
+            }
+        }
+    }
+
+    fn write_indent(&mut self) {
+        for _ in 0..self.indent {
+            self.result.push_str(INDENT);
+        }
+    }
+
+    /// Visits a query nested in parens: the query is indented one level
+    /// deeper than the line containing the open paren.
+    fn visit_nested_query<'a>(
+        &mut self,
+        open_paren: &'a ParseTree<'a>,
+        query: &'a ParseTree<'a>,
+        close_paren: &'a ParseTree<'a>,
+    ) {
+        let depth = self.depth;
+        self.visit(open_paren);
+        self.depth += 1;
+        self.break_before(query, self.depth);
+        self.visit(query);
+        self.depth = depth;
+        self.break_before(close_paren, depth);
+        self.visit(close_paren);
+    }
+
+    fn visit_join_tail(&mut self, tail: &ParseTree) {
+        self.break_before(tail, self.depth + 1);
+    }
+}
+
+impl<'a> Visitor<'a> for Formatter {
+    fn visit_token(&mut self, token: &'a parse_tree::Token<'a>) {
+        let token = &token.token;
+        let line_break = self.line_breaks.get(&token.range.start).cloned();
+        if let Some(depth) = line_break {
+            self.new_line();
+            self.indent = depth;
+        }
+        for comment in &token.leading_comments {
+            if let CommentKind::LineComment = comment.kind {
+                self.new_line();
+            }
+            self.write_comment(comment);
+        }
+        if !token.value.is_empty() {
+            if self.at_line_start {
+                self.write_indent();
+            } else if needs_space(self.previous, token.kind) || self.result.ends_with("*/") {
+                self.result.push(' ');
+            }
+            if token.kind.is_keyword() || self.keywords.contains(&token.range.start) {
+                self.result.push_str(&token.value.to_uppercase());
+            } else {
+                self.result.push_str(token.value);
+            }
+            self.at_line_start = false;
+            self.previous = token.kind;
+        }
+        for comment in &token.trailing_comments {
+            self.write_comment(comment);
+        }
+    }
+
+    fn visit_query(&mut self, query: &'a parse_tree::Query<'a>) {
+        if !query.with.is_empty() {
+            self.break_before(&query.query_no_with, self.depth);
+        }
+        visitor::walk_query(self, query)
+    }
+
+    fn visit_with(&mut self, with: &'a parse_tree::With<'a>) {
+        if let ParseTree::List(named_queries) = &*with.named_queries {
+            for (named_query, _) in named_queries.elements_and_separators.iter().skip(1) {
+                self.break_before(named_query, self.depth);
+            }
+        }
+        visitor::walk_with(self, with)
+    }
+
+    fn visit_named_query(&mut self, named_query: &'a parse_tree::NamedQuery<'a>) {
+        self.visit(&named_query.name);
+        self.visit(&named_query.column_aliases_opt);
+        self.visit(&named_query.as_);
+        self.visit_nested_query(
+            &named_query.open_paren,
+            &named_query.query,
+            &named_query.close_paren,
+        );
+    }
+
+    fn visit_query_no_with(&mut self, query_no_with: &'a parse_tree::QueryNoWith<'a>) {
+        self.break_before(&query_no_with.order_by_opt, self.depth);
+        self.break_before(&query_no_with.limit_opt, self.depth);
+        visitor::walk_query_no_with(self, query_no_with)
+    }
+
+    fn visit_limit(&mut self, limit: &'a parse_tree::Limit<'a>) {
+        self.keywords.insert(limit.limit.get_start());
+        visitor::walk_limit(self, limit)
+    }
+
+    fn visit_query_set_operation(
+        &mut self,
+        query_set_operation: &'a parse_tree::QuerySetOperation<'a>,
+    ) {
+        self.break_before(&query_set_operation.operator, self.depth);
+        self.break_before(&query_set_operation.right, self.depth);
+        visitor::walk_query_set_operation(self, query_set_operation)
+    }
+
+    fn visit_query_specification(
+        &mut self,
+        query_specification: &'a parse_tree::QuerySpecification<'a>,
+    ) {
+        self.break_before(&query_specification.from, self.depth);
+        self.break_before(&query_specification.where_, self.depth);
+        self.break_before(&query_specification.group, self.depth);
+        self.break_before(&query_specification.having, self.depth);
+        visitor::walk_query_specification(self, query_specification)
+    }
+
+    fn visit_subquery(&mut self, subquery: &'a parse_tree::Subquery<'a>) {
+        self.visit_nested_query(
+            &subquery.open_paren,
+            &subquery.query_no_with,
+            &subquery.close_paren,
+        );
+    }
+
+    fn visit_subquery_relation(&mut self, subquery_relation: &'a parse_tree::SubqueryRelation<'a>) {
+        self.visit_nested_query(
+            &subquery_relation.open_paren,
+            &subquery_relation.query,
+            &subquery_relation.close_paren,
+        );
+    }
+
+    fn visit_subquery_expression(
+        &mut self,
+        subquery_expression: &'a parse_tree::SubqueryExpression<'a>,
+    ) {
+        self.visit_nested_query(
+            &subquery_expression.open_paren,
+            &subquery_expression.query,
+            &subquery_expression.close_paren,
+        );
+    }
+
+    fn visit_exists(&mut self, exists: &'a parse_tree::Exists<'a>) {
+        self.visit(&exists.exists);
+        self.visit_nested_query(&exists.open_paren, &exists.query, &exists.close_paren);
+    }
+
+    fn visit_in_subquery(&mut self, in_subquery: &'a parse_tree::InSubquery<'a>) {
+        self.visit(&in_subquery.value);
+        self.visit(&in_subquery.not_opt);
+        self.visit(&in_subquery.in_);
+        self.visit_nested_query(
+            &in_subquery.open_paren,
+            &in_subquery.query,
+            &in_subquery.close_paren,
+        );
+    }
+
+    fn visit_cross_join(&mut self, cross_join: &'a parse_tree::CrossJoin<'a>) {
+        self.visit_join_tail(&cross_join.cross);
+        visitor::walk_cross_join(self, cross_join)
+    }
+
+    fn visit_join(&mut self, join: &'a parse_tree::Join<'a>) {
+        if join.join_type.is_empty() {
+            self.visit_join_tail(&join.join);
+        } else {
+            self.visit_join_tail(&join.join_type);
+        }
+        visitor::walk_join(self, join)
+    }
+
+    fn visit_natural_join(&mut self, natural_join: &'a parse_tree::NaturalJoin<'a>) {
+        self.visit_join_tail(&natural_join.natural);
+        visitor::walk_natural_join(self, natural_join)
+    }
+}
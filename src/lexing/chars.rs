@@ -1,4 +1,5 @@
 //! Type tests for characters.
+use unicode_xid::UnicodeXID;
 
 pub const NULL: char = '\0';
 pub const TAB: char = '\t';
@@ -21,16 +22,18 @@ pub fn is_sign(ch: char) -> bool {
     ch == '-' || ch == '+'
 }
 
+/// XID_Start or _.
 pub fn is_identifier_start(ch: char) -> bool {
-    match ch {
-        'a'..='z' | 'A'..='Z' | '_' => true,
-        _ => false,
-    }
+    ch == '_' || UnicodeXID::is_xid_start(ch)
 }
 
+/// XID_Continue, which includes digits and _.
 pub fn is_identifier_part(ch: char) -> bool {
-    match ch {
-        'a'..='z' | 'A'..='Z' | '_' | '0'..='9' | '@' | ':' => true,
-        _ => false,
-    }
+    UnicodeXID::is_xid_continue(ch)
+}
+
+/// Presto's grammar also allows @ and : after the first character of an
+/// identifier; see LexerOptions.
+pub fn is_identifier_extension(ch: char) -> bool {
+    ch == '@' || ch == ':'
 }
@@ -1,4 +1,4 @@
-use crate::utils::TextRange;
+use crate::utils::{Json, TextRange};
 use std::fmt;
 
 /// The kinds of comment trivia.
@@ -33,3 +33,14 @@ impl fmt::Display for Comment<'_> {
         write!(f, "comment({},{},{})", self.kind, self.range, self.value)
     }
 }
+
+impl Comment<'_> {
+    /// {"kind": "LineComment" | "DelimitedComment", "range": range, "value": string}
+    pub fn to_json(self) -> Json {
+        Json::object(vec![
+            ("kind", self.kind.to_string().into()),
+            ("range", self.range.to_json()),
+            ("value", self.value.into()),
+        ])
+    }
+}
@@ -0,0 +1,177 @@
+//! The values of identifier tokens.
+//!
+//! Presto folds unquoted identifiers to lower case, so Foo and foo are the
+//! same name, while delimited identifiers such as "Foo" keep their case.
+//! Identifiers compare and hash by their folded names, and display with the
+//! fewest quotes that keep that name.
+use super::{chars, keywords, Token, TokenKind};
+use crate::utils::syntax_error::ERROR_SYNTAX_ERROR;
+use crate::utils::{Message, SyntaxError};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone, Debug, Eq)]
+pub struct Identifier {
+    /// The name, with quotes removed and doubled quotes unescaped; lower
+    /// cased unless delimited.
+    pub name: String,
+    /// Was the identifier quoted, as in "a""b" or `a`?
+    pub is_delimited: bool,
+}
+
+impl Identifier {
+    /// An unquoted identifier, which is lower cased.
+    pub fn unquoted(text: &str) -> Identifier {
+        Identifier {
+            name: text.to_lowercase(),
+            is_delimited: false,
+        }
+    }
+
+    /// A delimited identifier, whose case is kept.
+    pub fn delimited(name: &str) -> Identifier {
+        Identifier {
+            name: name.to_string(),
+            is_delimited: true,
+        }
+    }
+}
+
+/// Identifiers with the same folded name are equal, however they were
+/// written: FOO equals "foo", but not "FOO".
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Identifier) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", render_name(&self.name))
+    }
+}
+
+/// Writes a folded name as an identifier, quoting it only if it would
+/// otherwise lex differently: as a keyword, with upper case letters
+/// folded, or as something other than a single identifier.
+pub fn render_name(name: &str) -> String {
+    let mut name_chars = name.chars();
+    let is_plain = name_chars.next().is_some_and(chars::is_identifier_start)
+        && name_chars.all(chars::is_identifier_part)
+        && name.to_lowercase() == name
+        && keywords::maybe_get_keyword(name).is_none();
+    if is_plain {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+impl<'a> Token<'a> {
+    /// The value of an Identifier, DigitIdentifier, QuotedIdentifier or
+    /// BackquotedIdentifier token.
+    pub fn identifier_value(&self) -> Result<Identifier, SyntaxError> {
+        let value = self.value;
+        match self.kind {
+            TokenKind::Identifier | TokenKind::DigitIdentifier => Ok(Identifier::unquoted(value)),
+            TokenKind::QuotedIdentifier => Ok(Identifier::delimited(&unquote(value, '"'))),
+            TokenKind::BackquotedIdentifier => Ok(Identifier::delimited(&unquote(value, '`'))),
+            _ => Err(SyntaxError::from_message(
+                ERROR_SYNTAX_ERROR,
+                Message::new(
+                    self.range,
+                    format!("Expected an identifier, found {}.", self.kind),
+                ),
+            )),
+        }
+    }
+}
+
+/// The contents of a delimited identifier, with doubled quotes unescaped.
+/// The closing quote is removed only if value has one.
+fn unquote(value: &str, quote: char) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().skip(1).peekable();
+    while let Some(ch) = chars.next() {
+        if ch == quote && chars.next_if_eq(&quote).is_none() {
+            break;
+        }
+        result.push(ch);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexing::Lexer;
+    use crate::utils::text_range;
+
+    fn value(text: &str) -> Identifier {
+        Lexer::new(text).next().unwrap().identifier_value().unwrap()
+    }
+
+    #[test]
+    fn delimited() {
+        assert_eq!(value("\"Abc\"").name, "Abc");
+        assert_eq!(value("\"a\"\"b\"").name, "a\"b");
+        assert_eq!(value("`a``b`").name, "a`b");
+        assert_eq!(value("\"\"").name, "");
+    }
+
+    fn unterminated(kind: TokenKind, text: &str) -> Identifier {
+        Token::new(
+            kind,
+            text_range::NONE,
+            text,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .identifier_value()
+        .unwrap()
+    }
+
+    #[test]
+    fn unterminated_delimited() {
+        let quoted = TokenKind::QuotedIdentifier;
+        assert_eq!(unterminated(quoted, "\"abc").name, "abc");
+        assert_eq!(unterminated(quoted, "\"ab\"\"").name, "ab\"");
+        assert_eq!(unterminated(quoted, "\"").name, "");
+        let backquoted = TokenKind::BackquotedIdentifier;
+        assert_eq!(unterminated(backquoted, "`abc").name, "abc");
+    }
+
+    #[test]
+    fn unquoted_is_lower_cased() {
+        assert_eq!(value("FooBar"), Identifier::unquoted("foobar"));
+        assert!(!value("FooBar").is_delimited);
+    }
+
+    #[test]
+    fn render_name_quotes_only_when_needed() {
+        assert_eq!(render_name("abc"), "abc");
+        assert_eq!(render_name("_a1"), "_a1");
+        assert_eq!(render_name("café"), "café");
+        // Keywords, upper case and non-identifier characters need quotes.
+        assert_eq!(render_name("select"), "\"select\"");
+        assert_eq!(render_name("Abc"), "\"Abc\"");
+        assert_eq!(render_name("a\"b"), "\"a\"\"b\"");
+        assert_eq!(render_name("1a"), "\"1a\"");
+        assert_eq!(render_name("a b"), "\"a b\"");
+        assert_eq!(render_name(""), "\"\"");
+    }
+
+    #[test]
+    fn rendered_names_lex_to_the_same_name() {
+        for name in &["abc", "select", "Abc", "a\"b", "1a", "a b", ""] {
+            assert_eq!(value(&render_name(name)).name, *name);
+        }
+    }
+}
@@ -11,7 +11,9 @@ use std::mem;
 /// the current location. peek()ing beyond the end of the string yields
 /// an infinite stream of NULL(0) characters.
 ///
-/// lex_token() returns the next token and consumes it.
+/// lex_token() returns the next token and consumes it. Lexer is also an
+/// Iterator over the tokens, ending after the EndOfFile token, which holds
+/// any comments at the end of the input.
 ///
 /// The tokens returned by lex_token will include every non-whitespace
 /// character in the input exactly once: either as a token, or as
@@ -27,19 +29,45 @@ use std::mem;
 #[derive(Clone, Debug)]
 pub struct Lexer<'a> {
     pub input: &'a str,
+    options: LexerOptions,
     position: LexerPosition<'a>,
     comments: Vec<Comment<'a>>,
     errors: Vec<SyntaxError>,
+    // Has the iterator returned the EndOfFile token?
+    is_done: bool,
+}
+
+/// Identifiers start with a Unicode XID_Start character or _, and continue
+/// with XID_Continue characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LexerOptions {
+    /// Presto's extension allowing @ and : after the first character of an
+    /// identifier, as in a@b:c. On by default.
+    pub allow_identifier_extensions: bool,
+}
+
+impl Default for LexerOptions {
+    fn default() -> LexerOptions {
+        LexerOptions {
+            allow_identifier_extensions: true,
+        }
+    }
 }
 
 // All the non-language specific infrastructure goes here
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Lexer<'a> {
+        Lexer::with_options(input, LexerOptions::default())
+    }
+
+    pub fn with_options(input: &'a str, options: LexerOptions) -> Lexer<'a> {
         Lexer {
             input,
+            options,
             position: LexerPosition::new(input),
             errors: Vec::new(),
             comments: Vec::new(),
+            is_done: false,
         }
     }
 
@@ -48,9 +76,11 @@ impl<'a> Lexer<'a> {
     fn try_lexer(&self) -> Lexer<'a> {
         Lexer {
             input: self.input,
+            options: self.options,
             position: self.position,
             errors: Vec::new(),
             comments: Vec::new(),
+            is_done: false,
         }
     }
 
@@ -93,7 +123,7 @@ impl<'a> Lexer<'a> {
     /// Returns whether a char was consumed.
     fn eat_opt(&mut self, ch: char) -> bool {
         if self.peek_char(ch) {
-            self.next();
+            self.next_char();
             true
         } else {
             false
@@ -131,7 +161,7 @@ impl<'a> Lexer<'a> {
 
     /// Returns the next char in the input.
     /// Advanced past the char consumed.
-    fn next(&mut self) -> char {
+    fn next_char(&mut self) -> char {
         self.position.next()
     }
 
@@ -240,12 +270,21 @@ impl<'a> Lexer<'a> {
         self.skip_while(chars::is_whitespace)
     }
 
+    /// Skips the characters after the start of an identifier.
+    fn skip_identifier_parts(&mut self) -> bool {
+        let allow_extensions = self.options.allow_identifier_extensions;
+        self.skip_while(|ch| {
+            chars::is_identifier_part(ch)
+                || (allow_extensions && chars::is_identifier_extension(ch))
+        })
+    }
+
     fn skip_to_end_of_line(&mut self) {
         loop {
             if self.at_end() {
                 break;
             }
-            let ch = self.next();
+            let ch = self.next_char();
             match ch {
                 chars::CARRIAGE_RETURN | chars::LINE_FEED => break,
                 _ => (),
@@ -263,11 +302,11 @@ impl<'a> Lexer<'a> {
                 );
                 break;
             } else if self.peek_char('*') && self.peek_char_offset('/', 1) {
-                self.next();
-                self.next();
+                self.next_char();
+                self.next_char();
                 break ();
             } else {
-                self.next();
+                self.next_char();
             }
         }
     }
@@ -353,12 +392,12 @@ impl<'a> Lexer<'a> {
             }
             if self.eat_opt('\'') {
                 if self.peek_char('\'') {
-                    self.next();
+                    self.next_char();
                 } else {
                     return self.create_token(start, kind);
                 }
             } else {
-                self.next();
+                self.next_char();
             }
         }
     }
@@ -374,12 +413,12 @@ impl<'a> Lexer<'a> {
             }
             if self.eat_opt('"') {
                 if self.peek_char('"') {
-                    self.next();
+                    self.next_char();
                 } else {
                     return self.create_token(start, TokenKind::QuotedIdentifier);
                 }
             } else {
-                self.next();
+                self.next_char();
             }
         }
     }
@@ -395,19 +434,19 @@ impl<'a> Lexer<'a> {
             }
             if self.eat_opt('`') {
                 if self.peek_char('`') {
-                    self.next();
+                    self.next_char();
                 } else {
                     return self.create_token(start, TokenKind::BackquotedIdentifier);
                 }
             } else {
-                self.next();
+                self.next_char();
             }
         }
     }
 
     fn skip_digits(&mut self) {
         while chars::is_digit(self.peek()) {
-            self.next();
+            self.next_char();
         }
     }
 
@@ -426,7 +465,7 @@ impl<'a> Lexer<'a> {
     fn skip_fraction(&mut self) -> bool {
         if self.peek_fraction() {
             // '.'
-            self.next();
+            self.next_char();
             self.skip_digits();
             true
         } else {
@@ -437,9 +476,9 @@ impl<'a> Lexer<'a> {
     fn skip_exponent(&mut self) -> bool {
         if self.peek_exponent() {
             // E
-            self.next();
+            self.next_char();
             // sign or first digit
-            self.next();
+            self.next_char();
             // remaining digits
             self.skip_digits();
             true
@@ -466,7 +505,7 @@ impl<'a> Lexer<'a> {
                     TokenKind::Decimal
                 }
             } else {
-                if self.skip_while(chars::is_identifier_part) {
+                if self.skip_identifier_parts() {
                     TokenKind::DigitIdentifier
                 } else {
                     TokenKind::Integer
@@ -502,13 +541,13 @@ impl<'a> Lexer<'a> {
 
     /// Used for multi-word tokens; so does not check for comments.
     fn skip_word(&mut self) {
-        self.skip_while(chars::is_identifier_part);
+        self.skip_identifier_parts();
     }
 
     /// Lexes an identifier, keywordor multi-word token.
     fn lex_word(&mut self, start: &LexerPosition<'a>, ch: char) -> Token<'a> {
         debug_assert!(chars::is_identifier_start(ch));
-        self.skip_while(chars::is_identifier_part);
+        self.skip_identifier_parts();
         let text = self.get_text(start);
         if let Some(keyword) = keywords::maybe_get_keyword(text) {
             self.create_token(start, keyword.to_token_kind())
@@ -557,7 +596,7 @@ impl<'a> Lexer<'a> {
         if self.at_end() {
             self.create_token(&start, TokenKind::EndOfFile)
         } else {
-            let ch = self.next();
+            let ch = self.next_char();
             match ch {
                 // operators and punctuators
                 '(' => self.create_token(&start, TokenKind::OpenParen),
@@ -572,11 +611,11 @@ impl<'a> Lexer<'a> {
                 }
                 '<' => match self.peek() {
                     '>' => {
-                        self.next();
+                        self.next_char();
                         self.create_token(&start, TokenKind::LessGreater)
                     }
                     '=' => {
-                        self.next();
+                        self.next_char();
                         self.create_token(&start, TokenKind::LessEqual)
                     }
                     _ => self.create_token(&start, TokenKind::OpenAngle),
@@ -649,6 +688,7 @@ impl<'a> Lexer<'a> {
                         self.lex_word(&start, ch)
                     }
                 }
+                ch if chars::is_identifier_start(ch) => self.lex_word(&start, ch),
                 _ => self.add_and_create_error(
                     &start,
                     syntax_error::ERROR_INVALID_TOKEN_START,
@@ -658,3 +698,139 @@ impl<'a> Lexer<'a> {
         }
     }
 }
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        if self.is_done {
+            return None;
+        }
+        let token = self.lex_token();
+        self.is_done = token.kind == TokenKind::EndOfFile;
+        Some(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(text: &str, options: LexerOptions) -> Vec<(TokenKind, &str)> {
+        Lexer::with_options(text, options)
+            .map(|token| (token.kind, token.value))
+            .collect()
+    }
+
+    fn strict() -> LexerOptions {
+        LexerOptions {
+            allow_identifier_extensions: false,
+        }
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        use self::TokenKind::*;
+        for text in &["café", "名前", "_été", "x名1"] {
+            assert_eq!(
+                tokens(text, LexerOptions::default()),
+                vec![(Identifier, *text), (EndOfFile, "")],
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn identifier_extensions() {
+        use self::TokenKind::*;
+        assert_eq!(
+            tokens("a@b:c", LexerOptions::default()),
+            vec![(Identifier, "a@b:c"), (EndOfFile, "")]
+        );
+        assert_eq!(
+            tokens("a@b:c", strict()),
+            vec![
+                (Identifier, "a"),
+                (Error, "@"),
+                (Identifier, "b"),
+                (Error, ":"),
+                (Identifier, "c"),
+                (EndOfFile, "")
+            ]
+        );
+        // Extensions never start an identifier.
+        assert_eq!(tokens("@a", LexerOptions::default())[0].0, TokenKind::Error);
+    }
+
+    #[test]
+    fn invalid_token_start() {
+        // · (U+00B7) continues identifiers but cannot start them.
+        for text in &["·a", "€"] {
+            let token = Lexer::new(text).next().unwrap();
+            assert_eq!(token.kind, TokenKind::Error, "{}", text);
+            assert_eq!(
+                token.errors[0].error_code,
+                syntax_error::ERROR_INVALID_TOKEN_START,
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            tokens("a·b", LexerOptions::default()),
+            vec![(TokenKind::Identifier, "a·b"), (TokenKind::EndOfFile, "")]
+        );
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let ranges: Vec<TextRange> = Lexer::new("名前 = 'é'\n  café")
+            .map(|token| token.range)
+            .collect();
+        let range =
+            |line, start, end| TextRange::new(Position::new(line, start), Position::new(line, end));
+        assert_eq!(
+            ranges,
+            vec![
+                range(0, 0, 2),
+                range(0, 3, 4),
+                range(0, 5, 8),
+                range(1, 2, 6),
+                range(1, 6, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn iterator_ends_after_one_end_of_file() {
+        for text in &["", "  ", "select 1", "-- only a comment"] {
+            let mut lexer = Lexer::new(text);
+            let kinds: Vec<TokenKind> = lexer.by_ref().map(|token| token.kind).collect();
+            assert_eq!(kinds.last(), Some(&TokenKind::EndOfFile), "{:?}", text);
+            assert_eq!(
+                kinds
+                    .iter()
+                    .filter(|&&kind| kind == TokenKind::EndOfFile)
+                    .count(),
+                1,
+                "{:?}",
+                text
+            );
+            assert!(lexer.next().is_none());
+            assert!(lexer.next().is_none());
+        }
+    }
+
+    #[test]
+    fn trailing_comments_are_kept() {
+        let tokens: Vec<Token> = Lexer::new("select 1 -- done\n/* end */").collect();
+        assert_eq!(tokens.len(), 3);
+        let one = &tokens[1];
+        assert_eq!(one.trailing_comments.len(), 1);
+        assert_eq!(one.trailing_comments[0].value, "-- done\n");
+        let end_of_file = &tokens[2];
+        assert_eq!(end_of_file.kind, TokenKind::EndOfFile);
+        assert_eq!(end_of_file.leading_comments.len(), 1);
+        assert_eq!(end_of_file.leading_comments[0].value, "/* end */");
+    }
+}
@@ -0,0 +1,336 @@
+//! Decoding of the values of literal tokens.
+//!
+//! A token's value is its raw text, such as 'abc''d', U&'\0041', X'0fAB' or
+//! 1.5E10. These methods unescape and convert it, reporting malformed and
+//! out of range literals as SyntaxErrors within the token's range.
+use super::{Token, TokenKind};
+use crate::utils::syntax_error::{
+    ERROR_INVALID_BINARY_LITERAL, ERROR_INVALID_NUMERIC_LITERAL, ERROR_INVALID_UNICODE_ESCAPE,
+    ERROR_SYNTAX_ERROR,
+};
+use crate::utils::{Message, Position, SyntaxError, TextRange};
+use std::fmt;
+
+/// The escape character of Unicode strings without UESCAPE.
+pub const DEFAULT_UNICODE_ESCAPE: char = '\\';
+
+/// An exact decimal number of any precision: unscaled / 10^scale.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// The digits, without leading zeros; "0" for zero.
+    pub unscaled: String,
+    /// The number of digits after the decimal point.
+    pub scale: usize,
+}
+
+impl Decimal {
+    /// The number of significant digits, as in Presto's DECIMAL(precision,
+    /// scale): 0.05 has precision 2.
+    pub fn precision(&self) -> usize {
+        self.unscaled.len().max(self.scale)
+    }
+
+    /// The nearest f64.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.unscaled);
+        }
+        let digits = format!("{:0>width$}", self.unscaled, width = self.scale + 1);
+        let point = digits.len() - self.scale;
+        write!(f, "{}.{}", &digits[..point], &digits[point..])
+    }
+}
+
+/// The value of an Integer, Decimal or Double token.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NumberValue {
+    Integer(i64),
+    Decimal(Decimal),
+    Double(f64),
+}
+
+impl<'a> Token<'a> {
+    /// The value of a String token, or of a UnicodeString token without
+    /// UESCAPE.
+    pub fn string_value(&self) -> Result<String, SyntaxError> {
+        match self.kind {
+            TokenKind::String => Ok(self.quoted_contents(1).replace("''", "'")),
+            TokenKind::UnicodeString => self.unicode_string_value(DEFAULT_UNICODE_ESCAPE),
+            _ => Err(self.error(
+                ERROR_SYNTAX_ERROR,
+                format!("Expected a string literal, found {}.", self.kind),
+            )),
+        }
+    }
+
+    /// The value of a UnicodeString token whose escape character is escape:
+    /// escape followed by 4 hex digits, or by + and 6 hex digits, is a code
+    /// point, and a doubled escape is the escape itself.
+    pub fn unicode_string_value(&self, escape: char) -> Result<String, SyntaxError> {
+        let contents = self.quoted_contents(3);
+        // U&' precedes the contents.
+        let mut position = Position::new(self.range.start.line, self.range.start.column + 3);
+        let chars: Vec<char> = contents.chars().collect();
+        let mut result = String::new();
+        let mut index = 0;
+        while index < chars.len() {
+            let ch = chars[index];
+            let start = position;
+            let length = if ch == '\'' {
+                result.push('\'');
+                2
+            } else if ch != escape {
+                result.push(ch);
+                1
+            } else if chars.get(index + 1) == Some(&escape) {
+                result.push(escape);
+                2
+            } else {
+                let (digit_count, offset) = if chars.get(index + 1) == Some(&'+') {
+                    (6, 2)
+                } else {
+                    (4, 1)
+                };
+                let digits: String = chars[index + offset..]
+                    .iter()
+                    .take(digit_count)
+                    .take_while(|ch| ch.is_ascii_hexdigit())
+                    .collect();
+                let length = offset + digits.len();
+                let range = TextRange::new(start, advance(start, &chars[index..index + length]));
+                if digits.len() < digit_count {
+                    return Err(SyntaxError::from_message(
+                        ERROR_INVALID_UNICODE_ESCAPE,
+                        Message::new(
+                            range,
+                            format!(
+                                "Unicode escape must be followed by {} hexadecimal digits.",
+                                if digit_count == 6 { "+ and 6" } else { "4" }
+                            ),
+                        ),
+                    ));
+                }
+                let code_point = u32::from_str_radix(&digits, 16).unwrap_or(u32::MAX);
+                match char::from_u32(code_point) {
+                    Some(ch) => result.push(ch),
+                    None => {
+                        let reason = if (0xD800..=0xDFFF).contains(&code_point) {
+                            " (a surrogate)"
+                        } else {
+                            ""
+                        };
+                        return Err(SyntaxError::from_message(
+                            ERROR_INVALID_UNICODE_ESCAPE,
+                            Message::new(
+                                range,
+                                format!(
+                                    "Invalid Unicode code point U+{:04X}{}.",
+                                    code_point, reason
+                                ),
+                            ),
+                        ));
+                    }
+                }
+                length
+            };
+            let end = (index + length).min(chars.len());
+            position = advance(position, &chars[index..end]);
+            index = end;
+        }
+        Ok(result)
+    }
+
+    /// The bytes of a BinaryLiteral token: pairs of hex digits, which may
+    /// be separated by whitespace.
+    pub fn binary_value(&self) -> Result<Vec<u8>, SyntaxError> {
+        if self.kind != TokenKind::BinaryLiteral {
+            return Err(self.error(
+                ERROR_SYNTAX_ERROR,
+                format!("Expected a binary literal, found {}.", self.kind),
+            ));
+        }
+        let digits: Vec<char> = self
+            .quoted_contents(2)
+            .chars()
+            .filter(|ch| !ch.is_whitespace())
+            .collect();
+        if let Some(ch) = digits.iter().find(|ch| !ch.is_ascii_hexdigit()) {
+            return Err(self.error(
+                ERROR_INVALID_BINARY_LITERAL,
+                format!(
+                    "Binary literal can only contain hexadecimal digits, found '{}'.",
+                    ch
+                ),
+            ));
+        }
+        if !digits.len().is_multiple_of(2) {
+            return Err(self.error(
+                ERROR_INVALID_BINARY_LITERAL,
+                "Binary literal must contain an even number of digits.".to_string(),
+            ));
+        }
+        Ok(digits
+            .chunks(2)
+            .map(|pair| {
+                let pair: String = pair.iter().collect();
+                u8::from_str_radix(&pair, 16).unwrap_or_default()
+            })
+            .collect())
+    }
+
+    /// The value of an Integer, Decimal or Double token. Integers must fit
+    /// a BIGINT, and doubles a DOUBLE.
+    pub fn number_value(&self) -> Result<NumberValue, SyntaxError> {
+        let invalid = |message: String| self.error(ERROR_INVALID_NUMERIC_LITERAL, message);
+        match self.kind {
+            TokenKind::Integer => self.value.parse().map(NumberValue::Integer).map_err(|_| {
+                invalid(format!(
+                    "Integer literal {} is out of range for BIGINT.",
+                    self.value
+                ))
+            }),
+            TokenKind::Decimal => {
+                let (integer, fraction) = self.value.split_once('.').unwrap_or((self.value, ""));
+                let digits = format!("{}{}", integer, fraction);
+                if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+                    return Err(invalid(format!("Invalid decimal literal {}.", self.value)));
+                }
+                let unscaled = digits.trim_start_matches('0');
+                Ok(NumberValue::Decimal(Decimal {
+                    unscaled: if unscaled.is_empty() { "0" } else { unscaled }.to_string(),
+                    scale: fraction.len(),
+                }))
+            }
+            TokenKind::Double => match self.value.parse::<f64>() {
+                Ok(value) if value.is_finite() => Ok(NumberValue::Double(value)),
+                Ok(_) => Err(invalid(format!(
+                    "Double literal {} is out of range for DOUBLE.",
+                    self.value
+                ))),
+                Err(_) => Err(invalid(format!("Invalid double literal {}.", self.value))),
+            },
+            _ => Err(self.error(
+                ERROR_SYNTAX_ERROR,
+                format!("Expected a numeric literal, found {}.", self.kind),
+            )),
+        }
+    }
+
+    /// The text between the quotes of a string token, whose first
+    /// prefix_length characters are its prefix and opening quote, as in U&'.
+    fn quoted_contents(&self, prefix_length: usize) -> &'a str {
+        let value = self.value;
+        if value.len() > prefix_length && value.ends_with('\'') {
+            &value[prefix_length..value.len() - 1]
+        } else {
+            ""
+        }
+    }
+
+    fn error(&self, error_code: i32, message: String) -> SyntaxError {
+        SyntaxError::from_message(error_code, Message::new(self.range, message))
+    }
+}
+
+/// The position after chars, starting at start.
+fn advance(start: Position, chars: &[char]) -> Position {
+    let mut position = start;
+    let mut previous = None;
+    for &ch in chars {
+        position = match ch {
+            '\n' if previous == Some('\r') => position,
+            '\n' | '\r' => position.next_line(),
+            _ => position.next_column(),
+        };
+        previous = Some(ch);
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexing::Lexer;
+
+    fn token(text: &str) -> Token<'_> {
+        Lexer::new(text).next().unwrap()
+    }
+
+    fn error_code<T>(result: Result<T, SyntaxError>) -> i32 {
+        match result {
+            Ok(_) => panic!("expected an error"),
+            Err(error) => error.error_code,
+        }
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(token("'abc'").string_value().unwrap(), "abc");
+        assert_eq!(token("'it''s'").string_value().unwrap(), "it's");
+        assert_eq!(token("''''").string_value().unwrap(), "'");
+        assert_eq!(token("''").string_value().unwrap(), "");
+        assert_eq!(token("U&'\\0041b'").string_value().unwrap(), "Ab");
+        assert_eq!(error_code(token("1").string_value()), ERROR_SYNTAX_ERROR);
+    }
+
+    #[test]
+    fn binary() {
+        assert_eq!(token("X'0fAB'").binary_value().unwrap(), vec![0x0f, 0xab]);
+        assert_eq!(token("X'0f ab'").binary_value().unwrap(), vec![0x0f, 0xab]);
+        assert_eq!(token("X''").binary_value().unwrap(), Vec::<u8>::new());
+        assert_eq!(
+            error_code(token("X'abc'").binary_value()),
+            ERROR_INVALID_BINARY_LITERAL
+        );
+        assert_eq!(
+            error_code(token("X'0g'").binary_value()),
+            ERROR_INVALID_BINARY_LITERAL
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            token("9223372036854775807").number_value().unwrap(),
+            NumberValue::Integer(i64::MAX)
+        );
+        assert_eq!(
+            error_code(token("9223372036854775808").number_value()),
+            ERROR_INVALID_NUMERIC_LITERAL
+        );
+        // Decimals have any precision.
+        let decimal = match token("099999999999999999999.50").number_value().unwrap() {
+            NumberValue::Decimal(decimal) => decimal,
+            value => panic!("expected a decimal, found {:?}", value),
+        };
+        assert_eq!(decimal.unscaled, "9999999999999999999950");
+        assert_eq!(decimal.scale, 2);
+        assert_eq!(decimal.to_string(), "99999999999999999999.50");
+        assert_eq!(
+            token("1.5E10").number_value().unwrap(),
+            NumberValue::Double(1.5e10)
+        );
+        assert_eq!(
+            error_code(token("1e400").number_value()),
+            ERROR_INVALID_NUMERIC_LITERAL
+        );
+    }
+
+    #[test]
+    fn decimal_display_and_precision() {
+        let decimal = |unscaled: &str, scale| Decimal {
+            unscaled: unscaled.to_string(),
+            scale,
+        };
+        assert_eq!(decimal("5", 2).to_string(), "0.05");
+        assert_eq!(decimal("5", 2).precision(), 2);
+        assert_eq!(decimal("1250", 2).to_string(), "12.50");
+        assert_eq!(decimal("0", 0).to_string(), "0");
+    }
+}
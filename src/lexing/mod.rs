@@ -1,7 +1,9 @@
 mod chars;
 mod comment;
+pub mod identifier;
 mod keywords;
 pub mod lexer;
+pub mod literal;
 mod lexer_position;
 pub mod predefined_names;
 pub mod token;
@@ -10,7 +12,7 @@ pub mod token_kind;
 pub use self::comment::Comment;
 pub use self::comment::CommentKind;
 pub use self::keywords::Keyword;
-pub use self::lexer::Lexer;
+pub use self::lexer::{Lexer, LexerOptions};
 pub use self::predefined_names::PredefinedName;
 pub use self::token::Token;
 pub use self::token_kind::TokenKind;
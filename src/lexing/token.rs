@@ -1,5 +1,5 @@
 use super::{Comment, TokenKind};
-use crate::utils::{Position, SyntaxError, TextRange};
+use crate::utils::{Json, Position, SyntaxError, TextRange};
 use std::fmt;
 
 /// A lexical token (aka lexeme) in a query.
@@ -81,4 +81,29 @@ impl<'a> Token<'a> {
     pub fn full_range(&self) -> TextRange {
         TextRange::new(self.full_start(), self.full_end())
     }
+
+    /// {"token_kind": string, "range": range, "value": string,
+    ///  "leading_comments": [comment], "trailing_comments": [comment], "errors": [error]}
+    ///
+    /// leading_comments, trailing_comments and errors are omitted when empty.
+    pub fn to_json(&self) -> Json {
+        let mut result = Json::object(vec![
+            ("token_kind", self.kind.to_string().into()),
+            ("range", self.range.to_json()),
+            ("value", self.value.into()),
+        ]);
+        if !self.leading_comments.is_empty() {
+            let comments = self.leading_comments.iter().map(|comment| comment.to_json());
+            result.push("leading_comments", Json::Array(comments.collect()));
+        }
+        if !self.trailing_comments.is_empty() {
+            let comments = self.trailing_comments.iter().map(|comment| comment.to_json());
+            result.push("trailing_comments", Json::Array(comments.collect()));
+        }
+        if !self.errors.is_empty() {
+            let errors = self.errors.iter().map(SyntaxError::to_json);
+            result.push("errors", Json::Array(errors.collect()));
+        }
+        result
+    }
 }
@@ -1,7 +1,7 @@
 use std::fmt;
 
 /// The kinds of tokens in the grammar.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[allow(non_upper_case_globals, non_camel_case_types)]
 pub enum TokenKind {
     // Common kinds
@@ -136,7 +136,7 @@ pub enum TokenKind {
 }
 
 impl TokenKind {
-    fn is_keyword(&self) -> bool {
+    pub fn is_keyword(&self) -> bool {
         match self {
             TokenKind::ALTER
             | TokenKind::AND
@@ -222,6 +222,119 @@ impl TokenKind {
         }
     }
 
+    /// The text of tokens with this kind; None for kinds whose tokens have differing text.
+    /// Keywords are upper case.
+    pub fn simple_text(&self) -> Option<&'static str> {
+        match self {
+            TokenKind::OpenParen => Some("("),
+            TokenKind::CloseParen => Some(")"),
+            TokenKind::Comma => Some(","),
+            TokenKind::Period => Some("."),
+            TokenKind::OpenAngle => Some("<"),
+            TokenKind::CloseAngle => Some(">"),
+            TokenKind::OpenSquare => Some("["),
+            TokenKind::CloseSquare => Some("]"),
+            TokenKind::Equal => Some("="),
+            TokenKind::LessGreater => Some("<>"),
+            TokenKind::BangEqual => Some("!="),
+            TokenKind::LessEqual => Some("<="),
+            TokenKind::GreaterEqual => Some(">="),
+            TokenKind::Plus => Some("+"),
+            TokenKind::Minus => Some("-"),
+            TokenKind::Asterisk => Some("*"),
+            TokenKind::Slash => Some("/"),
+            TokenKind::Percent => Some("%"),
+            TokenKind::BarBar => Some("||"),
+            TokenKind::Arrow => Some("->"),
+            TokenKind::DoubleArrow => Some("=>"),
+            TokenKind::Question => Some("?"),
+            TokenKind::ALTER => Some("ALTER"),
+            TokenKind::AND => Some("AND"),
+            TokenKind::AS => Some("AS"),
+            TokenKind::BETWEEN => Some("BETWEEN"),
+            TokenKind::BIGINT => Some("BIGINT"),
+            TokenKind::BOOLEAN => Some("BOOLEAN"),
+            TokenKind::BY => Some("BY"),
+            TokenKind::CASE => Some("CASE"),
+            TokenKind::CAST => Some("CAST"),
+            TokenKind::CONFIGURE => Some("CONFIGURE"),
+            TokenKind::CONSTRAINT => Some("CONSTRAINT"),
+            TokenKind::CREATE => Some("CREATE"),
+            TokenKind::CROSS => Some("CROSS"),
+            TokenKind::CUBE => Some("CUBE"),
+            TokenKind::CURRENT_DATE => Some("CURRENT_DATE"),
+            TokenKind::CURRENT_PATH => Some("CURRENT_PATH"),
+            TokenKind::CURRENT_TIME => Some("CURRENT_TIME"),
+            TokenKind::CURRENT_TIMESTAMP => Some("CURRENT_TIMESTAMP"),
+            TokenKind::CURRENT_USER => Some("CURRENT_USER"),
+            TokenKind::DEALLOCATE => Some("DEALLOCATE"),
+            TokenKind::DECIMAL => Some("DECIMAL"),
+            TokenKind::DELETE => Some("DELETE"),
+            TokenKind::DESCRIBE => Some("DESCRIBE"),
+            TokenKind::DISTINCT => Some("DISTINCT"),
+            TokenKind::DROP => Some("DROP"),
+            TokenKind::ELSE => Some("ELSE"),
+            TokenKind::END => Some("END"),
+            TokenKind::ESCAPE => Some("ESCAPE"),
+            TokenKind::EXCEPT => Some("EXCEPT"),
+            TokenKind::EXECUTE => Some("EXECUTE"),
+            TokenKind::EXISTS => Some("EXISTS"),
+            TokenKind::EXTRACT => Some("EXTRACT"),
+            TokenKind::FALSE => Some("FALSE"),
+            TokenKind::FOR => Some("FOR"),
+            TokenKind::FROM => Some("FROM"),
+            TokenKind::FULL => Some("FULL"),
+            TokenKind::GROUP => Some("GROUP"),
+            TokenKind::GROUPING => Some("GROUPING"),
+            TokenKind::HAVING => Some("HAVING"),
+            TokenKind::IN => Some("IN"),
+            TokenKind::INNER => Some("INNER"),
+            TokenKind::INSERT => Some("INSERT"),
+            TokenKind::INTEGER => Some("INTEGER"),
+            TokenKind::INTERSECT => Some("INTERSECT"),
+            TokenKind::INTO => Some("INTO"),
+            TokenKind::IS => Some("IS"),
+            TokenKind::JOIN => Some("JOIN"),
+            TokenKind::LEFT => Some("LEFT"),
+            TokenKind::LIKE => Some("LIKE"),
+            TokenKind::LOCALTIME => Some("LOCALTIME"),
+            TokenKind::LOCALTIMESTAMP => Some("LOCALTIMESTAMP"),
+            TokenKind::NATURAL => Some("NATURAL"),
+            TokenKind::NORMALIZE => Some("NORMALIZE"),
+            TokenKind::NOT => Some("NOT"),
+            TokenKind::NULL => Some("NULL"),
+            TokenKind::ON => Some("ON"),
+            TokenKind::OR => Some("OR"),
+            TokenKind::ORDER => Some("ORDER"),
+            TokenKind::OUTER => Some("OUTER"),
+            TokenKind::PREPARE => Some("PREPARE"),
+            TokenKind::REAL => Some("REAL"),
+            TokenKind::RECURSIVE => Some("RECURSIVE"),
+            TokenKind::RIGHT => Some("RIGHT"),
+            TokenKind::ROLLUP => Some("ROLLUP"),
+            TokenKind::SELECT => Some("SELECT"),
+            TokenKind::SMALLINT => Some("SMALLINT"),
+            TokenKind::TABLE => Some("TABLE"),
+            TokenKind::THEN => Some("THEN"),
+            TokenKind::TINYINT => Some("TINYINT"),
+            TokenKind::TRUE => Some("TRUE"),
+            TokenKind::UESCAPE => Some("UESCAPE"),
+            TokenKind::UNION => Some("UNION"),
+            TokenKind::UNNEST => Some("UNNEST"),
+            TokenKind::USING => Some("USING"),
+            TokenKind::VALUES => Some("VALUES"),
+            TokenKind::VARBINARY => Some("VARBINARY"),
+            TokenKind::VARCHAR => Some("VARCHAR"),
+            TokenKind::WHEN => Some("WHEN"),
+            TokenKind::WHERE => Some("WHERE"),
+            TokenKind::WITH => Some("WITH"),
+            TokenKind::DoublePrecision => Some("DOUBLE PRECISION"),
+            TokenKind::TimeWithTimeZone => Some("TIME WITH TIME ZONE"),
+            TokenKind::TimestampWithTimeZone => Some("TIMESTAMP WITH TIME ZONE"),
+            _ => None,
+        }
+    }
+
     /// Do tokens with this kind have differing text.
     fn is_complex(&self) -> bool {
         match self {
@@ -0,0 +1,125 @@
+//! Lint rules: constructs which parse, but which are likely mistakes or
+//! make queries fragile.
+//!
+//! Lint warnings are reported as SyntaxErrors with error codes in the
+//! 900-999 range, so that they can be reported alongside parse errors.
+use crate::lexing::TokenKind;
+use crate::parsing::visitor;
+use crate::parsing::{parse_tree, ParseTree, Visitor};
+use crate::utils::syntax_error::{
+    WARNING_COMPARISON_WITH_NULL, WARNING_IMPLICIT_CROSS_JOIN, WARNING_NOT_IN_SUBQUERY,
+    WARNING_SELECT_STAR, WARNING_UNION_WITHOUT_QUANTIFIER,
+};
+use crate::utils::{Message, SyntaxError, TextRange};
+
+/// Returns the lint warnings for a tree, in source order.
+pub fn lint(tree: &ParseTree) -> Vec<SyntaxError> {
+    let mut linter = Linter {
+        warnings: Vec::new(),
+    };
+    linter.visit(tree);
+    linter.warnings.sort_by_key(|warning| warning.get_range());
+    linter.warnings
+}
+
+struct Linter {
+    warnings: Vec<SyntaxError>,
+}
+
+impl Linter {
+    fn warn(&mut self, error_code: i32, range: TextRange, message: &str) {
+        self.warnings.push(SyntaxError::from_message(
+            error_code,
+            Message::new(range, message.to_string()),
+        ));
+    }
+}
+
+fn is_null_literal(tree: &ParseTree) -> bool {
+    match tree {
+        ParseTree::Literal(literal) => match &*literal.value {
+            ParseTree::Token(token) => token.token.kind == TokenKind::NULL,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+impl<'a> Visitor<'a> for Linter {
+    fn visit_select_all(&mut self, select_all: &'a parse_tree::SelectAll<'a>) {
+        self.warn(
+            WARNING_SELECT_STAR,
+            select_all.asterisk.get_range(),
+            "SELECT * depends on the columns of the source tables; list the columns explicitly.",
+        );
+        visitor::walk_select_all(self, select_all)
+    }
+
+    fn visit_query_specification(
+        &mut self,
+        query_specification: &'a parse_tree::QuerySpecification<'a>,
+    ) {
+        if let ParseTree::List(relations) = &*query_specification.relations {
+            for (_, separator) in &relations.elements_and_separators {
+                if separator.is_token() {
+                    self.warn(
+                        WARNING_IMPLICIT_CROSS_JOIN,
+                        separator.get_range(),
+                        "Implicit cross join; use CROSS JOIN, or JOIN with a join condition.",
+                    );
+                }
+            }
+        }
+        visitor::walk_query_specification(self, query_specification)
+    }
+
+    fn visit_query_set_operation(
+        &mut self,
+        query_set_operation: &'a parse_tree::QuerySetOperation<'a>,
+    ) {
+        let is_union = match &*query_set_operation.operator {
+            ParseTree::Token(token) => token.token.kind == TokenKind::UNION,
+            _ => false,
+        };
+        if is_union && query_set_operation.set_quantifier_opt.is_empty() {
+            self.warn(
+                WARNING_UNION_WITHOUT_QUANTIFIER,
+                query_set_operation.operator.get_range(),
+                "UNION removes duplicate rows; use UNION ALL, or UNION DISTINCT to make this explicit.",
+            );
+        }
+        visitor::walk_query_set_operation(self, query_set_operation)
+    }
+
+    fn visit_in_subquery(&mut self, in_subquery: &'a parse_tree::InSubquery<'a>) {
+        if !in_subquery.not_opt.is_empty() {
+            self.warn(
+                WARNING_NOT_IN_SUBQUERY,
+                TextRange::new(in_subquery.not_opt.get_start(), in_subquery.in_.get_end()),
+                "NOT IN is never true when the subquery returns a NULL; consider NOT EXISTS.",
+            );
+        }
+        visitor::walk_in_subquery(self, in_subquery)
+    }
+
+    fn visit_binary_expression(&mut self, binary_expression: &'a parse_tree::BinaryExpression<'a>) {
+        let is_comparison = match &*binary_expression.operator {
+            ParseTree::Token(token) => matches!(
+                token.token.kind,
+                TokenKind::Equal | TokenKind::LessGreater | TokenKind::BangEqual
+            ),
+            _ => false,
+        };
+        if is_comparison
+            && (is_null_literal(&binary_expression.left)
+                || is_null_literal(&binary_expression.right))
+        {
+            self.warn(
+                WARNING_COMPARISON_WITH_NULL,
+                binary_expression.operator.get_range(),
+                "Comparison with NULL is never true; use IS NULL or IS NOT NULL.",
+            );
+        }
+        visitor::walk_binary_expression(self, binary_expression)
+    }
+}
@@ -0,0 +1,200 @@
+//! Compact text renderings of parse trees.
+//!
+//! to_sexp() renders an indented S-expression, and to_dot() renders a
+//! Graphviz DOT digraph. Both show kind names, field names and token text.
+//! Empty trees, and tokens without text (BeginningOfFile, EndOfFile), are
+//! skipped.
+use super::ParseTree;
+use crate::utils::json;
+use std::fmt::Write;
+
+// S-expressions which fit within this width are rendered on one line.
+const LINE_WIDTH: usize = 80;
+const INDENT: &str = "  ";
+
+fn is_skipped(tree: &ParseTree) -> bool {
+    match tree {
+        ParseTree::Empty(_) => true,
+        ParseTree::Token(token) => token.token.value.is_empty(),
+        _ => false,
+    }
+}
+
+/// The non-skipped children of a tree, paired with their field names.
+/// List elements and separators have no field name.
+fn named_children<'t, 'a>(
+    tree: &'t ParseTree<'a>,
+) -> Vec<(Option<&'static str>, &'t ParseTree<'a>)> {
+    let field_names = tree.field_names();
+    tree.children()
+        .into_iter()
+        .enumerate()
+        .map(|(index, child)| (field_names.get(index).cloned(), child))
+        .filter(|(_, child)| !is_skipped(child))
+        .collect()
+}
+
+/// Renders a tree as a single line S-expression, unless the result extends
+/// beyond limit bytes. Returns false, with the partial rendering left in
+/// result, if it does; rendering stops soon after reaching the limit.
+fn sexp_line(tree: &ParseTree, limit: usize, result: &mut String) -> bool {
+    match tree {
+        ParseTree::Token(token) => json::write_string(token.token.value, result),
+        ParseTree::Error(error) => {
+            result.push_str("(Error ");
+            json::write_string(&error.error.to_string(), result);
+            result.push(')');
+        }
+        _ => {
+            result.push('(');
+            result.push_str(tree.kind_name());
+            for (field_name, child) in named_children(tree) {
+                if result.len() > limit {
+                    return false;
+                }
+                result.push(' ');
+                if let Some(field_name) = field_name {
+                    result.push_str(field_name);
+                    result.push_str(": ");
+                }
+                if !sexp_line(child, limit, result) {
+                    return false;
+                }
+            }
+            result.push(')');
+        }
+    }
+    result.len() <= limit
+}
+
+fn sexp(tree: &ParseTree, indent: usize, result: &mut String) {
+    let start = result.len();
+    let limit = (start + LINE_WIDTH).saturating_sub(indent * INDENT.len());
+    let is_leaf = tree.is_token() || tree.is_error();
+    if sexp_line(tree, limit, result) || is_leaf {
+        return;
+    }
+
+    result.truncate(start);
+    result.push('(');
+    result.push_str(tree.kind_name());
+    for (field_name, child) in named_children(tree) {
+        result.push('\n');
+        for _ in 0..=indent {
+            result.push_str(INDENT);
+        }
+        if let Some(field_name) = field_name {
+            result.push_str(field_name);
+            result.push_str(": ");
+        }
+        sexp(child, indent + 1, result);
+    }
+    result.push(')');
+}
+
+/// Renders a tree as an indented S-expression.
+///
+/// Trees are rendered as (Kind field: child ...), tokens as their quoted
+/// text, and errors as (Error "message"); text is escaped as in JSON.
+/// Trees which fit on a line are rendered on one line; otherwise each child
+/// is rendered on its own line.
+pub fn to_sexp(tree: &ParseTree) -> String {
+    let mut result = String::new();
+    sexp(tree, 0, &mut result);
+    result
+}
+
+fn escape_dot(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' | '\\' => {
+                result.push('\\');
+                result.push(ch);
+            }
+            '\n' => result.push_str("\\n"),
+            '\r' => (),
+            _ => result.push(ch),
+        }
+    }
+    result
+}
+
+/// Adds the node for tree, and its descendants, to result.
+/// Returns the id of the node.
+fn dot_node(tree: &ParseTree, next_id: &mut usize, result: &mut String) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    match tree {
+        ParseTree::Token(token) => {
+            let _ = writeln!(
+                result,
+                "  n{} [label=\"{}\", shape=plaintext];",
+                id,
+                escape_dot(token.token.value)
+            );
+        }
+        ParseTree::Error(error) => {
+            let _ = writeln!(
+                result,
+                "  n{} [label=\"{}\", color=red];",
+                id,
+                escape_dot(&error.error.to_string())
+            );
+        }
+        _ => {
+            let _ = writeln!(result, "  n{} [label=\"{}\"];", id, tree.kind_name());
+            for (field_name, child) in named_children(tree) {
+                let child_id = dot_node(child, next_id, result);
+                match field_name {
+                    Some(field_name) => {
+                        let _ = writeln!(
+                            result,
+                            "  n{} -> n{} [label=\"{}\"];",
+                            id, child_id, field_name
+                        );
+                    }
+                    None => {
+                        let _ = writeln!(result, "  n{} -> n{};", id, child_id);
+                    }
+                }
+            }
+        }
+    }
+    id
+}
+
+/// Renders a tree as a Graphviz DOT digraph.
+///
+/// Each tree is a node labelled with its kind; edges are labelled with
+/// field names. Tokens are rendered as plain text nodes.
+pub fn to_dot(tree: &ParseTree) -> String {
+    let mut result = String::new();
+    result.push_str("digraph ParseTree {\n");
+    result.push_str("  node [shape=box];\n");
+    let mut next_id = 0;
+    dot_node(tree, &mut next_id, &mut result);
+    result.push_str("}\n");
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+
+    #[test]
+    fn sexp_quotes_tokens() {
+        let (tree, _) = parse_statement("select \"a\"\"b\"");
+        assert!(to_sexp(&tree).contains(r#"(Identifier value: "\"a\"\"b\"")"#));
+    }
+
+    #[test]
+    fn sexp_breaks_long_lines() {
+        let text = format!("select {} from t", vec!["a"; 40].join(", "));
+        let (tree, _) = parse_statement(&text);
+        let sexp = to_sexp(&tree);
+        assert!(sexp.lines().count() > 1);
+        assert!(sexp.contains(r#"(SelectItem expression: (Identifier value: "a"))"#));
+    }
+}
@@ -0,0 +1,483 @@
+//! Editing of parse trees, and emitting the text of edited trees.
+//!
+//! Trees are edited in place: children are replaced, inserted or removed,
+//! and new tokens and trees are synthesized. The edited tree is then
+//! converted back into text against the source it was parsed from.
+//!
+//! Tokens from the original source keep their text, and the whitespace and
+//! comments surrounding them, wherever the tokens around them are unchanged.
+//! Synthesized tokens have no range in the original source; they are
+//! emitted with a single space between tokens where one is needed.
+//!
+//! The result of an edit is available both as the complete edited text,
+//! and as a list of replacements of ranges in the original text.
+use super::folder::{self, Folder};
+use super::parse_tree::{self, List, ParseTree};
+use super::parser;
+use crate::lexing::{token, CommentKind, TokenKind};
+use crate::utils::{position, text_range, Position, SourceText, SyntaxError, TextRange};
+use std::mem;
+
+impl<'a> ParseTree<'a> {
+    /// Replaces this tree with new, returning the original tree.
+    pub fn replace(&mut self, new: ParseTree<'a>) -> ParseTree<'a> {
+        mem::replace(self, new)
+    }
+
+    /// Replaces the child at index with new, returning the original child.
+    /// Indexes are the same as the indexes of children(). Returns None,
+    /// leaving the tree unchanged, if there is no child at index.
+    pub fn replace_child(&mut self, index: usize, new: ParseTree<'a>) -> Option<ParseTree<'a>> {
+        let child = self.children_mut().into_iter().nth(index)?;
+        Some(child.replace(new))
+    }
+
+    /// Removes the child at index, leaving an Empty tree in its place.
+    /// Returns the removed child, or None if there is no child at index.
+    pub fn remove_child(&mut self, index: usize) -> Option<ParseTree<'a>> {
+        let range = TextRange::empty(self.children().get(index)?.get_full_start());
+        self.replace_child(index, parse_tree::empty(range))
+    }
+}
+
+impl<'a> List<'a> {
+    /// Inserts element into the list so that it becomes the element at index.
+    ///
+    /// Separators are synthesized as needed. The separator kind is taken from
+    /// the existing separators of the list, and defaults to a comma.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is greater than the length of the list.
+    pub fn insert_element(&mut self, index: usize, element: ParseTree<'a>) {
+        assert!(index <= self.len());
+        let separator = self.synthesize_separator();
+        let at_end = index == self.len();
+        if at_end {
+            if let Some((_, last_separator)) = self.elements_and_separators.last_mut() {
+                last_separator.replace(separator);
+            }
+            let empty = parse_tree::empty(text_range::NONE);
+            self.elements_and_separators.push((element, empty));
+        } else {
+            self.elements_and_separators
+                .insert(index, (element, separator));
+        }
+    }
+
+    /// Removes the element at index and its separator from the list.
+    /// Returns the removed element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if index is out of range.
+    pub fn remove_element(&mut self, index: usize) -> ParseTree<'a> {
+        let (element, separator) = self.elements_and_separators.remove(index);
+        if separator.is_empty() && index > 0 {
+            // Removed the last element; its predecessor is now last.
+            let (_, last_separator) = &mut self.elements_and_separators[index - 1];
+            let range = TextRange::empty(last_separator.get_full_start());
+            last_separator.replace(parse_tree::empty(range));
+        }
+        element
+    }
+
+    fn synthesize_separator(&self) -> ParseTree<'a> {
+        for (_, separator) in &self.elements_and_separators {
+            if let ParseTree::Token(separator) = separator {
+                return synthesize_token(separator.token.kind, separator.token.value);
+            }
+        }
+        synthesize_simple_token(TokenKind::Comma)
+    }
+}
+
+/// Creates a Token tree which is not part of the original source.
+pub fn synthesize_token<'a>(kind: TokenKind, value: &'a str) -> ParseTree<'a> {
+    debug_assert!(!value.is_empty());
+    parse_tree::token(token::Token::new(
+        kind,
+        text_range::NONE,
+        value,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ))
+}
+
+/// Creates a Token tree for a punctuator or keyword.
+pub fn synthesize_simple_token<'a>(kind: TokenKind) -> ParseTree<'a> {
+    match kind.simple_text() {
+        Some(value) => synthesize_token(kind, value),
+        None => panic!("Expected a token kind with simple text, found {}", kind),
+    }
+}
+
+/// Creates an Identifier token tree.
+pub fn synthesize_identifier(value: &str) -> ParseTree<'_> {
+    synthesize_token(TokenKind::Identifier, value)
+}
+
+struct Detacher {}
+
+impl<'a> Folder<'a> for Detacher {
+    fn fold_token(&mut self, mut token: parse_tree::Token<'a>) -> ParseTree<'a> {
+        token.token.range = text_range::NONE;
+        for comment in &mut token.token.leading_comments {
+            comment.range = text_range::NONE;
+        }
+        for comment in &mut token.token.trailing_comments {
+            comment.range = text_range::NONE;
+        }
+        ParseTree::Token(token)
+    }
+
+    fn fold_empty(&mut self, _empty: parse_tree::Empty) -> ParseTree<'a> {
+        parse_tree::empty(text_range::NONE)
+    }
+}
+
+/// Detaches a tree from the source it was parsed from, so that it can be
+/// inserted into another tree as though it were synthesized.
+/// Comments are kept.
+pub fn detach(tree: ParseTree<'_>) -> ParseTree<'_> {
+    folder::fold_tree(&mut Detacher {}, tree)
+}
+
+/// Parses text containing an expression, and detaches the result
+/// so that it can be inserted into another tree.
+pub fn synthesize_expression(text: &str) -> Result<ParseTree<'_>, Vec<SyntaxError>> {
+    let (tree, errors) = parser::parse_expression(text);
+    if errors.is_empty() {
+        let (_, expression, _) = tree.unbox_entrypoint();
+        Ok(detach(expression))
+    } else {
+        Err(errors)
+    }
+}
+
+fn is_synthesized(token: &token::Token) -> bool {
+    token.range.is_none()
+}
+
+/// Does a space separate two adjacent tokens.
+pub fn needs_space(previous: TokenKind, next: TokenKind) -> bool {
+    !matches!(
+        (previous, next),
+        (TokenKind::BeginningOfFile, _)
+            | (_, TokenKind::EndOfFile)
+            | (_, TokenKind::Comma)
+            | (_, TokenKind::CloseParen)
+            | (_, TokenKind::CloseSquare)
+            | (_, TokenKind::Period)
+            | (_, TokenKind::OpenSquare)
+            | (TokenKind::OpenParen, _)
+            | (TokenKind::OpenSquare, _)
+            | (TokenKind::Period, _)
+            | (TokenKind::Identifier, TokenKind::OpenParen)
+            | (TokenKind::QuotedIdentifier, TokenKind::OpenParen)
+    )
+}
+
+/// The text of a token which is not anchored in the original source,
+/// including its comments.
+fn detached_text(token: &token::Token, result: &mut String) {
+    for comment in &token.leading_comments {
+        result.push_str(comment.value);
+        if let CommentKind::DelimitedComment = comment.kind {
+            result.push(' ');
+        }
+    }
+    result.push_str(token.value);
+    for comment in &token.trailing_comments {
+        result.push(' ');
+        result.push_str(comment.value);
+    }
+}
+
+/// A run of unanchored tokens between two tokens which keep their
+/// original position in the source.
+struct Gap<'t, 'a> {
+    previous: TokenKind,
+    // The end of the previous anchor, excluding its trailing comments.
+    previous_end: Position,
+    // The end of the previous anchor, including its trailing comments.
+    start: Position,
+    tokens: Vec<&'t token::Token<'a>>,
+}
+
+impl<'t, 'a> Gap<'t, 'a> {
+    fn new(previous: &token::Token) -> Gap<'t, 'a> {
+        Gap {
+            previous: previous.kind,
+            previous_end: previous.range.end,
+            start: previous.full_end(),
+            tokens: Vec::new(),
+        }
+    }
+
+    fn start_of_file() -> Gap<'t, 'a> {
+        Gap {
+            previous: TokenKind::BeginningOfFile,
+            previous_end: position::START,
+            start: position::START,
+            tokens: Vec::new(),
+        }
+    }
+
+    /// Computes the replacement of the gap, if it has changed.
+    fn edit(
+        &self,
+        source: &SourceText,
+        end: Position,
+        next: TokenKind,
+    ) -> Option<(TextRange, String)> {
+        let original = source.slice(TextRange::new(self.start, end));
+        let is_whitespace = original.trim().is_empty();
+        if self.tokens.is_empty() && is_whitespace {
+            return None;
+        }
+
+        // Inserted tokens go before the comments trailing the previous anchor,
+        // so that they stay on the same line as the previous anchor.
+        let trailing_comments = source.slice(TextRange::new(self.previous_end, self.start));
+        let move_comments = !self.tokens.is_empty() && !trailing_comments.trim().is_empty();
+        let start = if move_comments {
+            self.previous_end
+        } else {
+            self.start
+        };
+
+        let trimmed_start = original.trim_start();
+        let prefix = &original[..original.len() - trimmed_start.len()];
+        let suffix = &original[original.trim_end().len()..];
+        let (prefix, suffix) = if is_whitespace || move_comments {
+            ("", original)
+        } else if self.tokens.is_empty() && start.column == 0 {
+            // Removed text on lines of its own; keep the indentation before
+            // it, as the line break after it would leave a blank line.
+            ("", prefix)
+        } else {
+            (prefix, suffix)
+        };
+
+        let mut result = String::new();
+        let mut previous = self.previous;
+        for token in &self.tokens {
+            if result.is_empty() && !prefix.is_empty() {
+                result.push_str(prefix);
+            } else if !result.ends_with('\n') && needs_space(previous, token.kind) {
+                result.push(' ');
+            }
+            detached_text(token, &mut result);
+            previous = token.kind;
+        }
+        if move_comments {
+            result.push(' ');
+            result.push_str(trailing_comments.trim_start());
+        }
+        if is_whitespace || !suffix.is_empty() {
+            result.push_str(suffix);
+        } else if self.tokens.is_empty() && !prefix.is_empty() {
+            result.push_str(prefix);
+        }
+        let at_line_start = if result.is_empty() {
+            start.column == 0
+        } else {
+            result.ends_with('\n')
+        };
+        if suffix.is_empty() && !at_line_start && needs_space(previous, next) {
+            result.push(' ');
+        }
+
+        let range = TextRange::new(start, end);
+        if result == source.slice(range) {
+            None
+        } else {
+            Some((range, result))
+        }
+    }
+}
+
+/// Computes the replacements which convert the source a tree was parsed
+/// from into the text of the edited tree.
+///
+/// Tokens which remain in their original order are anchors: their text,
+/// and the comments attached to them, are kept. Each replacement covers the
+/// text between two anchors; comments trailing an anchor are moved after
+/// any tokens inserted immediately after the anchor. Replacements are
+/// returned in source order and do not overlap.
+pub fn text_edits(source: &str, tree: &ParseTree) -> Vec<(TextRange, String)> {
+    let source = SourceText::new(source);
+    let mut tokens = Vec::new();
+    super::visit_pre_order(tree, &mut |tree| {
+        if let ParseTree::Token(token) = tree {
+            tokens.push(&token.token);
+        }
+    });
+
+    let mut edits = Vec::new();
+    let mut gap = Gap::start_of_file();
+    for token in tokens {
+        if token.value.is_empty() && (is_synthesized(token) || token.kind != TokenKind::EndOfFile) {
+            // BeginningOfFile, or a detached EndOfFile; neither has any text.
+            continue;
+        }
+        if is_synthesized(token) || token.full_start() < gap.start {
+            gap.tokens.push(token);
+        } else {
+            edits.extend(gap.edit(&source, token.full_start(), token.kind));
+            gap = Gap::new(token);
+        }
+    }
+    let end = source.end();
+    if gap.start <= end {
+        edits.extend(gap.edit(&source, end, TokenKind::EndOfFile));
+    }
+    edits
+}
+
+/// Applies replacements, as returned by text_edits(), to source.
+pub fn apply_text_edits(source: &str, edits: &[(TextRange, String)]) -> String {
+    let source = SourceText::new(source);
+    let mut result = String::with_capacity(source.text.len());
+    let mut offset = 0;
+    for (range, text) in edits {
+        let start = source.offset(range.start);
+        debug_assert!(start >= offset);
+        result.push_str(&source.text[offset..start]);
+        result.push_str(text);
+        offset = source.offset(range.end);
+    }
+    result.push_str(&source.text[offset..]);
+    result
+}
+
+/// Returns the text of an edited tree.
+/// source must be the text that the tree was originally parsed from.
+pub fn edited_text(source: &str, tree: &ParseTree) -> String {
+    apply_text_edits(source, &text_edits(source, tree))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_child_out_of_range() {
+        let (mut tree, _) = parser::parse_statement("select 1");
+        let count = tree.children().len();
+        assert!(tree
+            .replace_child(count, synthesize_identifier("a"))
+            .is_none());
+        assert!(tree.remove_child(count).is_none());
+        assert_eq!(edited_text("select 1", &tree), "select 1");
+    }
+
+    #[test]
+    fn replace_child_returns_original() {
+        let text = "select 1";
+        let (mut tree, _) = parser::parse_statement(text);
+        let statement = tree.remove_child(1).unwrap();
+        assert!(tree.children()[1].is_empty());
+        assert!(tree.replace_child(1, statement).unwrap().is_empty());
+        assert_eq!(edited_text(text, &tree), text);
+    }
+
+    /// The select items of a statement such as select a, b from t.
+    fn select_items<'t, 'a>(tree: &'t mut ParseTree<'a>) -> &'t mut List<'a> {
+        // Entrypoint.tree, Query.query_no_with, QueryNoWith.query_term,
+        // QuerySpecification.select_items.
+        let tree = [1, 1, 0, 2].iter().fold(tree, |tree, &index| {
+            tree.children_mut().into_iter().nth(index).unwrap()
+        });
+        match tree {
+            ParseTree::List(list) => list,
+            tree => panic!("expected a List, found {}", tree.kind_name()),
+        }
+    }
+
+    /// The text of the statement after editing its select items.
+    fn edit_select_items(text: &str, edit: impl FnOnce(&mut List)) -> String {
+        let (mut tree, errors) = parser::parse_statement(text);
+        assert!(errors.is_empty(), "{:?}", errors);
+        edit(select_items(&mut tree));
+        edited_text(text, &tree)
+    }
+
+    #[test]
+    fn text_edits_cover_changed_tokens() {
+        let text = "select a, b -- b\nfrom t";
+        let (mut tree, _) = parser::parse_statement(text);
+        assert!(text_edits(text, &tree).is_empty());
+        let list = select_items(&mut tree);
+        list.elements_and_separators[1]
+            .0
+            .replace(synthesize_identifier("c"));
+        // The comment trailing b is replaced along with it.
+        let edits = text_edits(text, &tree);
+        assert_eq!(edits.len(), 1);
+        let (range, replacement) = &edits[0];
+        assert_eq!(SourceText::new(text).slice(*range), " b -- b\n");
+        assert_eq!(replacement, " c\n");
+        assert_eq!(apply_text_edits(text, &edits), "select a, c\nfrom t");
+    }
+
+    #[test]
+    fn insert_element() {
+        let insert = |text, index, name| {
+            edit_select_items(text, |list| {
+                list.insert_element(index, synthesize_identifier(name))
+            })
+        };
+        assert_eq!(
+            insert("select a, b from t", 0, "c"),
+            "select c, a, b from t"
+        );
+        assert_eq!(
+            insert("select a, b from t", 1, "c"),
+            "select a, c, b from t"
+        );
+        assert_eq!(insert("select a from t", 1, "c"), "select a, c from t");
+        assert_eq!(
+            insert("select a, -- a\n  b -- b\nfrom t", 2, "c"),
+            "select a, -- a\n  b, c -- b\nfrom t"
+        );
+        assert_eq!(
+            insert("select /* a */ a,\n       b\nfrom t", 1, "c"),
+            "select /* a */ a, c,\n       b\nfrom t"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_element_out_of_range() {
+        edit_select_items("select a from t", |list| {
+            list.insert_element(2, synthesize_identifier("c"))
+        });
+    }
+
+    #[test]
+    fn remove_element() {
+        let remove = |text, index| {
+            edit_select_items(text, |list| {
+                list.remove_element(index);
+            })
+        };
+        assert_eq!(remove("select a, b, c from t", 0), "select b, c from t");
+        assert_eq!(remove("select a, b, c from t", 1), "select a, c from t");
+        assert_eq!(remove("select a, b, c from t", 2), "select a, b from t");
+        assert_eq!(
+            remove("select a, -- a\n  b, -- b\n  c -- c\nfrom t", 1),
+            "select a, -- a\n  c -- c\nfrom t"
+        );
+        assert_eq!(
+            remove("select a, -- a\n\n  b, -- b\n  c\nfrom t", 1),
+            "select a, -- a\n\n  c\nfrom t"
+        );
+        assert_eq!(
+            remove("select a,\n  b,\n  c\nfrom t", 2),
+            "select a,\n  b\nfrom t"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Structural equality and hashing of parse trees.
+//!
+//! Trees are compared by kind, shape and token text, so ranges, whitespace
+//! and comments never matter. EqualityOptions can also ignore the case of
+//! keywords, compare identifiers by their folded names, and ignore
+//! ParenthesizedExpressions, which only affect precedence through the
+//! shape of the tree they wrap.
+//!
+//! tree_hash is consistent with trees_equal: equal trees have equal hashes
+//! under the same options.
+use super::{parse_tree, ParseTree};
+use crate::lexing::{token, TokenKind};
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EqualityOptions {
+    /// SELECT equals select.
+    pub ignore_keyword_case: bool,
+    /// Identifiers compare as Presto names: Foo equals foo and "foo", but
+    /// not "Foo".
+    pub ignore_identifier_case: bool,
+    /// (a + b) equals a + b.
+    pub ignore_redundant_parentheses: bool,
+}
+
+impl EqualityOptions {
+    /// Ignores keyword case, unquoted identifier case and redundant
+    /// parentheses.
+    pub fn lenient() -> EqualityOptions {
+        EqualityOptions {
+            ignore_keyword_case: true,
+            ignore_identifier_case: true,
+            ignore_redundant_parentheses: true,
+        }
+    }
+}
+
+/// Are two trees equal apart from ranges, whitespace and comments, and
+/// what options ignores?
+pub fn trees_equal(left: &ParseTree, right: &ParseTree, options: &EqualityOptions) -> bool {
+    let left = skip_parentheses(left, options);
+    let right = skip_parentheses(right, options);
+    if left.kind_name() != right.kind_name() {
+        return false;
+    }
+    match (left, right) {
+        (ParseTree::Token(left), ParseTree::Token(right)) => {
+            token_kind(&left.token, options) == token_kind(&right.token, options)
+                && token_text(&left.token, options) == token_text(&right.token, options)
+        }
+        (ParseTree::Error(left), ParseTree::Error(right)) => {
+            left.error.error_code == right.error.error_code
+                && error_messages(left) == error_messages(right)
+        }
+        _ => {
+            let left_children = left.children();
+            let right_children = right.children();
+            left_children.len() == right_children.len()
+                && left_children
+                    .iter()
+                    .zip(right_children)
+                    .all(|(left, right)| trees_equal(left, right, options))
+        }
+    }
+}
+
+/// Feeds the structure of tree to state, so that trees which are equal
+/// under options hash equally.
+pub fn tree_hash<H: Hasher>(tree: &ParseTree, options: &EqualityOptions, state: &mut H) {
+    let tree = skip_parentheses(tree, options);
+    tree.kind_name().hash(state);
+    match tree {
+        ParseTree::Token(token) => {
+            token_kind(&token.token, options).hash(state);
+            token_text(&token.token, options).hash(state);
+        }
+        ParseTree::Error(error) => {
+            error.error.error_code.hash(state);
+            error_messages(error).hash(state);
+        }
+        tree => {
+            let children = tree.children();
+            children.len().hash(state);
+            for child in children {
+                tree_hash(child, options, state);
+            }
+        }
+    }
+}
+
+fn error_messages(error: &parse_tree::Error) -> Vec<&str> {
+    error
+        .error
+        .messages
+        .iter()
+        .map(|message| message.message.as_str())
+        .collect()
+}
+
+fn skip_parentheses<'t, 'a>(
+    tree: &'t ParseTree<'a>,
+    options: &EqualityOptions,
+) -> &'t ParseTree<'a> {
+    match tree {
+        ParseTree::ParenthesizedExpression(parenthesized)
+            if options.ignore_redundant_parentheses =>
+        {
+            skip_parentheses(&parenthesized.value, options)
+        }
+        tree => tree,
+    }
+}
+
+/// The kind of a token as compared: identifiers of all kinds are
+/// Identifiers when comparing names.
+fn token_kind(token: &token::Token, options: &EqualityOptions) -> TokenKind {
+    if options.ignore_identifier_case && token.identifier_value().is_ok() {
+        TokenKind::Identifier
+    } else {
+        token.kind
+    }
+}
+
+/// The text of a token as compared: multi word keywords, such as DOUBLE
+/// PRECISION, have their whitespace collapsed, and identifiers are written
+/// as their folded names when comparing names.
+fn token_text<'a>(token: &token::Token<'a>, options: &EqualityOptions) -> Cow<'a, str> {
+    if options.ignore_identifier_case {
+        if let Ok(identifier) = token.identifier_value() {
+            return Cow::Owned(identifier.name);
+        }
+    }
+    let is_multi_word = matches!(
+        token.kind,
+        TokenKind::DoublePrecision | TokenKind::TimeWithTimeZone | TokenKind::TimestampWithTimeZone
+    );
+    let text = if is_multi_word {
+        Cow::Owned(token.value.split_whitespace().collect::<Vec<_>>().join(" "))
+    } else {
+        Cow::Borrowed(token.value)
+    };
+    if options.ignore_keyword_case && (token.kind.is_keyword() || is_multi_word) {
+        Cow::Owned(text.to_lowercase())
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+    use std::collections::hash_map::DefaultHasher;
+
+    fn hash(tree: &ParseTree, options: &EqualityOptions) -> u64 {
+        let mut state = DefaultHasher::new();
+        tree_hash(tree, options, &mut state);
+        state.finish()
+    }
+
+    /// Are the two statements equal under options? Also checks that equal
+    /// trees hash equally.
+    fn equal(left: &str, right: &str, options: &EqualityOptions) -> bool {
+        let (left, _) = parse_statement(left);
+        let (right, _) = parse_statement(right);
+        let is_equal = trees_equal(&left, &right, options);
+        if is_equal {
+            assert_eq!(hash(&left, options), hash(&right, options));
+        }
+        is_equal
+    }
+
+    #[test]
+    fn identifier_case() {
+        let names = EqualityOptions {
+            ignore_identifier_case: true,
+            ..EqualityOptions::default()
+        };
+        assert!(equal("select Foo from t", "select \"foo\" from t", &names));
+        assert!(equal("select FOO from t", "select `foo` from t", &names));
+        assert!(!equal("select Foo from t", "select \"Foo\" from t", &names));
+        assert!(!equal("select foo from t", "select bar from t", &names));
+        let exact = EqualityOptions::default();
+        assert!(!equal("select Foo from t", "select foo from t", &exact));
+        assert!(!equal("select foo from t", "select \"foo\" from t", &exact));
+    }
+
+    #[test]
+    fn exact_ignores_only_formatting() {
+        let exact = EqualityOptions::default();
+        assert!(equal(
+            "select a, b from t",
+            "select   a,\n  b -- b\nfrom /* t */ t",
+            &exact
+        ));
+        assert!(!equal("select a from t", "SELECT a FROM t", &exact));
+        assert!(!equal(
+            "select a + b from t",
+            "select (a + b) from t",
+            &exact
+        ));
+        assert!(!equal("select a, b from t", "select b, a from t", &exact));
+    }
+
+    #[test]
+    fn keyword_case() {
+        let keywords = EqualityOptions {
+            ignore_keyword_case: true,
+            ..EqualityOptions::default()
+        };
+        assert!(equal("select a from t", "SELECT a From t", &keywords));
+        assert!(equal(
+            "select cast(a as double precision) from t",
+            "SELECT CAST(a AS DOUBLE   PRECISION) FROM t",
+            &keywords
+        ));
+        assert!(!equal("select a from t", "select A from t", &keywords));
+        assert!(!equal("select 'a' from t", "select 'A' from t", &keywords));
+    }
+
+    #[test]
+    fn redundant_parentheses() {
+        let parentheses = EqualityOptions {
+            ignore_redundant_parentheses: true,
+            ..EqualityOptions::default()
+        };
+        assert!(equal(
+            "select a + b from t",
+            "select ((a + b)) from t",
+            &parentheses
+        ));
+        assert!(equal(
+            "select (a) * b from t",
+            "select a * (b) from t",
+            &parentheses
+        ));
+        // Parentheses which change the shape of the tree still count.
+        assert!(!equal(
+            "select a + b * c from t",
+            "select (a + b) * c from t",
+            &parentheses
+        ));
+    }
+
+    #[test]
+    fn lenient_combines_options() {
+        let lenient = EqualityOptions::lenient();
+        assert!(equal(
+            "select Foo, (a + b) from t",
+            "SELECT \"foo\", a + b FROM T",
+            &lenient
+        ));
+        assert!(!equal(
+            "select \"Foo\" from t",
+            "select foo from t",
+            &lenient
+        ));
+    }
+
+    #[test]
+    fn hashes_follow_the_options() {
+        let pairs = [
+            ("select a from t", "SELECT a FROM t"),
+            ("select Foo from t", "select \"foo\" from t"),
+            ("select (a) from t", "select a from t"),
+            ("select x from t", "select y from t"),
+        ];
+        let all_options = [
+            EqualityOptions::default(),
+            EqualityOptions::lenient(),
+            EqualityOptions {
+                ignore_keyword_case: true,
+                ..EqualityOptions::default()
+            },
+        ];
+        for options in all_options.iter() {
+            for (left, right) in pairs.iter() {
+                let (left, _) = parse_statement(left);
+                let (right, _) = parse_statement(right);
+                // Equal trees hash equally; these unequal ones do not
+                // collide.
+                assert_eq!(
+                    trees_equal(&left, &right, options),
+                    hash(&left, options) == hash(&right, options),
+                    "{:?}",
+                    options
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,2083 @@
+//! Generated by tools/generate_parse_trees. Do not edit.
+use super::parse_tree::{self, ParseTree};
+
+/// Rebuilds ParseTrees bottom up.
+///
+/// fold() dispatches on the kind of tree to the matching fold_*() method.
+/// The default fold_*() methods fold each child of the tree, then rebuild
+/// the tree from the folded children by calling the matching fold_*_children()
+/// function. Implementations which override a fold_*() method typically call
+/// fold_*_children() first, then inspect or replace the rebuilt tree.
+///
+/// A fold_*() method may return a tree of any kind.
+pub trait Folder<'a> {
+    fn fold(&mut self, tree: ParseTree<'a>) -> ParseTree<'a> {
+        fold_tree(self, tree)
+    }
+
+    fn fold_empty(&mut self, empty: parse_tree::Empty) -> ParseTree<'a> {
+        ParseTree::Empty(empty)
+    }
+
+    fn fold_token(&mut self, token: parse_tree::Token<'a>) -> ParseTree<'a> {
+        ParseTree::Token(token)
+    }
+
+    fn fold_list(&mut self, list: parse_tree::List<'a>) -> ParseTree<'a> {
+        fold_list_children(self, list)
+    }
+
+    fn fold_error(&mut self, error: parse_tree::Error) -> ParseTree<'a> {
+        ParseTree::Error(error)
+    }
+
+    fn fold_query(&mut self, query: parse_tree::Query<'a>) -> ParseTree<'a> {
+        fold_query_children(self, query)
+    }
+
+    fn fold_with(&mut self, with: parse_tree::With<'a>) -> ParseTree<'a> {
+        fold_with_children(self, with)
+    }
+
+    fn fold_named_query(&mut self, named_query: parse_tree::NamedQuery<'a>) -> ParseTree<'a> {
+        fold_named_query_children(self, named_query)
+    }
+
+    fn fold_query_no_with(&mut self, query_no_with: parse_tree::QueryNoWith<'a>) -> ParseTree<'a> {
+        fold_query_no_with_children(self, query_no_with)
+    }
+
+    fn fold_order_by(&mut self, order_by: parse_tree::OrderBy<'a>) -> ParseTree<'a> {
+        fold_order_by_children(self, order_by)
+    }
+
+    fn fold_limit(&mut self, limit: parse_tree::Limit<'a>) -> ParseTree<'a> {
+        fold_limit_children(self, limit)
+    }
+
+    fn fold_query_set_operation(
+        &mut self,
+        query_set_operation: parse_tree::QuerySetOperation<'a>,
+    ) -> ParseTree<'a> {
+        fold_query_set_operation_children(self, query_set_operation)
+    }
+
+    fn fold_sort_item(&mut self, sort_item: parse_tree::SortItem<'a>) -> ParseTree<'a> {
+        fold_sort_item_children(self, sort_item)
+    }
+
+    fn fold_subquery(&mut self, subquery: parse_tree::Subquery<'a>) -> ParseTree<'a> {
+        fold_subquery_children(self, subquery)
+    }
+
+    fn fold_inline_table(&mut self, inline_table: parse_tree::InlineTable<'a>) -> ParseTree<'a> {
+        fold_inline_table_children(self, inline_table)
+    }
+
+    fn fold_table(&mut self, table: parse_tree::Table<'a>) -> ParseTree<'a> {
+        fold_table_children(self, table)
+    }
+
+    fn fold_query_specification(
+        &mut self,
+        query_specification: parse_tree::QuerySpecification<'a>,
+    ) -> ParseTree<'a> {
+        fold_query_specification_children(self, query_specification)
+    }
+
+    fn fold_qualified_name(
+        &mut self,
+        qualified_name: parse_tree::QualifiedName<'a>,
+    ) -> ParseTree<'a> {
+        fold_qualified_name_children(self, qualified_name)
+    }
+
+    fn fold_select_all(&mut self, select_all: parse_tree::SelectAll<'a>) -> ParseTree<'a> {
+        fold_select_all_children(self, select_all)
+    }
+
+    fn fold_qualified_select_all(
+        &mut self,
+        qualified_select_all: parse_tree::QualifiedSelectAll<'a>,
+    ) -> ParseTree<'a> {
+        fold_qualified_select_all_children(self, qualified_select_all)
+    }
+
+    fn fold_select_item(&mut self, select_item: parse_tree::SelectItem<'a>) -> ParseTree<'a> {
+        fold_select_item_children(self, select_item)
+    }
+
+    fn fold_subquery_relation(
+        &mut self,
+        subquery_relation: parse_tree::SubqueryRelation<'a>,
+    ) -> ParseTree<'a> {
+        fold_subquery_relation_children(self, subquery_relation)
+    }
+
+    fn fold_parenthesized_relation(
+        &mut self,
+        parenthesized_relation: parse_tree::ParenthesizedRelation<'a>,
+    ) -> ParseTree<'a> {
+        fold_parenthesized_relation_children(self, parenthesized_relation)
+    }
+
+    fn fold_table_name(&mut self, table_name: parse_tree::TableName<'a>) -> ParseTree<'a> {
+        fold_table_name_children(self, table_name)
+    }
+
+    fn fold_lateral(&mut self, lateral: parse_tree::Lateral<'a>) -> ParseTree<'a> {
+        fold_lateral_children(self, lateral)
+    }
+
+    fn fold_unnest(&mut self, unnest: parse_tree::Unnest<'a>) -> ParseTree<'a> {
+        fold_unnest_children(self, unnest)
+    }
+
+    fn fold_sampled_relation(
+        &mut self,
+        sampled_relation: parse_tree::SampledRelation<'a>,
+    ) -> ParseTree<'a> {
+        fold_sampled_relation_children(self, sampled_relation)
+    }
+
+    fn fold_aliased_relation(
+        &mut self,
+        aliased_relation: parse_tree::AliasedRelation<'a>,
+    ) -> ParseTree<'a> {
+        fold_aliased_relation_children(self, aliased_relation)
+    }
+
+    fn fold_cross_join(&mut self, cross_join: parse_tree::CrossJoin<'a>) -> ParseTree<'a> {
+        fold_cross_join_children(self, cross_join)
+    }
+
+    fn fold_join(&mut self, join: parse_tree::Join<'a>) -> ParseTree<'a> {
+        fold_join_children(self, join)
+    }
+
+    fn fold_natural_join(&mut self, natural_join: parse_tree::NaturalJoin<'a>) -> ParseTree<'a> {
+        fold_natural_join_children(self, natural_join)
+    }
+
+    fn fold_outer_join_kind(
+        &mut self,
+        outer_join_kind: parse_tree::OuterJoinKind<'a>,
+    ) -> ParseTree<'a> {
+        fold_outer_join_kind_children(self, outer_join_kind)
+    }
+
+    fn fold_on_join_criteria(
+        &mut self,
+        on_join_criteria: parse_tree::OnJoinCriteria<'a>,
+    ) -> ParseTree<'a> {
+        fold_on_join_criteria_children(self, on_join_criteria)
+    }
+
+    fn fold_using_join_criteria(
+        &mut self,
+        using_join_criteria: parse_tree::UsingJoinCriteria<'a>,
+    ) -> ParseTree<'a> {
+        fold_using_join_criteria_children(self, using_join_criteria)
+    }
+
+    fn fold_group_by(&mut self, group_by: parse_tree::GroupBy<'a>) -> ParseTree<'a> {
+        fold_group_by_children(self, group_by)
+    }
+
+    fn fold_rollup(&mut self, rollup: parse_tree::Rollup<'a>) -> ParseTree<'a> {
+        fold_rollup_children(self, rollup)
+    }
+
+    fn fold_cube(&mut self, cube: parse_tree::Cube<'a>) -> ParseTree<'a> {
+        fold_cube_children(self, cube)
+    }
+
+    fn fold_grouping_sets(&mut self, grouping_sets: parse_tree::GroupingSets<'a>) -> ParseTree<'a> {
+        fold_grouping_sets_children(self, grouping_sets)
+    }
+
+    fn fold_binary_expression(
+        &mut self,
+        binary_expression: parse_tree::BinaryExpression<'a>,
+    ) -> ParseTree<'a> {
+        fold_binary_expression_children(self, binary_expression)
+    }
+
+    fn fold_unary_expression(
+        &mut self,
+        unary_expression: parse_tree::UnaryExpression<'a>,
+    ) -> ParseTree<'a> {
+        fold_unary_expression_children(self, unary_expression)
+    }
+
+    fn fold_quantified_comparison(
+        &mut self,
+        quantified_comparison: parse_tree::QuantifiedComparison<'a>,
+    ) -> ParseTree<'a> {
+        fold_quantified_comparison_children(self, quantified_comparison)
+    }
+
+    fn fold_null_predicate(
+        &mut self,
+        null_predicate: parse_tree::NullPredicate<'a>,
+    ) -> ParseTree<'a> {
+        fold_null_predicate_children(self, null_predicate)
+    }
+
+    fn fold_distinct_from(&mut self, distinct_from: parse_tree::DistinctFrom<'a>) -> ParseTree<'a> {
+        fold_distinct_from_children(self, distinct_from)
+    }
+
+    fn fold_between(&mut self, between: parse_tree::Between<'a>) -> ParseTree<'a> {
+        fold_between_children(self, between)
+    }
+
+    fn fold_like(&mut self, like: parse_tree::Like<'a>) -> ParseTree<'a> {
+        fold_like_children(self, like)
+    }
+
+    fn fold_in_subquery(&mut self, in_subquery: parse_tree::InSubquery<'a>) -> ParseTree<'a> {
+        fold_in_subquery_children(self, in_subquery)
+    }
+
+    fn fold_in_list(&mut self, in_list: parse_tree::InList<'a>) -> ParseTree<'a> {
+        fold_in_list_children(self, in_list)
+    }
+
+    fn fold_at_time_zone(&mut self, at_time_zone: parse_tree::AtTimeZone<'a>) -> ParseTree<'a> {
+        fold_at_time_zone_children(self, at_time_zone)
+    }
+
+    fn fold_dereference(&mut self, dereference: parse_tree::Dereference<'a>) -> ParseTree<'a> {
+        fold_dereference_children(self, dereference)
+    }
+
+    fn fold_subscript(&mut self, subscript: parse_tree::Subscript<'a>) -> ParseTree<'a> {
+        fold_subscript_children(self, subscript)
+    }
+
+    fn fold_lambda(&mut self, lambda: parse_tree::Lambda<'a>) -> ParseTree<'a> {
+        fold_lambda_children(self, lambda)
+    }
+
+    fn fold_literal(&mut self, literal: parse_tree::Literal<'a>) -> ParseTree<'a> {
+        fold_literal_children(self, literal)
+    }
+
+    fn fold_row_constructor(
+        &mut self,
+        row_constructor: parse_tree::RowConstructor<'a>,
+    ) -> ParseTree<'a> {
+        fold_row_constructor_children(self, row_constructor)
+    }
+
+    fn fold_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: parse_tree::ParenthesizedExpression<'a>,
+    ) -> ParseTree<'a> {
+        fold_parenthesized_expression_children(self, parenthesized_expression)
+    }
+
+    fn fold_identifier(&mut self, identifier: parse_tree::Identifier<'a>) -> ParseTree<'a> {
+        fold_identifier_children(self, identifier)
+    }
+
+    fn fold_function_call(&mut self, function_call: parse_tree::FunctionCall<'a>) -> ParseTree<'a> {
+        fold_function_call_children(self, function_call)
+    }
+
+    fn fold_filter(&mut self, filter: parse_tree::Filter<'a>) -> ParseTree<'a> {
+        fold_filter_children(self, filter)
+    }
+
+    fn fold_over(&mut self, over: parse_tree::Over<'a>) -> ParseTree<'a> {
+        fold_over_children(self, over)
+    }
+
+    fn fold_window_frame(&mut self, window_frame: parse_tree::WindowFrame<'a>) -> ParseTree<'a> {
+        fold_window_frame_children(self, window_frame)
+    }
+
+    fn fold_unbounded_frame(
+        &mut self,
+        unbounded_frame: parse_tree::UnboundedFrame<'a>,
+    ) -> ParseTree<'a> {
+        fold_unbounded_frame_children(self, unbounded_frame)
+    }
+
+    fn fold_current_row_bound(
+        &mut self,
+        current_row_bound: parse_tree::CurrentRowBound<'a>,
+    ) -> ParseTree<'a> {
+        fold_current_row_bound_children(self, current_row_bound)
+    }
+
+    fn fold_bounded_frame(&mut self, bounded_frame: parse_tree::BoundedFrame<'a>) -> ParseTree<'a> {
+        fold_bounded_frame_children(self, bounded_frame)
+    }
+
+    fn fold_unicode_string(
+        &mut self,
+        unicode_string: parse_tree::UnicodeString<'a>,
+    ) -> ParseTree<'a> {
+        fold_unicode_string_children(self, unicode_string)
+    }
+
+    fn fold_configure_expression(
+        &mut self,
+        configure_expression: parse_tree::ConfigureExpression<'a>,
+    ) -> ParseTree<'a> {
+        fold_configure_expression_children(self, configure_expression)
+    }
+
+    fn fold_subquery_expression(
+        &mut self,
+        subquery_expression: parse_tree::SubqueryExpression<'a>,
+    ) -> ParseTree<'a> {
+        fold_subquery_expression_children(self, subquery_expression)
+    }
+
+    fn fold_grouping(&mut self, grouping: parse_tree::Grouping<'a>) -> ParseTree<'a> {
+        fold_grouping_children(self, grouping)
+    }
+
+    fn fold_extract(&mut self, extract: parse_tree::Extract<'a>) -> ParseTree<'a> {
+        fold_extract_children(self, extract)
+    }
+
+    fn fold_current_time(&mut self, current_time: parse_tree::CurrentTime<'a>) -> ParseTree<'a> {
+        fold_current_time_children(self, current_time)
+    }
+
+    fn fold_current_timestamp(
+        &mut self,
+        current_timestamp: parse_tree::CurrentTimestamp<'a>,
+    ) -> ParseTree<'a> {
+        fold_current_timestamp_children(self, current_timestamp)
+    }
+
+    fn fold_normalize(&mut self, normalize: parse_tree::Normalize<'a>) -> ParseTree<'a> {
+        fold_normalize_children(self, normalize)
+    }
+
+    fn fold_localtime(&mut self, localtime: parse_tree::Localtime<'a>) -> ParseTree<'a> {
+        fold_localtime_children(self, localtime)
+    }
+
+    fn fold_localtimestamp(
+        &mut self,
+        localtimestamp: parse_tree::Localtimestamp<'a>,
+    ) -> ParseTree<'a> {
+        fold_localtimestamp_children(self, localtimestamp)
+    }
+
+    fn fold_cast(&mut self, cast: parse_tree::Cast<'a>) -> ParseTree<'a> {
+        fold_cast_children(self, cast)
+    }
+
+    fn fold_when_clause(&mut self, when_clause: parse_tree::WhenClause<'a>) -> ParseTree<'a> {
+        fold_when_clause_children(self, when_clause)
+    }
+
+    fn fold_case(&mut self, case: parse_tree::Case<'a>) -> ParseTree<'a> {
+        fold_case_children(self, case)
+    }
+
+    fn fold_exists(&mut self, exists: parse_tree::Exists<'a>) -> ParseTree<'a> {
+        fold_exists_children(self, exists)
+    }
+
+    fn fold_type_constructor(
+        &mut self,
+        type_constructor: parse_tree::TypeConstructor<'a>,
+    ) -> ParseTree<'a> {
+        fold_type_constructor_children(self, type_constructor)
+    }
+
+    fn fold_array(&mut self, array: parse_tree::Array<'a>) -> ParseTree<'a> {
+        fold_array_children(self, array)
+    }
+
+    fn fold_interval(&mut self, interval: parse_tree::Interval<'a>) -> ParseTree<'a> {
+        fold_interval_children(self, interval)
+    }
+
+    fn fold_row(&mut self, row: parse_tree::Row<'a>) -> ParseTree<'a> {
+        fold_row_children(self, row)
+    }
+
+    fn fold_try_cast(&mut self, try_cast: parse_tree::TryCast<'a>) -> ParseTree<'a> {
+        fold_try_cast_children(self, try_cast)
+    }
+
+    fn fold_substring(&mut self, substring: parse_tree::Substring<'a>) -> ParseTree<'a> {
+        fold_substring_children(self, substring)
+    }
+
+    fn fold_position(&mut self, position: parse_tree::Position<'a>) -> ParseTree<'a> {
+        fold_position_children(self, position)
+    }
+
+    fn fold_array_type_suffix(
+        &mut self,
+        array_type_suffix: parse_tree::ArrayTypeSuffix<'a>,
+    ) -> ParseTree<'a> {
+        fold_array_type_suffix_children(self, array_type_suffix)
+    }
+
+    fn fold_named_type(&mut self, named_type: parse_tree::NamedType<'a>) -> ParseTree<'a> {
+        fold_named_type_children(self, named_type)
+    }
+
+    fn fold_array_type(&mut self, array_type: parse_tree::ArrayType<'a>) -> ParseTree<'a> {
+        fold_array_type_children(self, array_type)
+    }
+
+    fn fold_map_type(&mut self, map_type: parse_tree::MapType<'a>) -> ParseTree<'a> {
+        fold_map_type_children(self, map_type)
+    }
+
+    fn fold_row_type(&mut self, row_type: parse_tree::RowType<'a>) -> ParseTree<'a> {
+        fold_row_type_children(self, row_type)
+    }
+
+    fn fold_row_type_element(
+        &mut self,
+        row_type_element: parse_tree::RowTypeElement<'a>,
+    ) -> ParseTree<'a> {
+        fold_row_type_element_children(self, row_type_element)
+    }
+
+    fn fold_interval_type(&mut self, interval_type: parse_tree::IntervalType<'a>) -> ParseTree<'a> {
+        fold_interval_type_children(self, interval_type)
+    }
+
+    fn fold_if_not_exists(&mut self, if_not_exists: parse_tree::IfNotExists<'a>) -> ParseTree<'a> {
+        fold_if_not_exists_children(self, if_not_exists)
+    }
+
+    fn fold_create_table(&mut self, create_table: parse_tree::CreateTable<'a>) -> ParseTree<'a> {
+        fold_create_table_children(self, create_table)
+    }
+
+    fn fold_create_view(&mut self, create_view: parse_tree::CreateView<'a>) -> ParseTree<'a> {
+        fold_create_view_children(self, create_view)
+    }
+
+    fn fold_create_role(&mut self, create_role: parse_tree::CreateRole<'a>) -> ParseTree<'a> {
+        fold_create_role_children(self, create_role)
+    }
+
+    fn fold_with_admin_grantor(
+        &mut self,
+        with_admin_grantor: parse_tree::WithAdminGrantor<'a>,
+    ) -> ParseTree<'a> {
+        fold_with_admin_grantor_children(self, with_admin_grantor)
+    }
+
+    fn fold_user_principal(
+        &mut self,
+        user_principal: parse_tree::UserPrincipal<'a>,
+    ) -> ParseTree<'a> {
+        fold_user_principal_children(self, user_principal)
+    }
+
+    fn fold_role_principal(
+        &mut self,
+        role_principal: parse_tree::RolePrincipal<'a>,
+    ) -> ParseTree<'a> {
+        fold_role_principal_children(self, role_principal)
+    }
+
+    fn fold_unspecified_principal(
+        &mut self,
+        unspecified_principal: parse_tree::UnspecifiedPrincipal<'a>,
+    ) -> ParseTree<'a> {
+        fold_unspecified_principal_children(self, unspecified_principal)
+    }
+
+    fn fold_create_table_as_select(
+        &mut self,
+        create_table_as_select: parse_tree::CreateTableAsSelect<'a>,
+    ) -> ParseTree<'a> {
+        fold_create_table_as_select_children(self, create_table_as_select)
+    }
+
+    fn fold_with_properties(
+        &mut self,
+        with_properties: parse_tree::WithProperties<'a>,
+    ) -> ParseTree<'a> {
+        fold_with_properties_children(self, with_properties)
+    }
+
+    fn fold_property(&mut self, property: parse_tree::Property<'a>) -> ParseTree<'a> {
+        fold_property_children(self, property)
+    }
+
+    fn fold_with_data(&mut self, with_data: parse_tree::WithData<'a>) -> ParseTree<'a> {
+        fold_with_data_children(self, with_data)
+    }
+
+    fn fold_comment(&mut self, comment: parse_tree::Comment<'a>) -> ParseTree<'a> {
+        fold_comment_children(self, comment)
+    }
+
+    fn fold_column_definition(
+        &mut self,
+        column_definition: parse_tree::ColumnDefinition<'a>,
+    ) -> ParseTree<'a> {
+        fold_column_definition_children(self, column_definition)
+    }
+
+    fn fold_not_null(&mut self, not_null: parse_tree::NotNull<'a>) -> ParseTree<'a> {
+        fold_not_null_children(self, not_null)
+    }
+
+    fn fold_like_clause(&mut self, like_clause: parse_tree::LikeClause<'a>) -> ParseTree<'a> {
+        fold_like_clause_children(self, like_clause)
+    }
+
+    fn fold_insert_into(&mut self, insert_into: parse_tree::InsertInto<'a>) -> ParseTree<'a> {
+        fold_insert_into_children(self, insert_into)
+    }
+
+    fn fold_delete(&mut self, delete: parse_tree::Delete<'a>) -> ParseTree<'a> {
+        fold_delete_children(self, delete)
+    }
+
+    fn fold_grouping_set(&mut self, grouping_set: parse_tree::GroupingSet<'a>) -> ParseTree<'a> {
+        fold_grouping_set_children(self, grouping_set)
+    }
+
+    fn fold_relation_or_query(
+        &mut self,
+        relation_or_query: parse_tree::RelationOrQuery<'a>,
+    ) -> ParseTree<'a> {
+        fold_relation_or_query_children(self, relation_or_query)
+    }
+
+    fn fold_empty_grouping_set(
+        &mut self,
+        empty_grouping_set: parse_tree::EmptyGroupingSet<'a>,
+    ) -> ParseTree<'a> {
+        fold_empty_grouping_set_children(self, empty_grouping_set)
+    }
+
+    fn fold_expression_or_query(
+        &mut self,
+        expression_or_query: parse_tree::ExpressionOrQuery<'a>,
+    ) -> ParseTree<'a> {
+        fold_expression_or_query_children(self, expression_or_query)
+    }
+
+    fn fold_entrypoint(&mut self, entrypoint: parse_tree::Entrypoint<'a>) -> ParseTree<'a> {
+        fold_entrypoint_children(self, entrypoint)
+    }
+
+    fn fold_null_treatment(
+        &mut self,
+        null_treatment: parse_tree::NullTreatment<'a>,
+    ) -> ParseTree<'a> {
+        fold_null_treatment_children(self, null_treatment)
+    }
+}
+
+/// Dispatches to the fold_*() method matching the kind of tree.
+pub fn fold_tree<'a, F: Folder<'a> + ?Sized>(folder: &mut F, tree: ParseTree<'a>) -> ParseTree<'a> {
+    match tree {
+        ParseTree::Empty(empty) => folder.fold_empty(empty),
+        ParseTree::Token(token) => folder.fold_token(token),
+        ParseTree::List(list) => folder.fold_list(list),
+        ParseTree::Error(error) => folder.fold_error(error),
+        ParseTree::Query(query) => folder.fold_query(query),
+        ParseTree::With(with) => folder.fold_with(with),
+        ParseTree::NamedQuery(named_query) => folder.fold_named_query(named_query),
+        ParseTree::QueryNoWith(query_no_with) => folder.fold_query_no_with(query_no_with),
+        ParseTree::OrderBy(order_by) => folder.fold_order_by(order_by),
+        ParseTree::Limit(limit) => folder.fold_limit(limit),
+        ParseTree::QuerySetOperation(query_set_operation) => {
+            folder.fold_query_set_operation(query_set_operation)
+        }
+        ParseTree::SortItem(sort_item) => folder.fold_sort_item(sort_item),
+        ParseTree::Subquery(subquery) => folder.fold_subquery(subquery),
+        ParseTree::InlineTable(inline_table) => folder.fold_inline_table(inline_table),
+        ParseTree::Table(table) => folder.fold_table(table),
+        ParseTree::QuerySpecification(query_specification) => {
+            folder.fold_query_specification(query_specification)
+        }
+        ParseTree::QualifiedName(qualified_name) => folder.fold_qualified_name(qualified_name),
+        ParseTree::SelectAll(select_all) => folder.fold_select_all(select_all),
+        ParseTree::QualifiedSelectAll(qualified_select_all) => {
+            folder.fold_qualified_select_all(qualified_select_all)
+        }
+        ParseTree::SelectItem(select_item) => folder.fold_select_item(select_item),
+        ParseTree::SubqueryRelation(subquery_relation) => {
+            folder.fold_subquery_relation(subquery_relation)
+        }
+        ParseTree::ParenthesizedRelation(parenthesized_relation) => {
+            folder.fold_parenthesized_relation(parenthesized_relation)
+        }
+        ParseTree::TableName(table_name) => folder.fold_table_name(table_name),
+        ParseTree::Lateral(lateral) => folder.fold_lateral(lateral),
+        ParseTree::Unnest(unnest) => folder.fold_unnest(unnest),
+        ParseTree::SampledRelation(sampled_relation) => {
+            folder.fold_sampled_relation(sampled_relation)
+        }
+        ParseTree::AliasedRelation(aliased_relation) => {
+            folder.fold_aliased_relation(aliased_relation)
+        }
+        ParseTree::CrossJoin(cross_join) => folder.fold_cross_join(cross_join),
+        ParseTree::Join(join) => folder.fold_join(join),
+        ParseTree::NaturalJoin(natural_join) => folder.fold_natural_join(natural_join),
+        ParseTree::OuterJoinKind(outer_join_kind) => folder.fold_outer_join_kind(outer_join_kind),
+        ParseTree::OnJoinCriteria(on_join_criteria) => {
+            folder.fold_on_join_criteria(on_join_criteria)
+        }
+        ParseTree::UsingJoinCriteria(using_join_criteria) => {
+            folder.fold_using_join_criteria(using_join_criteria)
+        }
+        ParseTree::GroupBy(group_by) => folder.fold_group_by(group_by),
+        ParseTree::Rollup(rollup) => folder.fold_rollup(rollup),
+        ParseTree::Cube(cube) => folder.fold_cube(cube),
+        ParseTree::GroupingSets(grouping_sets) => folder.fold_grouping_sets(grouping_sets),
+        ParseTree::BinaryExpression(binary_expression) => {
+            folder.fold_binary_expression(binary_expression)
+        }
+        ParseTree::UnaryExpression(unary_expression) => {
+            folder.fold_unary_expression(unary_expression)
+        }
+        ParseTree::QuantifiedComparison(quantified_comparison) => {
+            folder.fold_quantified_comparison(quantified_comparison)
+        }
+        ParseTree::NullPredicate(null_predicate) => folder.fold_null_predicate(null_predicate),
+        ParseTree::DistinctFrom(distinct_from) => folder.fold_distinct_from(distinct_from),
+        ParseTree::Between(between) => folder.fold_between(between),
+        ParseTree::Like(like) => folder.fold_like(like),
+        ParseTree::InSubquery(in_subquery) => folder.fold_in_subquery(in_subquery),
+        ParseTree::InList(in_list) => folder.fold_in_list(in_list),
+        ParseTree::AtTimeZone(at_time_zone) => folder.fold_at_time_zone(at_time_zone),
+        ParseTree::Dereference(dereference) => folder.fold_dereference(dereference),
+        ParseTree::Subscript(subscript) => folder.fold_subscript(subscript),
+        ParseTree::Lambda(lambda) => folder.fold_lambda(lambda),
+        ParseTree::Literal(literal) => folder.fold_literal(literal),
+        ParseTree::RowConstructor(row_constructor) => folder.fold_row_constructor(row_constructor),
+        ParseTree::ParenthesizedExpression(parenthesized_expression) => {
+            folder.fold_parenthesized_expression(parenthesized_expression)
+        }
+        ParseTree::Identifier(identifier) => folder.fold_identifier(identifier),
+        ParseTree::FunctionCall(function_call) => folder.fold_function_call(function_call),
+        ParseTree::Filter(filter) => folder.fold_filter(filter),
+        ParseTree::Over(over) => folder.fold_over(over),
+        ParseTree::WindowFrame(window_frame) => folder.fold_window_frame(window_frame),
+        ParseTree::UnboundedFrame(unbounded_frame) => folder.fold_unbounded_frame(unbounded_frame),
+        ParseTree::CurrentRowBound(current_row_bound) => {
+            folder.fold_current_row_bound(current_row_bound)
+        }
+        ParseTree::BoundedFrame(bounded_frame) => folder.fold_bounded_frame(bounded_frame),
+        ParseTree::UnicodeString(unicode_string) => folder.fold_unicode_string(unicode_string),
+        ParseTree::ConfigureExpression(configure_expression) => {
+            folder.fold_configure_expression(configure_expression)
+        }
+        ParseTree::SubqueryExpression(subquery_expression) => {
+            folder.fold_subquery_expression(subquery_expression)
+        }
+        ParseTree::Grouping(grouping) => folder.fold_grouping(grouping),
+        ParseTree::Extract(extract) => folder.fold_extract(extract),
+        ParseTree::CurrentTime(current_time) => folder.fold_current_time(current_time),
+        ParseTree::CurrentTimestamp(current_timestamp) => {
+            folder.fold_current_timestamp(current_timestamp)
+        }
+        ParseTree::Normalize(normalize) => folder.fold_normalize(normalize),
+        ParseTree::Localtime(localtime) => folder.fold_localtime(localtime),
+        ParseTree::Localtimestamp(localtimestamp) => folder.fold_localtimestamp(localtimestamp),
+        ParseTree::Cast(cast) => folder.fold_cast(cast),
+        ParseTree::WhenClause(when_clause) => folder.fold_when_clause(when_clause),
+        ParseTree::Case(case) => folder.fold_case(case),
+        ParseTree::Exists(exists) => folder.fold_exists(exists),
+        ParseTree::TypeConstructor(type_constructor) => {
+            folder.fold_type_constructor(type_constructor)
+        }
+        ParseTree::Array(array) => folder.fold_array(array),
+        ParseTree::Interval(interval) => folder.fold_interval(interval),
+        ParseTree::Row(row) => folder.fold_row(row),
+        ParseTree::TryCast(try_cast) => folder.fold_try_cast(try_cast),
+        ParseTree::Substring(substring) => folder.fold_substring(substring),
+        ParseTree::Position(position) => folder.fold_position(position),
+        ParseTree::ArrayTypeSuffix(array_type_suffix) => {
+            folder.fold_array_type_suffix(array_type_suffix)
+        }
+        ParseTree::NamedType(named_type) => folder.fold_named_type(named_type),
+        ParseTree::ArrayType(array_type) => folder.fold_array_type(array_type),
+        ParseTree::MapType(map_type) => folder.fold_map_type(map_type),
+        ParseTree::RowType(row_type) => folder.fold_row_type(row_type),
+        ParseTree::RowTypeElement(row_type_element) => {
+            folder.fold_row_type_element(row_type_element)
+        }
+        ParseTree::IntervalType(interval_type) => folder.fold_interval_type(interval_type),
+        ParseTree::IfNotExists(if_not_exists) => folder.fold_if_not_exists(if_not_exists),
+        ParseTree::CreateTable(create_table) => folder.fold_create_table(create_table),
+        ParseTree::CreateView(create_view) => folder.fold_create_view(create_view),
+        ParseTree::CreateRole(create_role) => folder.fold_create_role(create_role),
+        ParseTree::WithAdminGrantor(with_admin_grantor) => {
+            folder.fold_with_admin_grantor(with_admin_grantor)
+        }
+        ParseTree::UserPrincipal(user_principal) => folder.fold_user_principal(user_principal),
+        ParseTree::RolePrincipal(role_principal) => folder.fold_role_principal(role_principal),
+        ParseTree::UnspecifiedPrincipal(unspecified_principal) => {
+            folder.fold_unspecified_principal(unspecified_principal)
+        }
+        ParseTree::CreateTableAsSelect(create_table_as_select) => {
+            folder.fold_create_table_as_select(create_table_as_select)
+        }
+        ParseTree::WithProperties(with_properties) => folder.fold_with_properties(with_properties),
+        ParseTree::Property(property) => folder.fold_property(property),
+        ParseTree::WithData(with_data) => folder.fold_with_data(with_data),
+        ParseTree::Comment(comment) => folder.fold_comment(comment),
+        ParseTree::ColumnDefinition(column_definition) => {
+            folder.fold_column_definition(column_definition)
+        }
+        ParseTree::NotNull(not_null) => folder.fold_not_null(not_null),
+        ParseTree::LikeClause(like_clause) => folder.fold_like_clause(like_clause),
+        ParseTree::InsertInto(insert_into) => folder.fold_insert_into(insert_into),
+        ParseTree::Delete(delete) => folder.fold_delete(delete),
+        ParseTree::GroupingSet(grouping_set) => folder.fold_grouping_set(grouping_set),
+        ParseTree::RelationOrQuery(relation_or_query) => {
+            folder.fold_relation_or_query(relation_or_query)
+        }
+        ParseTree::EmptyGroupingSet(empty_grouping_set) => {
+            folder.fold_empty_grouping_set(empty_grouping_set)
+        }
+        ParseTree::ExpressionOrQuery(expression_or_query) => {
+            folder.fold_expression_or_query(expression_or_query)
+        }
+        ParseTree::Entrypoint(entrypoint) => folder.fold_entrypoint(entrypoint),
+        ParseTree::NullTreatment(null_treatment) => folder.fold_null_treatment(null_treatment),
+    }
+}
+
+/// Folds the delimiters, elements and separators of a list.
+pub fn fold_list_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    list: parse_tree::List<'a>,
+) -> ParseTree<'a> {
+    let (start_delimiter, elements_and_separators, end_delimiter) = list.unbox();
+    let start_delimiter = folder.fold(start_delimiter);
+    let elements_and_separators = elements_and_separators
+        .into_iter()
+        .map(|(element, separator)| (folder.fold(element), folder.fold(separator)))
+        .collect();
+    let end_delimiter = folder.fold(end_delimiter);
+    parse_tree::list(start_delimiter, elements_and_separators, end_delimiter)
+}
+
+pub fn fold_query_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    query: parse_tree::Query<'a>,
+) -> ParseTree<'a> {
+    let (with, query_no_with) = query.unbox();
+    parse_tree::query(folder.fold(with), folder.fold(query_no_with))
+}
+
+pub fn fold_with_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    with: parse_tree::With<'a>,
+) -> ParseTree<'a> {
+    let (with, recursive, named_queries) = with.unbox();
+    parse_tree::with(
+        folder.fold(with),
+        folder.fold(recursive),
+        folder.fold(named_queries),
+    )
+}
+
+pub fn fold_named_query_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    named_query: parse_tree::NamedQuery<'a>,
+) -> ParseTree<'a> {
+    let (name, column_aliases_opt, as_, open_paren, query, close_paren) = named_query.unbox();
+    parse_tree::named_query(
+        folder.fold(name),
+        folder.fold(column_aliases_opt),
+        folder.fold(as_),
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_query_no_with_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    query_no_with: parse_tree::QueryNoWith<'a>,
+) -> ParseTree<'a> {
+    let (query_term, order_by_opt, limit_opt) = query_no_with.unbox();
+    parse_tree::query_no_with(
+        folder.fold(query_term),
+        folder.fold(order_by_opt),
+        folder.fold(limit_opt),
+    )
+}
+
+pub fn fold_order_by_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    order_by: parse_tree::OrderBy<'a>,
+) -> ParseTree<'a> {
+    let (order, by, sort_items) = order_by.unbox();
+    parse_tree::order_by(folder.fold(order), folder.fold(by), folder.fold(sort_items))
+}
+
+pub fn fold_limit_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    limit: parse_tree::Limit<'a>,
+) -> ParseTree<'a> {
+    let (limit, value) = limit.unbox();
+    parse_tree::limit(folder.fold(limit), folder.fold(value))
+}
+
+pub fn fold_query_set_operation_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    query_set_operation: parse_tree::QuerySetOperation<'a>,
+) -> ParseTree<'a> {
+    let (left, operator, set_quantifier_opt, right) = query_set_operation.unbox();
+    parse_tree::query_set_operation(
+        folder.fold(left),
+        folder.fold(operator),
+        folder.fold(set_quantifier_opt),
+        folder.fold(right),
+    )
+}
+
+pub fn fold_sort_item_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    sort_item: parse_tree::SortItem<'a>,
+) -> ParseTree<'a> {
+    let (expression, ordering_opt, nulls, null_ordering_opt) = sort_item.unbox();
+    parse_tree::sort_item(
+        folder.fold(expression),
+        folder.fold(ordering_opt),
+        folder.fold(nulls),
+        folder.fold(null_ordering_opt),
+    )
+}
+
+pub fn fold_subquery_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    subquery: parse_tree::Subquery<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, query_no_with, close_paren) = subquery.unbox();
+    parse_tree::subquery(
+        folder.fold(open_paren),
+        folder.fold(query_no_with),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_inline_table_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    inline_table: parse_tree::InlineTable<'a>,
+) -> ParseTree<'a> {
+    let (values, expressions) = inline_table.unbox();
+    parse_tree::inline_table(folder.fold(values), folder.fold(expressions))
+}
+
+pub fn fold_table_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    table: parse_tree::Table<'a>,
+) -> ParseTree<'a> {
+    let (table, qualified_name) = table.unbox();
+    parse_tree::table(folder.fold(table), folder.fold(qualified_name))
+}
+
+pub fn fold_query_specification_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    query_specification: parse_tree::QuerySpecification<'a>,
+) -> ParseTree<'a> {
+    let (
+        select,
+        set_quantifier_opt,
+        select_items,
+        from,
+        relations,
+        where_,
+        where_predicate,
+        group,
+        by,
+        group_by,
+        having,
+        having_predicate,
+    ) = query_specification.unbox();
+    parse_tree::query_specification(
+        folder.fold(select),
+        folder.fold(set_quantifier_opt),
+        folder.fold(select_items),
+        folder.fold(from),
+        folder.fold(relations),
+        folder.fold(where_),
+        folder.fold(where_predicate),
+        folder.fold(group),
+        folder.fold(by),
+        folder.fold(group_by),
+        folder.fold(having),
+        folder.fold(having_predicate),
+    )
+}
+
+pub fn fold_qualified_name_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    qualified_name: parse_tree::QualifiedName<'a>,
+) -> ParseTree<'a> {
+    let (names,) = qualified_name.unbox();
+    parse_tree::qualified_name(folder.fold(names))
+}
+
+pub fn fold_select_all_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    select_all: parse_tree::SelectAll<'a>,
+) -> ParseTree<'a> {
+    let (asterisk,) = select_all.unbox();
+    parse_tree::select_all(folder.fold(asterisk))
+}
+
+pub fn fold_qualified_select_all_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    qualified_select_all: parse_tree::QualifiedSelectAll<'a>,
+) -> ParseTree<'a> {
+    let (qualifier, period, asterisk) = qualified_select_all.unbox();
+    parse_tree::qualified_select_all(
+        folder.fold(qualifier),
+        folder.fold(period),
+        folder.fold(asterisk),
+    )
+}
+
+pub fn fold_select_item_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    select_item: parse_tree::SelectItem<'a>,
+) -> ParseTree<'a> {
+    let (expression, as_, identifier) = select_item.unbox();
+    parse_tree::select_item(
+        folder.fold(expression),
+        folder.fold(as_),
+        folder.fold(identifier),
+    )
+}
+
+pub fn fold_subquery_relation_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    subquery_relation: parse_tree::SubqueryRelation<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, query, close_paren) = subquery_relation.unbox();
+    parse_tree::subquery_relation(
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_parenthesized_relation_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    parenthesized_relation: parse_tree::ParenthesizedRelation<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, relation, close_paren) = parenthesized_relation.unbox();
+    parse_tree::parenthesized_relation(
+        folder.fold(open_paren),
+        folder.fold(relation),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_table_name_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    table_name: parse_tree::TableName<'a>,
+) -> ParseTree<'a> {
+    let (name,) = table_name.unbox();
+    parse_tree::table_name(folder.fold(name))
+}
+
+pub fn fold_lateral_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    lateral: parse_tree::Lateral<'a>,
+) -> ParseTree<'a> {
+    let (lateral, open_paren, query, close_paren) = lateral.unbox();
+    parse_tree::lateral(
+        folder.fold(lateral),
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_unnest_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    unnest: parse_tree::Unnest<'a>,
+) -> ParseTree<'a> {
+    let (unnest, expressions, with, ordinality) = unnest.unbox();
+    parse_tree::unnest(
+        folder.fold(unnest),
+        folder.fold(expressions),
+        folder.fold(with),
+        folder.fold(ordinality),
+    )
+}
+
+pub fn fold_sampled_relation_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    sampled_relation: parse_tree::SampledRelation<'a>,
+) -> ParseTree<'a> {
+    let (aliased_relation, tablesample, sample_type, open_paren, expression, close_paren) =
+        sampled_relation.unbox();
+    parse_tree::sampled_relation(
+        folder.fold(aliased_relation),
+        folder.fold(tablesample),
+        folder.fold(sample_type),
+        folder.fold(open_paren),
+        folder.fold(expression),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_aliased_relation_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    aliased_relation: parse_tree::AliasedRelation<'a>,
+) -> ParseTree<'a> {
+    let (relation_primary, as_opt, identifier, column_aliases_opt) = aliased_relation.unbox();
+    parse_tree::aliased_relation(
+        folder.fold(relation_primary),
+        folder.fold(as_opt),
+        folder.fold(identifier),
+        folder.fold(column_aliases_opt),
+    )
+}
+
+pub fn fold_cross_join_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    cross_join: parse_tree::CrossJoin<'a>,
+) -> ParseTree<'a> {
+    let (left, cross, join, right) = cross_join.unbox();
+    parse_tree::cross_join(
+        folder.fold(left),
+        folder.fold(cross),
+        folder.fold(join),
+        folder.fold(right),
+    )
+}
+
+pub fn fold_join_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    join: parse_tree::Join<'a>,
+) -> ParseTree<'a> {
+    let (left, join_type, join, right, join_criteria) = join.unbox();
+    parse_tree::join(
+        folder.fold(left),
+        folder.fold(join_type),
+        folder.fold(join),
+        folder.fold(right),
+        folder.fold(join_criteria),
+    )
+}
+
+pub fn fold_natural_join_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    natural_join: parse_tree::NaturalJoin<'a>,
+) -> ParseTree<'a> {
+    let (left, natural, join_type, join, right) = natural_join.unbox();
+    parse_tree::natural_join(
+        folder.fold(left),
+        folder.fold(natural),
+        folder.fold(join_type),
+        folder.fold(join),
+        folder.fold(right),
+    )
+}
+
+pub fn fold_outer_join_kind_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    outer_join_kind: parse_tree::OuterJoinKind<'a>,
+) -> ParseTree<'a> {
+    let (kind, outer_opt) = outer_join_kind.unbox();
+    parse_tree::outer_join_kind(folder.fold(kind), folder.fold(outer_opt))
+}
+
+pub fn fold_on_join_criteria_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    on_join_criteria: parse_tree::OnJoinCriteria<'a>,
+) -> ParseTree<'a> {
+    let (on, predicate) = on_join_criteria.unbox();
+    parse_tree::on_join_criteria(folder.fold(on), folder.fold(predicate))
+}
+
+pub fn fold_using_join_criteria_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    using_join_criteria: parse_tree::UsingJoinCriteria<'a>,
+) -> ParseTree<'a> {
+    let (using, names) = using_join_criteria.unbox();
+    parse_tree::using_join_criteria(folder.fold(using), folder.fold(names))
+}
+
+pub fn fold_group_by_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    group_by: parse_tree::GroupBy<'a>,
+) -> ParseTree<'a> {
+    let (set_quantifier_opt, grouping_elements) = group_by.unbox();
+    parse_tree::group_by(
+        folder.fold(set_quantifier_opt),
+        folder.fold(grouping_elements),
+    )
+}
+
+pub fn fold_rollup_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    rollup: parse_tree::Rollup<'a>,
+) -> ParseTree<'a> {
+    let (rollup, expressions) = rollup.unbox();
+    parse_tree::rollup(folder.fold(rollup), folder.fold(expressions))
+}
+
+pub fn fold_cube_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    cube: parse_tree::Cube<'a>,
+) -> ParseTree<'a> {
+    let (cube, expressions) = cube.unbox();
+    parse_tree::cube(folder.fold(cube), folder.fold(expressions))
+}
+
+pub fn fold_grouping_sets_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    grouping_sets: parse_tree::GroupingSets<'a>,
+) -> ParseTree<'a> {
+    let (grouping, sets, grouping_sets) = grouping_sets.unbox();
+    parse_tree::grouping_sets(
+        folder.fold(grouping),
+        folder.fold(sets),
+        folder.fold(grouping_sets),
+    )
+}
+
+pub fn fold_binary_expression_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    binary_expression: parse_tree::BinaryExpression<'a>,
+) -> ParseTree<'a> {
+    let (left, operator, right) = binary_expression.unbox();
+    parse_tree::binary_expression(folder.fold(left), folder.fold(operator), folder.fold(right))
+}
+
+pub fn fold_unary_expression_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    unary_expression: parse_tree::UnaryExpression<'a>,
+) -> ParseTree<'a> {
+    let (operator, operand) = unary_expression.unbox();
+    parse_tree::unary_expression(folder.fold(operator), folder.fold(operand))
+}
+
+pub fn fold_quantified_comparison_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    quantified_comparison: parse_tree::QuantifiedComparison<'a>,
+) -> ParseTree<'a> {
+    let (operand, operator, comparison_quantifier, open_paren, query, close_paren) =
+        quantified_comparison.unbox();
+    parse_tree::quantified_comparison(
+        folder.fold(operand),
+        folder.fold(operator),
+        folder.fold(comparison_quantifier),
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_null_predicate_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    null_predicate: parse_tree::NullPredicate<'a>,
+) -> ParseTree<'a> {
+    let (value, is, not_opt, null) = null_predicate.unbox();
+    parse_tree::null_predicate(
+        folder.fold(value),
+        folder.fold(is),
+        folder.fold(not_opt),
+        folder.fold(null),
+    )
+}
+
+pub fn fold_distinct_from_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    distinct_from: parse_tree::DistinctFrom<'a>,
+) -> ParseTree<'a> {
+    let (left, distinct, from, right) = distinct_from.unbox();
+    parse_tree::distinct_from(
+        folder.fold(left),
+        folder.fold(distinct),
+        folder.fold(from),
+        folder.fold(right),
+    )
+}
+
+pub fn fold_between_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    between: parse_tree::Between<'a>,
+) -> ParseTree<'a> {
+    let (value, not_opt, between, lower, and, upper) = between.unbox();
+    parse_tree::between(
+        folder.fold(value),
+        folder.fold(not_opt),
+        folder.fold(between),
+        folder.fold(lower),
+        folder.fold(and),
+        folder.fold(upper),
+    )
+}
+
+pub fn fold_like_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    like: parse_tree::Like<'a>,
+) -> ParseTree<'a> {
+    let (value, not_opt, like, patrern, escape_opt, escape_value_opt) = like.unbox();
+    parse_tree::like(
+        folder.fold(value),
+        folder.fold(not_opt),
+        folder.fold(like),
+        folder.fold(patrern),
+        folder.fold(escape_opt),
+        folder.fold(escape_value_opt),
+    )
+}
+
+pub fn fold_in_subquery_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    in_subquery: parse_tree::InSubquery<'a>,
+) -> ParseTree<'a> {
+    let (value, not_opt, in_, open_paren, query, close_paren) = in_subquery.unbox();
+    parse_tree::in_subquery(
+        folder.fold(value),
+        folder.fold(not_opt),
+        folder.fold(in_),
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_in_list_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    in_list: parse_tree::InList<'a>,
+) -> ParseTree<'a> {
+    let (value, not_opt, in_, expressions) = in_list.unbox();
+    parse_tree::in_list(
+        folder.fold(value),
+        folder.fold(not_opt),
+        folder.fold(in_),
+        folder.fold(expressions),
+    )
+}
+
+pub fn fold_at_time_zone_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    at_time_zone: parse_tree::AtTimeZone<'a>,
+) -> ParseTree<'a> {
+    let (value, at, time, zone, specifier) = at_time_zone.unbox();
+    parse_tree::at_time_zone(
+        folder.fold(value),
+        folder.fold(at),
+        folder.fold(time),
+        folder.fold(zone),
+        folder.fold(specifier),
+    )
+}
+
+pub fn fold_dereference_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    dereference: parse_tree::Dereference<'a>,
+) -> ParseTree<'a> {
+    let (object, period, field_name) = dereference.unbox();
+    parse_tree::dereference(
+        folder.fold(object),
+        folder.fold(period),
+        folder.fold(field_name),
+    )
+}
+
+pub fn fold_subscript_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    subscript: parse_tree::Subscript<'a>,
+) -> ParseTree<'a> {
+    let (operand, open_square, index, close_square) = subscript.unbox();
+    parse_tree::subscript(
+        folder.fold(operand),
+        folder.fold(open_square),
+        folder.fold(index),
+        folder.fold(close_square),
+    )
+}
+
+pub fn fold_lambda_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    lambda: parse_tree::Lambda<'a>,
+) -> ParseTree<'a> {
+    let (parameters, array, body) = lambda.unbox();
+    parse_tree::lambda(
+        folder.fold(parameters),
+        folder.fold(array),
+        folder.fold(body),
+    )
+}
+
+pub fn fold_literal_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    literal: parse_tree::Literal<'a>,
+) -> ParseTree<'a> {
+    let (value,) = literal.unbox();
+    parse_tree::literal(folder.fold(value))
+}
+
+pub fn fold_row_constructor_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    row_constructor: parse_tree::RowConstructor<'a>,
+) -> ParseTree<'a> {
+    let (elements,) = row_constructor.unbox();
+    parse_tree::row_constructor(folder.fold(elements))
+}
+
+pub fn fold_parenthesized_expression_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    parenthesized_expression: parse_tree::ParenthesizedExpression<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, value, close_paren) = parenthesized_expression.unbox();
+    parse_tree::parenthesized_expression(
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_identifier_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    identifier: parse_tree::Identifier<'a>,
+) -> ParseTree<'a> {
+    let (value,) = identifier.unbox();
+    parse_tree::identifier(folder.fold(value))
+}
+
+pub fn fold_function_call_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    function_call: parse_tree::FunctionCall<'a>,
+) -> ParseTree<'a> {
+    let (
+        name,
+        open_paren,
+        set_quantifier_opt,
+        arguments,
+        order_by_opt,
+        close_paren,
+        filter_opt,
+        null_treatment_opt,
+        over_opt,
+    ) = function_call.unbox();
+    parse_tree::function_call(
+        folder.fold(name),
+        folder.fold(open_paren),
+        folder.fold(set_quantifier_opt),
+        folder.fold(arguments),
+        folder.fold(order_by_opt),
+        folder.fold(close_paren),
+        folder.fold(filter_opt),
+        folder.fold(null_treatment_opt),
+        folder.fold(over_opt),
+    )
+}
+
+pub fn fold_filter_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    filter: parse_tree::Filter<'a>,
+) -> ParseTree<'a> {
+    let (filter, open_paren, where_, predicate, close_paren) = filter.unbox();
+    parse_tree::filter(
+        folder.fold(filter),
+        folder.fold(open_paren),
+        folder.fold(where_),
+        folder.fold(predicate),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_over_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    over: parse_tree::Over<'a>,
+) -> ParseTree<'a> {
+    let (over, open_paren, partition_opt, by, partitions, order_by_opt, window_frame, close_paren) =
+        over.unbox();
+    parse_tree::over(
+        folder.fold(over),
+        folder.fold(open_paren),
+        folder.fold(partition_opt),
+        folder.fold(by),
+        folder.fold(partitions),
+        folder.fold(order_by_opt),
+        folder.fold(window_frame),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_window_frame_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    window_frame: parse_tree::WindowFrame<'a>,
+) -> ParseTree<'a> {
+    let (frame_type, between_opt, start, and, end) = window_frame.unbox();
+    parse_tree::window_frame(
+        folder.fold(frame_type),
+        folder.fold(between_opt),
+        folder.fold(start),
+        folder.fold(and),
+        folder.fold(end),
+    )
+}
+
+pub fn fold_unbounded_frame_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    unbounded_frame: parse_tree::UnboundedFrame<'a>,
+) -> ParseTree<'a> {
+    let (unbounded, bound_type) = unbounded_frame.unbox();
+    parse_tree::unbounded_frame(folder.fold(unbounded), folder.fold(bound_type))
+}
+
+pub fn fold_current_row_bound_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    current_row_bound: parse_tree::CurrentRowBound<'a>,
+) -> ParseTree<'a> {
+    let (current, row) = current_row_bound.unbox();
+    parse_tree::current_row_bound(folder.fold(current), folder.fold(row))
+}
+
+pub fn fold_bounded_frame_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    bounded_frame: parse_tree::BoundedFrame<'a>,
+) -> ParseTree<'a> {
+    let (bound, bound_type) = bounded_frame.unbox();
+    parse_tree::bounded_frame(folder.fold(bound), folder.fold(bound_type))
+}
+
+pub fn fold_unicode_string_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    unicode_string: parse_tree::UnicodeString<'a>,
+) -> ParseTree<'a> {
+    let (string, uescape_opt, escape) = unicode_string.unbox();
+    parse_tree::unicode_string(
+        folder.fold(string),
+        folder.fold(uescape_opt),
+        folder.fold(escape),
+    )
+}
+
+pub fn fold_configure_expression_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    configure_expression: parse_tree::ConfigureExpression<'a>,
+) -> ParseTree<'a> {
+    let (configure, open_paren, identifier, comma, value, close_paren) =
+        configure_expression.unbox();
+    parse_tree::configure_expression(
+        folder.fold(configure),
+        folder.fold(open_paren),
+        folder.fold(identifier),
+        folder.fold(comma),
+        folder.fold(value),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_subquery_expression_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    subquery_expression: parse_tree::SubqueryExpression<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, query, close_paren) = subquery_expression.unbox();
+    parse_tree::subquery_expression(
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_grouping_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    grouping: parse_tree::Grouping<'a>,
+) -> ParseTree<'a> {
+    let (grouping, groups) = grouping.unbox();
+    parse_tree::grouping(folder.fold(grouping), folder.fold(groups))
+}
+
+pub fn fold_extract_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    extract: parse_tree::Extract<'a>,
+) -> ParseTree<'a> {
+    let (extract, open_paren, identifier, from, value, close_paren) = extract.unbox();
+    parse_tree::extract(
+        folder.fold(extract),
+        folder.fold(open_paren),
+        folder.fold(identifier),
+        folder.fold(from),
+        folder.fold(value),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_current_time_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    current_time: parse_tree::CurrentTime<'a>,
+) -> ParseTree<'a> {
+    let (current_time, open_paren, precision, close_paren) = current_time.unbox();
+    parse_tree::current_time(
+        folder.fold(current_time),
+        folder.fold(open_paren),
+        folder.fold(precision),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_current_timestamp_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    current_timestamp: parse_tree::CurrentTimestamp<'a>,
+) -> ParseTree<'a> {
+    let (current_timestamp, open_paren, precision, close_paren) = current_timestamp.unbox();
+    parse_tree::current_timestamp(
+        folder.fold(current_timestamp),
+        folder.fold(open_paren),
+        folder.fold(precision),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_normalize_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    normalize: parse_tree::Normalize<'a>,
+) -> ParseTree<'a> {
+    let (normalize, open_paren, value, comma_opt, normal_form, close_paren) = normalize.unbox();
+    parse_tree::normalize(
+        folder.fold(normalize),
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(comma_opt),
+        folder.fold(normal_form),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_localtime_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    localtime: parse_tree::Localtime<'a>,
+) -> ParseTree<'a> {
+    let (localtime, open_paren, precision, close_paren) = localtime.unbox();
+    parse_tree::localtime(
+        folder.fold(localtime),
+        folder.fold(open_paren),
+        folder.fold(precision),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_localtimestamp_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    localtimestamp: parse_tree::Localtimestamp<'a>,
+) -> ParseTree<'a> {
+    let (localtimestamp, open_paren, precision, close_paren) = localtimestamp.unbox();
+    parse_tree::localtimestamp(
+        folder.fold(localtimestamp),
+        folder.fold(open_paren),
+        folder.fold(precision),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_cast_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    cast: parse_tree::Cast<'a>,
+) -> ParseTree<'a> {
+    let (cast, open_paren, value, as_, type_, close_paren) = cast.unbox();
+    parse_tree::cast(
+        folder.fold(cast),
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(as_),
+        folder.fold(type_),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_when_clause_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    when_clause: parse_tree::WhenClause<'a>,
+) -> ParseTree<'a> {
+    let (when, condition, then, result) = when_clause.unbox();
+    parse_tree::when_clause(
+        folder.fold(when),
+        folder.fold(condition),
+        folder.fold(then),
+        folder.fold(result),
+    )
+}
+
+pub fn fold_case_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    case: parse_tree::Case<'a>,
+) -> ParseTree<'a> {
+    let (case, value_opt, when_clauses, else_opt, default, end) = case.unbox();
+    parse_tree::case(
+        folder.fold(case),
+        folder.fold(value_opt),
+        folder.fold(when_clauses),
+        folder.fold(else_opt),
+        folder.fold(default),
+        folder.fold(end),
+    )
+}
+
+pub fn fold_exists_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    exists: parse_tree::Exists<'a>,
+) -> ParseTree<'a> {
+    let (exists, open_paren, query, close_paren) = exists.unbox();
+    parse_tree::exists(
+        folder.fold(exists),
+        folder.fold(open_paren),
+        folder.fold(query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_type_constructor_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    type_constructor: parse_tree::TypeConstructor<'a>,
+) -> ParseTree<'a> {
+    let (type_, value) = type_constructor.unbox();
+    parse_tree::type_constructor(folder.fold(type_), folder.fold(value))
+}
+
+pub fn fold_array_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    array: parse_tree::Array<'a>,
+) -> ParseTree<'a> {
+    let (array, elements) = array.unbox();
+    parse_tree::array(folder.fold(array), folder.fold(elements))
+}
+
+pub fn fold_interval_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    interval: parse_tree::Interval<'a>,
+) -> ParseTree<'a> {
+    let (interval, sign_opt, value, from, to_kw_opt, to) = interval.unbox();
+    parse_tree::interval(
+        folder.fold(interval),
+        folder.fold(sign_opt),
+        folder.fold(value),
+        folder.fold(from),
+        folder.fold(to_kw_opt),
+        folder.fold(to),
+    )
+}
+
+pub fn fold_row_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    row: parse_tree::Row<'a>,
+) -> ParseTree<'a> {
+    let (row, elements) = row.unbox();
+    parse_tree::row(folder.fold(row), folder.fold(elements))
+}
+
+pub fn fold_try_cast_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    try_cast: parse_tree::TryCast<'a>,
+) -> ParseTree<'a> {
+    let (try_cast, open_paren, value, as_, type_, close_paren) = try_cast.unbox();
+    parse_tree::try_cast(
+        folder.fold(try_cast),
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(as_),
+        folder.fold(type_),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_substring_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    substring: parse_tree::Substring<'a>,
+) -> ParseTree<'a> {
+    let (substring, open_paren, value, from, from_value, for_opt, for_value, close_paren) =
+        substring.unbox();
+    parse_tree::substring(
+        folder.fold(substring),
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(from),
+        folder.fold(from_value),
+        folder.fold(for_opt),
+        folder.fold(for_value),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_position_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    position: parse_tree::Position<'a>,
+) -> ParseTree<'a> {
+    let (position, open_paren, value, in_, target, close_paren) = position.unbox();
+    parse_tree::position(
+        folder.fold(position),
+        folder.fold(open_paren),
+        folder.fold(value),
+        folder.fold(in_),
+        folder.fold(target),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_array_type_suffix_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    array_type_suffix: parse_tree::ArrayTypeSuffix<'a>,
+) -> ParseTree<'a> {
+    let (base_type, array) = array_type_suffix.unbox();
+    parse_tree::array_type_suffix(folder.fold(base_type), folder.fold(array))
+}
+
+pub fn fold_named_type_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    named_type: parse_tree::NamedType<'a>,
+) -> ParseTree<'a> {
+    let (name, type_parameters) = named_type.unbox();
+    parse_tree::named_type(folder.fold(name), folder.fold(type_parameters))
+}
+
+pub fn fold_array_type_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    array_type: parse_tree::ArrayType<'a>,
+) -> ParseTree<'a> {
+    let (array, open_angle, element_type, close_angle) = array_type.unbox();
+    parse_tree::array_type(
+        folder.fold(array),
+        folder.fold(open_angle),
+        folder.fold(element_type),
+        folder.fold(close_angle),
+    )
+}
+
+pub fn fold_map_type_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    map_type: parse_tree::MapType<'a>,
+) -> ParseTree<'a> {
+    let (map, open_angle, key_type, comma, value_type, close_angle) = map_type.unbox();
+    parse_tree::map_type(
+        folder.fold(map),
+        folder.fold(open_angle),
+        folder.fold(key_type),
+        folder.fold(comma),
+        folder.fold(value_type),
+        folder.fold(close_angle),
+    )
+}
+
+pub fn fold_row_type_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    row_type: parse_tree::RowType<'a>,
+) -> ParseTree<'a> {
+    let (row, element_types) = row_type.unbox();
+    parse_tree::row_type(folder.fold(row), folder.fold(element_types))
+}
+
+pub fn fold_row_type_element_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    row_type_element: parse_tree::RowTypeElement<'a>,
+) -> ParseTree<'a> {
+    let (identifier, type_) = row_type_element.unbox();
+    parse_tree::row_type_element(folder.fold(identifier), folder.fold(type_))
+}
+
+pub fn fold_interval_type_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    interval_type: parse_tree::IntervalType<'a>,
+) -> ParseTree<'a> {
+    let (interval, from, to_kw, to) = interval_type.unbox();
+    parse_tree::interval_type(
+        folder.fold(interval),
+        folder.fold(from),
+        folder.fold(to_kw),
+        folder.fold(to),
+    )
+}
+
+pub fn fold_if_not_exists_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    if_not_exists: parse_tree::IfNotExists<'a>,
+) -> ParseTree<'a> {
+    let (if_, not, exists) = if_not_exists.unbox();
+    parse_tree::if_not_exists(folder.fold(if_), folder.fold(not), folder.fold(exists))
+}
+
+pub fn fold_create_table_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    create_table: parse_tree::CreateTable<'a>,
+) -> ParseTree<'a> {
+    let (
+        create,
+        table,
+        if_not_exists_opt,
+        table_name,
+        table_elements,
+        comment_opt,
+        with_properties_opt,
+    ) = create_table.unbox();
+    parse_tree::create_table(
+        folder.fold(create),
+        folder.fold(table),
+        folder.fold(if_not_exists_opt),
+        folder.fold(table_name),
+        folder.fold(table_elements),
+        folder.fold(comment_opt),
+        folder.fold(with_properties_opt),
+    )
+}
+
+pub fn fold_create_view_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    create_view: parse_tree::CreateView<'a>,
+) -> ParseTree<'a> {
+    let (create, or_opt, replace_opt, view, qualified_name, as_, query) = create_view.unbox();
+    parse_tree::create_view(
+        folder.fold(create),
+        folder.fold(or_opt),
+        folder.fold(replace_opt),
+        folder.fold(view),
+        folder.fold(qualified_name),
+        folder.fold(as_),
+        folder.fold(query),
+    )
+}
+
+pub fn fold_create_role_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    create_role: parse_tree::CreateRole<'a>,
+) -> ParseTree<'a> {
+    let (create, role, name, with_admin_grantor_opt) = create_role.unbox();
+    parse_tree::create_role(
+        folder.fold(create),
+        folder.fold(role),
+        folder.fold(name),
+        folder.fold(with_admin_grantor_opt),
+    )
+}
+
+pub fn fold_with_admin_grantor_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    with_admin_grantor: parse_tree::WithAdminGrantor<'a>,
+) -> ParseTree<'a> {
+    let (with, admin, grantor) = with_admin_grantor.unbox();
+    parse_tree::with_admin_grantor(folder.fold(with), folder.fold(admin), folder.fold(grantor))
+}
+
+pub fn fold_user_principal_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    user_principal: parse_tree::UserPrincipal<'a>,
+) -> ParseTree<'a> {
+    let (user, identifier) = user_principal.unbox();
+    parse_tree::user_principal(folder.fold(user), folder.fold(identifier))
+}
+
+pub fn fold_role_principal_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    role_principal: parse_tree::RolePrincipal<'a>,
+) -> ParseTree<'a> {
+    let (role, identifier) = role_principal.unbox();
+    parse_tree::role_principal(folder.fold(role), folder.fold(identifier))
+}
+
+pub fn fold_unspecified_principal_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    unspecified_principal: parse_tree::UnspecifiedPrincipal<'a>,
+) -> ParseTree<'a> {
+    let (identifier,) = unspecified_principal.unbox();
+    parse_tree::unspecified_principal(folder.fold(identifier))
+}
+
+pub fn fold_create_table_as_select_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    create_table_as_select: parse_tree::CreateTableAsSelect<'a>,
+) -> ParseTree<'a> {
+    let (
+        create,
+        table,
+        if_not_exists_opt,
+        table_name,
+        column_aliases_opt,
+        comment_opt,
+        with_properties_opt,
+        as_,
+        open_paren_opt,
+        query,
+        close_paren_opt,
+        with_data_opt,
+    ) = create_table_as_select.unbox();
+    parse_tree::create_table_as_select(
+        folder.fold(create),
+        folder.fold(table),
+        folder.fold(if_not_exists_opt),
+        folder.fold(table_name),
+        folder.fold(column_aliases_opt),
+        folder.fold(comment_opt),
+        folder.fold(with_properties_opt),
+        folder.fold(as_),
+        folder.fold(open_paren_opt),
+        folder.fold(query),
+        folder.fold(close_paren_opt),
+        folder.fold(with_data_opt),
+    )
+}
+
+pub fn fold_with_properties_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    with_properties: parse_tree::WithProperties<'a>,
+) -> ParseTree<'a> {
+    let (with, properties) = with_properties.unbox();
+    parse_tree::with_properties(folder.fold(with), folder.fold(properties))
+}
+
+pub fn fold_property_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    property: parse_tree::Property<'a>,
+) -> ParseTree<'a> {
+    let (identifier, eq, value) = property.unbox();
+    parse_tree::property(folder.fold(identifier), folder.fold(eq), folder.fold(value))
+}
+
+pub fn fold_with_data_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    with_data: parse_tree::WithData<'a>,
+) -> ParseTree<'a> {
+    let (with, no_opt, data) = with_data.unbox();
+    parse_tree::with_data(folder.fold(with), folder.fold(no_opt), folder.fold(data))
+}
+
+pub fn fold_comment_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    comment: parse_tree::Comment<'a>,
+) -> ParseTree<'a> {
+    let (comment, value) = comment.unbox();
+    parse_tree::comment(folder.fold(comment), folder.fold(value))
+}
+
+pub fn fold_column_definition_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    column_definition: parse_tree::ColumnDefinition<'a>,
+) -> ParseTree<'a> {
+    let (identifier, type_, not_null_opt, comment_opt, with_properties_opt) =
+        column_definition.unbox();
+    parse_tree::column_definition(
+        folder.fold(identifier),
+        folder.fold(type_),
+        folder.fold(not_null_opt),
+        folder.fold(comment_opt),
+        folder.fold(with_properties_opt),
+    )
+}
+
+pub fn fold_not_null_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    not_null: parse_tree::NotNull<'a>,
+) -> ParseTree<'a> {
+    let (not, null) = not_null.unbox();
+    parse_tree::not_null(folder.fold(not), folder.fold(null))
+}
+
+pub fn fold_like_clause_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    like_clause: parse_tree::LikeClause<'a>,
+) -> ParseTree<'a> {
+    let (like, name, option_type_opt, properties) = like_clause.unbox();
+    parse_tree::like_clause(
+        folder.fold(like),
+        folder.fold(name),
+        folder.fold(option_type_opt),
+        folder.fold(properties),
+    )
+}
+
+pub fn fold_insert_into_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    insert_into: parse_tree::InsertInto<'a>,
+) -> ParseTree<'a> {
+    let (insert, into, table_name, column_aliases_opt, query) = insert_into.unbox();
+    parse_tree::insert_into(
+        folder.fold(insert),
+        folder.fold(into),
+        folder.fold(table_name),
+        folder.fold(column_aliases_opt),
+        folder.fold(query),
+    )
+}
+
+pub fn fold_delete_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    delete: parse_tree::Delete<'a>,
+) -> ParseTree<'a> {
+    let (delete, from, table_name, where_opt, predicate) = delete.unbox();
+    parse_tree::delete(
+        folder.fold(delete),
+        folder.fold(from),
+        folder.fold(table_name),
+        folder.fold(where_opt),
+        folder.fold(predicate),
+    )
+}
+
+pub fn fold_grouping_set_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    grouping_set: parse_tree::GroupingSet<'a>,
+) -> ParseTree<'a> {
+    let (elements,) = grouping_set.unbox();
+    parse_tree::grouping_set(folder.fold(elements))
+}
+
+pub fn fold_relation_or_query_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    relation_or_query: parse_tree::RelationOrQuery<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, query_or_relation, close_paren) = relation_or_query.unbox();
+    parse_tree::relation_or_query(
+        folder.fold(open_paren),
+        folder.fold(query_or_relation),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_empty_grouping_set_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    empty_grouping_set: parse_tree::EmptyGroupingSet<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, close_paren) = empty_grouping_set.unbox();
+    parse_tree::empty_grouping_set(folder.fold(open_paren), folder.fold(close_paren))
+}
+
+pub fn fold_expression_or_query_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    expression_or_query: parse_tree::ExpressionOrQuery<'a>,
+) -> ParseTree<'a> {
+    let (open_paren, expression_or_query, close_paren) = expression_or_query.unbox();
+    parse_tree::expression_or_query(
+        folder.fold(open_paren),
+        folder.fold(expression_or_query),
+        folder.fold(close_paren),
+    )
+}
+
+pub fn fold_entrypoint_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    entrypoint: parse_tree::Entrypoint<'a>,
+) -> ParseTree<'a> {
+    let (beginning_of_file, tree, end_of_file) = entrypoint.unbox();
+    parse_tree::entrypoint(
+        folder.fold(beginning_of_file),
+        folder.fold(tree),
+        folder.fold(end_of_file),
+    )
+}
+
+pub fn fold_null_treatment_children<'a, F: Folder<'a> + ?Sized>(
+    folder: &mut F,
+    null_treatment: parse_tree::NullTreatment<'a>,
+) -> ParseTree<'a> {
+    let (treatment, nulls) = null_treatment.unbox();
+    parse_tree::null_treatment(folder.fold(treatment), folder.fold(nulls))
+}
@@ -0,0 +1,248 @@
+//! Decoding of the values of Literal trees.
+use super::parse_tree::{Literal, UnicodeString};
+use super::ParseTree;
+use crate::lexing::literal::{NumberValue, DEFAULT_UNICODE_ESCAPE};
+use crate::lexing::TokenKind;
+use crate::utils::syntax_error::{ERROR_INVALID_UNICODE_ESCAPE, ERROR_SYNTAX_ERROR};
+use crate::utils::{Message, SyntaxError};
+
+/// The value of a literal.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LiteralValue {
+    Null,
+    Boolean(bool),
+    String(String),
+    Binary(Vec<u8>),
+    Number(NumberValue),
+}
+
+impl<'a> Literal<'a> {
+    /// The decoded value of the literal; see lexing::literal for the
+    /// errors.
+    pub fn decode(&self) -> Result<LiteralValue, SyntaxError> {
+        match &*self.value {
+            ParseTree::UnicodeString(unicode_string) => {
+                unicode_string.decode().map(LiteralValue::String)
+            }
+            ParseTree::Token(token) => {
+                let token = &token.token;
+                match token.kind {
+                    TokenKind::NULL => Ok(LiteralValue::Null),
+                    TokenKind::TRUE => Ok(LiteralValue::Boolean(true)),
+                    TokenKind::FALSE => Ok(LiteralValue::Boolean(false)),
+                    TokenKind::String | TokenKind::UnicodeString => {
+                        token.string_value().map(LiteralValue::String)
+                    }
+                    TokenKind::BinaryLiteral => token.binary_value().map(LiteralValue::Binary),
+                    _ => token.number_value().map(LiteralValue::Number),
+                }
+            }
+            value => Err(SyntaxError::from_message(
+                ERROR_SYNTAX_ERROR,
+                Message::new(
+                    value.get_range(),
+                    format!("Expected a literal, found {}.", value.kind_name()),
+                ),
+            )),
+        }
+    }
+}
+
+impl<'a> UnicodeString<'a> {
+    /// The decoded value of the string, with the UESCAPE character if any.
+    pub fn decode(&self) -> Result<String, SyntaxError> {
+        let escape = self.escape_character()?;
+        match &*self.string {
+            ParseTree::Token(string) => string.token.unicode_string_value(escape),
+            string => Err(SyntaxError::from_message(
+                ERROR_SYNTAX_ERROR,
+                Message::new(string.get_range(), "Expected a Unicode string.".to_string()),
+            )),
+        }
+    }
+
+    /// The UESCAPE character, or the default \. As in Presto, it must be a
+    /// single character other than a hex digit, +, a quote or whitespace.
+    pub fn escape_character(&self) -> Result<char, SyntaxError> {
+        let escape = match &*self.escape {
+            ParseTree::Token(escape) => escape,
+            _ => return Ok(DEFAULT_UNICODE_ESCAPE),
+        };
+        let value = escape.token.string_value()?;
+        let mut chars = value.chars();
+        let message = match (chars.next(), chars.next()) {
+            (Some(ch), None)
+                if !(ch.is_ascii_hexdigit()
+                    || ch == '+'
+                    || ch == '\''
+                    || ch == '"'
+                    || ch.is_whitespace()) =>
+            {
+                return Ok(ch)
+            }
+            (Some(_), None) => {
+                "Unicode escape character cannot be a hexadecimal digit, +, a quote or whitespace."
+            }
+            _ => "Unicode escape character must be a single character.",
+        };
+        Err(SyntaxError::from_message(
+            ERROR_INVALID_UNICODE_ESCAPE,
+            Message::new(escape.token.range, message.to_string()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::{parse_statement, visit_pre_order};
+    use crate::utils::syntax_error::{ERROR_INVALID_BINARY_LITERAL, ERROR_INVALID_NUMERIC_LITERAL};
+    use crate::utils::{Position, TextRange};
+
+    fn decode(expression: &str) -> Result<LiteralValue, SyntaxError> {
+        let text = format!("select {}", expression);
+        let (tree, _) = parse_statement(&text);
+        let mut value = None;
+        visit_pre_order(&tree, &mut |tree| {
+            if tree.is_literal() && value.is_none() {
+                value = Some(tree.as_literal().decode());
+            }
+        });
+        value.expect("a literal")
+    }
+
+    /// The error codes of parsing the statement.
+    fn error_codes(text: &str) -> Vec<i32> {
+        let (_, errors) = parse_statement(text);
+        errors.iter().map(|error| error.error_code).collect()
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(decode("null"), Ok(LiteralValue::Null));
+        assert_eq!(decode("TRUE"), Ok(LiteralValue::Boolean(true)));
+        assert_eq!(
+            decode("'a''b'"),
+            Ok(LiteralValue::String("a'b".to_string()))
+        );
+        assert_eq!(
+            decode("U&'#0041' UESCAPE '#'"),
+            Ok(LiteralValue::String("A".to_string()))
+        );
+        assert_eq!(
+            decode("X'0fAB'"),
+            Ok(LiteralValue::Binary(vec![0x0f, 0xab]))
+        );
+        assert_eq!(
+            decode("42"),
+            Ok(LiteralValue::Number(NumberValue::Integer(42)))
+        );
+    }
+
+    #[test]
+    fn parser_reports_invalid_literals() {
+        assert_eq!(
+            error_codes("select X'0g'"),
+            vec![ERROR_INVALID_BINARY_LITERAL]
+        );
+        assert_eq!(
+            error_codes("select X'abc'"),
+            vec![ERROR_INVALID_BINARY_LITERAL]
+        );
+        assert_eq!(
+            error_codes("select 99999999999999999999"),
+            vec![ERROR_INVALID_NUMERIC_LITERAL]
+        );
+        assert_eq!(
+            error_codes("select 1e400"),
+            vec![ERROR_INVALID_NUMERIC_LITERAL]
+        );
+        assert!(error_codes("select 1.5, 1e10, X'', current_user, current_date").is_empty());
+    }
+
+    fn string(expression: &str) -> String {
+        match decode(expression) {
+            Ok(LiteralValue::String(value)) => value,
+            value => panic!("expected a string, found {:?}", value),
+        }
+    }
+
+    /// The error of parsing the statement, which must have exactly one.
+    fn error(text: &str) -> (i32, TextRange, String) {
+        let (_, errors) = parse_statement(text);
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        let message = &errors[0].messages[0];
+        (errors[0].error_code, message.range, message.message.clone())
+    }
+
+    fn columns(start: i32, end: i32) -> TextRange {
+        TextRange::new(Position::new(0, start), Position::new(0, end))
+    }
+
+    #[test]
+    fn unicode_escapes() {
+        assert_eq!(string(r"U&'\0041\00e9'"), "Aé");
+        assert_eq!(string(r"U&'\+01F600'"), "\u{1F600}");
+        assert_eq!(string(r"U&'a\\b'"), r"a\b");
+        assert_eq!(string(r"U&'it''s'"), "it's");
+    }
+
+    #[test]
+    fn uescape() {
+        assert_eq!(string("U&'#0041' UESCAPE '#'"), "A");
+        assert_eq!(string("U&'#+01F600' UESCAPE '#'"), "\u{1F600}");
+        assert_eq!(string("U&'##' UESCAPE '#'"), "#");
+        // With UESCAPE, \ is an ordinary character.
+        assert_eq!(string(r"U&'\0041' UESCAPE '#'"), r"\0041");
+    }
+
+    #[test]
+    fn invalid_escapes_are_reported_with_their_range() {
+        let (code, range, message) = error(r"select U&'\D800'");
+        assert_eq!(code, ERROR_INVALID_UNICODE_ESCAPE);
+        assert_eq!(range, columns(10, 15));
+        assert_eq!(message, "Invalid Unicode code point U+D800 (a surrogate).");
+
+        let (code, range, message) = error(r"select U&'ab\00'");
+        assert_eq!(code, ERROR_INVALID_UNICODE_ESCAPE);
+        assert_eq!(range, columns(12, 15));
+        assert_eq!(
+            message,
+            "Unicode escape must be followed by 4 hexadecimal digits."
+        );
+
+        let (code, range, message) = error(r"select U&'\+0041'");
+        assert_eq!(code, ERROR_INVALID_UNICODE_ESCAPE);
+        assert_eq!(range, columns(10, 16));
+        assert_eq!(
+            message,
+            "Unicode escape must be followed by + and 6 hexadecimal digits."
+        );
+
+        let (_, _, message) = error(r"select U&'\+110000'");
+        assert_eq!(message, "Invalid Unicode code point U+110000.");
+    }
+
+    #[test]
+    fn invalid_uescape_characters() {
+        for escape in &["a", "0", "+", "''", "\"", " "] {
+            let text = format!("select U&'x' UESCAPE '{}'", escape);
+            let (code, range, message) = error(&text);
+            assert_eq!(code, ERROR_INVALID_UNICODE_ESCAPE, "{}", text);
+            // The range of the escape's string literal.
+            assert_eq!(range, columns(21, text.len() as i32), "{}", text);
+            assert_eq!(
+                message,
+                "Unicode escape character cannot be a hexadecimal digit, +, a quote or whitespace."
+            );
+        }
+        for escape in &["", "##"] {
+            let (code, _, message) = error(&format!("select U&'x' UESCAPE '{}'", escape));
+            assert_eq!(code, ERROR_INVALID_UNICODE_ESCAPE);
+            assert_eq!(
+                message,
+                "Unicode escape character must be a single character."
+            );
+        }
+    }
+}
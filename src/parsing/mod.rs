@@ -1,7 +1,15 @@
+pub mod dump;
+pub mod edit;
+pub mod equality;
+pub mod folder;
+pub mod literal;
 pub mod parse_tree;
+pub mod parse_tree_json;
 pub mod parse_tree_visitor;
 mod parser;
+pub mod visitor;
 
 pub use self::parse_tree::ParseTree;
 pub use self::parse_tree_visitor::{visit_post_order, visit_pre_order};
-pub use self::parser::parse_statement;
+pub use self::parser::{parse_statement, parse_statement_with_options, parse_type, ParseOptions};
+pub use self::visitor::Visitor;
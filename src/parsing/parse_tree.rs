@@ -42,6 +42,10 @@ use crate::utils::{position, syntax_error::SyntaxError, text_range::TextRange};
 ///  as_*() - returns a ref to the typed tree. Must only be called it is_*() is true.
 ///  unbox_*() -> consumes a parse tree, destructuring it into its unboxed components.
 ///  children() -> returns a Vec containing refs to all immediate children of the tree.
+///  children_mut() -> returns a Vec containing mutable refs to all immediate children of the tree.
+///  kind_name() -> returns the name of the tree's kind.
+///  field_names() -> returns the names of the fields of the tree's kind, in the same order as children().
+///                   Empty for the structural kinds.
 ///
 /// This mod also contains a top level factory function for each kind of ParseTree.
 ///
@@ -249,6 +253,17 @@ impl<'a> List<'a> {
         result
     }
 
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        let mut result = Vec::with_capacity(2 + self.elements_and_separators.len() * 2);
+        result.push(&mut *self.start_delimiter);
+        for (element, separator) in &mut self.elements_and_separators {
+            result.push(element);
+            result.push(separator);
+        }
+        result.push(&mut *self.end_delimiter);
+        result
+    }
+
     pub fn get_first_token(&self) -> Option<&token::Token<'a>> {
         self.start_delimiter
             .get_first_token()
@@ -3241,6 +3256,424 @@ impl<'a> ParseTree<'a> {
         }
     }
 
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            ParseTree::Token(_) => "Token",
+            ParseTree::List(_) => "List",
+            ParseTree::Error(_) => "Error",
+            ParseTree::Empty(_) => "Empty",
+            ParseTree::Query(_) => "Query",
+            ParseTree::With(_) => "With",
+            ParseTree::NamedQuery(_) => "NamedQuery",
+            ParseTree::QueryNoWith(_) => "QueryNoWith",
+            ParseTree::OrderBy(_) => "OrderBy",
+            ParseTree::Limit(_) => "Limit",
+            ParseTree::QuerySetOperation(_) => "QuerySetOperation",
+            ParseTree::SortItem(_) => "SortItem",
+            ParseTree::Subquery(_) => "Subquery",
+            ParseTree::InlineTable(_) => "InlineTable",
+            ParseTree::Table(_) => "Table",
+            ParseTree::QuerySpecification(_) => "QuerySpecification",
+            ParseTree::QualifiedName(_) => "QualifiedName",
+            ParseTree::SelectAll(_) => "SelectAll",
+            ParseTree::QualifiedSelectAll(_) => "QualifiedSelectAll",
+            ParseTree::SelectItem(_) => "SelectItem",
+            ParseTree::SubqueryRelation(_) => "SubqueryRelation",
+            ParseTree::ParenthesizedRelation(_) => "ParenthesizedRelation",
+            ParseTree::TableName(_) => "TableName",
+            ParseTree::Lateral(_) => "Lateral",
+            ParseTree::Unnest(_) => "Unnest",
+            ParseTree::SampledRelation(_) => "SampledRelation",
+            ParseTree::AliasedRelation(_) => "AliasedRelation",
+            ParseTree::CrossJoin(_) => "CrossJoin",
+            ParseTree::Join(_) => "Join",
+            ParseTree::NaturalJoin(_) => "NaturalJoin",
+            ParseTree::OuterJoinKind(_) => "OuterJoinKind",
+            ParseTree::OnJoinCriteria(_) => "OnJoinCriteria",
+            ParseTree::UsingJoinCriteria(_) => "UsingJoinCriteria",
+            ParseTree::GroupBy(_) => "GroupBy",
+            ParseTree::Rollup(_) => "Rollup",
+            ParseTree::Cube(_) => "Cube",
+            ParseTree::GroupingSets(_) => "GroupingSets",
+            ParseTree::BinaryExpression(_) => "BinaryExpression",
+            ParseTree::UnaryExpression(_) => "UnaryExpression",
+            ParseTree::QuantifiedComparison(_) => "QuantifiedComparison",
+            ParseTree::NullPredicate(_) => "NullPredicate",
+            ParseTree::DistinctFrom(_) => "DistinctFrom",
+            ParseTree::Between(_) => "Between",
+            ParseTree::Like(_) => "Like",
+            ParseTree::InSubquery(_) => "InSubquery",
+            ParseTree::InList(_) => "InList",
+            ParseTree::AtTimeZone(_) => "AtTimeZone",
+            ParseTree::Dereference(_) => "Dereference",
+            ParseTree::Subscript(_) => "Subscript",
+            ParseTree::Lambda(_) => "Lambda",
+            ParseTree::Literal(_) => "Literal",
+            ParseTree::RowConstructor(_) => "RowConstructor",
+            ParseTree::ParenthesizedExpression(_) => "ParenthesizedExpression",
+            ParseTree::Identifier(_) => "Identifier",
+            ParseTree::FunctionCall(_) => "FunctionCall",
+            ParseTree::Filter(_) => "Filter",
+            ParseTree::Over(_) => "Over",
+            ParseTree::WindowFrame(_) => "WindowFrame",
+            ParseTree::UnboundedFrame(_) => "UnboundedFrame",
+            ParseTree::CurrentRowBound(_) => "CurrentRowBound",
+            ParseTree::BoundedFrame(_) => "BoundedFrame",
+            ParseTree::UnicodeString(_) => "UnicodeString",
+            ParseTree::ConfigureExpression(_) => "ConfigureExpression",
+            ParseTree::SubqueryExpression(_) => "SubqueryExpression",
+            ParseTree::Grouping(_) => "Grouping",
+            ParseTree::Extract(_) => "Extract",
+            ParseTree::CurrentTime(_) => "CurrentTime",
+            ParseTree::CurrentTimestamp(_) => "CurrentTimestamp",
+            ParseTree::Normalize(_) => "Normalize",
+            ParseTree::Localtime(_) => "Localtime",
+            ParseTree::Localtimestamp(_) => "Localtimestamp",
+            ParseTree::Cast(_) => "Cast",
+            ParseTree::WhenClause(_) => "WhenClause",
+            ParseTree::Case(_) => "Case",
+            ParseTree::Exists(_) => "Exists",
+            ParseTree::TypeConstructor(_) => "TypeConstructor",
+            ParseTree::Array(_) => "Array",
+            ParseTree::Interval(_) => "Interval",
+            ParseTree::Row(_) => "Row",
+            ParseTree::TryCast(_) => "TryCast",
+            ParseTree::Substring(_) => "Substring",
+            ParseTree::Position(_) => "Position",
+            ParseTree::ArrayTypeSuffix(_) => "ArrayTypeSuffix",
+            ParseTree::NamedType(_) => "NamedType",
+            ParseTree::ArrayType(_) => "ArrayType",
+            ParseTree::MapType(_) => "MapType",
+            ParseTree::RowType(_) => "RowType",
+            ParseTree::RowTypeElement(_) => "RowTypeElement",
+            ParseTree::IntervalType(_) => "IntervalType",
+            ParseTree::IfNotExists(_) => "IfNotExists",
+            ParseTree::CreateTable(_) => "CreateTable",
+            ParseTree::CreateView(_) => "CreateView",
+            ParseTree::CreateRole(_) => "CreateRole",
+            ParseTree::WithAdminGrantor(_) => "WithAdminGrantor",
+            ParseTree::UserPrincipal(_) => "UserPrincipal",
+            ParseTree::RolePrincipal(_) => "RolePrincipal",
+            ParseTree::UnspecifiedPrincipal(_) => "UnspecifiedPrincipal",
+            ParseTree::CreateTableAsSelect(_) => "CreateTableAsSelect",
+            ParseTree::WithProperties(_) => "WithProperties",
+            ParseTree::Property(_) => "Property",
+            ParseTree::WithData(_) => "WithData",
+            ParseTree::Comment(_) => "Comment",
+            ParseTree::ColumnDefinition(_) => "ColumnDefinition",
+            ParseTree::NotNull(_) => "NotNull",
+            ParseTree::LikeClause(_) => "LikeClause",
+            ParseTree::InsertInto(_) => "InsertInto",
+            ParseTree::Delete(_) => "Delete",
+            ParseTree::GroupingSet(_) => "GroupingSet",
+            ParseTree::RelationOrQuery(_) => "RelationOrQuery",
+            ParseTree::EmptyGroupingSet(_) => "EmptyGroupingSet",
+            ParseTree::ExpressionOrQuery(_) => "ExpressionOrQuery",
+            ParseTree::Entrypoint(_) => "Entrypoint",
+            ParseTree::NullTreatment(_) => "NullTreatment",
+        }
+    }
+
+    pub fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            ParseTree::Token(_)
+            | ParseTree::List(_)
+            | ParseTree::Error(_)
+            | ParseTree::Empty(_) => &[],
+            ParseTree::Query(_) => &["with", "query_no_with"],
+            ParseTree::With(_) => &["with", "recursive", "named_queries"],
+            ParseTree::NamedQuery(_) => &[
+                "name",
+                "column_aliases_opt",
+                "as_",
+                "open_paren",
+                "query",
+                "close_paren",
+            ],
+            ParseTree::QueryNoWith(_) => &["query_term", "order_by_opt", "limit_opt"],
+            ParseTree::OrderBy(_) => &["order", "by", "sort_items"],
+            ParseTree::Limit(_) => &["limit", "value"],
+            ParseTree::QuerySetOperation(_) => &["left", "operator", "set_quantifier_opt", "right"],
+            ParseTree::SortItem(_) => &["expression", "ordering_opt", "nulls", "null_ordering_opt"],
+            ParseTree::Subquery(_) => &["open_paren", "query_no_with", "close_paren"],
+            ParseTree::InlineTable(_) => &["values", "expressions"],
+            ParseTree::Table(_) => &["table", "qualified_name"],
+            ParseTree::QuerySpecification(_) => &[
+                "select",
+                "set_quantifier_opt",
+                "select_items",
+                "from",
+                "relations",
+                "where_",
+                "where_predicate",
+                "group",
+                "by",
+                "group_by",
+                "having",
+                "having_predicate",
+            ],
+            ParseTree::QualifiedName(_) => &["names"],
+            ParseTree::SelectAll(_) => &["asterisk"],
+            ParseTree::QualifiedSelectAll(_) => &["qualifier", "period", "asterisk"],
+            ParseTree::SelectItem(_) => &["expression", "as_", "identifier"],
+            ParseTree::SubqueryRelation(_) => &["open_paren", "query", "close_paren"],
+            ParseTree::ParenthesizedRelation(_) => &["open_paren", "relation", "close_paren"],
+            ParseTree::TableName(_) => &["name"],
+            ParseTree::Lateral(_) => &["lateral", "open_paren", "query", "close_paren"],
+            ParseTree::Unnest(_) => &["unnest", "expressions", "with", "ordinality"],
+            ParseTree::SampledRelation(_) => &[
+                "aliased_relation",
+                "tablesample",
+                "sample_type",
+                "open_paren",
+                "expression",
+                "close_paren",
+            ],
+            ParseTree::AliasedRelation(_) => &[
+                "relation_primary",
+                "as_opt",
+                "identifier",
+                "column_aliases_opt",
+            ],
+            ParseTree::CrossJoin(_) => &["left", "cross", "join", "right"],
+            ParseTree::Join(_) => &["left", "join_type", "join", "right", "join_criteria"],
+            ParseTree::NaturalJoin(_) => &["left", "natural", "join_type", "join", "right"],
+            ParseTree::OuterJoinKind(_) => &["kind", "outer_opt"],
+            ParseTree::OnJoinCriteria(_) => &["on", "predicate"],
+            ParseTree::UsingJoinCriteria(_) => &["using", "names"],
+            ParseTree::GroupBy(_) => &["set_quantifier_opt", "grouping_elements"],
+            ParseTree::Rollup(_) => &["rollup", "expressions"],
+            ParseTree::Cube(_) => &["cube", "expressions"],
+            ParseTree::GroupingSets(_) => &["grouping", "sets", "grouping_sets"],
+            ParseTree::BinaryExpression(_) => &["left", "operator", "right"],
+            ParseTree::UnaryExpression(_) => &["operator", "operand"],
+            ParseTree::QuantifiedComparison(_) => &[
+                "operand",
+                "operator",
+                "comparison_quantifier",
+                "open_paren",
+                "query",
+                "close_paren",
+            ],
+            ParseTree::NullPredicate(_) => &["value", "is", "not_opt", "null"],
+            ParseTree::DistinctFrom(_) => &["left", "distinct", "from", "right"],
+            ParseTree::Between(_) => &["value", "not_opt", "between", "lower", "and", "upper"],
+            ParseTree::Like(_) => &[
+                "value",
+                "not_opt",
+                "like",
+                "patrern",
+                "escape_opt",
+                "escape_value_opt",
+            ],
+            ParseTree::InSubquery(_) => &[
+                "value",
+                "not_opt",
+                "in_",
+                "open_paren",
+                "query",
+                "close_paren",
+            ],
+            ParseTree::InList(_) => &["value", "not_opt", "in_", "expressions"],
+            ParseTree::AtTimeZone(_) => &["value", "at", "time", "zone", "specifier"],
+            ParseTree::Dereference(_) => &["object", "period", "field_name"],
+            ParseTree::Subscript(_) => &["operand", "open_square", "index", "close_square"],
+            ParseTree::Lambda(_) => &["parameters", "array", "body"],
+            ParseTree::Literal(_) => &["value"],
+            ParseTree::RowConstructor(_) => &["elements"],
+            ParseTree::ParenthesizedExpression(_) => &["open_paren", "value", "close_paren"],
+            ParseTree::Identifier(_) => &["value"],
+            ParseTree::FunctionCall(_) => &[
+                "name",
+                "open_paren",
+                "set_quantifier_opt",
+                "arguments",
+                "order_by_opt",
+                "close_paren",
+                "filter_opt",
+                "null_treatment_opt",
+                "over_opt",
+            ],
+            ParseTree::Filter(_) => &["filter", "open_paren", "where_", "predicate", "close_paren"],
+            ParseTree::Over(_) => &[
+                "over",
+                "open_paren",
+                "partition_opt",
+                "by",
+                "partitions",
+                "order_by_opt",
+                "window_frame",
+                "close_paren",
+            ],
+            ParseTree::WindowFrame(_) => &["frame_type", "between_opt", "start", "and", "end"],
+            ParseTree::UnboundedFrame(_) => &["unbounded", "bound_type"],
+            ParseTree::CurrentRowBound(_) => &["current", "row"],
+            ParseTree::BoundedFrame(_) => &["bound", "bound_type"],
+            ParseTree::UnicodeString(_) => &["string", "uescape_opt", "escape"],
+            ParseTree::ConfigureExpression(_) => &[
+                "configure",
+                "open_paren",
+                "identifier",
+                "comma",
+                "value",
+                "close_paren",
+            ],
+            ParseTree::SubqueryExpression(_) => &["open_paren", "query", "close_paren"],
+            ParseTree::Grouping(_) => &["grouping", "groups"],
+            ParseTree::Extract(_) => &[
+                "extract",
+                "open_paren",
+                "identifier",
+                "from",
+                "value",
+                "close_paren",
+            ],
+            ParseTree::CurrentTime(_) => {
+                &["current_time", "open_paren", "precision", "close_paren"]
+            }
+            ParseTree::CurrentTimestamp(_) => &[
+                "current_timestamp",
+                "open_paren",
+                "precision",
+                "close_paren",
+            ],
+            ParseTree::Normalize(_) => &[
+                "normalize",
+                "open_paren",
+                "value",
+                "comma_opt",
+                "normal_form",
+                "close_paren",
+            ],
+            ParseTree::Localtime(_) => &["localtime", "open_paren", "precision", "close_paren"],
+            ParseTree::Localtimestamp(_) => {
+                &["localtimestamp", "open_paren", "precision", "close_paren"]
+            }
+            ParseTree::Cast(_) => &["cast", "open_paren", "value", "as_", "type_", "close_paren"],
+            ParseTree::WhenClause(_) => &["when", "condition", "then", "result"],
+            ParseTree::Case(_) => &[
+                "case",
+                "value_opt",
+                "when_clauses",
+                "else_opt",
+                "default",
+                "end",
+            ],
+            ParseTree::Exists(_) => &["exists", "open_paren", "query", "close_paren"],
+            ParseTree::TypeConstructor(_) => &["type_", "value"],
+            ParseTree::Array(_) => &["array", "elements"],
+            ParseTree::Interval(_) => &["interval", "sign_opt", "value", "from", "to_kw_opt", "to"],
+            ParseTree::Row(_) => &["row", "elements"],
+            ParseTree::TryCast(_) => &[
+                "try_cast",
+                "open_paren",
+                "value",
+                "as_",
+                "type_",
+                "close_paren",
+            ],
+            ParseTree::Substring(_) => &[
+                "substring",
+                "open_paren",
+                "value",
+                "from",
+                "from_value",
+                "for_opt",
+                "for_value",
+                "close_paren",
+            ],
+            ParseTree::Position(_) => &[
+                "position",
+                "open_paren",
+                "value",
+                "in_",
+                "target",
+                "close_paren",
+            ],
+            ParseTree::ArrayTypeSuffix(_) => &["base_type", "array"],
+            ParseTree::NamedType(_) => &["name", "type_parameters"],
+            ParseTree::ArrayType(_) => &["array", "open_angle", "element_type", "close_angle"],
+            ParseTree::MapType(_) => &[
+                "map",
+                "open_angle",
+                "key_type",
+                "comma",
+                "value_type",
+                "close_angle",
+            ],
+            ParseTree::RowType(_) => &["row", "element_types"],
+            ParseTree::RowTypeElement(_) => &["identifier", "type_"],
+            ParseTree::IntervalType(_) => &["interval", "from", "to_kw", "to"],
+            ParseTree::IfNotExists(_) => &["if_", "not", "exists"],
+            ParseTree::CreateTable(_) => &[
+                "create",
+                "table",
+                "if_not_exists_opt",
+                "table_name",
+                "table_elements",
+                "comment_opt",
+                "with_properties_opt",
+            ],
+            ParseTree::CreateView(_) => &[
+                "create",
+                "or_opt",
+                "replace_opt",
+                "view",
+                "qualified_name",
+                "as_",
+                "query",
+            ],
+            ParseTree::CreateRole(_) => &["create", "role", "name", "with_admin_grantor_opt"],
+            ParseTree::WithAdminGrantor(_) => &["with", "admin", "grantor"],
+            ParseTree::UserPrincipal(_) => &["user", "identifier"],
+            ParseTree::RolePrincipal(_) => &["role", "identifier"],
+            ParseTree::UnspecifiedPrincipal(_) => &["identifier"],
+            ParseTree::CreateTableAsSelect(_) => &[
+                "create",
+                "table",
+                "if_not_exists_opt",
+                "table_name",
+                "column_aliases_opt",
+                "comment_opt",
+                "with_properties_opt",
+                "as_",
+                "open_paren_opt",
+                "query",
+                "close_paren_opt",
+                "with_data_opt",
+            ],
+            ParseTree::WithProperties(_) => &["with", "properties"],
+            ParseTree::Property(_) => &["identifier", "eq", "value"],
+            ParseTree::WithData(_) => &["with", "no_opt", "data"],
+            ParseTree::Comment(_) => &["comment", "value"],
+            ParseTree::ColumnDefinition(_) => &[
+                "identifier",
+                "type_",
+                "not_null_opt",
+                "comment_opt",
+                "with_properties_opt",
+            ],
+            ParseTree::NotNull(_) => &["not", "null"],
+            ParseTree::LikeClause(_) => &["like", "name", "option_type_opt", "properties"],
+            ParseTree::InsertInto(_) => &[
+                "insert",
+                "into",
+                "table_name",
+                "column_aliases_opt",
+                "query",
+            ],
+            ParseTree::Delete(_) => &["delete", "from", "table_name", "where_opt", "predicate"],
+            ParseTree::GroupingSet(_) => &["elements"],
+            ParseTree::RelationOrQuery(_) => &["open_paren", "query_or_relation", "close_paren"],
+            ParseTree::EmptyGroupingSet(_) => &["open_paren", "close_paren"],
+            ParseTree::ExpressionOrQuery(_) => {
+                &["open_paren", "expression_or_query", "close_paren"]
+            }
+            ParseTree::Entrypoint(_) => &["beginning_of_file", "tree", "end_of_file"],
+            ParseTree::NullTreatment(_) => &["treatment", "nulls"],
+        }
+    }
+
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
         match self {
             ParseTree::Token(token) => token.children(),
@@ -3369,6 +3802,142 @@ impl<'a> ParseTree<'a> {
         }
     }
 
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        match self {
+            ParseTree::Token(_) => Vec::new(),
+            ParseTree::List(list) => list.children_mut(),
+            ParseTree::Error(_) => Vec::new(),
+            ParseTree::Empty(_) => Vec::new(),
+            ParseTree::Query(query) => query.children_mut(),
+            ParseTree::With(with) => with.children_mut(),
+            ParseTree::NamedQuery(named_query) => named_query.children_mut(),
+            ParseTree::QueryNoWith(query_no_with) => query_no_with.children_mut(),
+            ParseTree::OrderBy(order_by) => order_by.children_mut(),
+            ParseTree::Limit(limit) => limit.children_mut(),
+            ParseTree::QuerySetOperation(query_set_operation) => query_set_operation.children_mut(),
+            ParseTree::SortItem(sort_item) => sort_item.children_mut(),
+            ParseTree::Subquery(subquery) => subquery.children_mut(),
+            ParseTree::InlineTable(inline_table) => inline_table.children_mut(),
+            ParseTree::Table(table) => table.children_mut(),
+            ParseTree::QuerySpecification(query_specification) => {
+                query_specification.children_mut()
+            }
+            ParseTree::QualifiedName(qualified_name) => qualified_name.children_mut(),
+            ParseTree::SelectAll(select_all) => select_all.children_mut(),
+            ParseTree::QualifiedSelectAll(qualified_select_all) => {
+                qualified_select_all.children_mut()
+            }
+            ParseTree::SelectItem(select_item) => select_item.children_mut(),
+            ParseTree::SubqueryRelation(subquery_relation) => subquery_relation.children_mut(),
+            ParseTree::ParenthesizedRelation(parenthesized_relation) => {
+                parenthesized_relation.children_mut()
+            }
+            ParseTree::TableName(table_name) => table_name.children_mut(),
+            ParseTree::Lateral(lateral) => lateral.children_mut(),
+            ParseTree::Unnest(unnest) => unnest.children_mut(),
+            ParseTree::SampledRelation(sampled_relation) => sampled_relation.children_mut(),
+            ParseTree::AliasedRelation(aliased_relation) => aliased_relation.children_mut(),
+            ParseTree::CrossJoin(cross_join) => cross_join.children_mut(),
+            ParseTree::Join(join) => join.children_mut(),
+            ParseTree::NaturalJoin(natural_join) => natural_join.children_mut(),
+            ParseTree::OuterJoinKind(outer_join_kind) => outer_join_kind.children_mut(),
+            ParseTree::OnJoinCriteria(on_join_criteria) => on_join_criteria.children_mut(),
+            ParseTree::UsingJoinCriteria(using_join_criteria) => using_join_criteria.children_mut(),
+            ParseTree::GroupBy(group_by) => group_by.children_mut(),
+            ParseTree::Rollup(rollup) => rollup.children_mut(),
+            ParseTree::Cube(cube) => cube.children_mut(),
+            ParseTree::GroupingSets(grouping_sets) => grouping_sets.children_mut(),
+            ParseTree::BinaryExpression(binary_expression) => binary_expression.children_mut(),
+            ParseTree::UnaryExpression(unary_expression) => unary_expression.children_mut(),
+            ParseTree::QuantifiedComparison(quantified_comparison) => {
+                quantified_comparison.children_mut()
+            }
+            ParseTree::NullPredicate(null_predicate) => null_predicate.children_mut(),
+            ParseTree::DistinctFrom(distinct_from) => distinct_from.children_mut(),
+            ParseTree::Between(between) => between.children_mut(),
+            ParseTree::Like(like) => like.children_mut(),
+            ParseTree::InSubquery(in_subquery) => in_subquery.children_mut(),
+            ParseTree::InList(in_list) => in_list.children_mut(),
+            ParseTree::AtTimeZone(at_time_zone) => at_time_zone.children_mut(),
+            ParseTree::Dereference(dereference) => dereference.children_mut(),
+            ParseTree::Subscript(subscript) => subscript.children_mut(),
+            ParseTree::Lambda(lambda) => lambda.children_mut(),
+            ParseTree::Literal(literal) => literal.children_mut(),
+            ParseTree::RowConstructor(row_constructor) => row_constructor.children_mut(),
+            ParseTree::ParenthesizedExpression(parenthesized_expression) => {
+                parenthesized_expression.children_mut()
+            }
+            ParseTree::Identifier(identifier) => identifier.children_mut(),
+            ParseTree::FunctionCall(function_call) => function_call.children_mut(),
+            ParseTree::Filter(filter) => filter.children_mut(),
+            ParseTree::Over(over) => over.children_mut(),
+            ParseTree::WindowFrame(window_frame) => window_frame.children_mut(),
+            ParseTree::UnboundedFrame(unbounded_frame) => unbounded_frame.children_mut(),
+            ParseTree::CurrentRowBound(current_row_bound) => current_row_bound.children_mut(),
+            ParseTree::BoundedFrame(bounded_frame) => bounded_frame.children_mut(),
+            ParseTree::UnicodeString(unicode_string) => unicode_string.children_mut(),
+            ParseTree::ConfigureExpression(configure_expression) => {
+                configure_expression.children_mut()
+            }
+            ParseTree::SubqueryExpression(subquery_expression) => {
+                subquery_expression.children_mut()
+            }
+            ParseTree::Grouping(grouping) => grouping.children_mut(),
+            ParseTree::Extract(extract) => extract.children_mut(),
+            ParseTree::CurrentTime(current_time) => current_time.children_mut(),
+            ParseTree::CurrentTimestamp(current_timestamp) => current_timestamp.children_mut(),
+            ParseTree::Normalize(normalize) => normalize.children_mut(),
+            ParseTree::Localtime(localtime) => localtime.children_mut(),
+            ParseTree::Localtimestamp(localtimestamp) => localtimestamp.children_mut(),
+            ParseTree::Cast(cast) => cast.children_mut(),
+            ParseTree::WhenClause(when_clause) => when_clause.children_mut(),
+            ParseTree::Case(case) => case.children_mut(),
+            ParseTree::Exists(exists) => exists.children_mut(),
+            ParseTree::TypeConstructor(type_constructor) => type_constructor.children_mut(),
+            ParseTree::Array(array) => array.children_mut(),
+            ParseTree::Interval(interval) => interval.children_mut(),
+            ParseTree::Row(row) => row.children_mut(),
+            ParseTree::TryCast(try_cast) => try_cast.children_mut(),
+            ParseTree::Substring(substring) => substring.children_mut(),
+            ParseTree::Position(position) => position.children_mut(),
+            ParseTree::ArrayTypeSuffix(array_type_suffix) => array_type_suffix.children_mut(),
+            ParseTree::NamedType(named_type) => named_type.children_mut(),
+            ParseTree::ArrayType(array_type) => array_type.children_mut(),
+            ParseTree::MapType(map_type) => map_type.children_mut(),
+            ParseTree::RowType(row_type) => row_type.children_mut(),
+            ParseTree::RowTypeElement(row_type_element) => row_type_element.children_mut(),
+            ParseTree::IntervalType(interval_type) => interval_type.children_mut(),
+            ParseTree::IfNotExists(if_not_exists) => if_not_exists.children_mut(),
+            ParseTree::CreateTable(create_table) => create_table.children_mut(),
+            ParseTree::CreateView(create_view) => create_view.children_mut(),
+            ParseTree::CreateRole(create_role) => create_role.children_mut(),
+            ParseTree::WithAdminGrantor(with_admin_grantor) => with_admin_grantor.children_mut(),
+            ParseTree::UserPrincipal(user_principal) => user_principal.children_mut(),
+            ParseTree::RolePrincipal(role_principal) => role_principal.children_mut(),
+            ParseTree::UnspecifiedPrincipal(unspecified_principal) => {
+                unspecified_principal.children_mut()
+            }
+            ParseTree::CreateTableAsSelect(create_table_as_select) => {
+                create_table_as_select.children_mut()
+            }
+            ParseTree::WithProperties(with_properties) => with_properties.children_mut(),
+            ParseTree::Property(property) => property.children_mut(),
+            ParseTree::WithData(with_data) => with_data.children_mut(),
+            ParseTree::Comment(comment) => comment.children_mut(),
+            ParseTree::ColumnDefinition(column_definition) => column_definition.children_mut(),
+            ParseTree::NotNull(not_null) => not_null.children_mut(),
+            ParseTree::LikeClause(like_clause) => like_clause.children_mut(),
+            ParseTree::InsertInto(insert_into) => insert_into.children_mut(),
+            ParseTree::Delete(delete) => delete.children_mut(),
+            ParseTree::GroupingSet(grouping_set) => grouping_set.children_mut(),
+            ParseTree::RelationOrQuery(relation_or_query) => relation_or_query.children_mut(),
+            ParseTree::EmptyGroupingSet(empty_grouping_set) => empty_grouping_set.children_mut(),
+            ParseTree::ExpressionOrQuery(expression_or_query) => expression_or_query.children_mut(),
+            ParseTree::Entrypoint(entrypoint) => entrypoint.children_mut(),
+            ParseTree::NullTreatment(null_treatment) => null_treatment.children_mut(),
+        }
+    }
+
     pub fn get_first_child(&self) -> &ParseTree<'a> {
         match self {
             ParseTree::Token(_) => self,
@@ -3958,10 +4527,11 @@ impl<'a> Query<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.with);
-        result.push(&*self.query_no_with);
-        result
+        vec![&*self.with, &*self.query_no_with]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.with, &mut *self.query_no_with]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -4021,11 +4591,15 @@ impl<'a> With<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.with);
-        result.push(&*self.recursive);
-        result.push(&*self.named_queries);
-        result
+        vec![&*self.with, &*self.recursive, &*self.named_queries]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.with,
+            &mut *self.recursive,
+            &mut *self.named_queries,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4100,14 +4674,25 @@ impl<'a> NamedQuery<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.name);
-        result.push(&*self.column_aliases_opt);
-        result.push(&*self.as_);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.name,
+            &*self.column_aliases_opt,
+            &*self.as_,
+            &*self.open_paren,
+            &*self.query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.name,
+            &mut *self.column_aliases_opt,
+            &mut *self.as_,
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -4207,11 +4792,15 @@ impl<'a> QueryNoWith<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.query_term);
-        result.push(&*self.order_by_opt);
-        result.push(&*self.limit_opt);
-        result
+        vec![&*self.query_term, &*self.order_by_opt, &*self.limit_opt]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.query_term,
+            &mut *self.order_by_opt,
+            &mut *self.limit_opt,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4277,11 +4866,11 @@ impl<'a> OrderBy<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.order);
-        result.push(&*self.by);
-        result.push(&*self.sort_items);
-        result
+        vec![&*self.order, &*self.by, &*self.sort_items]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.order, &mut *self.by, &mut *self.sort_items]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4341,10 +4930,11 @@ impl<'a> Limit<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.limit);
-        result.push(&*self.value);
-        result
+        vec![&*self.limit, &*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.limit, &mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -4407,12 +4997,21 @@ impl<'a> QuerySetOperation<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.left);
-        result.push(&*self.operator);
-        result.push(&*self.set_quantifier_opt);
-        result.push(&*self.right);
-        result
+        vec![
+            &*self.left,
+            &*self.operator,
+            &*self.set_quantifier_opt,
+            &*self.right,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.left,
+            &mut *self.operator,
+            &mut *self.set_quantifier_opt,
+            &mut *self.right,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4492,12 +5091,21 @@ impl<'a> SortItem<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.expression);
-        result.push(&*self.ordering_opt);
-        result.push(&*self.nulls);
-        result.push(&*self.null_ordering_opt);
-        result
+        vec![
+            &*self.expression,
+            &*self.ordering_opt,
+            &*self.nulls,
+            &*self.null_ordering_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.expression,
+            &mut *self.ordering_opt,
+            &mut *self.nulls,
+            &mut *self.null_ordering_opt,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4574,11 +5182,15 @@ impl<'a> Subquery<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.query_no_with);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.query_no_with, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.query_no_with,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -4638,10 +5250,11 @@ impl<'a> InlineTable<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.values);
-        result.push(&*self.expressions);
-        result
+        vec![&*self.values, &*self.expressions]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.values, &mut *self.expressions]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -4695,10 +5308,11 @@ impl<'a> Table<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.table);
-        result.push(&*self.qualified_name);
-        result
+        vec![&*self.table, &*self.qualified_name]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.table, &mut *self.qualified_name]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -4785,20 +5399,37 @@ impl<'a> QuerySpecification<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(12);
-        result.push(&*self.select);
-        result.push(&*self.set_quantifier_opt);
-        result.push(&*self.select_items);
-        result.push(&*self.from);
-        result.push(&*self.relations);
-        result.push(&*self.where_);
-        result.push(&*self.where_predicate);
-        result.push(&*self.group);
-        result.push(&*self.by);
-        result.push(&*self.group_by);
-        result.push(&*self.having);
-        result.push(&*self.having_predicate);
-        result
+        vec![
+            &*self.select,
+            &*self.set_quantifier_opt,
+            &*self.select_items,
+            &*self.from,
+            &*self.relations,
+            &*self.where_,
+            &*self.where_predicate,
+            &*self.group,
+            &*self.by,
+            &*self.group_by,
+            &*self.having,
+            &*self.having_predicate,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.select,
+            &mut *self.set_quantifier_opt,
+            &mut *self.select_items,
+            &mut *self.from,
+            &mut *self.relations,
+            &mut *self.where_,
+            &mut *self.where_predicate,
+            &mut *self.group,
+            &mut *self.by,
+            &mut *self.group_by,
+            &mut *self.having,
+            &mut *self.having_predicate,
+        ]
     }
 
     pub fn unbox(
@@ -4938,9 +5569,11 @@ impl<'a> QualifiedName<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.names);
-        result
+        vec![&*self.names]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.names]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -4986,9 +5619,11 @@ impl<'a> SelectAll<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.asterisk);
-        result
+        vec![&*self.asterisk]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.asterisk]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -5042,11 +5677,11 @@ impl<'a> QualifiedSelectAll<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.qualifier);
-        result.push(&*self.period);
-        result.push(&*self.asterisk);
-        result
+        vec![&*self.qualifier, &*self.period, &*self.asterisk]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.qualifier, &mut *self.period, &mut *self.asterisk]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5112,11 +5747,11 @@ impl<'a> SelectItem<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.expression);
-        result.push(&*self.as_);
-        result.push(&*self.identifier);
-        result
+        vec![&*self.expression, &*self.as_, &*self.identifier]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.expression, &mut *self.as_, &mut *self.identifier]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5182,11 +5817,15 @@ impl<'a> SubqueryRelation<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.query, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5252,11 +5891,15 @@ impl<'a> ParenthesizedRelation<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.relation);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.relation, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.relation,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5314,9 +5957,11 @@ impl<'a> TableName<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.name);
-        result
+        vec![&*self.name]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.name]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -5373,12 +6018,21 @@ impl<'a> Lateral<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.lateral);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.lateral,
+            &*self.open_paren,
+            &*self.query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.lateral,
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5458,12 +6112,21 @@ impl<'a> Unnest<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.unnest);
-        result.push(&*self.expressions);
-        result.push(&*self.with);
-        result.push(&*self.ordinality);
-        result
+        vec![
+            &*self.unnest,
+            &*self.expressions,
+            &*self.with,
+            &*self.ordinality,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.unnest,
+            &mut *self.expressions,
+            &mut *self.with,
+            &mut *self.ordinality,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5549,14 +6212,25 @@ impl<'a> SampledRelation<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.aliased_relation);
-        result.push(&*self.tablesample);
-        result.push(&*self.sample_type);
-        result.push(&*self.open_paren);
-        result.push(&*self.expression);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.aliased_relation,
+            &*self.tablesample,
+            &*self.sample_type,
+            &*self.open_paren,
+            &*self.expression,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.aliased_relation,
+            &mut *self.tablesample,
+            &mut *self.sample_type,
+            &mut *self.open_paren,
+            &mut *self.expression,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -5659,12 +6333,21 @@ impl<'a> AliasedRelation<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.relation_primary);
-        result.push(&*self.as_opt);
-        result.push(&*self.identifier);
-        result.push(&*self.column_aliases_opt);
-        result
+        vec![
+            &*self.relation_primary,
+            &*self.as_opt,
+            &*self.identifier,
+            &*self.column_aliases_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.relation_primary,
+            &mut *self.as_opt,
+            &mut *self.identifier,
+            &mut *self.column_aliases_opt,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5744,12 +6427,16 @@ impl<'a> CrossJoin<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.left);
-        result.push(&*self.cross);
-        result.push(&*self.join);
-        result.push(&*self.right);
-        result
+        vec![&*self.left, &*self.cross, &*self.join, &*self.right]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.left,
+            &mut *self.cross,
+            &mut *self.join,
+            &mut *self.right,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -5827,13 +6514,23 @@ impl<'a> Join<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.left);
-        result.push(&*self.join_type);
-        result.push(&*self.join);
-        result.push(&*self.right);
-        result.push(&*self.join_criteria);
-        result
+        vec![
+            &*self.left,
+            &*self.join_type,
+            &*self.join,
+            &*self.right,
+            &*self.join_criteria,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.left,
+            &mut *self.join_type,
+            &mut *self.join,
+            &mut *self.right,
+            &mut *self.join_criteria,
+        ]
     }
 
     pub fn unbox(
@@ -5931,13 +6628,23 @@ impl<'a> NaturalJoin<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.left);
-        result.push(&*self.natural);
-        result.push(&*self.join_type);
-        result.push(&*self.join);
-        result.push(&*self.right);
-        result
+        vec![
+            &*self.left,
+            &*self.natural,
+            &*self.join_type,
+            &*self.join,
+            &*self.right,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.left,
+            &mut *self.natural,
+            &mut *self.join_type,
+            &mut *self.join,
+            &mut *self.right,
+        ]
     }
 
     pub fn unbox(
@@ -6023,10 +6730,11 @@ impl<'a> OuterJoinKind<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.kind);
-        result.push(&*self.outer_opt);
-        result
+        vec![&*self.kind, &*self.outer_opt]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.kind, &mut *self.outer_opt]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6080,10 +6788,11 @@ impl<'a> OnJoinCriteria<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.on);
-        result.push(&*self.predicate);
-        result
+        vec![&*self.on, &*self.predicate]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.on, &mut *self.predicate]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6137,10 +6846,11 @@ impl<'a> UsingJoinCriteria<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.using);
-        result.push(&*self.names);
-        result
+        vec![&*self.using, &*self.names]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.using, &mut *self.names]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6197,10 +6907,11 @@ impl<'a> GroupBy<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.set_quantifier_opt);
-        result.push(&*self.grouping_elements);
-        result
+        vec![&*self.set_quantifier_opt, &*self.grouping_elements]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.set_quantifier_opt, &mut *self.grouping_elements]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6254,10 +6965,11 @@ impl<'a> Rollup<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.rollup);
-        result.push(&*self.expressions);
-        result
+        vec![&*self.rollup, &*self.expressions]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.rollup, &mut *self.expressions]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6311,10 +7023,11 @@ impl<'a> Cube<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.cube);
-        result.push(&*self.expressions);
-        result
+        vec![&*self.cube, &*self.expressions]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.cube, &mut *self.expressions]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6374,11 +7087,15 @@ impl<'a> GroupingSets<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.grouping);
-        result.push(&*self.sets);
-        result.push(&*self.grouping_sets);
-        result
+        vec![&*self.grouping, &*self.sets, &*self.grouping_sets]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.grouping,
+            &mut *self.sets,
+            &mut *self.grouping_sets,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -6444,11 +7161,11 @@ impl<'a> BinaryExpression<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.left);
-        result.push(&*self.operator);
-        result.push(&*self.right);
-        result
+        vec![&*self.left, &*self.operator, &*self.right]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.left, &mut *self.operator, &mut *self.right]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -6508,10 +7225,11 @@ impl<'a> UnaryExpression<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.operator);
-        result.push(&*self.operand);
-        result
+        vec![&*self.operator, &*self.operand]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.operator, &mut *self.operand]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -6580,14 +7298,25 @@ impl<'a> QuantifiedComparison<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.operand);
-        result.push(&*self.operator);
-        result.push(&*self.comparison_quantifier);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.operand,
+            &*self.operator,
+            &*self.comparison_quantifier,
+            &*self.open_paren,
+            &*self.query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.operand,
+            &mut *self.operator,
+            &mut *self.comparison_quantifier,
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -6690,12 +7419,16 @@ impl<'a> NullPredicate<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.value);
-        result.push(&*self.is);
-        result.push(&*self.not_opt);
-        result.push(&*self.null);
-        result
+        vec![&*self.value, &*self.is, &*self.not_opt, &*self.null]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.is,
+            &mut *self.not_opt,
+            &mut *self.null,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -6770,12 +7503,16 @@ impl<'a> DistinctFrom<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.left);
-        result.push(&*self.distinct);
-        result.push(&*self.from);
-        result.push(&*self.right);
-        result
+        vec![&*self.left, &*self.distinct, &*self.from, &*self.right]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.left,
+            &mut *self.distinct,
+            &mut *self.from,
+            &mut *self.right,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -6856,14 +7593,25 @@ impl<'a> Between<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.value);
-        result.push(&*self.not_opt);
-        result.push(&*self.between);
-        result.push(&*self.lower);
-        result.push(&*self.and);
-        result.push(&*self.upper);
-        result
+        vec![
+            &*self.value,
+            &*self.not_opt,
+            &*self.between,
+            &*self.lower,
+            &*self.and,
+            &*self.upper,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.not_opt,
+            &mut *self.between,
+            &mut *self.lower,
+            &mut *self.and,
+            &mut *self.upper,
+        ]
     }
 
     pub fn unbox(
@@ -6972,14 +7720,25 @@ impl<'a> Like<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.value);
-        result.push(&*self.not_opt);
-        result.push(&*self.like);
-        result.push(&*self.patrern);
-        result.push(&*self.escape_opt);
-        result.push(&*self.escape_value_opt);
-        result
+        vec![
+            &*self.value,
+            &*self.not_opt,
+            &*self.like,
+            &*self.patrern,
+            &*self.escape_opt,
+            &*self.escape_value_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.not_opt,
+            &mut *self.like,
+            &mut *self.patrern,
+            &mut *self.escape_opt,
+            &mut *self.escape_value_opt,
+        ]
     }
 
     pub fn unbox(
@@ -7088,14 +7847,25 @@ impl<'a> InSubquery<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.value);
-        result.push(&*self.not_opt);
-        result.push(&*self.in_);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.value,
+            &*self.not_opt,
+            &*self.in_,
+            &*self.open_paren,
+            &*self.query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.not_opt,
+            &mut *self.in_,
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -7198,12 +7968,16 @@ impl<'a> InList<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.value);
-        result.push(&*self.not_opt);
-        result.push(&*self.in_);
-        result.push(&*self.expressions);
-        result
+        vec![&*self.value, &*self.not_opt, &*self.in_, &*self.expressions]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.not_opt,
+            &mut *self.in_,
+            &mut *self.expressions,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -7281,13 +8055,23 @@ impl<'a> AtTimeZone<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.value);
-        result.push(&*self.at);
-        result.push(&*self.time);
-        result.push(&*self.zone);
-        result.push(&*self.specifier);
-        result
+        vec![
+            &*self.value,
+            &*self.at,
+            &*self.time,
+            &*self.zone,
+            &*self.specifier,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.value,
+            &mut *self.at,
+            &mut *self.time,
+            &mut *self.zone,
+            &mut *self.specifier,
+        ]
     }
 
     pub fn unbox(
@@ -7379,11 +8163,11 @@ impl<'a> Dereference<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.object);
-        result.push(&*self.period);
-        result.push(&*self.field_name);
-        result
+        vec![&*self.object, &*self.period, &*self.field_name]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.object, &mut *self.period, &mut *self.field_name]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -7452,12 +8236,21 @@ impl<'a> Subscript<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.operand);
-        result.push(&*self.open_square);
-        result.push(&*self.index);
-        result.push(&*self.close_square);
-        result
+        vec![
+            &*self.operand,
+            &*self.open_square,
+            &*self.index,
+            &*self.close_square,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.operand,
+            &mut *self.open_square,
+            &mut *self.index,
+            &mut *self.close_square,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -7534,11 +8327,11 @@ impl<'a> Lambda<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.parameters);
-        result.push(&*self.array);
-        result.push(&*self.body);
-        result
+        vec![&*self.parameters, &*self.array, &*self.body]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.parameters, &mut *self.array, &mut *self.body]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -7596,9 +8389,11 @@ impl<'a> Literal<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.value);
-        result
+        vec![&*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -7644,9 +8439,11 @@ impl<'a> RowConstructor<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.elements);
-        result
+        vec![&*self.elements]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.elements]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -7700,11 +8497,15 @@ impl<'a> ParenthesizedExpression<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.value, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -7762,9 +8563,11 @@ impl<'a> Identifier<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.value);
-        result
+        vec![&*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -7836,17 +8639,31 @@ impl<'a> FunctionCall<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(9);
-        result.push(&*self.name);
-        result.push(&*self.open_paren);
-        result.push(&*self.set_quantifier_opt);
-        result.push(&*self.arguments);
-        result.push(&*self.order_by_opt);
-        result.push(&*self.close_paren);
-        result.push(&*self.filter_opt);
-        result.push(&*self.null_treatment_opt);
-        result.push(&*self.over_opt);
-        result
+        vec![
+            &*self.name,
+            &*self.open_paren,
+            &*self.set_quantifier_opt,
+            &*self.arguments,
+            &*self.order_by_opt,
+            &*self.close_paren,
+            &*self.filter_opt,
+            &*self.null_treatment_opt,
+            &*self.over_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.name,
+            &mut *self.open_paren,
+            &mut *self.set_quantifier_opt,
+            &mut *self.arguments,
+            &mut *self.order_by_opt,
+            &mut *self.close_paren,
+            &mut *self.filter_opt,
+            &mut *self.null_treatment_opt,
+            &mut *self.over_opt,
+        ]
     }
 
     pub fn unbox(
@@ -7976,13 +8793,23 @@ impl<'a> Filter<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.filter);
-        result.push(&*self.open_paren);
-        result.push(&*self.where_);
-        result.push(&*self.predicate);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.filter,
+            &*self.open_paren,
+            &*self.where_,
+            &*self.predicate,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.filter,
+            &mut *self.open_paren,
+            &mut *self.where_,
+            &mut *self.predicate,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -8089,16 +8916,29 @@ impl<'a> Over<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(8);
-        result.push(&*self.over);
-        result.push(&*self.open_paren);
-        result.push(&*self.partition_opt);
-        result.push(&*self.by);
-        result.push(&*self.partitions);
-        result.push(&*self.order_by_opt);
-        result.push(&*self.window_frame);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.over,
+            &*self.open_paren,
+            &*self.partition_opt,
+            &*self.by,
+            &*self.partitions,
+            &*self.order_by_opt,
+            &*self.window_frame,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.over,
+            &mut *self.open_paren,
+            &mut *self.partition_opt,
+            &mut *self.by,
+            &mut *self.partitions,
+            &mut *self.order_by_opt,
+            &mut *self.window_frame,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -8220,13 +9060,23 @@ impl<'a> WindowFrame<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.frame_type);
-        result.push(&*self.between_opt);
-        result.push(&*self.start);
-        result.push(&*self.and);
-        result.push(&*self.end);
-        result
+        vec![
+            &*self.frame_type,
+            &*self.between_opt,
+            &*self.start,
+            &*self.and,
+            &*self.end,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.frame_type,
+            &mut *self.between_opt,
+            &mut *self.start,
+            &mut *self.and,
+            &mut *self.end,
+        ]
     }
 
     pub fn unbox(
@@ -8312,10 +9162,11 @@ impl<'a> UnboundedFrame<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.unbounded);
-        result.push(&*self.bound_type);
-        result
+        vec![&*self.unbounded, &*self.bound_type]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.unbounded, &mut *self.bound_type]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -8369,10 +9220,11 @@ impl<'a> CurrentRowBound<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.current);
-        result.push(&*self.row);
-        result
+        vec![&*self.current, &*self.row]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.current, &mut *self.row]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -8426,10 +9278,11 @@ impl<'a> BoundedFrame<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.bound);
-        result.push(&*self.bound_type);
-        result
+        vec![&*self.bound, &*self.bound_type]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.bound, &mut *self.bound_type]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -8489,11 +9342,11 @@ impl<'a> UnicodeString<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.string);
-        result.push(&*self.uescape_opt);
-        result.push(&*self.escape);
-        result
+        vec![&*self.string, &*self.uescape_opt, &*self.escape]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.string, &mut *self.uescape_opt, &mut *self.escape]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -8568,14 +9421,25 @@ impl<'a> ConfigureExpression<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.configure);
-        result.push(&*self.open_paren);
-        result.push(&*self.identifier);
-        result.push(&*self.comma);
-        result.push(&*self.value);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.configure,
+            &*self.open_paren,
+            &*self.identifier,
+            &*self.comma,
+            &*self.value,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.configure,
+            &mut *self.open_paren,
+            &mut *self.identifier,
+            &mut *self.comma,
+            &mut *self.value,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -8675,11 +9539,15 @@ impl<'a> SubqueryExpression<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.query, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -8739,10 +9607,11 @@ impl<'a> Grouping<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.grouping);
-        result.push(&*self.groups);
-        result
+        vec![&*self.grouping, &*self.groups]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.grouping, &mut *self.groups]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -8811,14 +9680,25 @@ impl<'a> Extract<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.extract);
-        result.push(&*self.open_paren);
-        result.push(&*self.identifier);
-        result.push(&*self.from);
-        result.push(&*self.value);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.extract,
+            &*self.open_paren,
+            &*self.identifier,
+            &*self.from,
+            &*self.value,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.extract,
+            &mut *self.open_paren,
+            &mut *self.identifier,
+            &mut *self.from,
+            &mut *self.value,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -8921,12 +9801,21 @@ impl<'a> CurrentTime<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.current_time);
-        result.push(&*self.open_paren);
-        result.push(&*self.precision);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.current_time,
+            &*self.open_paren,
+            &*self.precision,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.current_time,
+            &mut *self.open_paren,
+            &mut *self.precision,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9006,12 +9895,21 @@ impl<'a> CurrentTimestamp<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.current_timestamp);
-        result.push(&*self.open_paren);
-        result.push(&*self.precision);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.current_timestamp,
+            &*self.open_paren,
+            &*self.precision,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.current_timestamp,
+            &mut *self.open_paren,
+            &mut *self.precision,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9097,14 +9995,25 @@ impl<'a> Normalize<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.normalize);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.comma_opt);
-        result.push(&*self.normal_form);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.normalize,
+            &*self.open_paren,
+            &*self.value,
+            &*self.comma_opt,
+            &*self.normal_form,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.normalize,
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.comma_opt,
+            &mut *self.normal_form,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -9207,12 +10116,21 @@ impl<'a> Localtime<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.localtime);
-        result.push(&*self.open_paren);
-        result.push(&*self.precision);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.localtime,
+            &*self.open_paren,
+            &*self.precision,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.localtime,
+            &mut *self.open_paren,
+            &mut *self.precision,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9292,12 +10210,21 @@ impl<'a> Localtimestamp<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.localtimestamp);
-        result.push(&*self.open_paren);
-        result.push(&*self.precision);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.localtimestamp,
+            &*self.open_paren,
+            &*self.precision,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.localtimestamp,
+            &mut *self.open_paren,
+            &mut *self.precision,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9383,14 +10310,25 @@ impl<'a> Cast<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.cast);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.as_);
-        result.push(&*self.type_);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.cast,
+            &*self.open_paren,
+            &*self.value,
+            &*self.as_,
+            &*self.type_,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.cast,
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.as_,
+            &mut *self.type_,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -9493,12 +10431,16 @@ impl<'a> WhenClause<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.when);
-        result.push(&*self.condition);
-        result.push(&*self.then);
-        result.push(&*self.result);
-        result
+        vec![&*self.when, &*self.condition, &*self.then, &*self.result]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.when,
+            &mut *self.condition,
+            &mut *self.then,
+            &mut *self.result,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9579,14 +10521,25 @@ impl<'a> Case<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.case);
-        result.push(&*self.value_opt);
-        result.push(&*self.when_clauses);
-        result.push(&*self.else_opt);
-        result.push(&*self.default);
-        result.push(&*self.end);
-        result
+        vec![
+            &*self.case,
+            &*self.value_opt,
+            &*self.when_clauses,
+            &*self.else_opt,
+            &*self.default,
+            &*self.end,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.case,
+            &mut *self.value_opt,
+            &mut *self.when_clauses,
+            &mut *self.else_opt,
+            &mut *self.default,
+            &mut *self.end,
+        ]
     }
 
     pub fn unbox(
@@ -9689,12 +10642,21 @@ impl<'a> Exists<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.exists);
-        result.push(&*self.open_paren);
-        result.push(&*self.query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.exists,
+            &*self.open_paren,
+            &*self.query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.exists,
+            &mut *self.open_paren,
+            &mut *self.query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -9765,10 +10727,11 @@ impl<'a> TypeConstructor<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.type_);
-        result.push(&*self.value);
-        result
+        vec![&*self.type_, &*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.type_, &mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -9822,10 +10785,11 @@ impl<'a> Array<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.array);
-        result.push(&*self.elements);
-        result
+        vec![&*self.array, &*self.elements]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.array, &mut *self.elements]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -9894,14 +10858,25 @@ impl<'a> Interval<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.interval);
-        result.push(&*self.sign_opt);
-        result.push(&*self.value);
-        result.push(&*self.from);
-        result.push(&*self.to_kw_opt);
-        result.push(&*self.to);
-        result
+        vec![
+            &*self.interval,
+            &*self.sign_opt,
+            &*self.value,
+            &*self.from,
+            &*self.to_kw_opt,
+            &*self.to,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.interval,
+            &mut *self.sign_opt,
+            &mut *self.value,
+            &mut *self.from,
+            &mut *self.to_kw_opt,
+            &mut *self.to,
+        ]
     }
 
     pub fn unbox(
@@ -9995,10 +10970,11 @@ impl<'a> Row<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.row);
-        result.push(&*self.elements);
-        result
+        vec![&*self.row, &*self.elements]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.row, &mut *self.elements]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -10067,14 +11043,25 @@ impl<'a> TryCast<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.try_cast);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.as_);
-        result.push(&*self.type_);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.try_cast,
+            &*self.open_paren,
+            &*self.value,
+            &*self.as_,
+            &*self.type_,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.try_cast,
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.as_,
+            &mut *self.type_,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -10189,16 +11176,29 @@ impl<'a> Substring<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(8);
-        result.push(&*self.substring);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.from);
-        result.push(&*self.from_value);
-        result.push(&*self.for_opt);
-        result.push(&*self.for_value);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.substring,
+            &*self.open_paren,
+            &*self.value,
+            &*self.from,
+            &*self.from_value,
+            &*self.for_opt,
+            &*self.for_value,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.substring,
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.from,
+            &mut *self.from_value,
+            &mut *self.for_opt,
+            &mut *self.for_value,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -10323,14 +11323,25 @@ impl<'a> Position<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.position);
-        result.push(&*self.open_paren);
-        result.push(&*self.value);
-        result.push(&*self.in_);
-        result.push(&*self.target);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.position,
+            &*self.open_paren,
+            &*self.value,
+            &*self.in_,
+            &*self.target,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.position,
+            &mut *self.open_paren,
+            &mut *self.value,
+            &mut *self.in_,
+            &mut *self.target,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(
@@ -10424,10 +11435,11 @@ impl<'a> ArrayTypeSuffix<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.base_type);
-        result.push(&*self.array);
-        result
+        vec![&*self.base_type, &*self.array]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.base_type, &mut *self.array]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -10481,10 +11493,11 @@ impl<'a> NamedType<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.name);
-        result.push(&*self.type_parameters);
-        result
+        vec![&*self.name, &*self.type_parameters]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.name, &mut *self.type_parameters]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -10547,12 +11560,21 @@ impl<'a> ArrayType<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.array);
-        result.push(&*self.open_angle);
-        result.push(&*self.element_type);
-        result.push(&*self.close_angle);
-        result
+        vec![
+            &*self.array,
+            &*self.open_angle,
+            &*self.element_type,
+            &*self.close_angle,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.array,
+            &mut *self.open_angle,
+            &mut *self.element_type,
+            &mut *self.close_angle,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -10638,14 +11660,25 @@ impl<'a> MapType<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(6);
-        result.push(&*self.map);
-        result.push(&*self.open_angle);
-        result.push(&*self.key_type);
-        result.push(&*self.comma);
-        result.push(&*self.value_type);
-        result.push(&*self.close_angle);
-        result
+        vec![
+            &*self.map,
+            &*self.open_angle,
+            &*self.key_type,
+            &*self.comma,
+            &*self.value_type,
+            &*self.close_angle,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.map,
+            &mut *self.open_angle,
+            &mut *self.key_type,
+            &mut *self.comma,
+            &mut *self.value_type,
+            &mut *self.close_angle,
+        ]
     }
 
     pub fn unbox(
@@ -10739,10 +11772,11 @@ impl<'a> RowType<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.row);
-        result.push(&*self.element_types);
-        result
+        vec![&*self.row, &*self.element_types]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.row, &mut *self.element_types]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -10796,10 +11830,11 @@ impl<'a> RowTypeElement<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.identifier);
-        result.push(&*self.type_);
-        result
+        vec![&*self.identifier, &*self.type_]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.identifier, &mut *self.type_]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -10862,12 +11897,16 @@ impl<'a> IntervalType<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.interval);
-        result.push(&*self.from);
-        result.push(&*self.to_kw);
-        result.push(&*self.to);
-        result
+        vec![&*self.interval, &*self.from, &*self.to_kw, &*self.to]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.interval,
+            &mut *self.from,
+            &mut *self.to_kw,
+            &mut *self.to,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -10939,11 +11978,11 @@ impl<'a> IfNotExists<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.if_);
-        result.push(&*self.not);
-        result.push(&*self.exists);
-        result
+        vec![&*self.if_, &*self.not, &*self.exists]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.if_, &mut *self.not, &mut *self.exists]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -11021,15 +12060,27 @@ impl<'a> CreateTable<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(7);
-        result.push(&*self.create);
-        result.push(&*self.table);
-        result.push(&*self.if_not_exists_opt);
-        result.push(&*self.table_name);
-        result.push(&*self.table_elements);
-        result.push(&*self.comment_opt);
-        result.push(&*self.with_properties_opt);
-        result
+        vec![
+            &*self.create,
+            &*self.table,
+            &*self.if_not_exists_opt,
+            &*self.table_name,
+            &*self.table_elements,
+            &*self.comment_opt,
+            &*self.with_properties_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.create,
+            &mut *self.table,
+            &mut *self.if_not_exists_opt,
+            &mut *self.table_name,
+            &mut *self.table_elements,
+            &mut *self.comment_opt,
+            &mut *self.with_properties_opt,
+        ]
     }
 
     pub fn unbox(
@@ -11149,15 +12200,27 @@ impl<'a> CreateView<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(7);
-        result.push(&*self.create);
-        result.push(&*self.or_opt);
-        result.push(&*self.replace_opt);
-        result.push(&*self.view);
-        result.push(&*self.qualified_name);
-        result.push(&*self.as_);
-        result.push(&*self.query);
-        result
+        vec![
+            &*self.create,
+            &*self.or_opt,
+            &*self.replace_opt,
+            &*self.view,
+            &*self.qualified_name,
+            &*self.as_,
+            &*self.query,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.create,
+            &mut *self.or_opt,
+            &mut *self.replace_opt,
+            &mut *self.view,
+            &mut *self.qualified_name,
+            &mut *self.as_,
+            &mut *self.query,
+        ]
     }
 
     pub fn unbox(
@@ -11268,12 +12331,21 @@ impl<'a> CreateRole<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.create);
-        result.push(&*self.role);
-        result.push(&*self.name);
-        result.push(&*self.with_admin_grantor_opt);
-        result
+        vec![
+            &*self.create,
+            &*self.role,
+            &*self.name,
+            &*self.with_admin_grantor_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.create,
+            &mut *self.role,
+            &mut *self.name,
+            &mut *self.with_admin_grantor_opt,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -11350,11 +12422,11 @@ impl<'a> WithAdminGrantor<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.with);
-        result.push(&*self.admin);
-        result.push(&*self.grantor);
-        result
+        vec![&*self.with, &*self.admin, &*self.grantor]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.with, &mut *self.admin, &mut *self.grantor]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -11414,10 +12486,11 @@ impl<'a> UserPrincipal<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.user);
-        result.push(&*self.identifier);
-        result
+        vec![&*self.user, &*self.identifier]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.user, &mut *self.identifier]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -11471,10 +12544,11 @@ impl<'a> RolePrincipal<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.role);
-        result.push(&*self.identifier);
-        result
+        vec![&*self.role, &*self.identifier]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.role, &mut *self.identifier]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -11526,9 +12600,11 @@ impl<'a> UnspecifiedPrincipal<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.identifier);
-        result
+        vec![&*self.identifier]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.identifier]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -11609,20 +12685,37 @@ impl<'a> CreateTableAsSelect<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(12);
-        result.push(&*self.create);
-        result.push(&*self.table);
-        result.push(&*self.if_not_exists_opt);
-        result.push(&*self.table_name);
-        result.push(&*self.column_aliases_opt);
-        result.push(&*self.comment_opt);
-        result.push(&*self.with_properties_opt);
-        result.push(&*self.as_);
-        result.push(&*self.open_paren_opt);
-        result.push(&*self.query);
-        result.push(&*self.close_paren_opt);
-        result.push(&*self.with_data_opt);
-        result
+        vec![
+            &*self.create,
+            &*self.table,
+            &*self.if_not_exists_opt,
+            &*self.table_name,
+            &*self.column_aliases_opt,
+            &*self.comment_opt,
+            &*self.with_properties_opt,
+            &*self.as_,
+            &*self.open_paren_opt,
+            &*self.query,
+            &*self.close_paren_opt,
+            &*self.with_data_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.create,
+            &mut *self.table,
+            &mut *self.if_not_exists_opt,
+            &mut *self.table_name,
+            &mut *self.column_aliases_opt,
+            &mut *self.comment_opt,
+            &mut *self.with_properties_opt,
+            &mut *self.as_,
+            &mut *self.open_paren_opt,
+            &mut *self.query,
+            &mut *self.close_paren_opt,
+            &mut *self.with_data_opt,
+        ]
     }
 
     pub fn unbox(
@@ -11764,10 +12857,11 @@ impl<'a> WithProperties<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.with);
-        result.push(&*self.properties);
-        result
+        vec![&*self.with, &*self.properties]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.with, &mut *self.properties]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -11827,11 +12921,11 @@ impl<'a> Property<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.identifier);
-        result.push(&*self.eq);
-        result.push(&*self.value);
-        result
+        vec![&*self.identifier, &*self.eq, &*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.identifier, &mut *self.eq, &mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -11897,11 +12991,11 @@ impl<'a> WithData<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.with);
-        result.push(&*self.no_opt);
-        result.push(&*self.data);
-        result
+        vec![&*self.with, &*self.no_opt, &*self.data]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.with, &mut *self.no_opt, &mut *self.data]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -11961,10 +13055,11 @@ impl<'a> Comment<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.comment);
-        result.push(&*self.value);
-        result
+        vec![&*self.comment, &*self.value]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.comment, &mut *self.value]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -12030,13 +13125,23 @@ impl<'a> ColumnDefinition<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.identifier);
-        result.push(&*self.type_);
-        result.push(&*self.not_null_opt);
-        result.push(&*self.comment_opt);
-        result.push(&*self.with_properties_opt);
-        result
+        vec![
+            &*self.identifier,
+            &*self.type_,
+            &*self.not_null_opt,
+            &*self.comment_opt,
+            &*self.with_properties_opt,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.identifier,
+            &mut *self.type_,
+            &mut *self.not_null_opt,
+            &mut *self.comment_opt,
+            &mut *self.with_properties_opt,
+        ]
     }
 
     pub fn unbox(
@@ -12122,10 +13227,11 @@ impl<'a> NotNull<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.not);
-        result.push(&*self.null);
-        result
+        vec![&*self.not, &*self.null]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.not, &mut *self.null]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -12188,12 +13294,21 @@ impl<'a> LikeClause<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(4);
-        result.push(&*self.like);
-        result.push(&*self.name);
-        result.push(&*self.option_type_opt);
-        result.push(&*self.properties);
-        result
+        vec![
+            &*self.like,
+            &*self.name,
+            &*self.option_type_opt,
+            &*self.properties,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.like,
+            &mut *self.name,
+            &mut *self.option_type_opt,
+            &mut *self.properties,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -12276,13 +13391,23 @@ impl<'a> InsertInto<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.insert);
-        result.push(&*self.into);
-        result.push(&*self.table_name);
-        result.push(&*self.column_aliases_opt);
-        result.push(&*self.query);
-        result
+        vec![
+            &*self.insert,
+            &*self.into,
+            &*self.table_name,
+            &*self.column_aliases_opt,
+            &*self.query,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.insert,
+            &mut *self.into,
+            &mut *self.table_name,
+            &mut *self.column_aliases_opt,
+            &mut *self.query,
+        ]
     }
 
     pub fn unbox(
@@ -12380,13 +13505,23 @@ impl<'a> Delete<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(5);
-        result.push(&*self.delete);
-        result.push(&*self.from);
-        result.push(&*self.table_name);
-        result.push(&*self.where_opt);
-        result.push(&*self.predicate);
-        result
+        vec![
+            &*self.delete,
+            &*self.from,
+            &*self.table_name,
+            &*self.where_opt,
+            &*self.predicate,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.delete,
+            &mut *self.from,
+            &mut *self.table_name,
+            &mut *self.where_opt,
+            &mut *self.predicate,
+        ]
     }
 
     pub fn unbox(
@@ -12470,9 +13605,11 @@ impl<'a> GroupingSet<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(1);
-        result.push(&*self.elements);
-        result
+        vec![&*self.elements]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.elements]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>,) {
@@ -12526,11 +13663,19 @@ impl<'a> RelationOrQuery<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.query_or_relation);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.open_paren,
+            &*self.query_or_relation,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.query_or_relation,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -12593,10 +13738,11 @@ impl<'a> EmptyGroupingSet<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.open_paren);
-        result.push(&*self.close_paren);
-        result
+        vec![&*self.open_paren, &*self.close_paren]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.open_paren, &mut *self.close_paren]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
@@ -12656,11 +13802,19 @@ impl<'a> ExpressionOrQuery<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.open_paren);
-        result.push(&*self.expression_or_query);
-        result.push(&*self.close_paren);
-        result
+        vec![
+            &*self.open_paren,
+            &*self.expression_or_query,
+            &*self.close_paren,
+        ]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.open_paren,
+            &mut *self.expression_or_query,
+            &mut *self.close_paren,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -12730,11 +13884,15 @@ impl<'a> Entrypoint<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(3);
-        result.push(&*self.beginning_of_file);
-        result.push(&*self.tree);
-        result.push(&*self.end_of_file);
-        result
+        vec![&*self.beginning_of_file, &*self.tree, &*self.end_of_file]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![
+            &mut *self.beginning_of_file,
+            &mut *self.tree,
+            &mut *self.end_of_file,
+        ]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>, ParseTree<'a>) {
@@ -12794,10 +13952,11 @@ impl<'a> NullTreatment<'a> {
     }
 
     pub fn children(&self) -> Vec<&ParseTree<'a>> {
-        let mut result = Vec::with_capacity(2);
-        result.push(&*self.treatment);
-        result.push(&*self.nulls);
-        result
+        vec![&*self.treatment, &*self.nulls]
+    }
+
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        vec![&mut *self.treatment, &mut *self.nulls]
     }
 
     pub fn unbox(self) -> (ParseTree<'a>, ParseTree<'a>) {
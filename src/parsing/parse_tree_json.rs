@@ -0,0 +1,1178 @@
+//! Generated by tools/generate_parse_trees. Do not edit.
+//!
+//! JSON serialization of parse trees.
+//!
+//! The schema is versioned by SCHEMA_VERSION. Adding tree kinds or fields
+//! does not change the version; renaming or removing them does.
+//!
+//! parse result:
+//!   {"schema_version": number, "tree": tree, "errors": [error]}
+//!
+//! tree is one of:
+//!   null
+//!     An Empty tree.
+//!   {"kind": "Token", "token_kind": string, "range": range, "value": string,
+//!    "leading_comments": [comment], "trailing_comments": [comment], "errors": [error]}
+//!     leading_comments, trailing_comments and errors are omitted when empty.
+//!     value is the raw text of the token.
+//!   {"kind": "List", "range": range, "start_delimiter": tree,
+//!    "elements": [tree], "separators": [tree], "end_delimiter": tree}
+//!     separators has the same length as elements; the last separator is
+//!     typically null.
+//!   {"kind": "Error", "error": error}
+//!   {"kind": string, "range": range, "fields": {string: tree, ...}}
+//!     All other kinds. kind is the ParseTree variant name, and fields has a
+//!     member for every field of the kind, in source order.
+//!
+//! range:   {"start": position, "end": position}
+//! position: {"line": number, "column": number}
+//!   Lines and columns are 0 based. Columns count chars, not bytes.
+//! comment: {"kind": "LineComment" | "DelimitedComment", "range": range, "value": string}
+//! error:   {"error_code": number, "messages": [{"range": range, "message": string}]}
+use super::parse_tree::{List, ParseTree};
+use crate::utils::{Json, SyntaxError};
+
+pub const SCHEMA_VERSION: i32 = 1;
+
+/// Serializes a parse tree and the errors from parsing it.
+pub fn parse_result_to_json(tree: &ParseTree, errors: &[SyntaxError]) -> Json {
+    Json::object(vec![
+        ("schema_version", SCHEMA_VERSION.into()),
+        ("tree", to_json(tree)),
+        (
+            "errors",
+            Json::Array(errors.iter().map(SyntaxError::to_json).collect()),
+        ),
+    ])
+}
+
+fn list_to_json(tree: &ParseTree, list: &List) -> Json {
+    Json::object(vec![
+        ("kind", "List".into()),
+        ("range", tree.get_range().to_json()),
+        ("start_delimiter", to_json(&list.start_delimiter)),
+        (
+            "elements",
+            Json::Array(
+                list.elements_and_separators
+                    .iter()
+                    .map(|(element, _)| to_json(element))
+                    .collect(),
+            ),
+        ),
+        (
+            "separators",
+            Json::Array(
+                list.elements_and_separators
+                    .iter()
+                    .map(|(_, separator)| to_json(separator))
+                    .collect(),
+            ),
+        ),
+        ("end_delimiter", to_json(&list.end_delimiter)),
+    ])
+}
+
+fn tree_to_json(tree: &ParseTree, fields: Vec<(&str, Json)>) -> Json {
+    Json::object(vec![
+        ("kind", tree.kind_name().into()),
+        ("range", tree.get_range().to_json()),
+        ("fields", Json::object(fields)),
+    ])
+}
+
+/// Serializes a parse tree.
+pub fn to_json(tree: &ParseTree) -> Json {
+    match tree {
+        ParseTree::Empty(_) => Json::Null,
+        ParseTree::Token(token) => {
+            let mut result = Json::object(vec![("kind", "Token".into())]);
+            if let Json::Object(members) = token.token.to_json() {
+                for (name, value) in members {
+                    result.push(&name, value);
+                }
+            }
+            result
+        }
+        ParseTree::List(list) => list_to_json(tree, list),
+        ParseTree::Error(error) => Json::object(vec![
+            ("kind", "Error".into()),
+            ("error", error.error.to_json()),
+        ]),
+        ParseTree::Query(query) => tree_to_json(
+            tree,
+            vec![
+                ("with", to_json(&query.with)),
+                ("query_no_with", to_json(&query.query_no_with)),
+            ],
+        ),
+        ParseTree::With(with) => tree_to_json(
+            tree,
+            vec![
+                ("with", to_json(&with.with)),
+                ("recursive", to_json(&with.recursive)),
+                ("named_queries", to_json(&with.named_queries)),
+            ],
+        ),
+        ParseTree::NamedQuery(named_query) => tree_to_json(
+            tree,
+            vec![
+                ("name", to_json(&named_query.name)),
+                (
+                    "column_aliases_opt",
+                    to_json(&named_query.column_aliases_opt),
+                ),
+                ("as_", to_json(&named_query.as_)),
+                ("open_paren", to_json(&named_query.open_paren)),
+                ("query", to_json(&named_query.query)),
+                ("close_paren", to_json(&named_query.close_paren)),
+            ],
+        ),
+        ParseTree::QueryNoWith(query_no_with) => tree_to_json(
+            tree,
+            vec![
+                ("query_term", to_json(&query_no_with.query_term)),
+                ("order_by_opt", to_json(&query_no_with.order_by_opt)),
+                ("limit_opt", to_json(&query_no_with.limit_opt)),
+            ],
+        ),
+        ParseTree::OrderBy(order_by) => tree_to_json(
+            tree,
+            vec![
+                ("order", to_json(&order_by.order)),
+                ("by", to_json(&order_by.by)),
+                ("sort_items", to_json(&order_by.sort_items)),
+            ],
+        ),
+        ParseTree::Limit(limit) => tree_to_json(
+            tree,
+            vec![
+                ("limit", to_json(&limit.limit)),
+                ("value", to_json(&limit.value)),
+            ],
+        ),
+        ParseTree::QuerySetOperation(query_set_operation) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&query_set_operation.left)),
+                ("operator", to_json(&query_set_operation.operator)),
+                (
+                    "set_quantifier_opt",
+                    to_json(&query_set_operation.set_quantifier_opt),
+                ),
+                ("right", to_json(&query_set_operation.right)),
+            ],
+        ),
+        ParseTree::SortItem(sort_item) => tree_to_json(
+            tree,
+            vec![
+                ("expression", to_json(&sort_item.expression)),
+                ("ordering_opt", to_json(&sort_item.ordering_opt)),
+                ("nulls", to_json(&sort_item.nulls)),
+                ("null_ordering_opt", to_json(&sort_item.null_ordering_opt)),
+            ],
+        ),
+        ParseTree::Subquery(subquery) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&subquery.open_paren)),
+                ("query_no_with", to_json(&subquery.query_no_with)),
+                ("close_paren", to_json(&subquery.close_paren)),
+            ],
+        ),
+        ParseTree::InlineTable(inline_table) => tree_to_json(
+            tree,
+            vec![
+                ("values", to_json(&inline_table.values)),
+                ("expressions", to_json(&inline_table.expressions)),
+            ],
+        ),
+        ParseTree::Table(table) => tree_to_json(
+            tree,
+            vec![
+                ("table", to_json(&table.table)),
+                ("qualified_name", to_json(&table.qualified_name)),
+            ],
+        ),
+        ParseTree::QuerySpecification(query_specification) => tree_to_json(
+            tree,
+            vec![
+                ("select", to_json(&query_specification.select)),
+                (
+                    "set_quantifier_opt",
+                    to_json(&query_specification.set_quantifier_opt),
+                ),
+                ("select_items", to_json(&query_specification.select_items)),
+                ("from", to_json(&query_specification.from)),
+                ("relations", to_json(&query_specification.relations)),
+                ("where_", to_json(&query_specification.where_)),
+                (
+                    "where_predicate",
+                    to_json(&query_specification.where_predicate),
+                ),
+                ("group", to_json(&query_specification.group)),
+                ("by", to_json(&query_specification.by)),
+                ("group_by", to_json(&query_specification.group_by)),
+                ("having", to_json(&query_specification.having)),
+                (
+                    "having_predicate",
+                    to_json(&query_specification.having_predicate),
+                ),
+            ],
+        ),
+        ParseTree::QualifiedName(qualified_name) => {
+            tree_to_json(tree, vec![("names", to_json(&qualified_name.names))])
+        }
+        ParseTree::SelectAll(select_all) => {
+            tree_to_json(tree, vec![("asterisk", to_json(&select_all.asterisk))])
+        }
+        ParseTree::QualifiedSelectAll(qualified_select_all) => tree_to_json(
+            tree,
+            vec![
+                ("qualifier", to_json(&qualified_select_all.qualifier)),
+                ("period", to_json(&qualified_select_all.period)),
+                ("asterisk", to_json(&qualified_select_all.asterisk)),
+            ],
+        ),
+        ParseTree::SelectItem(select_item) => tree_to_json(
+            tree,
+            vec![
+                ("expression", to_json(&select_item.expression)),
+                ("as_", to_json(&select_item.as_)),
+                ("identifier", to_json(&select_item.identifier)),
+            ],
+        ),
+        ParseTree::SubqueryRelation(subquery_relation) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&subquery_relation.open_paren)),
+                ("query", to_json(&subquery_relation.query)),
+                ("close_paren", to_json(&subquery_relation.close_paren)),
+            ],
+        ),
+        ParseTree::ParenthesizedRelation(parenthesized_relation) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&parenthesized_relation.open_paren)),
+                ("relation", to_json(&parenthesized_relation.relation)),
+                ("close_paren", to_json(&parenthesized_relation.close_paren)),
+            ],
+        ),
+        ParseTree::TableName(table_name) => {
+            tree_to_json(tree, vec![("name", to_json(&table_name.name))])
+        }
+        ParseTree::Lateral(lateral) => tree_to_json(
+            tree,
+            vec![
+                ("lateral", to_json(&lateral.lateral)),
+                ("open_paren", to_json(&lateral.open_paren)),
+                ("query", to_json(&lateral.query)),
+                ("close_paren", to_json(&lateral.close_paren)),
+            ],
+        ),
+        ParseTree::Unnest(unnest) => tree_to_json(
+            tree,
+            vec![
+                ("unnest", to_json(&unnest.unnest)),
+                ("expressions", to_json(&unnest.expressions)),
+                ("with", to_json(&unnest.with)),
+                ("ordinality", to_json(&unnest.ordinality)),
+            ],
+        ),
+        ParseTree::SampledRelation(sampled_relation) => tree_to_json(
+            tree,
+            vec![
+                (
+                    "aliased_relation",
+                    to_json(&sampled_relation.aliased_relation),
+                ),
+                ("tablesample", to_json(&sampled_relation.tablesample)),
+                ("sample_type", to_json(&sampled_relation.sample_type)),
+                ("open_paren", to_json(&sampled_relation.open_paren)),
+                ("expression", to_json(&sampled_relation.expression)),
+                ("close_paren", to_json(&sampled_relation.close_paren)),
+            ],
+        ),
+        ParseTree::AliasedRelation(aliased_relation) => tree_to_json(
+            tree,
+            vec![
+                (
+                    "relation_primary",
+                    to_json(&aliased_relation.relation_primary),
+                ),
+                ("as_opt", to_json(&aliased_relation.as_opt)),
+                ("identifier", to_json(&aliased_relation.identifier)),
+                (
+                    "column_aliases_opt",
+                    to_json(&aliased_relation.column_aliases_opt),
+                ),
+            ],
+        ),
+        ParseTree::CrossJoin(cross_join) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&cross_join.left)),
+                ("cross", to_json(&cross_join.cross)),
+                ("join", to_json(&cross_join.join)),
+                ("right", to_json(&cross_join.right)),
+            ],
+        ),
+        ParseTree::Join(join) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&join.left)),
+                ("join_type", to_json(&join.join_type)),
+                ("join", to_json(&join.join)),
+                ("right", to_json(&join.right)),
+                ("join_criteria", to_json(&join.join_criteria)),
+            ],
+        ),
+        ParseTree::NaturalJoin(natural_join) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&natural_join.left)),
+                ("natural", to_json(&natural_join.natural)),
+                ("join_type", to_json(&natural_join.join_type)),
+                ("join", to_json(&natural_join.join)),
+                ("right", to_json(&natural_join.right)),
+            ],
+        ),
+        ParseTree::OuterJoinKind(outer_join_kind) => tree_to_json(
+            tree,
+            vec![
+                ("kind", to_json(&outer_join_kind.kind)),
+                ("outer_opt", to_json(&outer_join_kind.outer_opt)),
+            ],
+        ),
+        ParseTree::OnJoinCriteria(on_join_criteria) => tree_to_json(
+            tree,
+            vec![
+                ("on", to_json(&on_join_criteria.on)),
+                ("predicate", to_json(&on_join_criteria.predicate)),
+            ],
+        ),
+        ParseTree::UsingJoinCriteria(using_join_criteria) => tree_to_json(
+            tree,
+            vec![
+                ("using", to_json(&using_join_criteria.using)),
+                ("names", to_json(&using_join_criteria.names)),
+            ],
+        ),
+        ParseTree::GroupBy(group_by) => tree_to_json(
+            tree,
+            vec![
+                ("set_quantifier_opt", to_json(&group_by.set_quantifier_opt)),
+                ("grouping_elements", to_json(&group_by.grouping_elements)),
+            ],
+        ),
+        ParseTree::Rollup(rollup) => tree_to_json(
+            tree,
+            vec![
+                ("rollup", to_json(&rollup.rollup)),
+                ("expressions", to_json(&rollup.expressions)),
+            ],
+        ),
+        ParseTree::Cube(cube) => tree_to_json(
+            tree,
+            vec![
+                ("cube", to_json(&cube.cube)),
+                ("expressions", to_json(&cube.expressions)),
+            ],
+        ),
+        ParseTree::GroupingSets(grouping_sets) => tree_to_json(
+            tree,
+            vec![
+                ("grouping", to_json(&grouping_sets.grouping)),
+                ("sets", to_json(&grouping_sets.sets)),
+                ("grouping_sets", to_json(&grouping_sets.grouping_sets)),
+            ],
+        ),
+        ParseTree::BinaryExpression(binary_expression) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&binary_expression.left)),
+                ("operator", to_json(&binary_expression.operator)),
+                ("right", to_json(&binary_expression.right)),
+            ],
+        ),
+        ParseTree::UnaryExpression(unary_expression) => tree_to_json(
+            tree,
+            vec![
+                ("operator", to_json(&unary_expression.operator)),
+                ("operand", to_json(&unary_expression.operand)),
+            ],
+        ),
+        ParseTree::QuantifiedComparison(quantified_comparison) => tree_to_json(
+            tree,
+            vec![
+                ("operand", to_json(&quantified_comparison.operand)),
+                ("operator", to_json(&quantified_comparison.operator)),
+                (
+                    "comparison_quantifier",
+                    to_json(&quantified_comparison.comparison_quantifier),
+                ),
+                ("open_paren", to_json(&quantified_comparison.open_paren)),
+                ("query", to_json(&quantified_comparison.query)),
+                ("close_paren", to_json(&quantified_comparison.close_paren)),
+            ],
+        ),
+        ParseTree::NullPredicate(null_predicate) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&null_predicate.value)),
+                ("is", to_json(&null_predicate.is)),
+                ("not_opt", to_json(&null_predicate.not_opt)),
+                ("null", to_json(&null_predicate.null)),
+            ],
+        ),
+        ParseTree::DistinctFrom(distinct_from) => tree_to_json(
+            tree,
+            vec![
+                ("left", to_json(&distinct_from.left)),
+                ("distinct", to_json(&distinct_from.distinct)),
+                ("from", to_json(&distinct_from.from)),
+                ("right", to_json(&distinct_from.right)),
+            ],
+        ),
+        ParseTree::Between(between) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&between.value)),
+                ("not_opt", to_json(&between.not_opt)),
+                ("between", to_json(&between.between)),
+                ("lower", to_json(&between.lower)),
+                ("and", to_json(&between.and)),
+                ("upper", to_json(&between.upper)),
+            ],
+        ),
+        ParseTree::Like(like) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&like.value)),
+                ("not_opt", to_json(&like.not_opt)),
+                ("like", to_json(&like.like)),
+                ("patrern", to_json(&like.patrern)),
+                ("escape_opt", to_json(&like.escape_opt)),
+                ("escape_value_opt", to_json(&like.escape_value_opt)),
+            ],
+        ),
+        ParseTree::InSubquery(in_subquery) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&in_subquery.value)),
+                ("not_opt", to_json(&in_subquery.not_opt)),
+                ("in_", to_json(&in_subquery.in_)),
+                ("open_paren", to_json(&in_subquery.open_paren)),
+                ("query", to_json(&in_subquery.query)),
+                ("close_paren", to_json(&in_subquery.close_paren)),
+            ],
+        ),
+        ParseTree::InList(in_list) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&in_list.value)),
+                ("not_opt", to_json(&in_list.not_opt)),
+                ("in_", to_json(&in_list.in_)),
+                ("expressions", to_json(&in_list.expressions)),
+            ],
+        ),
+        ParseTree::AtTimeZone(at_time_zone) => tree_to_json(
+            tree,
+            vec![
+                ("value", to_json(&at_time_zone.value)),
+                ("at", to_json(&at_time_zone.at)),
+                ("time", to_json(&at_time_zone.time)),
+                ("zone", to_json(&at_time_zone.zone)),
+                ("specifier", to_json(&at_time_zone.specifier)),
+            ],
+        ),
+        ParseTree::Dereference(dereference) => tree_to_json(
+            tree,
+            vec![
+                ("object", to_json(&dereference.object)),
+                ("period", to_json(&dereference.period)),
+                ("field_name", to_json(&dereference.field_name)),
+            ],
+        ),
+        ParseTree::Subscript(subscript) => tree_to_json(
+            tree,
+            vec![
+                ("operand", to_json(&subscript.operand)),
+                ("open_square", to_json(&subscript.open_square)),
+                ("index", to_json(&subscript.index)),
+                ("close_square", to_json(&subscript.close_square)),
+            ],
+        ),
+        ParseTree::Lambda(lambda) => tree_to_json(
+            tree,
+            vec![
+                ("parameters", to_json(&lambda.parameters)),
+                ("array", to_json(&lambda.array)),
+                ("body", to_json(&lambda.body)),
+            ],
+        ),
+        ParseTree::Literal(literal) => tree_to_json(tree, vec![("value", to_json(&literal.value))]),
+        ParseTree::RowConstructor(row_constructor) => {
+            tree_to_json(tree, vec![("elements", to_json(&row_constructor.elements))])
+        }
+        ParseTree::ParenthesizedExpression(parenthesized_expression) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&parenthesized_expression.open_paren)),
+                ("value", to_json(&parenthesized_expression.value)),
+                (
+                    "close_paren",
+                    to_json(&parenthesized_expression.close_paren),
+                ),
+            ],
+        ),
+        ParseTree::Identifier(identifier) => {
+            tree_to_json(tree, vec![("value", to_json(&identifier.value))])
+        }
+        ParseTree::FunctionCall(function_call) => tree_to_json(
+            tree,
+            vec![
+                ("name", to_json(&function_call.name)),
+                ("open_paren", to_json(&function_call.open_paren)),
+                (
+                    "set_quantifier_opt",
+                    to_json(&function_call.set_quantifier_opt),
+                ),
+                ("arguments", to_json(&function_call.arguments)),
+                ("order_by_opt", to_json(&function_call.order_by_opt)),
+                ("close_paren", to_json(&function_call.close_paren)),
+                ("filter_opt", to_json(&function_call.filter_opt)),
+                (
+                    "null_treatment_opt",
+                    to_json(&function_call.null_treatment_opt),
+                ),
+                ("over_opt", to_json(&function_call.over_opt)),
+            ],
+        ),
+        ParseTree::Filter(filter) => tree_to_json(
+            tree,
+            vec![
+                ("filter", to_json(&filter.filter)),
+                ("open_paren", to_json(&filter.open_paren)),
+                ("where_", to_json(&filter.where_)),
+                ("predicate", to_json(&filter.predicate)),
+                ("close_paren", to_json(&filter.close_paren)),
+            ],
+        ),
+        ParseTree::Over(over) => tree_to_json(
+            tree,
+            vec![
+                ("over", to_json(&over.over)),
+                ("open_paren", to_json(&over.open_paren)),
+                ("partition_opt", to_json(&over.partition_opt)),
+                ("by", to_json(&over.by)),
+                ("partitions", to_json(&over.partitions)),
+                ("order_by_opt", to_json(&over.order_by_opt)),
+                ("window_frame", to_json(&over.window_frame)),
+                ("close_paren", to_json(&over.close_paren)),
+            ],
+        ),
+        ParseTree::WindowFrame(window_frame) => tree_to_json(
+            tree,
+            vec![
+                ("frame_type", to_json(&window_frame.frame_type)),
+                ("between_opt", to_json(&window_frame.between_opt)),
+                ("start", to_json(&window_frame.start)),
+                ("and", to_json(&window_frame.and)),
+                ("end", to_json(&window_frame.end)),
+            ],
+        ),
+        ParseTree::UnboundedFrame(unbounded_frame) => tree_to_json(
+            tree,
+            vec![
+                ("unbounded", to_json(&unbounded_frame.unbounded)),
+                ("bound_type", to_json(&unbounded_frame.bound_type)),
+            ],
+        ),
+        ParseTree::CurrentRowBound(current_row_bound) => tree_to_json(
+            tree,
+            vec![
+                ("current", to_json(&current_row_bound.current)),
+                ("row", to_json(&current_row_bound.row)),
+            ],
+        ),
+        ParseTree::BoundedFrame(bounded_frame) => tree_to_json(
+            tree,
+            vec![
+                ("bound", to_json(&bounded_frame.bound)),
+                ("bound_type", to_json(&bounded_frame.bound_type)),
+            ],
+        ),
+        ParseTree::UnicodeString(unicode_string) => tree_to_json(
+            tree,
+            vec![
+                ("string", to_json(&unicode_string.string)),
+                ("uescape_opt", to_json(&unicode_string.uescape_opt)),
+                ("escape", to_json(&unicode_string.escape)),
+            ],
+        ),
+        ParseTree::ConfigureExpression(configure_expression) => tree_to_json(
+            tree,
+            vec![
+                ("configure", to_json(&configure_expression.configure)),
+                ("open_paren", to_json(&configure_expression.open_paren)),
+                ("identifier", to_json(&configure_expression.identifier)),
+                ("comma", to_json(&configure_expression.comma)),
+                ("value", to_json(&configure_expression.value)),
+                ("close_paren", to_json(&configure_expression.close_paren)),
+            ],
+        ),
+        ParseTree::SubqueryExpression(subquery_expression) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&subquery_expression.open_paren)),
+                ("query", to_json(&subquery_expression.query)),
+                ("close_paren", to_json(&subquery_expression.close_paren)),
+            ],
+        ),
+        ParseTree::Grouping(grouping) => tree_to_json(
+            tree,
+            vec![
+                ("grouping", to_json(&grouping.grouping)),
+                ("groups", to_json(&grouping.groups)),
+            ],
+        ),
+        ParseTree::Extract(extract) => tree_to_json(
+            tree,
+            vec![
+                ("extract", to_json(&extract.extract)),
+                ("open_paren", to_json(&extract.open_paren)),
+                ("identifier", to_json(&extract.identifier)),
+                ("from", to_json(&extract.from)),
+                ("value", to_json(&extract.value)),
+                ("close_paren", to_json(&extract.close_paren)),
+            ],
+        ),
+        ParseTree::CurrentTime(current_time) => tree_to_json(
+            tree,
+            vec![
+                ("current_time", to_json(&current_time.current_time)),
+                ("open_paren", to_json(&current_time.open_paren)),
+                ("precision", to_json(&current_time.precision)),
+                ("close_paren", to_json(&current_time.close_paren)),
+            ],
+        ),
+        ParseTree::CurrentTimestamp(current_timestamp) => tree_to_json(
+            tree,
+            vec![
+                (
+                    "current_timestamp",
+                    to_json(&current_timestamp.current_timestamp),
+                ),
+                ("open_paren", to_json(&current_timestamp.open_paren)),
+                ("precision", to_json(&current_timestamp.precision)),
+                ("close_paren", to_json(&current_timestamp.close_paren)),
+            ],
+        ),
+        ParseTree::Normalize(normalize) => tree_to_json(
+            tree,
+            vec![
+                ("normalize", to_json(&normalize.normalize)),
+                ("open_paren", to_json(&normalize.open_paren)),
+                ("value", to_json(&normalize.value)),
+                ("comma_opt", to_json(&normalize.comma_opt)),
+                ("normal_form", to_json(&normalize.normal_form)),
+                ("close_paren", to_json(&normalize.close_paren)),
+            ],
+        ),
+        ParseTree::Localtime(localtime) => tree_to_json(
+            tree,
+            vec![
+                ("localtime", to_json(&localtime.localtime)),
+                ("open_paren", to_json(&localtime.open_paren)),
+                ("precision", to_json(&localtime.precision)),
+                ("close_paren", to_json(&localtime.close_paren)),
+            ],
+        ),
+        ParseTree::Localtimestamp(localtimestamp) => tree_to_json(
+            tree,
+            vec![
+                ("localtimestamp", to_json(&localtimestamp.localtimestamp)),
+                ("open_paren", to_json(&localtimestamp.open_paren)),
+                ("precision", to_json(&localtimestamp.precision)),
+                ("close_paren", to_json(&localtimestamp.close_paren)),
+            ],
+        ),
+        ParseTree::Cast(cast) => tree_to_json(
+            tree,
+            vec![
+                ("cast", to_json(&cast.cast)),
+                ("open_paren", to_json(&cast.open_paren)),
+                ("value", to_json(&cast.value)),
+                ("as_", to_json(&cast.as_)),
+                ("type_", to_json(&cast.type_)),
+                ("close_paren", to_json(&cast.close_paren)),
+            ],
+        ),
+        ParseTree::WhenClause(when_clause) => tree_to_json(
+            tree,
+            vec![
+                ("when", to_json(&when_clause.when)),
+                ("condition", to_json(&when_clause.condition)),
+                ("then", to_json(&when_clause.then)),
+                ("result", to_json(&when_clause.result)),
+            ],
+        ),
+        ParseTree::Case(case) => tree_to_json(
+            tree,
+            vec![
+                ("case", to_json(&case.case)),
+                ("value_opt", to_json(&case.value_opt)),
+                ("when_clauses", to_json(&case.when_clauses)),
+                ("else_opt", to_json(&case.else_opt)),
+                ("default", to_json(&case.default)),
+                ("end", to_json(&case.end)),
+            ],
+        ),
+        ParseTree::Exists(exists) => tree_to_json(
+            tree,
+            vec![
+                ("exists", to_json(&exists.exists)),
+                ("open_paren", to_json(&exists.open_paren)),
+                ("query", to_json(&exists.query)),
+                ("close_paren", to_json(&exists.close_paren)),
+            ],
+        ),
+        ParseTree::TypeConstructor(type_constructor) => tree_to_json(
+            tree,
+            vec![
+                ("type_", to_json(&type_constructor.type_)),
+                ("value", to_json(&type_constructor.value)),
+            ],
+        ),
+        ParseTree::Array(array) => tree_to_json(
+            tree,
+            vec![
+                ("array", to_json(&array.array)),
+                ("elements", to_json(&array.elements)),
+            ],
+        ),
+        ParseTree::Interval(interval) => tree_to_json(
+            tree,
+            vec![
+                ("interval", to_json(&interval.interval)),
+                ("sign_opt", to_json(&interval.sign_opt)),
+                ("value", to_json(&interval.value)),
+                ("from", to_json(&interval.from)),
+                ("to_kw_opt", to_json(&interval.to_kw_opt)),
+                ("to", to_json(&interval.to)),
+            ],
+        ),
+        ParseTree::Row(row) => tree_to_json(
+            tree,
+            vec![
+                ("row", to_json(&row.row)),
+                ("elements", to_json(&row.elements)),
+            ],
+        ),
+        ParseTree::TryCast(try_cast) => tree_to_json(
+            tree,
+            vec![
+                ("try_cast", to_json(&try_cast.try_cast)),
+                ("open_paren", to_json(&try_cast.open_paren)),
+                ("value", to_json(&try_cast.value)),
+                ("as_", to_json(&try_cast.as_)),
+                ("type_", to_json(&try_cast.type_)),
+                ("close_paren", to_json(&try_cast.close_paren)),
+            ],
+        ),
+        ParseTree::Substring(substring) => tree_to_json(
+            tree,
+            vec![
+                ("substring", to_json(&substring.substring)),
+                ("open_paren", to_json(&substring.open_paren)),
+                ("value", to_json(&substring.value)),
+                ("from", to_json(&substring.from)),
+                ("from_value", to_json(&substring.from_value)),
+                ("for_opt", to_json(&substring.for_opt)),
+                ("for_value", to_json(&substring.for_value)),
+                ("close_paren", to_json(&substring.close_paren)),
+            ],
+        ),
+        ParseTree::Position(position) => tree_to_json(
+            tree,
+            vec![
+                ("position", to_json(&position.position)),
+                ("open_paren", to_json(&position.open_paren)),
+                ("value", to_json(&position.value)),
+                ("in_", to_json(&position.in_)),
+                ("target", to_json(&position.target)),
+                ("close_paren", to_json(&position.close_paren)),
+            ],
+        ),
+        ParseTree::ArrayTypeSuffix(array_type_suffix) => tree_to_json(
+            tree,
+            vec![
+                ("base_type", to_json(&array_type_suffix.base_type)),
+                ("array", to_json(&array_type_suffix.array)),
+            ],
+        ),
+        ParseTree::NamedType(named_type) => tree_to_json(
+            tree,
+            vec![
+                ("name", to_json(&named_type.name)),
+                ("type_parameters", to_json(&named_type.type_parameters)),
+            ],
+        ),
+        ParseTree::ArrayType(array_type) => tree_to_json(
+            tree,
+            vec![
+                ("array", to_json(&array_type.array)),
+                ("open_angle", to_json(&array_type.open_angle)),
+                ("element_type", to_json(&array_type.element_type)),
+                ("close_angle", to_json(&array_type.close_angle)),
+            ],
+        ),
+        ParseTree::MapType(map_type) => tree_to_json(
+            tree,
+            vec![
+                ("map", to_json(&map_type.map)),
+                ("open_angle", to_json(&map_type.open_angle)),
+                ("key_type", to_json(&map_type.key_type)),
+                ("comma", to_json(&map_type.comma)),
+                ("value_type", to_json(&map_type.value_type)),
+                ("close_angle", to_json(&map_type.close_angle)),
+            ],
+        ),
+        ParseTree::RowType(row_type) => tree_to_json(
+            tree,
+            vec![
+                ("row", to_json(&row_type.row)),
+                ("element_types", to_json(&row_type.element_types)),
+            ],
+        ),
+        ParseTree::RowTypeElement(row_type_element) => tree_to_json(
+            tree,
+            vec![
+                ("identifier", to_json(&row_type_element.identifier)),
+                ("type_", to_json(&row_type_element.type_)),
+            ],
+        ),
+        ParseTree::IntervalType(interval_type) => tree_to_json(
+            tree,
+            vec![
+                ("interval", to_json(&interval_type.interval)),
+                ("from", to_json(&interval_type.from)),
+                ("to_kw", to_json(&interval_type.to_kw)),
+                ("to", to_json(&interval_type.to)),
+            ],
+        ),
+        ParseTree::IfNotExists(if_not_exists) => tree_to_json(
+            tree,
+            vec![
+                ("if_", to_json(&if_not_exists.if_)),
+                ("not", to_json(&if_not_exists.not)),
+                ("exists", to_json(&if_not_exists.exists)),
+            ],
+        ),
+        ParseTree::CreateTable(create_table) => tree_to_json(
+            tree,
+            vec![
+                ("create", to_json(&create_table.create)),
+                ("table", to_json(&create_table.table)),
+                (
+                    "if_not_exists_opt",
+                    to_json(&create_table.if_not_exists_opt),
+                ),
+                ("table_name", to_json(&create_table.table_name)),
+                ("table_elements", to_json(&create_table.table_elements)),
+                ("comment_opt", to_json(&create_table.comment_opt)),
+                (
+                    "with_properties_opt",
+                    to_json(&create_table.with_properties_opt),
+                ),
+            ],
+        ),
+        ParseTree::CreateView(create_view) => tree_to_json(
+            tree,
+            vec![
+                ("create", to_json(&create_view.create)),
+                ("or_opt", to_json(&create_view.or_opt)),
+                ("replace_opt", to_json(&create_view.replace_opt)),
+                ("view", to_json(&create_view.view)),
+                ("qualified_name", to_json(&create_view.qualified_name)),
+                ("as_", to_json(&create_view.as_)),
+                ("query", to_json(&create_view.query)),
+            ],
+        ),
+        ParseTree::CreateRole(create_role) => tree_to_json(
+            tree,
+            vec![
+                ("create", to_json(&create_role.create)),
+                ("role", to_json(&create_role.role)),
+                ("name", to_json(&create_role.name)),
+                (
+                    "with_admin_grantor_opt",
+                    to_json(&create_role.with_admin_grantor_opt),
+                ),
+            ],
+        ),
+        ParseTree::WithAdminGrantor(with_admin_grantor) => tree_to_json(
+            tree,
+            vec![
+                ("with", to_json(&with_admin_grantor.with)),
+                ("admin", to_json(&with_admin_grantor.admin)),
+                ("grantor", to_json(&with_admin_grantor.grantor)),
+            ],
+        ),
+        ParseTree::UserPrincipal(user_principal) => tree_to_json(
+            tree,
+            vec![
+                ("user", to_json(&user_principal.user)),
+                ("identifier", to_json(&user_principal.identifier)),
+            ],
+        ),
+        ParseTree::RolePrincipal(role_principal) => tree_to_json(
+            tree,
+            vec![
+                ("role", to_json(&role_principal.role)),
+                ("identifier", to_json(&role_principal.identifier)),
+            ],
+        ),
+        ParseTree::UnspecifiedPrincipal(unspecified_principal) => tree_to_json(
+            tree,
+            vec![("identifier", to_json(&unspecified_principal.identifier))],
+        ),
+        ParseTree::CreateTableAsSelect(create_table_as_select) => tree_to_json(
+            tree,
+            vec![
+                ("create", to_json(&create_table_as_select.create)),
+                ("table", to_json(&create_table_as_select.table)),
+                (
+                    "if_not_exists_opt",
+                    to_json(&create_table_as_select.if_not_exists_opt),
+                ),
+                ("table_name", to_json(&create_table_as_select.table_name)),
+                (
+                    "column_aliases_opt",
+                    to_json(&create_table_as_select.column_aliases_opt),
+                ),
+                ("comment_opt", to_json(&create_table_as_select.comment_opt)),
+                (
+                    "with_properties_opt",
+                    to_json(&create_table_as_select.with_properties_opt),
+                ),
+                ("as_", to_json(&create_table_as_select.as_)),
+                (
+                    "open_paren_opt",
+                    to_json(&create_table_as_select.open_paren_opt),
+                ),
+                ("query", to_json(&create_table_as_select.query)),
+                (
+                    "close_paren_opt",
+                    to_json(&create_table_as_select.close_paren_opt),
+                ),
+                (
+                    "with_data_opt",
+                    to_json(&create_table_as_select.with_data_opt),
+                ),
+            ],
+        ),
+        ParseTree::WithProperties(with_properties) => tree_to_json(
+            tree,
+            vec![
+                ("with", to_json(&with_properties.with)),
+                ("properties", to_json(&with_properties.properties)),
+            ],
+        ),
+        ParseTree::Property(property) => tree_to_json(
+            tree,
+            vec![
+                ("identifier", to_json(&property.identifier)),
+                ("eq", to_json(&property.eq)),
+                ("value", to_json(&property.value)),
+            ],
+        ),
+        ParseTree::WithData(with_data) => tree_to_json(
+            tree,
+            vec![
+                ("with", to_json(&with_data.with)),
+                ("no_opt", to_json(&with_data.no_opt)),
+                ("data", to_json(&with_data.data)),
+            ],
+        ),
+        ParseTree::Comment(comment) => tree_to_json(
+            tree,
+            vec![
+                ("comment", to_json(&comment.comment)),
+                ("value", to_json(&comment.value)),
+            ],
+        ),
+        ParseTree::ColumnDefinition(column_definition) => tree_to_json(
+            tree,
+            vec![
+                ("identifier", to_json(&column_definition.identifier)),
+                ("type_", to_json(&column_definition.type_)),
+                ("not_null_opt", to_json(&column_definition.not_null_opt)),
+                ("comment_opt", to_json(&column_definition.comment_opt)),
+                (
+                    "with_properties_opt",
+                    to_json(&column_definition.with_properties_opt),
+                ),
+            ],
+        ),
+        ParseTree::NotNull(not_null) => tree_to_json(
+            tree,
+            vec![
+                ("not", to_json(&not_null.not)),
+                ("null", to_json(&not_null.null)),
+            ],
+        ),
+        ParseTree::LikeClause(like_clause) => tree_to_json(
+            tree,
+            vec![
+                ("like", to_json(&like_clause.like)),
+                ("name", to_json(&like_clause.name)),
+                ("option_type_opt", to_json(&like_clause.option_type_opt)),
+                ("properties", to_json(&like_clause.properties)),
+            ],
+        ),
+        ParseTree::InsertInto(insert_into) => tree_to_json(
+            tree,
+            vec![
+                ("insert", to_json(&insert_into.insert)),
+                ("into", to_json(&insert_into.into)),
+                ("table_name", to_json(&insert_into.table_name)),
+                (
+                    "column_aliases_opt",
+                    to_json(&insert_into.column_aliases_opt),
+                ),
+                ("query", to_json(&insert_into.query)),
+            ],
+        ),
+        ParseTree::Delete(delete) => tree_to_json(
+            tree,
+            vec![
+                ("delete", to_json(&delete.delete)),
+                ("from", to_json(&delete.from)),
+                ("table_name", to_json(&delete.table_name)),
+                ("where_opt", to_json(&delete.where_opt)),
+                ("predicate", to_json(&delete.predicate)),
+            ],
+        ),
+        ParseTree::GroupingSet(grouping_set) => {
+            tree_to_json(tree, vec![("elements", to_json(&grouping_set.elements))])
+        }
+        ParseTree::RelationOrQuery(relation_or_query) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&relation_or_query.open_paren)),
+                (
+                    "query_or_relation",
+                    to_json(&relation_or_query.query_or_relation),
+                ),
+                ("close_paren", to_json(&relation_or_query.close_paren)),
+            ],
+        ),
+        ParseTree::EmptyGroupingSet(empty_grouping_set) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&empty_grouping_set.open_paren)),
+                ("close_paren", to_json(&empty_grouping_set.close_paren)),
+            ],
+        ),
+        ParseTree::ExpressionOrQuery(expression_or_query) => tree_to_json(
+            tree,
+            vec![
+                ("open_paren", to_json(&expression_or_query.open_paren)),
+                (
+                    "expression_or_query",
+                    to_json(&expression_or_query.expression_or_query),
+                ),
+                ("close_paren", to_json(&expression_or_query.close_paren)),
+            ],
+        ),
+        ParseTree::Entrypoint(entrypoint) => tree_to_json(
+            tree,
+            vec![
+                ("beginning_of_file", to_json(&entrypoint.beginning_of_file)),
+                ("tree", to_json(&entrypoint.tree)),
+                ("end_of_file", to_json(&entrypoint.end_of_file)),
+            ],
+        ),
+        ParseTree::NullTreatment(null_treatment) => tree_to_json(
+            tree,
+            vec![
+                ("treatment", to_json(&null_treatment.treatment)),
+                ("nulls", to_json(&null_treatment.nulls)),
+            ],
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+
+    fn parse_json(text: &str) -> Json {
+        let (tree, errors) = parse_statement(text);
+        parse_result_to_json(&tree, &errors)
+    }
+
+    /// The member at path, following "fields" for trees.
+    fn member<'a>(json: &'a Json, path: &[&str]) -> &'a Json {
+        path.iter().fold(json, |json, name| {
+            json.get(name)
+                .or_else(|| json.get("fields").and_then(|fields| fields.get(name)))
+                .unwrap_or_else(|| panic!("no member {}", name))
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = parse_json("select a, 'x''y' -- c\nfrom t where b");
+        assert_eq!(Json::parse(&json.to_string()), Ok(json.clone()));
+        assert_eq!(Json::parse(&json.to_pretty_string()), Ok(json));
+    }
+
+    #[test]
+    fn schema() {
+        let json = parse_json("select 1 -- c");
+        assert_eq!(member(&json, &["schema_version"]), &Json::Number(1.0));
+        assert_eq!(member(&json, &["errors"]), &Json::Array(Vec::new()));
+        let query = member(&json, &["tree", "tree", "query_no_with", "query_term"]);
+        assert_eq!(query.get("kind"), Some(&"QuerySpecification".into()));
+        assert_eq!(member(query, &["from"]), &Json::Null);
+        let item = &member(query, &["select_items", "elements"])
+            .as_array()
+            .unwrap()[0];
+        let token = member(item, &["expression", "value"]);
+        assert_eq!(token.get("token_kind"), Some(&"Integer".into()));
+        assert_eq!(token.get("value"), Some(&"1".into()));
+        assert_eq!(token.get("leading_comments"), None);
+        assert_eq!(
+            member(token, &["trailing_comments"]).to_string(),
+            concat!(
+                r##"[{"kind":"LineComment","range":{"start":{"line":0,"column":9},"##,
+                r##""end":{"line":0,"column":13}},"value":"-- c"}]"##
+            )
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let json = parse_json("select from");
+        let errors = member(&json, &["errors"]).as_array().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].to_string(),
+            concat!(
+                r##"{"error_code":201,"messages":[{"range":{"start":{"line":0,"column":7},"##,
+                r##""end":{"line":0,"column":7}},"message":"Expected expression, found FROM."}]}"##
+            )
+        );
+        let query = member(&json, &["tree", "tree", "query_no_with", "query_term"]);
+        let item = &member(query, &["select_items", "elements"])
+            .as_array()
+            .unwrap()[0];
+        let expression = member(item, &["expression"]);
+        assert_eq!(expression.get("kind"), Some(&"Error".into()));
+        assert_eq!(expression.get("error"), Some(&errors[0]));
+    }
+}
@@ -1,6 +1,7 @@
 use super::{parse_tree, visit_post_order, ParseTree};
 use crate::lexing::{
-    predefined_names, predefined_names::PredefinedName as PN, Lexer, Token, TokenKind as TK,
+    predefined_names, predefined_names::PredefinedName as PN, Lexer, LexerOptions, Token,
+    TokenKind as TK,
 };
 use crate::utils::{
     position, position::Position, syntax_error, syntax_error::Message, syntax_error::SyntaxError,
@@ -16,16 +17,20 @@ use parsing::parse_tree::ParseTree::Empty;
 /// Consuming a token advances index past the token. Lex errors in the consumed tokens
 /// become part of the parse results. Tokens must never be unconsumed.
 ///
+/// With drop_consumed_tokens, consumed tokens are moved into the tree rather
+/// than copied, so tokens holds only the lookahead and index stays 0.
+///
 /// peek() methods inspect upcoming tokens without consuming input.
 /// Only the advance() method consumes a token.
 struct ParsePosition<'a> {
     index: usize,
     tokens: Vec<Token<'a>>,
     lexer: Lexer<'a>,
+    drop_consumed_tokens: bool,
 }
 
 impl<'a> ParsePosition<'a> {
-    pub fn new(value: &'a str) -> ParsePosition<'a> {
+    pub fn new(value: &'a str, options: &ParseOptions) -> ParsePosition<'a> {
         ParsePosition {
             index: 0,
             tokens: vec![
@@ -40,7 +45,8 @@ impl<'a> ParsePosition<'a> {
                     Vec::new(),
                 ),
             ],
-            lexer: Lexer::new(value),
+            lexer: Lexer::with_options(value, options.lexer),
+            drop_consumed_tokens: options.drop_consumed_tokens,
         }
     }
 
@@ -107,9 +113,13 @@ impl<'a> ParsePosition<'a> {
     /// Consumes a token in the input.
     fn advance(&mut self) -> Token<'a> {
         debug_assert!(self.index < self.tokens.len());
-        let token = self.peek_token().clone();
-        self.index += 1;
-        token
+        if self.drop_consumed_tokens {
+            self.tokens.remove(self.index)
+        } else {
+            let token = self.peek_token().clone();
+            self.index += 1;
+            token
+        }
     }
 }
 
@@ -135,9 +145,9 @@ type OffsetPeeker<'a> = fn(&mut Parser<'a>, usize) -> bool;
 
 // Language independant parser functions
 impl<'a> Parser<'a> {
-    pub fn new(value: &'a str) -> Parser<'a> {
+    pub fn new(value: &'a str, options: &ParseOptions) -> Parser<'a> {
         Parser {
-            position: ParsePosition::new(value),
+            position: ParsePosition::new(value, options),
             errors: Vec::new(),
         }
     }
@@ -225,21 +235,13 @@ impl<'a> Parser<'a> {
     /// Create an Error tree at the current location.
     // TODO: Add error code parameter.
     fn error(&mut self, message: String) -> ParseTree<'a> {
-        let result = parse_tree::error(SyntaxError::from_message(
+        parse_tree::error(SyntaxError::from_message(
             syntax_error::ERROR_SYNTAX_ERROR,
             Message {
                 range: self.get_empty_range(),
                 message,
             },
-        ));
-        // TODO: Remove this once we're debugged
-        panic!(
-            "WTF\n{}\n {}",
-            self.position.lexer.input,
-            format!("{:#?}", result)
-        );
-        // TODO: restore this once we're debugged
-        // result
+        ))
     }
 
     /// Create an Error tree at the current location with a given message.
@@ -946,7 +948,7 @@ impl<'a> Parser<'a> {
                     parse_tree::join(left, join_type, join, right, join_criteria)
                 }
                 TK::NATURAL => {
-                    let natural = self.eat(TK::CROSS);
+                    let natural = self.eat(TK::NATURAL);
                     let join_type = self.parse_join_type();
                     let join = self.eat(TK::JOIN);
                     let right = self.parse_sampled_relation();
@@ -1766,7 +1768,8 @@ impl<'a> Parser<'a> {
             // | number                                                                              #numericLiteral
             TK::Decimal | TK::Double | TK::Integer => self.parse_literal(),
             // | string                                                                              #stringLiteral
-            TK::String | TK::UnicodeString => self.parse_literal(),
+            TK::String => self.parse_literal(),
+            TK::UnicodeString => parse_tree::literal(self.parse_string()),
             // | BINARY_LITERAL                                                                      #binaryLiteral
             TK::BinaryLiteral => self.parse_literal(),
             // | '?'                                                                                 #parameter
@@ -1887,7 +1890,7 @@ impl<'a> Parser<'a> {
             TK::QuotedIdentifier | TK::BackquotedIdentifier | TK::DigitIdentifier => {
                 self.parse_identifier_start_expression()
             }
-            _ => self.expected_error("Expected expression."),
+            _ => self.expected_error("expression"),
         }
     }
 
@@ -2485,7 +2488,28 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_literal(&mut self) -> ParseTree<'a> {
-        parse_tree::literal(self.eat_token())
+        let literal = parse_tree::literal(self.eat_token());
+        self.validate_literal(&literal);
+        literal
+    }
+
+    // Reports malformed and out of range binary and numeric literals,
+    // unless the token already has errors.
+    fn validate_literal(&mut self, tree: &ParseTree<'a>) {
+        if let ParseTree::Literal(literal) = tree {
+            let is_checked = match &*literal.value {
+                ParseTree::Token(token) => matches!(
+                    token.token.kind,
+                    TK::BinaryLiteral | TK::Integer | TK::Decimal | TK::Double
+                ),
+                _ => false,
+            };
+            if is_checked && errors_of_tree(tree).is_empty() {
+                if let Err(error) = literal.decode() {
+                    self.add_error(error);
+                }
+            }
+        }
     }
 
     // | identifier string                                                                   #typeConstructor
@@ -2750,12 +2774,26 @@ impl<'a> Parser<'a> {
                 } else {
                     self.eat(TK::String)
                 };
-                parse_tree::unicode_string(string, uescape_opt, escape)
+                let unicode_string = parse_tree::unicode_string(string, uescape_opt, escape);
+                self.validate_unicode_string(&unicode_string);
+                unicode_string
             }
             _ => self.expected_error("string"),
         }
     }
 
+    // Reports invalid escape sequences and UESCAPE characters, unless the
+    // string or escape already have errors.
+    fn validate_unicode_string(&mut self, tree: &ParseTree<'a>) {
+        if let ParseTree::UnicodeString(unicode_string) = tree {
+            if errors_of_tree(tree).is_empty() {
+                if let Err(error) = unicode_string.decode() {
+                    self.add_error(error);
+                }
+            }
+        }
+    }
+
     fn peek_string(&mut self) -> bool {
         match self.peek() {
             TK::String | TK::UnicodeString => true,
@@ -2907,7 +2945,8 @@ impl<'a> Parser<'a> {
             TK::CREATE => self.parse_create_statement(),
             TK::INSERT => self.parse_insert_into(),
             TK::DELETE => self.parse_delete(),
-            _ => panic!("TODO: Remaining statements"),
+            // TODO: Remaining statements
+            _ => self.expected_error("statement"),
         }
     }
 
@@ -3171,11 +3210,12 @@ impl<'a> Parser<'a> {
         let qualified_name = self.parse_qualified_name();
         let as_ = self.eat(TK::AS);
         let query = self.parse_query();
-        parse_tree::create_view(create, or ,replace, view, qualified_name, as_, query)
+        parse_tree::create_view(create, or, replace, view, qualified_name, as_, query)
     }
 
     fn parse_create_schema(&mut self) -> ParseTree<'a> {
-        panic!("TODO")
+        // TODO: CREATE SCHEMA
+        self.error("CREATE SCHEMA is not supported.".to_string())
     }
 
     fn parse_create_role(&mut self) -> ParseTree<'a> {
@@ -3228,10 +3268,23 @@ fn errors_of_tree<'a>(tree: &'a ParseTree<'a>) -> Vec<&'a SyntaxError> {
 
 type ParseResult<'a> = (ParseTree<'a>, Vec<SyntaxError>);
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub lexer: LexerOptions,
+    /// Keep only the lookahead tokens, rather than every token of the
+    /// input; bounds the parser's memory beyond the tree itself on very
+    /// large inputs.
+    pub drop_consumed_tokens: bool,
+}
+
 /// Parses text for the given element.
 /// Returns the parse tree and all errors.
-fn parse_entrypoint<'a>(text: &'a str, parse_element: ElementParser<'a>) -> ParseResult<'a> {
-    let mut parser = Parser::new(text);
+fn parse_entrypoint<'a>(
+    text: &'a str,
+    options: &ParseOptions,
+    parse_element: ElementParser<'a>,
+) -> ParseResult<'a> {
+    let mut parser = Parser::new(text, options);
     let tree = parser.parse_entrypoint(parse_element);
     let mut errors = Vec::new();
     for error in errors_of_tree(&tree) {
@@ -3245,5 +3298,58 @@ fn parse_entrypoint<'a>(text: &'a str, parse_element: ElementParser<'a>) -> Pars
 /// Parses text containing a statement.
 /// The errors returned includes all errors contained within the tree.
 pub fn parse_statement<'a>(text: &'a str) -> ParseResult<'a> {
-    parse_entrypoint(text, |parser| parser.parse_statement())
+    parse_statement_with_options(text, &ParseOptions::default())
+}
+
+/// Parses text containing a statement, lexing it with options.
+/// The errors returned includes all errors contained within the tree.
+pub fn parse_statement_with_options<'a>(text: &'a str, options: &ParseOptions) -> ParseResult<'a> {
+    parse_entrypoint(text, options, |parser| parser.parse_statement())
+}
+
+/// Parses text containing an expression.
+/// The errors returned includes all errors contained within the tree.
+pub fn parse_expression<'a>(text: &'a str) -> ParseResult<'a> {
+    parse_entrypoint(text, &ParseOptions::default(), |parser| {
+        parser.parse_expression()
+    })
+}
+
+/// Parses text containing a type, such as "array(varchar)".
+/// The errors returned includes all errors contained within the tree.
+pub fn parse_type<'a>(text: &'a str) -> ParseResult<'a> {
+    parse_entrypoint(text, &ParseOptions::default(), |parser| parser.parse_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dropping_consumed_tokens_keeps_trees_and_errors() {
+        let texts = [
+            "select a, b + 1 as c from t where d > 0 order by a limit 10",
+            "with q as (select * from t) select count(*) over (partition by a) from q",
+            "select * from a left join b on a.x = b.x natural join c",
+            "insert into t (a, b) select 1, 'x' union all values (2, 'y')",
+            "create table t as select array[1, 2] a, map(array['k'], array[1.5]) m",
+            "delete from t where a in (select b from u)",
+            "select -- comment\n case when a then b else c end from t /* end */",
+            "select from where",
+            "select (a, from t",
+            "select X'0g', 1e400, U&'\\D800'",
+            "",
+        ];
+        for text in texts.iter() {
+            let parse = |drop_consumed_tokens| {
+                let options = ParseOptions {
+                    drop_consumed_tokens,
+                    ..ParseOptions::default()
+                };
+                let (tree, errors) = parse_statement_with_options(text, &options);
+                (format!("{:?}", tree), errors)
+            };
+            assert_eq!(parse(false), parse(true), "{}", text);
+        }
+    }
 }
@@ -0,0 +1,1713 @@
+//! Generated by tools/generate_parse_trees. Do not edit.
+use super::parse_tree::{self, ParseTree};
+
+/// A typed visitor over ParseTrees.
+///
+/// visit() dispatches on the kind of tree to the matching visit_*() method.
+/// The default visit_*() methods recurse into the children of the tree by
+/// calling the matching walk_*() function. Implementations which override a
+/// visit_*() method can call walk_*() to continue into the children.
+///
+/// Children are visited in source order.
+pub trait Visitor<'a> {
+    fn visit(&mut self, tree: &'a ParseTree<'a>) {
+        walk(self, tree)
+    }
+
+    fn visit_empty(&mut self, _empty: &'a parse_tree::Empty) {}
+
+    fn visit_token(&mut self, _token: &'a parse_tree::Token<'a>) {}
+
+    fn visit_list(&mut self, list: &'a parse_tree::List<'a>) {
+        walk_list(self, list)
+    }
+
+    fn visit_error(&mut self, _error: &'a parse_tree::Error) {}
+
+    fn visit_query(&mut self, query: &'a parse_tree::Query<'a>) {
+        walk_query(self, query)
+    }
+
+    fn visit_with(&mut self, with: &'a parse_tree::With<'a>) {
+        walk_with(self, with)
+    }
+
+    fn visit_named_query(&mut self, named_query: &'a parse_tree::NamedQuery<'a>) {
+        walk_named_query(self, named_query)
+    }
+
+    fn visit_query_no_with(&mut self, query_no_with: &'a parse_tree::QueryNoWith<'a>) {
+        walk_query_no_with(self, query_no_with)
+    }
+
+    fn visit_order_by(&mut self, order_by: &'a parse_tree::OrderBy<'a>) {
+        walk_order_by(self, order_by)
+    }
+
+    fn visit_limit(&mut self, limit: &'a parse_tree::Limit<'a>) {
+        walk_limit(self, limit)
+    }
+
+    fn visit_query_set_operation(
+        &mut self,
+        query_set_operation: &'a parse_tree::QuerySetOperation<'a>,
+    ) {
+        walk_query_set_operation(self, query_set_operation)
+    }
+
+    fn visit_sort_item(&mut self, sort_item: &'a parse_tree::SortItem<'a>) {
+        walk_sort_item(self, sort_item)
+    }
+
+    fn visit_subquery(&mut self, subquery: &'a parse_tree::Subquery<'a>) {
+        walk_subquery(self, subquery)
+    }
+
+    fn visit_inline_table(&mut self, inline_table: &'a parse_tree::InlineTable<'a>) {
+        walk_inline_table(self, inline_table)
+    }
+
+    fn visit_table(&mut self, table: &'a parse_tree::Table<'a>) {
+        walk_table(self, table)
+    }
+
+    fn visit_query_specification(
+        &mut self,
+        query_specification: &'a parse_tree::QuerySpecification<'a>,
+    ) {
+        walk_query_specification(self, query_specification)
+    }
+
+    fn visit_qualified_name(&mut self, qualified_name: &'a parse_tree::QualifiedName<'a>) {
+        walk_qualified_name(self, qualified_name)
+    }
+
+    fn visit_select_all(&mut self, select_all: &'a parse_tree::SelectAll<'a>) {
+        walk_select_all(self, select_all)
+    }
+
+    fn visit_qualified_select_all(
+        &mut self,
+        qualified_select_all: &'a parse_tree::QualifiedSelectAll<'a>,
+    ) {
+        walk_qualified_select_all(self, qualified_select_all)
+    }
+
+    fn visit_select_item(&mut self, select_item: &'a parse_tree::SelectItem<'a>) {
+        walk_select_item(self, select_item)
+    }
+
+    fn visit_subquery_relation(&mut self, subquery_relation: &'a parse_tree::SubqueryRelation<'a>) {
+        walk_subquery_relation(self, subquery_relation)
+    }
+
+    fn visit_parenthesized_relation(
+        &mut self,
+        parenthesized_relation: &'a parse_tree::ParenthesizedRelation<'a>,
+    ) {
+        walk_parenthesized_relation(self, parenthesized_relation)
+    }
+
+    fn visit_table_name(&mut self, table_name: &'a parse_tree::TableName<'a>) {
+        walk_table_name(self, table_name)
+    }
+
+    fn visit_lateral(&mut self, lateral: &'a parse_tree::Lateral<'a>) {
+        walk_lateral(self, lateral)
+    }
+
+    fn visit_unnest(&mut self, unnest: &'a parse_tree::Unnest<'a>) {
+        walk_unnest(self, unnest)
+    }
+
+    fn visit_sampled_relation(&mut self, sampled_relation: &'a parse_tree::SampledRelation<'a>) {
+        walk_sampled_relation(self, sampled_relation)
+    }
+
+    fn visit_aliased_relation(&mut self, aliased_relation: &'a parse_tree::AliasedRelation<'a>) {
+        walk_aliased_relation(self, aliased_relation)
+    }
+
+    fn visit_cross_join(&mut self, cross_join: &'a parse_tree::CrossJoin<'a>) {
+        walk_cross_join(self, cross_join)
+    }
+
+    fn visit_join(&mut self, join: &'a parse_tree::Join<'a>) {
+        walk_join(self, join)
+    }
+
+    fn visit_natural_join(&mut self, natural_join: &'a parse_tree::NaturalJoin<'a>) {
+        walk_natural_join(self, natural_join)
+    }
+
+    fn visit_outer_join_kind(&mut self, outer_join_kind: &'a parse_tree::OuterJoinKind<'a>) {
+        walk_outer_join_kind(self, outer_join_kind)
+    }
+
+    fn visit_on_join_criteria(&mut self, on_join_criteria: &'a parse_tree::OnJoinCriteria<'a>) {
+        walk_on_join_criteria(self, on_join_criteria)
+    }
+
+    fn visit_using_join_criteria(
+        &mut self,
+        using_join_criteria: &'a parse_tree::UsingJoinCriteria<'a>,
+    ) {
+        walk_using_join_criteria(self, using_join_criteria)
+    }
+
+    fn visit_group_by(&mut self, group_by: &'a parse_tree::GroupBy<'a>) {
+        walk_group_by(self, group_by)
+    }
+
+    fn visit_rollup(&mut self, rollup: &'a parse_tree::Rollup<'a>) {
+        walk_rollup(self, rollup)
+    }
+
+    fn visit_cube(&mut self, cube: &'a parse_tree::Cube<'a>) {
+        walk_cube(self, cube)
+    }
+
+    fn visit_grouping_sets(&mut self, grouping_sets: &'a parse_tree::GroupingSets<'a>) {
+        walk_grouping_sets(self, grouping_sets)
+    }
+
+    fn visit_binary_expression(&mut self, binary_expression: &'a parse_tree::BinaryExpression<'a>) {
+        walk_binary_expression(self, binary_expression)
+    }
+
+    fn visit_unary_expression(&mut self, unary_expression: &'a parse_tree::UnaryExpression<'a>) {
+        walk_unary_expression(self, unary_expression)
+    }
+
+    fn visit_quantified_comparison(
+        &mut self,
+        quantified_comparison: &'a parse_tree::QuantifiedComparison<'a>,
+    ) {
+        walk_quantified_comparison(self, quantified_comparison)
+    }
+
+    fn visit_null_predicate(&mut self, null_predicate: &'a parse_tree::NullPredicate<'a>) {
+        walk_null_predicate(self, null_predicate)
+    }
+
+    fn visit_distinct_from(&mut self, distinct_from: &'a parse_tree::DistinctFrom<'a>) {
+        walk_distinct_from(self, distinct_from)
+    }
+
+    fn visit_between(&mut self, between: &'a parse_tree::Between<'a>) {
+        walk_between(self, between)
+    }
+
+    fn visit_like(&mut self, like: &'a parse_tree::Like<'a>) {
+        walk_like(self, like)
+    }
+
+    fn visit_in_subquery(&mut self, in_subquery: &'a parse_tree::InSubquery<'a>) {
+        walk_in_subquery(self, in_subquery)
+    }
+
+    fn visit_in_list(&mut self, in_list: &'a parse_tree::InList<'a>) {
+        walk_in_list(self, in_list)
+    }
+
+    fn visit_at_time_zone(&mut self, at_time_zone: &'a parse_tree::AtTimeZone<'a>) {
+        walk_at_time_zone(self, at_time_zone)
+    }
+
+    fn visit_dereference(&mut self, dereference: &'a parse_tree::Dereference<'a>) {
+        walk_dereference(self, dereference)
+    }
+
+    fn visit_subscript(&mut self, subscript: &'a parse_tree::Subscript<'a>) {
+        walk_subscript(self, subscript)
+    }
+
+    fn visit_lambda(&mut self, lambda: &'a parse_tree::Lambda<'a>) {
+        walk_lambda(self, lambda)
+    }
+
+    fn visit_literal(&mut self, literal: &'a parse_tree::Literal<'a>) {
+        walk_literal(self, literal)
+    }
+
+    fn visit_row_constructor(&mut self, row_constructor: &'a parse_tree::RowConstructor<'a>) {
+        walk_row_constructor(self, row_constructor)
+    }
+
+    fn visit_parenthesized_expression(
+        &mut self,
+        parenthesized_expression: &'a parse_tree::ParenthesizedExpression<'a>,
+    ) {
+        walk_parenthesized_expression(self, parenthesized_expression)
+    }
+
+    fn visit_identifier(&mut self, identifier: &'a parse_tree::Identifier<'a>) {
+        walk_identifier(self, identifier)
+    }
+
+    fn visit_function_call(&mut self, function_call: &'a parse_tree::FunctionCall<'a>) {
+        walk_function_call(self, function_call)
+    }
+
+    fn visit_filter(&mut self, filter: &'a parse_tree::Filter<'a>) {
+        walk_filter(self, filter)
+    }
+
+    fn visit_over(&mut self, over: &'a parse_tree::Over<'a>) {
+        walk_over(self, over)
+    }
+
+    fn visit_window_frame(&mut self, window_frame: &'a parse_tree::WindowFrame<'a>) {
+        walk_window_frame(self, window_frame)
+    }
+
+    fn visit_unbounded_frame(&mut self, unbounded_frame: &'a parse_tree::UnboundedFrame<'a>) {
+        walk_unbounded_frame(self, unbounded_frame)
+    }
+
+    fn visit_current_row_bound(&mut self, current_row_bound: &'a parse_tree::CurrentRowBound<'a>) {
+        walk_current_row_bound(self, current_row_bound)
+    }
+
+    fn visit_bounded_frame(&mut self, bounded_frame: &'a parse_tree::BoundedFrame<'a>) {
+        walk_bounded_frame(self, bounded_frame)
+    }
+
+    fn visit_unicode_string(&mut self, unicode_string: &'a parse_tree::UnicodeString<'a>) {
+        walk_unicode_string(self, unicode_string)
+    }
+
+    fn visit_configure_expression(
+        &mut self,
+        configure_expression: &'a parse_tree::ConfigureExpression<'a>,
+    ) {
+        walk_configure_expression(self, configure_expression)
+    }
+
+    fn visit_subquery_expression(
+        &mut self,
+        subquery_expression: &'a parse_tree::SubqueryExpression<'a>,
+    ) {
+        walk_subquery_expression(self, subquery_expression)
+    }
+
+    fn visit_grouping(&mut self, grouping: &'a parse_tree::Grouping<'a>) {
+        walk_grouping(self, grouping)
+    }
+
+    fn visit_extract(&mut self, extract: &'a parse_tree::Extract<'a>) {
+        walk_extract(self, extract)
+    }
+
+    fn visit_current_time(&mut self, current_time: &'a parse_tree::CurrentTime<'a>) {
+        walk_current_time(self, current_time)
+    }
+
+    fn visit_current_timestamp(&mut self, current_timestamp: &'a parse_tree::CurrentTimestamp<'a>) {
+        walk_current_timestamp(self, current_timestamp)
+    }
+
+    fn visit_normalize(&mut self, normalize: &'a parse_tree::Normalize<'a>) {
+        walk_normalize(self, normalize)
+    }
+
+    fn visit_localtime(&mut self, localtime: &'a parse_tree::Localtime<'a>) {
+        walk_localtime(self, localtime)
+    }
+
+    fn visit_localtimestamp(&mut self, localtimestamp: &'a parse_tree::Localtimestamp<'a>) {
+        walk_localtimestamp(self, localtimestamp)
+    }
+
+    fn visit_cast(&mut self, cast: &'a parse_tree::Cast<'a>) {
+        walk_cast(self, cast)
+    }
+
+    fn visit_when_clause(&mut self, when_clause: &'a parse_tree::WhenClause<'a>) {
+        walk_when_clause(self, when_clause)
+    }
+
+    fn visit_case(&mut self, case: &'a parse_tree::Case<'a>) {
+        walk_case(self, case)
+    }
+
+    fn visit_exists(&mut self, exists: &'a parse_tree::Exists<'a>) {
+        walk_exists(self, exists)
+    }
+
+    fn visit_type_constructor(&mut self, type_constructor: &'a parse_tree::TypeConstructor<'a>) {
+        walk_type_constructor(self, type_constructor)
+    }
+
+    fn visit_array(&mut self, array: &'a parse_tree::Array<'a>) {
+        walk_array(self, array)
+    }
+
+    fn visit_interval(&mut self, interval: &'a parse_tree::Interval<'a>) {
+        walk_interval(self, interval)
+    }
+
+    fn visit_row(&mut self, row: &'a parse_tree::Row<'a>) {
+        walk_row(self, row)
+    }
+
+    fn visit_try_cast(&mut self, try_cast: &'a parse_tree::TryCast<'a>) {
+        walk_try_cast(self, try_cast)
+    }
+
+    fn visit_substring(&mut self, substring: &'a parse_tree::Substring<'a>) {
+        walk_substring(self, substring)
+    }
+
+    fn visit_position(&mut self, position: &'a parse_tree::Position<'a>) {
+        walk_position(self, position)
+    }
+
+    fn visit_array_type_suffix(&mut self, array_type_suffix: &'a parse_tree::ArrayTypeSuffix<'a>) {
+        walk_array_type_suffix(self, array_type_suffix)
+    }
+
+    fn visit_named_type(&mut self, named_type: &'a parse_tree::NamedType<'a>) {
+        walk_named_type(self, named_type)
+    }
+
+    fn visit_array_type(&mut self, array_type: &'a parse_tree::ArrayType<'a>) {
+        walk_array_type(self, array_type)
+    }
+
+    fn visit_map_type(&mut self, map_type: &'a parse_tree::MapType<'a>) {
+        walk_map_type(self, map_type)
+    }
+
+    fn visit_row_type(&mut self, row_type: &'a parse_tree::RowType<'a>) {
+        walk_row_type(self, row_type)
+    }
+
+    fn visit_row_type_element(&mut self, row_type_element: &'a parse_tree::RowTypeElement<'a>) {
+        walk_row_type_element(self, row_type_element)
+    }
+
+    fn visit_interval_type(&mut self, interval_type: &'a parse_tree::IntervalType<'a>) {
+        walk_interval_type(self, interval_type)
+    }
+
+    fn visit_if_not_exists(&mut self, if_not_exists: &'a parse_tree::IfNotExists<'a>) {
+        walk_if_not_exists(self, if_not_exists)
+    }
+
+    fn visit_create_table(&mut self, create_table: &'a parse_tree::CreateTable<'a>) {
+        walk_create_table(self, create_table)
+    }
+
+    fn visit_create_view(&mut self, create_view: &'a parse_tree::CreateView<'a>) {
+        walk_create_view(self, create_view)
+    }
+
+    fn visit_create_role(&mut self, create_role: &'a parse_tree::CreateRole<'a>) {
+        walk_create_role(self, create_role)
+    }
+
+    fn visit_with_admin_grantor(
+        &mut self,
+        with_admin_grantor: &'a parse_tree::WithAdminGrantor<'a>,
+    ) {
+        walk_with_admin_grantor(self, with_admin_grantor)
+    }
+
+    fn visit_user_principal(&mut self, user_principal: &'a parse_tree::UserPrincipal<'a>) {
+        walk_user_principal(self, user_principal)
+    }
+
+    fn visit_role_principal(&mut self, role_principal: &'a parse_tree::RolePrincipal<'a>) {
+        walk_role_principal(self, role_principal)
+    }
+
+    fn visit_unspecified_principal(
+        &mut self,
+        unspecified_principal: &'a parse_tree::UnspecifiedPrincipal<'a>,
+    ) {
+        walk_unspecified_principal(self, unspecified_principal)
+    }
+
+    fn visit_create_table_as_select(
+        &mut self,
+        create_table_as_select: &'a parse_tree::CreateTableAsSelect<'a>,
+    ) {
+        walk_create_table_as_select(self, create_table_as_select)
+    }
+
+    fn visit_with_properties(&mut self, with_properties: &'a parse_tree::WithProperties<'a>) {
+        walk_with_properties(self, with_properties)
+    }
+
+    fn visit_property(&mut self, property: &'a parse_tree::Property<'a>) {
+        walk_property(self, property)
+    }
+
+    fn visit_with_data(&mut self, with_data: &'a parse_tree::WithData<'a>) {
+        walk_with_data(self, with_data)
+    }
+
+    fn visit_comment(&mut self, comment: &'a parse_tree::Comment<'a>) {
+        walk_comment(self, comment)
+    }
+
+    fn visit_column_definition(&mut self, column_definition: &'a parse_tree::ColumnDefinition<'a>) {
+        walk_column_definition(self, column_definition)
+    }
+
+    fn visit_not_null(&mut self, not_null: &'a parse_tree::NotNull<'a>) {
+        walk_not_null(self, not_null)
+    }
+
+    fn visit_like_clause(&mut self, like_clause: &'a parse_tree::LikeClause<'a>) {
+        walk_like_clause(self, like_clause)
+    }
+
+    fn visit_insert_into(&mut self, insert_into: &'a parse_tree::InsertInto<'a>) {
+        walk_insert_into(self, insert_into)
+    }
+
+    fn visit_delete(&mut self, delete: &'a parse_tree::Delete<'a>) {
+        walk_delete(self, delete)
+    }
+
+    fn visit_grouping_set(&mut self, grouping_set: &'a parse_tree::GroupingSet<'a>) {
+        walk_grouping_set(self, grouping_set)
+    }
+
+    fn visit_relation_or_query(&mut self, relation_or_query: &'a parse_tree::RelationOrQuery<'a>) {
+        walk_relation_or_query(self, relation_or_query)
+    }
+
+    fn visit_empty_grouping_set(
+        &mut self,
+        empty_grouping_set: &'a parse_tree::EmptyGroupingSet<'a>,
+    ) {
+        walk_empty_grouping_set(self, empty_grouping_set)
+    }
+
+    fn visit_expression_or_query(
+        &mut self,
+        expression_or_query: &'a parse_tree::ExpressionOrQuery<'a>,
+    ) {
+        walk_expression_or_query(self, expression_or_query)
+    }
+
+    fn visit_entrypoint(&mut self, entrypoint: &'a parse_tree::Entrypoint<'a>) {
+        walk_entrypoint(self, entrypoint)
+    }
+
+    fn visit_null_treatment(&mut self, null_treatment: &'a parse_tree::NullTreatment<'a>) {
+        walk_null_treatment(self, null_treatment)
+    }
+}
+
+/// Dispatches to the visit_*() method matching the kind of tree.
+pub fn walk<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, tree: &'a ParseTree<'a>) {
+    match tree {
+        ParseTree::Empty(empty) => visitor.visit_empty(empty),
+        ParseTree::Token(token) => visitor.visit_token(token),
+        ParseTree::List(list) => visitor.visit_list(list),
+        ParseTree::Error(error) => visitor.visit_error(error),
+        ParseTree::Query(query) => visitor.visit_query(query),
+        ParseTree::With(with) => visitor.visit_with(with),
+        ParseTree::NamedQuery(named_query) => visitor.visit_named_query(named_query),
+        ParseTree::QueryNoWith(query_no_with) => visitor.visit_query_no_with(query_no_with),
+        ParseTree::OrderBy(order_by) => visitor.visit_order_by(order_by),
+        ParseTree::Limit(limit) => visitor.visit_limit(limit),
+        ParseTree::QuerySetOperation(query_set_operation) => {
+            visitor.visit_query_set_operation(query_set_operation)
+        }
+        ParseTree::SortItem(sort_item) => visitor.visit_sort_item(sort_item),
+        ParseTree::Subquery(subquery) => visitor.visit_subquery(subquery),
+        ParseTree::InlineTable(inline_table) => visitor.visit_inline_table(inline_table),
+        ParseTree::Table(table) => visitor.visit_table(table),
+        ParseTree::QuerySpecification(query_specification) => {
+            visitor.visit_query_specification(query_specification)
+        }
+        ParseTree::QualifiedName(qualified_name) => visitor.visit_qualified_name(qualified_name),
+        ParseTree::SelectAll(select_all) => visitor.visit_select_all(select_all),
+        ParseTree::QualifiedSelectAll(qualified_select_all) => {
+            visitor.visit_qualified_select_all(qualified_select_all)
+        }
+        ParseTree::SelectItem(select_item) => visitor.visit_select_item(select_item),
+        ParseTree::SubqueryRelation(subquery_relation) => {
+            visitor.visit_subquery_relation(subquery_relation)
+        }
+        ParseTree::ParenthesizedRelation(parenthesized_relation) => {
+            visitor.visit_parenthesized_relation(parenthesized_relation)
+        }
+        ParseTree::TableName(table_name) => visitor.visit_table_name(table_name),
+        ParseTree::Lateral(lateral) => visitor.visit_lateral(lateral),
+        ParseTree::Unnest(unnest) => visitor.visit_unnest(unnest),
+        ParseTree::SampledRelation(sampled_relation) => {
+            visitor.visit_sampled_relation(sampled_relation)
+        }
+        ParseTree::AliasedRelation(aliased_relation) => {
+            visitor.visit_aliased_relation(aliased_relation)
+        }
+        ParseTree::CrossJoin(cross_join) => visitor.visit_cross_join(cross_join),
+        ParseTree::Join(join) => visitor.visit_join(join),
+        ParseTree::NaturalJoin(natural_join) => visitor.visit_natural_join(natural_join),
+        ParseTree::OuterJoinKind(outer_join_kind) => visitor.visit_outer_join_kind(outer_join_kind),
+        ParseTree::OnJoinCriteria(on_join_criteria) => {
+            visitor.visit_on_join_criteria(on_join_criteria)
+        }
+        ParseTree::UsingJoinCriteria(using_join_criteria) => {
+            visitor.visit_using_join_criteria(using_join_criteria)
+        }
+        ParseTree::GroupBy(group_by) => visitor.visit_group_by(group_by),
+        ParseTree::Rollup(rollup) => visitor.visit_rollup(rollup),
+        ParseTree::Cube(cube) => visitor.visit_cube(cube),
+        ParseTree::GroupingSets(grouping_sets) => visitor.visit_grouping_sets(grouping_sets),
+        ParseTree::BinaryExpression(binary_expression) => {
+            visitor.visit_binary_expression(binary_expression)
+        }
+        ParseTree::UnaryExpression(unary_expression) => {
+            visitor.visit_unary_expression(unary_expression)
+        }
+        ParseTree::QuantifiedComparison(quantified_comparison) => {
+            visitor.visit_quantified_comparison(quantified_comparison)
+        }
+        ParseTree::NullPredicate(null_predicate) => visitor.visit_null_predicate(null_predicate),
+        ParseTree::DistinctFrom(distinct_from) => visitor.visit_distinct_from(distinct_from),
+        ParseTree::Between(between) => visitor.visit_between(between),
+        ParseTree::Like(like) => visitor.visit_like(like),
+        ParseTree::InSubquery(in_subquery) => visitor.visit_in_subquery(in_subquery),
+        ParseTree::InList(in_list) => visitor.visit_in_list(in_list),
+        ParseTree::AtTimeZone(at_time_zone) => visitor.visit_at_time_zone(at_time_zone),
+        ParseTree::Dereference(dereference) => visitor.visit_dereference(dereference),
+        ParseTree::Subscript(subscript) => visitor.visit_subscript(subscript),
+        ParseTree::Lambda(lambda) => visitor.visit_lambda(lambda),
+        ParseTree::Literal(literal) => visitor.visit_literal(literal),
+        ParseTree::RowConstructor(row_constructor) => {
+            visitor.visit_row_constructor(row_constructor)
+        }
+        ParseTree::ParenthesizedExpression(parenthesized_expression) => {
+            visitor.visit_parenthesized_expression(parenthesized_expression)
+        }
+        ParseTree::Identifier(identifier) => visitor.visit_identifier(identifier),
+        ParseTree::FunctionCall(function_call) => visitor.visit_function_call(function_call),
+        ParseTree::Filter(filter) => visitor.visit_filter(filter),
+        ParseTree::Over(over) => visitor.visit_over(over),
+        ParseTree::WindowFrame(window_frame) => visitor.visit_window_frame(window_frame),
+        ParseTree::UnboundedFrame(unbounded_frame) => {
+            visitor.visit_unbounded_frame(unbounded_frame)
+        }
+        ParseTree::CurrentRowBound(current_row_bound) => {
+            visitor.visit_current_row_bound(current_row_bound)
+        }
+        ParseTree::BoundedFrame(bounded_frame) => visitor.visit_bounded_frame(bounded_frame),
+        ParseTree::UnicodeString(unicode_string) => visitor.visit_unicode_string(unicode_string),
+        ParseTree::ConfigureExpression(configure_expression) => {
+            visitor.visit_configure_expression(configure_expression)
+        }
+        ParseTree::SubqueryExpression(subquery_expression) => {
+            visitor.visit_subquery_expression(subquery_expression)
+        }
+        ParseTree::Grouping(grouping) => visitor.visit_grouping(grouping),
+        ParseTree::Extract(extract) => visitor.visit_extract(extract),
+        ParseTree::CurrentTime(current_time) => visitor.visit_current_time(current_time),
+        ParseTree::CurrentTimestamp(current_timestamp) => {
+            visitor.visit_current_timestamp(current_timestamp)
+        }
+        ParseTree::Normalize(normalize) => visitor.visit_normalize(normalize),
+        ParseTree::Localtime(localtime) => visitor.visit_localtime(localtime),
+        ParseTree::Localtimestamp(localtimestamp) => visitor.visit_localtimestamp(localtimestamp),
+        ParseTree::Cast(cast) => visitor.visit_cast(cast),
+        ParseTree::WhenClause(when_clause) => visitor.visit_when_clause(when_clause),
+        ParseTree::Case(case) => visitor.visit_case(case),
+        ParseTree::Exists(exists) => visitor.visit_exists(exists),
+        ParseTree::TypeConstructor(type_constructor) => {
+            visitor.visit_type_constructor(type_constructor)
+        }
+        ParseTree::Array(array) => visitor.visit_array(array),
+        ParseTree::Interval(interval) => visitor.visit_interval(interval),
+        ParseTree::Row(row) => visitor.visit_row(row),
+        ParseTree::TryCast(try_cast) => visitor.visit_try_cast(try_cast),
+        ParseTree::Substring(substring) => visitor.visit_substring(substring),
+        ParseTree::Position(position) => visitor.visit_position(position),
+        ParseTree::ArrayTypeSuffix(array_type_suffix) => {
+            visitor.visit_array_type_suffix(array_type_suffix)
+        }
+        ParseTree::NamedType(named_type) => visitor.visit_named_type(named_type),
+        ParseTree::ArrayType(array_type) => visitor.visit_array_type(array_type),
+        ParseTree::MapType(map_type) => visitor.visit_map_type(map_type),
+        ParseTree::RowType(row_type) => visitor.visit_row_type(row_type),
+        ParseTree::RowTypeElement(row_type_element) => {
+            visitor.visit_row_type_element(row_type_element)
+        }
+        ParseTree::IntervalType(interval_type) => visitor.visit_interval_type(interval_type),
+        ParseTree::IfNotExists(if_not_exists) => visitor.visit_if_not_exists(if_not_exists),
+        ParseTree::CreateTable(create_table) => visitor.visit_create_table(create_table),
+        ParseTree::CreateView(create_view) => visitor.visit_create_view(create_view),
+        ParseTree::CreateRole(create_role) => visitor.visit_create_role(create_role),
+        ParseTree::WithAdminGrantor(with_admin_grantor) => {
+            visitor.visit_with_admin_grantor(with_admin_grantor)
+        }
+        ParseTree::UserPrincipal(user_principal) => visitor.visit_user_principal(user_principal),
+        ParseTree::RolePrincipal(role_principal) => visitor.visit_role_principal(role_principal),
+        ParseTree::UnspecifiedPrincipal(unspecified_principal) => {
+            visitor.visit_unspecified_principal(unspecified_principal)
+        }
+        ParseTree::CreateTableAsSelect(create_table_as_select) => {
+            visitor.visit_create_table_as_select(create_table_as_select)
+        }
+        ParseTree::WithProperties(with_properties) => {
+            visitor.visit_with_properties(with_properties)
+        }
+        ParseTree::Property(property) => visitor.visit_property(property),
+        ParseTree::WithData(with_data) => visitor.visit_with_data(with_data),
+        ParseTree::Comment(comment) => visitor.visit_comment(comment),
+        ParseTree::ColumnDefinition(column_definition) => {
+            visitor.visit_column_definition(column_definition)
+        }
+        ParseTree::NotNull(not_null) => visitor.visit_not_null(not_null),
+        ParseTree::LikeClause(like_clause) => visitor.visit_like_clause(like_clause),
+        ParseTree::InsertInto(insert_into) => visitor.visit_insert_into(insert_into),
+        ParseTree::Delete(delete) => visitor.visit_delete(delete),
+        ParseTree::GroupingSet(grouping_set) => visitor.visit_grouping_set(grouping_set),
+        ParseTree::RelationOrQuery(relation_or_query) => {
+            visitor.visit_relation_or_query(relation_or_query)
+        }
+        ParseTree::EmptyGroupingSet(empty_grouping_set) => {
+            visitor.visit_empty_grouping_set(empty_grouping_set)
+        }
+        ParseTree::ExpressionOrQuery(expression_or_query) => {
+            visitor.visit_expression_or_query(expression_or_query)
+        }
+        ParseTree::Entrypoint(entrypoint) => visitor.visit_entrypoint(entrypoint),
+        ParseTree::NullTreatment(null_treatment) => visitor.visit_null_treatment(null_treatment),
+    }
+}
+
+/// Visits the delimiters, elements and separators of a list.
+pub fn walk_list<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, list: &'a parse_tree::List<'a>) {
+    for child in list.children() {
+        visitor.visit(child);
+    }
+}
+
+pub fn walk_query<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, query: &'a parse_tree::Query<'a>) {
+    visitor.visit(&query.with);
+    visitor.visit(&query.query_no_with);
+}
+
+pub fn walk_with<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, with: &'a parse_tree::With<'a>) {
+    visitor.visit(&with.with);
+    visitor.visit(&with.recursive);
+    visitor.visit(&with.named_queries);
+}
+
+pub fn walk_named_query<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    named_query: &'a parse_tree::NamedQuery<'a>,
+) {
+    visitor.visit(&named_query.name);
+    visitor.visit(&named_query.column_aliases_opt);
+    visitor.visit(&named_query.as_);
+    visitor.visit(&named_query.open_paren);
+    visitor.visit(&named_query.query);
+    visitor.visit(&named_query.close_paren);
+}
+
+pub fn walk_query_no_with<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    query_no_with: &'a parse_tree::QueryNoWith<'a>,
+) {
+    visitor.visit(&query_no_with.query_term);
+    visitor.visit(&query_no_with.order_by_opt);
+    visitor.visit(&query_no_with.limit_opt);
+}
+
+pub fn walk_order_by<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    order_by: &'a parse_tree::OrderBy<'a>,
+) {
+    visitor.visit(&order_by.order);
+    visitor.visit(&order_by.by);
+    visitor.visit(&order_by.sort_items);
+}
+
+pub fn walk_limit<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, limit: &'a parse_tree::Limit<'a>) {
+    visitor.visit(&limit.limit);
+    visitor.visit(&limit.value);
+}
+
+pub fn walk_query_set_operation<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    query_set_operation: &'a parse_tree::QuerySetOperation<'a>,
+) {
+    visitor.visit(&query_set_operation.left);
+    visitor.visit(&query_set_operation.operator);
+    visitor.visit(&query_set_operation.set_quantifier_opt);
+    visitor.visit(&query_set_operation.right);
+}
+
+pub fn walk_sort_item<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    sort_item: &'a parse_tree::SortItem<'a>,
+) {
+    visitor.visit(&sort_item.expression);
+    visitor.visit(&sort_item.ordering_opt);
+    visitor.visit(&sort_item.nulls);
+    visitor.visit(&sort_item.null_ordering_opt);
+}
+
+pub fn walk_subquery<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    subquery: &'a parse_tree::Subquery<'a>,
+) {
+    visitor.visit(&subquery.open_paren);
+    visitor.visit(&subquery.query_no_with);
+    visitor.visit(&subquery.close_paren);
+}
+
+pub fn walk_inline_table<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    inline_table: &'a parse_tree::InlineTable<'a>,
+) {
+    visitor.visit(&inline_table.values);
+    visitor.visit(&inline_table.expressions);
+}
+
+pub fn walk_table<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, table: &'a parse_tree::Table<'a>) {
+    visitor.visit(&table.table);
+    visitor.visit(&table.qualified_name);
+}
+
+pub fn walk_query_specification<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    query_specification: &'a parse_tree::QuerySpecification<'a>,
+) {
+    visitor.visit(&query_specification.select);
+    visitor.visit(&query_specification.set_quantifier_opt);
+    visitor.visit(&query_specification.select_items);
+    visitor.visit(&query_specification.from);
+    visitor.visit(&query_specification.relations);
+    visitor.visit(&query_specification.where_);
+    visitor.visit(&query_specification.where_predicate);
+    visitor.visit(&query_specification.group);
+    visitor.visit(&query_specification.by);
+    visitor.visit(&query_specification.group_by);
+    visitor.visit(&query_specification.having);
+    visitor.visit(&query_specification.having_predicate);
+}
+
+pub fn walk_qualified_name<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    qualified_name: &'a parse_tree::QualifiedName<'a>,
+) {
+    visitor.visit(&qualified_name.names);
+}
+
+pub fn walk_select_all<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    select_all: &'a parse_tree::SelectAll<'a>,
+) {
+    visitor.visit(&select_all.asterisk);
+}
+
+pub fn walk_qualified_select_all<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    qualified_select_all: &'a parse_tree::QualifiedSelectAll<'a>,
+) {
+    visitor.visit(&qualified_select_all.qualifier);
+    visitor.visit(&qualified_select_all.period);
+    visitor.visit(&qualified_select_all.asterisk);
+}
+
+pub fn walk_select_item<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    select_item: &'a parse_tree::SelectItem<'a>,
+) {
+    visitor.visit(&select_item.expression);
+    visitor.visit(&select_item.as_);
+    visitor.visit(&select_item.identifier);
+}
+
+pub fn walk_subquery_relation<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    subquery_relation: &'a parse_tree::SubqueryRelation<'a>,
+) {
+    visitor.visit(&subquery_relation.open_paren);
+    visitor.visit(&subquery_relation.query);
+    visitor.visit(&subquery_relation.close_paren);
+}
+
+pub fn walk_parenthesized_relation<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    parenthesized_relation: &'a parse_tree::ParenthesizedRelation<'a>,
+) {
+    visitor.visit(&parenthesized_relation.open_paren);
+    visitor.visit(&parenthesized_relation.relation);
+    visitor.visit(&parenthesized_relation.close_paren);
+}
+
+pub fn walk_table_name<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    table_name: &'a parse_tree::TableName<'a>,
+) {
+    visitor.visit(&table_name.name);
+}
+
+pub fn walk_lateral<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    lateral: &'a parse_tree::Lateral<'a>,
+) {
+    visitor.visit(&lateral.lateral);
+    visitor.visit(&lateral.open_paren);
+    visitor.visit(&lateral.query);
+    visitor.visit(&lateral.close_paren);
+}
+
+pub fn walk_unnest<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    unnest: &'a parse_tree::Unnest<'a>,
+) {
+    visitor.visit(&unnest.unnest);
+    visitor.visit(&unnest.expressions);
+    visitor.visit(&unnest.with);
+    visitor.visit(&unnest.ordinality);
+}
+
+pub fn walk_sampled_relation<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    sampled_relation: &'a parse_tree::SampledRelation<'a>,
+) {
+    visitor.visit(&sampled_relation.aliased_relation);
+    visitor.visit(&sampled_relation.tablesample);
+    visitor.visit(&sampled_relation.sample_type);
+    visitor.visit(&sampled_relation.open_paren);
+    visitor.visit(&sampled_relation.expression);
+    visitor.visit(&sampled_relation.close_paren);
+}
+
+pub fn walk_aliased_relation<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    aliased_relation: &'a parse_tree::AliasedRelation<'a>,
+) {
+    visitor.visit(&aliased_relation.relation_primary);
+    visitor.visit(&aliased_relation.as_opt);
+    visitor.visit(&aliased_relation.identifier);
+    visitor.visit(&aliased_relation.column_aliases_opt);
+}
+
+pub fn walk_cross_join<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    cross_join: &'a parse_tree::CrossJoin<'a>,
+) {
+    visitor.visit(&cross_join.left);
+    visitor.visit(&cross_join.cross);
+    visitor.visit(&cross_join.join);
+    visitor.visit(&cross_join.right);
+}
+
+pub fn walk_join<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, join: &'a parse_tree::Join<'a>) {
+    visitor.visit(&join.left);
+    visitor.visit(&join.join_type);
+    visitor.visit(&join.join);
+    visitor.visit(&join.right);
+    visitor.visit(&join.join_criteria);
+}
+
+pub fn walk_natural_join<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    natural_join: &'a parse_tree::NaturalJoin<'a>,
+) {
+    visitor.visit(&natural_join.left);
+    visitor.visit(&natural_join.natural);
+    visitor.visit(&natural_join.join_type);
+    visitor.visit(&natural_join.join);
+    visitor.visit(&natural_join.right);
+}
+
+pub fn walk_outer_join_kind<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    outer_join_kind: &'a parse_tree::OuterJoinKind<'a>,
+) {
+    visitor.visit(&outer_join_kind.kind);
+    visitor.visit(&outer_join_kind.outer_opt);
+}
+
+pub fn walk_on_join_criteria<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    on_join_criteria: &'a parse_tree::OnJoinCriteria<'a>,
+) {
+    visitor.visit(&on_join_criteria.on);
+    visitor.visit(&on_join_criteria.predicate);
+}
+
+pub fn walk_using_join_criteria<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    using_join_criteria: &'a parse_tree::UsingJoinCriteria<'a>,
+) {
+    visitor.visit(&using_join_criteria.using);
+    visitor.visit(&using_join_criteria.names);
+}
+
+pub fn walk_group_by<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    group_by: &'a parse_tree::GroupBy<'a>,
+) {
+    visitor.visit(&group_by.set_quantifier_opt);
+    visitor.visit(&group_by.grouping_elements);
+}
+
+pub fn walk_rollup<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    rollup: &'a parse_tree::Rollup<'a>,
+) {
+    visitor.visit(&rollup.rollup);
+    visitor.visit(&rollup.expressions);
+}
+
+pub fn walk_cube<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, cube: &'a parse_tree::Cube<'a>) {
+    visitor.visit(&cube.cube);
+    visitor.visit(&cube.expressions);
+}
+
+pub fn walk_grouping_sets<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    grouping_sets: &'a parse_tree::GroupingSets<'a>,
+) {
+    visitor.visit(&grouping_sets.grouping);
+    visitor.visit(&grouping_sets.sets);
+    visitor.visit(&grouping_sets.grouping_sets);
+}
+
+pub fn walk_binary_expression<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    binary_expression: &'a parse_tree::BinaryExpression<'a>,
+) {
+    visitor.visit(&binary_expression.left);
+    visitor.visit(&binary_expression.operator);
+    visitor.visit(&binary_expression.right);
+}
+
+pub fn walk_unary_expression<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    unary_expression: &'a parse_tree::UnaryExpression<'a>,
+) {
+    visitor.visit(&unary_expression.operator);
+    visitor.visit(&unary_expression.operand);
+}
+
+pub fn walk_quantified_comparison<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    quantified_comparison: &'a parse_tree::QuantifiedComparison<'a>,
+) {
+    visitor.visit(&quantified_comparison.operand);
+    visitor.visit(&quantified_comparison.operator);
+    visitor.visit(&quantified_comparison.comparison_quantifier);
+    visitor.visit(&quantified_comparison.open_paren);
+    visitor.visit(&quantified_comparison.query);
+    visitor.visit(&quantified_comparison.close_paren);
+}
+
+pub fn walk_null_predicate<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    null_predicate: &'a parse_tree::NullPredicate<'a>,
+) {
+    visitor.visit(&null_predicate.value);
+    visitor.visit(&null_predicate.is);
+    visitor.visit(&null_predicate.not_opt);
+    visitor.visit(&null_predicate.null);
+}
+
+pub fn walk_distinct_from<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    distinct_from: &'a parse_tree::DistinctFrom<'a>,
+) {
+    visitor.visit(&distinct_from.left);
+    visitor.visit(&distinct_from.distinct);
+    visitor.visit(&distinct_from.from);
+    visitor.visit(&distinct_from.right);
+}
+
+pub fn walk_between<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    between: &'a parse_tree::Between<'a>,
+) {
+    visitor.visit(&between.value);
+    visitor.visit(&between.not_opt);
+    visitor.visit(&between.between);
+    visitor.visit(&between.lower);
+    visitor.visit(&between.and);
+    visitor.visit(&between.upper);
+}
+
+pub fn walk_like<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, like: &'a parse_tree::Like<'a>) {
+    visitor.visit(&like.value);
+    visitor.visit(&like.not_opt);
+    visitor.visit(&like.like);
+    visitor.visit(&like.patrern);
+    visitor.visit(&like.escape_opt);
+    visitor.visit(&like.escape_value_opt);
+}
+
+pub fn walk_in_subquery<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    in_subquery: &'a parse_tree::InSubquery<'a>,
+) {
+    visitor.visit(&in_subquery.value);
+    visitor.visit(&in_subquery.not_opt);
+    visitor.visit(&in_subquery.in_);
+    visitor.visit(&in_subquery.open_paren);
+    visitor.visit(&in_subquery.query);
+    visitor.visit(&in_subquery.close_paren);
+}
+
+pub fn walk_in_list<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    in_list: &'a parse_tree::InList<'a>,
+) {
+    visitor.visit(&in_list.value);
+    visitor.visit(&in_list.not_opt);
+    visitor.visit(&in_list.in_);
+    visitor.visit(&in_list.expressions);
+}
+
+pub fn walk_at_time_zone<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    at_time_zone: &'a parse_tree::AtTimeZone<'a>,
+) {
+    visitor.visit(&at_time_zone.value);
+    visitor.visit(&at_time_zone.at);
+    visitor.visit(&at_time_zone.time);
+    visitor.visit(&at_time_zone.zone);
+    visitor.visit(&at_time_zone.specifier);
+}
+
+pub fn walk_dereference<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    dereference: &'a parse_tree::Dereference<'a>,
+) {
+    visitor.visit(&dereference.object);
+    visitor.visit(&dereference.period);
+    visitor.visit(&dereference.field_name);
+}
+
+pub fn walk_subscript<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    subscript: &'a parse_tree::Subscript<'a>,
+) {
+    visitor.visit(&subscript.operand);
+    visitor.visit(&subscript.open_square);
+    visitor.visit(&subscript.index);
+    visitor.visit(&subscript.close_square);
+}
+
+pub fn walk_lambda<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    lambda: &'a parse_tree::Lambda<'a>,
+) {
+    visitor.visit(&lambda.parameters);
+    visitor.visit(&lambda.array);
+    visitor.visit(&lambda.body);
+}
+
+pub fn walk_literal<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    literal: &'a parse_tree::Literal<'a>,
+) {
+    visitor.visit(&literal.value);
+}
+
+pub fn walk_row_constructor<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    row_constructor: &'a parse_tree::RowConstructor<'a>,
+) {
+    visitor.visit(&row_constructor.elements);
+}
+
+pub fn walk_parenthesized_expression<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    parenthesized_expression: &'a parse_tree::ParenthesizedExpression<'a>,
+) {
+    visitor.visit(&parenthesized_expression.open_paren);
+    visitor.visit(&parenthesized_expression.value);
+    visitor.visit(&parenthesized_expression.close_paren);
+}
+
+pub fn walk_identifier<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    identifier: &'a parse_tree::Identifier<'a>,
+) {
+    visitor.visit(&identifier.value);
+}
+
+pub fn walk_function_call<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    function_call: &'a parse_tree::FunctionCall<'a>,
+) {
+    visitor.visit(&function_call.name);
+    visitor.visit(&function_call.open_paren);
+    visitor.visit(&function_call.set_quantifier_opt);
+    visitor.visit(&function_call.arguments);
+    visitor.visit(&function_call.order_by_opt);
+    visitor.visit(&function_call.close_paren);
+    visitor.visit(&function_call.filter_opt);
+    visitor.visit(&function_call.null_treatment_opt);
+    visitor.visit(&function_call.over_opt);
+}
+
+pub fn walk_filter<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    filter: &'a parse_tree::Filter<'a>,
+) {
+    visitor.visit(&filter.filter);
+    visitor.visit(&filter.open_paren);
+    visitor.visit(&filter.where_);
+    visitor.visit(&filter.predicate);
+    visitor.visit(&filter.close_paren);
+}
+
+pub fn walk_over<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, over: &'a parse_tree::Over<'a>) {
+    visitor.visit(&over.over);
+    visitor.visit(&over.open_paren);
+    visitor.visit(&over.partition_opt);
+    visitor.visit(&over.by);
+    visitor.visit(&over.partitions);
+    visitor.visit(&over.order_by_opt);
+    visitor.visit(&over.window_frame);
+    visitor.visit(&over.close_paren);
+}
+
+pub fn walk_window_frame<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    window_frame: &'a parse_tree::WindowFrame<'a>,
+) {
+    visitor.visit(&window_frame.frame_type);
+    visitor.visit(&window_frame.between_opt);
+    visitor.visit(&window_frame.start);
+    visitor.visit(&window_frame.and);
+    visitor.visit(&window_frame.end);
+}
+
+pub fn walk_unbounded_frame<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    unbounded_frame: &'a parse_tree::UnboundedFrame<'a>,
+) {
+    visitor.visit(&unbounded_frame.unbounded);
+    visitor.visit(&unbounded_frame.bound_type);
+}
+
+pub fn walk_current_row_bound<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    current_row_bound: &'a parse_tree::CurrentRowBound<'a>,
+) {
+    visitor.visit(&current_row_bound.current);
+    visitor.visit(&current_row_bound.row);
+}
+
+pub fn walk_bounded_frame<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    bounded_frame: &'a parse_tree::BoundedFrame<'a>,
+) {
+    visitor.visit(&bounded_frame.bound);
+    visitor.visit(&bounded_frame.bound_type);
+}
+
+pub fn walk_unicode_string<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    unicode_string: &'a parse_tree::UnicodeString<'a>,
+) {
+    visitor.visit(&unicode_string.string);
+    visitor.visit(&unicode_string.uescape_opt);
+    visitor.visit(&unicode_string.escape);
+}
+
+pub fn walk_configure_expression<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    configure_expression: &'a parse_tree::ConfigureExpression<'a>,
+) {
+    visitor.visit(&configure_expression.configure);
+    visitor.visit(&configure_expression.open_paren);
+    visitor.visit(&configure_expression.identifier);
+    visitor.visit(&configure_expression.comma);
+    visitor.visit(&configure_expression.value);
+    visitor.visit(&configure_expression.close_paren);
+}
+
+pub fn walk_subquery_expression<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    subquery_expression: &'a parse_tree::SubqueryExpression<'a>,
+) {
+    visitor.visit(&subquery_expression.open_paren);
+    visitor.visit(&subquery_expression.query);
+    visitor.visit(&subquery_expression.close_paren);
+}
+
+pub fn walk_grouping<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    grouping: &'a parse_tree::Grouping<'a>,
+) {
+    visitor.visit(&grouping.grouping);
+    visitor.visit(&grouping.groups);
+}
+
+pub fn walk_extract<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    extract: &'a parse_tree::Extract<'a>,
+) {
+    visitor.visit(&extract.extract);
+    visitor.visit(&extract.open_paren);
+    visitor.visit(&extract.identifier);
+    visitor.visit(&extract.from);
+    visitor.visit(&extract.value);
+    visitor.visit(&extract.close_paren);
+}
+
+pub fn walk_current_time<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    current_time: &'a parse_tree::CurrentTime<'a>,
+) {
+    visitor.visit(&current_time.current_time);
+    visitor.visit(&current_time.open_paren);
+    visitor.visit(&current_time.precision);
+    visitor.visit(&current_time.close_paren);
+}
+
+pub fn walk_current_timestamp<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    current_timestamp: &'a parse_tree::CurrentTimestamp<'a>,
+) {
+    visitor.visit(&current_timestamp.current_timestamp);
+    visitor.visit(&current_timestamp.open_paren);
+    visitor.visit(&current_timestamp.precision);
+    visitor.visit(&current_timestamp.close_paren);
+}
+
+pub fn walk_normalize<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    normalize: &'a parse_tree::Normalize<'a>,
+) {
+    visitor.visit(&normalize.normalize);
+    visitor.visit(&normalize.open_paren);
+    visitor.visit(&normalize.value);
+    visitor.visit(&normalize.comma_opt);
+    visitor.visit(&normalize.normal_form);
+    visitor.visit(&normalize.close_paren);
+}
+
+pub fn walk_localtime<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    localtime: &'a parse_tree::Localtime<'a>,
+) {
+    visitor.visit(&localtime.localtime);
+    visitor.visit(&localtime.open_paren);
+    visitor.visit(&localtime.precision);
+    visitor.visit(&localtime.close_paren);
+}
+
+pub fn walk_localtimestamp<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    localtimestamp: &'a parse_tree::Localtimestamp<'a>,
+) {
+    visitor.visit(&localtimestamp.localtimestamp);
+    visitor.visit(&localtimestamp.open_paren);
+    visitor.visit(&localtimestamp.precision);
+    visitor.visit(&localtimestamp.close_paren);
+}
+
+pub fn walk_cast<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, cast: &'a parse_tree::Cast<'a>) {
+    visitor.visit(&cast.cast);
+    visitor.visit(&cast.open_paren);
+    visitor.visit(&cast.value);
+    visitor.visit(&cast.as_);
+    visitor.visit(&cast.type_);
+    visitor.visit(&cast.close_paren);
+}
+
+pub fn walk_when_clause<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    when_clause: &'a parse_tree::WhenClause<'a>,
+) {
+    visitor.visit(&when_clause.when);
+    visitor.visit(&when_clause.condition);
+    visitor.visit(&when_clause.then);
+    visitor.visit(&when_clause.result);
+}
+
+pub fn walk_case<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, case: &'a parse_tree::Case<'a>) {
+    visitor.visit(&case.case);
+    visitor.visit(&case.value_opt);
+    visitor.visit(&case.when_clauses);
+    visitor.visit(&case.else_opt);
+    visitor.visit(&case.default);
+    visitor.visit(&case.end);
+}
+
+pub fn walk_exists<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    exists: &'a parse_tree::Exists<'a>,
+) {
+    visitor.visit(&exists.exists);
+    visitor.visit(&exists.open_paren);
+    visitor.visit(&exists.query);
+    visitor.visit(&exists.close_paren);
+}
+
+pub fn walk_type_constructor<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    type_constructor: &'a parse_tree::TypeConstructor<'a>,
+) {
+    visitor.visit(&type_constructor.type_);
+    visitor.visit(&type_constructor.value);
+}
+
+pub fn walk_array<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, array: &'a parse_tree::Array<'a>) {
+    visitor.visit(&array.array);
+    visitor.visit(&array.elements);
+}
+
+pub fn walk_interval<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    interval: &'a parse_tree::Interval<'a>,
+) {
+    visitor.visit(&interval.interval);
+    visitor.visit(&interval.sign_opt);
+    visitor.visit(&interval.value);
+    visitor.visit(&interval.from);
+    visitor.visit(&interval.to_kw_opt);
+    visitor.visit(&interval.to);
+}
+
+pub fn walk_row<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, row: &'a parse_tree::Row<'a>) {
+    visitor.visit(&row.row);
+    visitor.visit(&row.elements);
+}
+
+pub fn walk_try_cast<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    try_cast: &'a parse_tree::TryCast<'a>,
+) {
+    visitor.visit(&try_cast.try_cast);
+    visitor.visit(&try_cast.open_paren);
+    visitor.visit(&try_cast.value);
+    visitor.visit(&try_cast.as_);
+    visitor.visit(&try_cast.type_);
+    visitor.visit(&try_cast.close_paren);
+}
+
+pub fn walk_substring<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    substring: &'a parse_tree::Substring<'a>,
+) {
+    visitor.visit(&substring.substring);
+    visitor.visit(&substring.open_paren);
+    visitor.visit(&substring.value);
+    visitor.visit(&substring.from);
+    visitor.visit(&substring.from_value);
+    visitor.visit(&substring.for_opt);
+    visitor.visit(&substring.for_value);
+    visitor.visit(&substring.close_paren);
+}
+
+pub fn walk_position<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    position: &'a parse_tree::Position<'a>,
+) {
+    visitor.visit(&position.position);
+    visitor.visit(&position.open_paren);
+    visitor.visit(&position.value);
+    visitor.visit(&position.in_);
+    visitor.visit(&position.target);
+    visitor.visit(&position.close_paren);
+}
+
+pub fn walk_array_type_suffix<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    array_type_suffix: &'a parse_tree::ArrayTypeSuffix<'a>,
+) {
+    visitor.visit(&array_type_suffix.base_type);
+    visitor.visit(&array_type_suffix.array);
+}
+
+pub fn walk_named_type<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    named_type: &'a parse_tree::NamedType<'a>,
+) {
+    visitor.visit(&named_type.name);
+    visitor.visit(&named_type.type_parameters);
+}
+
+pub fn walk_array_type<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    array_type: &'a parse_tree::ArrayType<'a>,
+) {
+    visitor.visit(&array_type.array);
+    visitor.visit(&array_type.open_angle);
+    visitor.visit(&array_type.element_type);
+    visitor.visit(&array_type.close_angle);
+}
+
+pub fn walk_map_type<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    map_type: &'a parse_tree::MapType<'a>,
+) {
+    visitor.visit(&map_type.map);
+    visitor.visit(&map_type.open_angle);
+    visitor.visit(&map_type.key_type);
+    visitor.visit(&map_type.comma);
+    visitor.visit(&map_type.value_type);
+    visitor.visit(&map_type.close_angle);
+}
+
+pub fn walk_row_type<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    row_type: &'a parse_tree::RowType<'a>,
+) {
+    visitor.visit(&row_type.row);
+    visitor.visit(&row_type.element_types);
+}
+
+pub fn walk_row_type_element<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    row_type_element: &'a parse_tree::RowTypeElement<'a>,
+) {
+    visitor.visit(&row_type_element.identifier);
+    visitor.visit(&row_type_element.type_);
+}
+
+pub fn walk_interval_type<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    interval_type: &'a parse_tree::IntervalType<'a>,
+) {
+    visitor.visit(&interval_type.interval);
+    visitor.visit(&interval_type.from);
+    visitor.visit(&interval_type.to_kw);
+    visitor.visit(&interval_type.to);
+}
+
+pub fn walk_if_not_exists<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    if_not_exists: &'a parse_tree::IfNotExists<'a>,
+) {
+    visitor.visit(&if_not_exists.if_);
+    visitor.visit(&if_not_exists.not);
+    visitor.visit(&if_not_exists.exists);
+}
+
+pub fn walk_create_table<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    create_table: &'a parse_tree::CreateTable<'a>,
+) {
+    visitor.visit(&create_table.create);
+    visitor.visit(&create_table.table);
+    visitor.visit(&create_table.if_not_exists_opt);
+    visitor.visit(&create_table.table_name);
+    visitor.visit(&create_table.table_elements);
+    visitor.visit(&create_table.comment_opt);
+    visitor.visit(&create_table.with_properties_opt);
+}
+
+pub fn walk_create_view<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    create_view: &'a parse_tree::CreateView<'a>,
+) {
+    visitor.visit(&create_view.create);
+    visitor.visit(&create_view.or_opt);
+    visitor.visit(&create_view.replace_opt);
+    visitor.visit(&create_view.view);
+    visitor.visit(&create_view.qualified_name);
+    visitor.visit(&create_view.as_);
+    visitor.visit(&create_view.query);
+}
+
+pub fn walk_create_role<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    create_role: &'a parse_tree::CreateRole<'a>,
+) {
+    visitor.visit(&create_role.create);
+    visitor.visit(&create_role.role);
+    visitor.visit(&create_role.name);
+    visitor.visit(&create_role.with_admin_grantor_opt);
+}
+
+pub fn walk_with_admin_grantor<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    with_admin_grantor: &'a parse_tree::WithAdminGrantor<'a>,
+) {
+    visitor.visit(&with_admin_grantor.with);
+    visitor.visit(&with_admin_grantor.admin);
+    visitor.visit(&with_admin_grantor.grantor);
+}
+
+pub fn walk_user_principal<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    user_principal: &'a parse_tree::UserPrincipal<'a>,
+) {
+    visitor.visit(&user_principal.user);
+    visitor.visit(&user_principal.identifier);
+}
+
+pub fn walk_role_principal<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    role_principal: &'a parse_tree::RolePrincipal<'a>,
+) {
+    visitor.visit(&role_principal.role);
+    visitor.visit(&role_principal.identifier);
+}
+
+pub fn walk_unspecified_principal<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    unspecified_principal: &'a parse_tree::UnspecifiedPrincipal<'a>,
+) {
+    visitor.visit(&unspecified_principal.identifier);
+}
+
+pub fn walk_create_table_as_select<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    create_table_as_select: &'a parse_tree::CreateTableAsSelect<'a>,
+) {
+    visitor.visit(&create_table_as_select.create);
+    visitor.visit(&create_table_as_select.table);
+    visitor.visit(&create_table_as_select.if_not_exists_opt);
+    visitor.visit(&create_table_as_select.table_name);
+    visitor.visit(&create_table_as_select.column_aliases_opt);
+    visitor.visit(&create_table_as_select.comment_opt);
+    visitor.visit(&create_table_as_select.with_properties_opt);
+    visitor.visit(&create_table_as_select.as_);
+    visitor.visit(&create_table_as_select.open_paren_opt);
+    visitor.visit(&create_table_as_select.query);
+    visitor.visit(&create_table_as_select.close_paren_opt);
+    visitor.visit(&create_table_as_select.with_data_opt);
+}
+
+pub fn walk_with_properties<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    with_properties: &'a parse_tree::WithProperties<'a>,
+) {
+    visitor.visit(&with_properties.with);
+    visitor.visit(&with_properties.properties);
+}
+
+pub fn walk_property<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    property: &'a parse_tree::Property<'a>,
+) {
+    visitor.visit(&property.identifier);
+    visitor.visit(&property.eq);
+    visitor.visit(&property.value);
+}
+
+pub fn walk_with_data<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    with_data: &'a parse_tree::WithData<'a>,
+) {
+    visitor.visit(&with_data.with);
+    visitor.visit(&with_data.no_opt);
+    visitor.visit(&with_data.data);
+}
+
+pub fn walk_comment<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    comment: &'a parse_tree::Comment<'a>,
+) {
+    visitor.visit(&comment.comment);
+    visitor.visit(&comment.value);
+}
+
+pub fn walk_column_definition<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    column_definition: &'a parse_tree::ColumnDefinition<'a>,
+) {
+    visitor.visit(&column_definition.identifier);
+    visitor.visit(&column_definition.type_);
+    visitor.visit(&column_definition.not_null_opt);
+    visitor.visit(&column_definition.comment_opt);
+    visitor.visit(&column_definition.with_properties_opt);
+}
+
+pub fn walk_not_null<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    not_null: &'a parse_tree::NotNull<'a>,
+) {
+    visitor.visit(&not_null.not);
+    visitor.visit(&not_null.null);
+}
+
+pub fn walk_like_clause<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    like_clause: &'a parse_tree::LikeClause<'a>,
+) {
+    visitor.visit(&like_clause.like);
+    visitor.visit(&like_clause.name);
+    visitor.visit(&like_clause.option_type_opt);
+    visitor.visit(&like_clause.properties);
+}
+
+pub fn walk_insert_into<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    insert_into: &'a parse_tree::InsertInto<'a>,
+) {
+    visitor.visit(&insert_into.insert);
+    visitor.visit(&insert_into.into);
+    visitor.visit(&insert_into.table_name);
+    visitor.visit(&insert_into.column_aliases_opt);
+    visitor.visit(&insert_into.query);
+}
+
+pub fn walk_delete<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    delete: &'a parse_tree::Delete<'a>,
+) {
+    visitor.visit(&delete.delete);
+    visitor.visit(&delete.from);
+    visitor.visit(&delete.table_name);
+    visitor.visit(&delete.where_opt);
+    visitor.visit(&delete.predicate);
+}
+
+pub fn walk_grouping_set<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    grouping_set: &'a parse_tree::GroupingSet<'a>,
+) {
+    visitor.visit(&grouping_set.elements);
+}
+
+pub fn walk_relation_or_query<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    relation_or_query: &'a parse_tree::RelationOrQuery<'a>,
+) {
+    visitor.visit(&relation_or_query.open_paren);
+    visitor.visit(&relation_or_query.query_or_relation);
+    visitor.visit(&relation_or_query.close_paren);
+}
+
+pub fn walk_empty_grouping_set<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    empty_grouping_set: &'a parse_tree::EmptyGroupingSet<'a>,
+) {
+    visitor.visit(&empty_grouping_set.open_paren);
+    visitor.visit(&empty_grouping_set.close_paren);
+}
+
+pub fn walk_expression_or_query<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    expression_or_query: &'a parse_tree::ExpressionOrQuery<'a>,
+) {
+    visitor.visit(&expression_or_query.open_paren);
+    visitor.visit(&expression_or_query.expression_or_query);
+    visitor.visit(&expression_or_query.close_paren);
+}
+
+pub fn walk_entrypoint<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    entrypoint: &'a parse_tree::Entrypoint<'a>,
+) {
+    visitor.visit(&entrypoint.beginning_of_file);
+    visitor.visit(&entrypoint.tree);
+    visitor.visit(&entrypoint.end_of_file);
+}
+
+pub fn walk_null_treatment<'a, V: Visitor<'a> + ?Sized>(
+    visitor: &mut V,
+    null_treatment: &'a parse_tree::NullTreatment<'a>,
+) {
+    visitor.visit(&null_treatment.treatment);
+    visitor.visit(&null_treatment.nulls);
+}
@@ -0,0 +1,488 @@
+//! A minimal JSON value, used to serialize trees and analysis results,
+//! and to read configuration such as catalogs.
+use std::fmt;
+
+/// A JSON value.
+///
+/// Objects preserve the order their members were added in, so that
+/// serialized output is stable.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        Json::Object(
+            members
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    pub fn array<T: Into<Json>>(values: Vec<T>) -> Json {
+        Json::Array(values.into_iter().map(|value| value.into()).collect())
+    }
+
+    /// Adds a member to an object.
+    pub fn push(&mut self, name: &str, value: Json) {
+        match self {
+            Json::Object(members) => members.push((name.to_string(), value)),
+            _ => panic!("Expected Object"),
+        }
+    }
+
+    /// Parses JSON text. Errors include the byte offset of the error.
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = JsonParser {
+            text: text.as_bytes(),
+            offset: 0,
+        };
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if parser.offset < text.len() {
+            return Err(parser.error("Expected end of input"));
+        }
+        Ok(value)
+    }
+
+    /// The value of an object member; None for missing members and non-objects.
+    pub fn get(&self, name: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(member_name, _)| member_name == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Json>> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&Vec<(String, Json)>> {
+        match self {
+            Json::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    /// Renders with one member or element per line, indented by 2 spaces.
+    pub fn to_pretty_string(&self) -> String {
+        let mut result = String::new();
+        self.write_pretty(0, &mut result);
+        result
+    }
+
+    fn write_pretty(&self, indent: usize, result: &mut String) {
+        fn new_line(indent: usize, result: &mut String) {
+            result.push('\n');
+            for _ in 0..indent {
+                result.push_str("  ");
+            }
+        }
+
+        match self {
+            Json::Array(values) if !values.is_empty() => {
+                result.push('[');
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        result.push(',');
+                    }
+                    new_line(indent + 1, result);
+                    value.write_pretty(indent + 1, result);
+                }
+                new_line(indent, result);
+                result.push(']');
+            }
+            Json::Object(members) if !members.is_empty() => {
+                result.push('{');
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        result.push(',');
+                    }
+                    new_line(indent + 1, result);
+                    write_string(name, result);
+                    result.push_str(": ");
+                    value.write_pretty(indent + 1, result);
+                }
+                new_line(indent, result);
+                result.push('}');
+            }
+            _ => result.push_str(&self.to_string()),
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    text: &'a [u8],
+    offset: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self, message: &str) -> String {
+        format!("{} at offset {}", message, self.offset)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.text.get(self.offset) {
+            self.offset += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.get(self.offset).cloned()
+    }
+
+    fn expect(&mut self, ch: u8) -> Result<(), String> {
+        if self.peek() == Some(ch) {
+            self.offset += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", ch as char)))
+        }
+    }
+
+    fn eat_literal(&mut self, literal: &str, value: Json) -> Result<Json, String> {
+        if self.text[self.offset..].starts_with(literal.as_bytes()) {
+            self.offset += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("Invalid literal"))
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(b'"') => Ok(Json::String(self.parse_string()?)),
+            Some(b't') => self.eat_literal("true", Json::Bool(true)),
+            Some(b'f') => self.eat_literal("false", Json::Bool(false)),
+            Some(b'n') => self.eat_literal("null", Json::Null),
+            Some(b'-' | b'0'..=b'9') => self.parse_number(),
+            Some(_) => Err(self.error("Expected value")),
+            None => Err(self.error("Unexpected end of input")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Json, String> {
+        self.expect(b'{')?;
+        let mut members = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.offset += 1;
+            return Ok(Json::Object(members));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.error("Expected member name"));
+            }
+            let name = self.parse_string()?;
+            self.expect(b':')?;
+            members.push((name, self.parse_value()?));
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b'}') => {
+                    self.offset += 1;
+                    return Ok(Json::Object(members));
+                }
+                _ => return Err(self.error("Expected ',' or '}'")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Result<Json, String> {
+        self.expect(b'[')?;
+        let mut values = Vec::new();
+        if self.peek() == Some(b']') {
+            self.offset += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.parse_value()?);
+            match self.peek() {
+                Some(b',') => self.offset += 1,
+                Some(b']') => {
+                    self.offset += 1;
+                    return Ok(Json::Array(values));
+                }
+                _ => return Err(self.error("Expected ',' or ']'")),
+            }
+        }
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        // from_str_radix alone would accept a sign, as in \u+041.
+        let digits = self
+            .text
+            .get(self.offset..self.offset + 4)
+            .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error("Invalid unicode escape"))?;
+        self.offset += 4;
+        Ok(digits)
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut result = Vec::new();
+        loop {
+            let ch = match self.text.get(self.offset) {
+                Some(ch) => *ch,
+                None => return Err(self.error("Unterminated string")),
+            };
+            self.offset += 1;
+            match ch {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = self.text.get(self.offset).cloned();
+                    self.offset += 1;
+                    match escaped {
+                        Some(b'"') => result.push(b'"'),
+                        Some(b'\\') => result.push(b'\\'),
+                        Some(b'/') => result.push(b'/'),
+                        Some(b'b') => result.push(8),
+                        Some(b'f') => result.push(12),
+                        Some(b'n') => result.push(b'\n'),
+                        Some(b'r') => result.push(b'\r'),
+                        Some(b't') => result.push(b'\t'),
+                        Some(b'u') => {
+                            let mut code = self.parse_hex4()?;
+                            if (0xD800..0xDC00).contains(&code)
+                                && self.text[self.offset..].starts_with(b"\\u")
+                            {
+                                self.offset += 2;
+                                let low = self.parse_hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("Invalid unicode escape"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            let ch = char::from_u32(code)
+                                .ok_or_else(|| self.error("Invalid unicode escape"))?;
+                            let mut buffer = [0; 4];
+                            result.extend_from_slice(ch.encode_utf8(&mut buffer).as_bytes());
+                        }
+                        _ => return Err(self.error("Invalid escape")),
+                    }
+                }
+                _ => result.push(ch),
+            }
+        }
+        String::from_utf8(result).map_err(|_| self.error("Invalid UTF-8"))
+    }
+
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.offset;
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.text.get(self.offset)
+        {
+            self.offset += 1;
+        }
+        std::str::from_utf8(&self.text[start..self.offset])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .map(Json::Number)
+            .ok_or_else(|| self.error("Invalid number"))
+    }
+}
+
+/// Writes value as a quoted, escaped JSON string.
+pub fn write_string(value: &str, result: &mut String) {
+    result.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\u{0}'..='\u{1f}' => result.push_str(&format!("\\u{:04x}", ch as u32)),
+            _ => result.push(ch),
+        }
+    }
+    result.push('"');
+}
+
+/// Renders compactly, with no whitespace.
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => {
+                if value.is_finite() {
+                    write!(f, "{}", value)
+                } else {
+                    write!(f, "null")
+                }
+            }
+            Json::String(value) => {
+                let mut result = String::new();
+                write_string(value, &mut result);
+                write!(f, "{}", result)
+            }
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    let mut result = String::new();
+                    write_string(name, &mut result);
+                    write!(f, "{}:{}", result, value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Json {
+        Json::Bool(value)
+    }
+}
+
+impl From<i32> for Json {
+    fn from(value: i32) -> Json {
+        Json::Number(value as f64)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Json {
+        Json::Number(value as f64)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Json {
+        Json::Number(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Json {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Json {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Json {
+        match value {
+            Some(value) => value.into(),
+            None => Json::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string(text: &str) -> Result<String, String> {
+        Json::parse(text).map(|value| value.as_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn round_trip() {
+        let value = Json::object(vec![
+            ("null", Json::Null),
+            ("bool", true.into()),
+            ("number", Json::Number(-1.5e3)),
+            ("string", "a\"b\\c\n\t\u{1}é\u{1F600}".into()),
+            ("array", Json::array(vec![1, 2])),
+            ("empty", Json::Array(Vec::new())),
+            (
+                "object",
+                Json::object(vec![("a", Json::object(Vec::new()))]),
+            ),
+        ]);
+        assert_eq!(Json::parse(&value.to_string()), Ok(value.clone()));
+        assert_eq!(Json::parse(&value.to_pretty_string()), Ok(value));
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            string(r#""\u0041\u00e9\/\b\f""#),
+            Ok("A\u{e9}/\u{8}\u{c}".to_string())
+        );
+        // A surrogate pair.
+        assert_eq!(string(r#""\uD83D\uDE00""#), Ok("\u{1F600}".to_string()));
+        assert_eq!(string(r#""\u00E9\u00e9""#), Ok("\u{e9}\u{e9}".to_string()));
+    }
+
+    #[test]
+    fn invalid_escapes() {
+        for text in &[
+            r#""\uD800\u0041""#,
+            r#""\uD800\uD800""#,
+            r#""\uD800""#,
+            r#""\uDC00""#,
+            r#""\u+041""#,
+            r#""\u-041""#,
+            r#""\u00g1""#,
+            r#""\u004""#,
+            r#""\x""#,
+        ] {
+            assert!(string(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(
+            Json::parse("[1, 2"),
+            Err("Expected ',' or ']' at offset 5".to_string())
+        );
+        for text in &[
+            "",
+            "[1,]",
+            "{\"a\" 1}",
+            "{a: 1}",
+            "\"abc",
+            "tru",
+            "1 2",
+            "-",
+            "nul",
+        ] {
+            assert!(Json::parse(text).is_err(), "{}", text);
+        }
+    }
+}
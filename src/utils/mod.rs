@@ -1,10 +1,14 @@
 //! Utilities shared by other modules.
 
+pub use self::json::Json;
 pub use self::position::Position;
+pub use self::source_text::SourceText;
 pub use self::syntax_error::Message;
 pub use self::syntax_error::SyntaxError;
 pub use self::text_range::TextRange;
 
+pub mod json;
 pub mod position;
+pub mod source_text;
 pub mod syntax_error;
 pub mod text_range;
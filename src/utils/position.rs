@@ -1,3 +1,4 @@
+use super::Json;
 use std::fmt;
 
 /// A position within a text buffer.
@@ -33,6 +34,14 @@ impl Position {
     pub fn beginning_of_line(&self) -> Position {
         Position::new(self.line, 0)
     }
+
+    /// {"line": number, "column": number}
+    pub fn to_json(self) -> Json {
+        Json::object(vec![
+            ("line", self.line.into()),
+            ("column", self.column.into()),
+        ])
+    }
 }
 
 impl fmt::Display for Position {
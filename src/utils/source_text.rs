@@ -0,0 +1,79 @@
+use super::{Position, TextRange};
+
+/// Source text along with the byte offset of the start of each line.
+///
+/// Converts the line/column Positions used by tokens and trees back into
+/// byte offsets into the text. Line breaks are counted the same way as
+/// the lexer counts them: "\n", "\r\n" and a lone "\r" each end a line.
+///
+/// The lifetime of a SourceText is scoped to the lifetime of the text.
+#[derive(Clone, Debug)]
+pub struct SourceText<'a> {
+    pub text: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceText<'a> {
+    pub fn new(text: &'a str) -> SourceText<'a> {
+        let mut line_starts = vec![0];
+        let bytes = text.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'\n' => line_starts.push(index + 1),
+                b'\r' => {
+                    if bytes.get(index + 1) == Some(&b'\n') {
+                        index += 1;
+                    }
+                    line_starts.push(index + 1);
+                }
+                _ => (),
+            }
+            index += 1;
+        }
+        SourceText { text, line_starts }
+    }
+
+    /// The number of lines in the text.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The text of a line, excluding the line break.
+    pub fn line(&self, line: i32) -> &'a str {
+        let start = self.line_starts[line as usize];
+        let end = self
+            .line_starts
+            .get(line as usize + 1)
+            .cloned()
+            .unwrap_or(self.text.len());
+        self.text[start..end].trim_end_matches(['\n', '\r'])
+    }
+
+    /// The byte offset of a position.
+    /// Positions past the end of a line or the end of the text are clamped.
+    pub fn offset(&self, position: Position) -> usize {
+        let line = position.line as usize;
+        if line >= self.line_starts.len() {
+            return self.text.len();
+        }
+        let line_text = self.line(position.line);
+        let start = self.line_starts[line];
+        match line_text.char_indices().nth(position.column as usize) {
+            Some((index, _)) => start + index,
+            None => start + line_text.len(),
+        }
+    }
+
+    /// The text within range.
+    pub fn slice(&self, range: TextRange) -> &'a str {
+        &self.text[self.offset(range.start)..self.offset(range.end)]
+    }
+
+    /// The Position of the end of the text.
+    pub fn end(&self) -> Position {
+        let line = self.line_starts.len() - 1;
+        let column = self.text[self.line_starts[line]..].chars().count();
+        Position::new(line as i32, column as i32)
+    }
+}
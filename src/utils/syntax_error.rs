@@ -1,4 +1,4 @@
-use super::TextRange;
+use super::{Json, TextRange};
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -17,6 +17,14 @@ impl Message {
     pub fn new(range: TextRange, message: String) -> Message {
         Message { range, message }
     }
+
+    /// {"range": range, "message": string}
+    pub fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("range", self.range.to_json()),
+            ("message", self.message.as_str().into()),
+        ])
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -55,6 +63,17 @@ impl SyntaxError {
     pub fn get_range(&self) -> TextRange {
         self.messages[0].range
     }
+
+    /// {"error_code": number, "messages": [message]}
+    pub fn to_json(&self) -> Json {
+        Json::object(vec![
+            ("error_code", self.error_code.into()),
+            (
+                "messages",
+                Json::array(self.messages.iter().map(Message::to_json).collect()),
+            ),
+        ])
+    }
 }
 
 // lex errors 100-199
@@ -64,6 +83,47 @@ pub const ERROR_INVALID_TOKEN_START: i32 = 103;
 pub const ERROR_UNTERMINATED_STRING_LITERAL: i32 = 104;
 pub const ERROR_UNTERMINATED_QUOTED_IDENTIFIER: i32 = 105;
 pub const ERROR_UNTERMINATED_BACK_QUOTED_IDENTIFIER: i32 = 106;
+pub const ERROR_INVALID_UNICODE_ESCAPE: i32 = 107;
+pub const ERROR_INVALID_BINARY_LITERAL: i32 = 108;
+pub const ERROR_INVALID_NUMERIC_LITERAL: i32 = 109;
 
 // parse errors 200-299
 pub const ERROR_SYNTAX_ERROR: i32 = 201;
+
+// semantic errors 300-399
+pub const ERROR_UNKNOWN_TABLE: i32 = 301;
+pub const ERROR_UNKNOWN_COLUMN: i32 = 302;
+pub const ERROR_AMBIGUOUS_COLUMN: i32 = 303;
+pub const ERROR_UNKNOWN_RELATION: i32 = 304;
+pub const ERROR_COLUMN_ALIAS_COUNT: i32 = 305;
+pub const ERROR_DUPLICATE_WITH_QUERY: i32 = 306;
+pub const ERROR_INVALID_TABLE_NAME: i32 = 307;
+pub const ERROR_UNNAMED_COLUMN: i32 = 308;
+pub const ERROR_DUPLICATE_COLUMN: i32 = 309;
+pub const ERROR_TABLE_EXISTS: i32 = 310;
+pub const ERROR_MULTIPLE_INCLUDING_PROPERTIES: i32 = 311;
+pub const ERROR_TYPE_MISMATCH: i32 = 312;
+pub const ERROR_NOT_BOOLEAN: i32 = 313;
+pub const ERROR_INCOMPATIBLE_TYPES: i32 = 314;
+pub const ERROR_INVALID_SUBSCRIPT: i32 = 315;
+pub const ERROR_UNKNOWN_FIELD: i32 = 316;
+pub const ERROR_INVALID_TYPE: i32 = 317;
+pub const ERROR_UNKNOWN_FUNCTION: i32 = 318;
+pub const ERROR_INVALID_ARGUMENTS: i32 = 319;
+pub const ERROR_INVALID_FUNCTION_CALL: i32 = 320;
+pub const ERROR_MUST_BE_AGGREGATE_OR_GROUP: i32 = 321;
+pub const ERROR_NESTED_AGGREGATION: i32 = 322;
+pub const ERROR_NESTED_WINDOW: i32 = 323;
+pub const ERROR_CANNOT_HAVE_AGGREGATIONS: i32 = 324;
+pub const ERROR_INVALID_ORDINAL: i32 = 325;
+pub const ERROR_INVALID_GROUPING: i32 = 326;
+pub const ERROR_INVALID_WINDOW_FRAME: i32 = 327;
+pub const ERROR_INVALID_NULL_TREATMENT: i32 = 328;
+pub const ERROR_MISSING_OVER: i32 = 329;
+
+// lint warnings 900-999
+pub const WARNING_SELECT_STAR: i32 = 901;
+pub const WARNING_IMPLICIT_CROSS_JOIN: i32 = 902;
+pub const WARNING_UNION_WITHOUT_QUANTIFIER: i32 = 903;
+pub const WARNING_NOT_IN_SUBQUERY: i32 = 904;
+pub const WARNING_COMPARISON_WITH_NULL: i32 = 905;
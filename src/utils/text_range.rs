@@ -1,5 +1,5 @@
 use super::position;
-use super::Position;
+use super::{Json, Position};
 use std::fmt;
 
 /// A range in a text buffer.
@@ -43,6 +43,14 @@ impl TextRange {
         self.start <= range.start && self.end >= range.end
     }
 
+    /// {"start": position, "end": position}
+    pub fn to_json(self) -> Json {
+        Json::object(vec![
+            ("start", self.start.to_json()),
+            ("end", self.end.to_json()),
+        ])
+    }
+
     // TODO: content_from_lines for error reporting
 }
 
@@ -1 +1 @@
-./scripts/generate_parse_trees.sh && RUST_BACKTRACE=full cargo run private/test.sql 2> private/t.err && cargo fmt && cargo run --release private/test.sql && pushd tools/generate_parse_trees && cargo fmt && popd
+./scripts/generate_parse_trees.sh && RUST_BACKTRACE=full cargo run -- check private/test.sql 2> private/t.err && cargo fmt && cargo run --release -- check private/test.sql && pushd tools/generate_parse_trees && cargo fmt && popd
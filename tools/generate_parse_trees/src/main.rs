@@ -1,6 +1,14 @@
 //! Generates parsing/parse_tree.rs.
 //! The ParseTrees contain a lot of boilerplate code, so we auto generate.
 //! To add a new tree, add an entry to the configs() function below.
+//!
+//! The first command line argument selects the file to generate:
+//!   parse_tree - parsing/parse_tree.rs (the default)
+//!   visitor    - parsing/visitor.rs
+//!   folder     - parsing/folder.rs
+//!   json       - parsing/parse_tree_json.rs
+
+use std::env;
 
 // class name, field names
 // class name must be in CamelCase. Field names must be in lower_case.
@@ -534,6 +542,10 @@ use crate::utils::{position, syntax_error::SyntaxError, text_range::TextRange};
 ///  as_*() - returns a ref to the typed tree. Must only be called it is_*() is true.
 ///  unbox_*() -> consumes a parse tree, destructuring it into its unboxed components.
 ///  children() -> returns a Vec containing refs to all immediate children of the tree.
+///  children_mut() -> returns a Vec containing mutable refs to all immediate children of the tree.
+///  kind_name() -> returns the name of the tree's kind.
+///  field_names() -> returns the names of the fields of the tree's kind, in the same order as children().
+///                   Empty for the structural kinds.
 ///
 /// This mod also contains a top level factory function for each kind of ParseTree.
 ///
@@ -631,6 +643,17 @@ impl<'a> List<'a> {
         result
     }
 
+    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {
+        let mut result = Vec::with_capacity(2 + self.elements_and_separators.len() * 2);
+        result.push(&mut *self.start_delimiter);
+        for (element, separator) in &mut self.elements_and_separators {
+            result.push(element);
+            result.push(separator);
+        }
+        result.push(&mut *self.end_delimiter);
+        result
+    }
+
     pub fn get_first_token(&self) -> Option<&token::Token<'a>> {
         self.start_delimiter.get_first_token().or_else(||{
             for (element, separator) in &self.elements_and_separators {
@@ -832,12 +855,10 @@ fn print_switch_body_rev(cs: &Vec<TreeConfig>, method_name: &str) {
     print!("{}", END);
 }
 
-fn main() {
-    let cs = configs();
-
+fn print_parse_tree(cs: &Vec<TreeConfig>) {
     // enum ParseTree
     print!("{}", FILE_HEADER);
-    for config in &cs {
+    for config in cs {
         let class_name = get_config(config).0;
         print!("    {0}({0}<'a>),\n", class_name);
     }
@@ -855,11 +876,42 @@ fn main() {
     print_is_as_impl("empty", "Empty");
     print_is_as_impl("token", "Token");
     print_is_as_impl("error", "Error");
-    for config in &cs {
+    for config in cs {
         let (class_name, ctor_name, fields) = get_config(config);
         print_is_as_impl(ctor_name.as_str(), class_name);
         print_unbox(ctor_name.as_str(), class_name, fields);
     }
+    // kind_name
+    print!("    pub fn kind_name(&self) -> &'static str {{\n");
+    print!("        match self {{\n");
+    print!("            ParseTree::Token(_) => \"Token\",\n");
+    print!("            ParseTree::List(_) => \"List\",\n");
+    print!("            ParseTree::Error(_) => \"Error\",\n");
+    print!("            ParseTree::Empty(_) => \"Empty\",\n");
+    for config in cs {
+        let class_name = get_config(config).0;
+        println!("            ParseTree::{0}(_) => \"{0}\",", class_name);
+    }
+    print!("        }}\n");
+    print!("{}", END);
+    // field_names
+    print!("    pub fn field_names(&self) -> &'static [&'static str] {{\n");
+    print!("        match self {{\n");
+    print!("            ParseTree::Token(_) | ParseTree::List(_) | ParseTree::Error(_) | ParseTree::Empty(_) => &[],\n");
+    for config in cs {
+        let (class_name, _, fields) = get_config(config);
+        println!(
+            "            ParseTree::{}(_) => &[{}],",
+            class_name,
+            fields
+                .iter()
+                .map(|field_name| format!("\"{}\"", field_name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    print!("        }}\n");
+    print!("{}", END);
     // children
     print!("    pub fn children(&self) -> Vec<&ParseTree<'a>> {{\n");
     print!("        match self {{\n");
@@ -867,7 +919,15 @@ fn main() {
     print!("            ParseTree::List(list) => list.children(),\n");
     print!("            ParseTree::Error(error) => error.children(),\n");
     print!("            ParseTree::Empty(empty) => empty.children(),\n");
-    print_switch_body(&cs, "children");
+    print_switch_body(cs, "children");
+    // children_mut
+    print!("    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {{\n");
+    print!("        match self {{\n");
+    print!("            ParseTree::Token(_) => Vec::new(),\n");
+    print!("            ParseTree::List(list) => list.children_mut(),\n");
+    print!("            ParseTree::Error(_) => Vec::new(),\n");
+    print!("            ParseTree::Empty(_) => Vec::new(),\n");
+    print_switch_body(cs, "children_mut");
     // get_first_child
     print!("    pub fn get_first_child(&self) -> &ParseTree<'a> {{\n");
     print!("        match self {{\n");
@@ -875,7 +935,7 @@ fn main() {
     print!("            ParseTree::List(list) => &list.start_delimiter,\n");
     print!("            ParseTree::Error(_) => self,\n");
     print!("            ParseTree::Empty(_) => self,\n");
-    print_switch_body(&cs, "get_first_child");
+    print_switch_body(cs, "get_first_child");
     // get_last_child
     print!("    pub fn get_last_child(&self) -> &ParseTree<'a> {{\n");
     print!("        match self {{\n");
@@ -883,7 +943,7 @@ fn main() {
     print!("            ParseTree::List(list) => &list.end_delimiter,\n");
     print!("            ParseTree::Error(_) => self,\n");
     print!("            ParseTree::Empty(_) => self,\n");
-    print_switch_body(&cs, "get_last_child");
+    print_switch_body(cs, "get_last_child");
     // get_first_token
     print!("    pub fn get_first_token(&self) -> Option<&token::Token<'a>> {{\n");
     print!("        match self {{\n");
@@ -891,7 +951,7 @@ fn main() {
     print!("            ParseTree::List(list) => list.get_first_token(),\n");
     print!("            ParseTree::Error(_) => None,\n");
     print!("            ParseTree::Empty(_) => None,\n");
-    print_switch_body(&cs, "get_first_token");
+    print_switch_body(cs, "get_first_token");
     // get_last_token
     print!("    pub fn get_last_token(&self) -> Option<&token::Token<'a>> {{\n");
     print!("        match self {{\n");
@@ -899,12 +959,12 @@ fn main() {
     print!("            ParseTree::List(list) => list.get_last_token(),\n");
     print!("            ParseTree::Error(_) => None,\n");
     print!("            ParseTree::Empty(_) => None,\n");
-    print_switch_body_rev(&cs, "get_last_token");
+    print_switch_body_rev(cs, "get_last_token");
     // end impl
     print!("{}", END);
 
     print!("{}", "// The language specific trees\n");
-    for config in &cs {
+    for config in cs {
         let (class_name, ctor_name, fields) = get_config(config);
 
         // struct definition
@@ -941,14 +1001,20 @@ fn main() {
 
         // children
         print!("    pub fn children(&self) -> Vec<&ParseTree<'a>> {{\n");
-        print!(
-            "        let mut result = Vec::with_capacity({});",
-            fields.len()
-        );
+        print!("        vec![\n");
         for field_name in fields {
-            print!("        result.push(&*self.{0});\n", field_name);
+            print!("            &*self.{0},\n", field_name);
         }
-        print!("        result");
+        print!("        ]\n");
+        print!("{}", END);
+
+        // children_mut
+        print!("    pub fn children_mut(&mut self) -> Vec<&mut ParseTree<'a>> {{\n");
+        print!("        vec![\n");
+        for field_name in fields {
+            print!("            &mut *self.{0},\n", field_name);
+        }
+        print!("        ]\n");
         print!("{}", END);
 
         // unbox
@@ -1004,3 +1070,375 @@ fn main() {
         print!("{}", END);
     }
 }
+
+const VISITOR_HEADER: &str = r#"//! Generated by tools/generate_parse_trees. Do not edit.
+use super::parse_tree::{self, ParseTree};
+
+/// A typed visitor over ParseTrees.
+///
+/// visit() dispatches on the kind of tree to the matching visit_*() method.
+/// The default visit_*() methods recurse into the children of the tree by
+/// calling the matching walk_*() function. Implementations which override a
+/// visit_*() method can call walk_*() to continue into the children.
+///
+/// Children are visited in source order.
+pub trait Visitor<'a> {
+    fn visit(&mut self, tree: &'a ParseTree<'a>) {
+        walk(self, tree)
+    }
+
+    fn visit_empty(&mut self, _empty: &'a parse_tree::Empty) {}
+
+    fn visit_token(&mut self, _token: &'a parse_tree::Token<'a>) {}
+
+    fn visit_list(&mut self, list: &'a parse_tree::List<'a>) {
+        walk_list(self, list)
+    }
+
+    fn visit_error(&mut self, _error: &'a parse_tree::Error) {}
+
+"#;
+
+const FOLDER_HEADER: &str = r#"//! Generated by tools/generate_parse_trees. Do not edit.
+use super::parse_tree::{self, ParseTree};
+
+/// Rebuilds ParseTrees bottom up.
+///
+/// fold() dispatches on the kind of tree to the matching fold_*() method.
+/// The default fold_*() methods fold each child of the tree, then rebuild
+/// the tree from the folded children by calling the matching fold_*_children()
+/// function. Implementations which override a fold_*() method typically call
+/// fold_*_children() first, then inspect or replace the rebuilt tree.
+///
+/// A fold_*() method may return a tree of any kind.
+pub trait Folder<'a> {
+    fn fold(&mut self, tree: ParseTree<'a>) -> ParseTree<'a> {
+        fold_tree(self, tree)
+    }
+
+    fn fold_empty(&mut self, empty: parse_tree::Empty) -> ParseTree<'a> {
+        ParseTree::Empty(empty)
+    }
+
+    fn fold_token(&mut self, token: parse_tree::Token<'a>) -> ParseTree<'a> {
+        ParseTree::Token(token)
+    }
+
+    fn fold_list(&mut self, list: parse_tree::List<'a>) -> ParseTree<'a> {
+        fold_list_children(self, list)
+    }
+
+    fn fold_error(&mut self, error: parse_tree::Error) -> ParseTree<'a> {
+        ParseTree::Error(error)
+    }
+
+"#;
+
+fn print_visitor(cs: &Vec<TreeConfig>) {
+    // trait Visitor
+    print!("{}", VISITOR_HEADER);
+    for config in cs {
+        let (class_name, ctor_name, _) = get_config(config);
+        println!(
+            "    fn visit_{0}(&mut self, {0}: &'a parse_tree::{1}<'a>) {{",
+            ctor_name, class_name
+        );
+        println!("        walk_{0}(self, {0})", ctor_name);
+        println!("    }}");
+        println!();
+    }
+    print!("{}", END);
+
+    // walk
+    println!("/// Dispatches to the visit_*() method matching the kind of tree.");
+    println!("pub fn walk<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, tree: &'a ParseTree<'a>) {{");
+    println!("    match tree {{");
+    println!("        ParseTree::Empty(empty) => visitor.visit_empty(empty),");
+    println!("        ParseTree::Token(token) => visitor.visit_token(token),");
+    println!("        ParseTree::List(list) => visitor.visit_list(list),");
+    println!("        ParseTree::Error(error) => visitor.visit_error(error),");
+    for config in cs {
+        let (class_name, ctor_name, _) = get_config(config);
+        println!(
+            "        ParseTree::{0}({1}) => visitor.visit_{1}({1}),",
+            class_name, ctor_name
+        );
+    }
+    println!("    }}");
+    print!("{}", END);
+
+    // walk_list
+    println!("/// Visits the delimiters, elements and separators of a list.");
+    println!("pub fn walk_list<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, list: &'a parse_tree::List<'a>) {{");
+    println!("    for child in list.children() {{");
+    println!("        visitor.visit(child);");
+    println!("    }}");
+    print!("{}", END);
+
+    // walk_*
+    for config in cs {
+        let (class_name, ctor_name, fields) = get_config(config);
+        println!(
+            "pub fn walk_{0}<'a, V: Visitor<'a> + ?Sized>(visitor: &mut V, {0}: &'a parse_tree::{1}<'a>) {{",
+            ctor_name, class_name
+        );
+        for field_name in fields {
+            println!("    visitor.visit(&{}.{});", ctor_name, field_name);
+        }
+        print!("{}", END);
+    }
+}
+
+fn print_folder(cs: &Vec<TreeConfig>) {
+    // trait Folder
+    print!("{}", FOLDER_HEADER);
+    for config in cs {
+        let (class_name, ctor_name, _) = get_config(config);
+        println!(
+            "    fn fold_{0}(&mut self, {0}: parse_tree::{1}<'a>) -> ParseTree<'a> {{",
+            ctor_name, class_name
+        );
+        println!("        fold_{0}_children(self, {0})", ctor_name);
+        println!("    }}");
+        println!();
+    }
+    print!("{}", END);
+
+    // fold_tree
+    println!("/// Dispatches to the fold_*() method matching the kind of tree.");
+    println!("pub fn fold_tree<'a, F: Folder<'a> + ?Sized>(folder: &mut F, tree: ParseTree<'a>) -> ParseTree<'a> {{");
+    println!("    match tree {{");
+    println!("        ParseTree::Empty(empty) => folder.fold_empty(empty),");
+    println!("        ParseTree::Token(token) => folder.fold_token(token),");
+    println!("        ParseTree::List(list) => folder.fold_list(list),");
+    println!("        ParseTree::Error(error) => folder.fold_error(error),");
+    for config in cs {
+        let (class_name, ctor_name, _) = get_config(config);
+        println!(
+            "        ParseTree::{0}({1}) => folder.fold_{1}({1}),",
+            class_name, ctor_name
+        );
+    }
+    println!("    }}");
+    print!("{}", END);
+
+    // fold_list_children
+    println!("/// Folds the delimiters, elements and separators of a list.");
+    println!("pub fn fold_list_children<'a, F: Folder<'a> + ?Sized>(folder: &mut F, list: parse_tree::List<'a>) -> ParseTree<'a> {{");
+    println!("    let (start_delimiter, elements_and_separators, end_delimiter) = list.unbox();");
+    println!("    let start_delimiter = folder.fold(start_delimiter);");
+    println!("    let elements_and_separators = elements_and_separators");
+    println!("        .into_iter()");
+    println!("        .map(|(element, separator)| (folder.fold(element), folder.fold(separator)))");
+    println!("        .collect();");
+    println!("    let end_delimiter = folder.fold(end_delimiter);");
+    println!("    parse_tree::list(start_delimiter, elements_and_separators, end_delimiter)");
+    print!("{}", END);
+
+    // fold_*_children
+    for config in cs {
+        let (class_name, ctor_name, fields) = get_config(config);
+        println!(
+            "pub fn fold_{0}_children<'a, F: Folder<'a> + ?Sized>(folder: &mut F, {0}: parse_tree::{1}<'a>) -> ParseTree<'a> {{",
+            ctor_name, class_name
+        );
+        println!("    let ({},) = {}.unbox();", fields.join(", "), ctor_name);
+        println!("    parse_tree::{}(", ctor_name);
+        for field_name in fields {
+            println!("        folder.fold({}),", field_name);
+        }
+        println!("    )");
+        print!("{}", END);
+    }
+}
+
+const JSON_HEADER: &str = r#"//! Generated by tools/generate_parse_trees. Do not edit.
+//!
+//! JSON serialization of parse trees.
+//!
+//! The schema is versioned by SCHEMA_VERSION. Adding tree kinds or fields
+//! does not change the version; renaming or removing them does.
+//!
+//! parse result:
+//!   {"schema_version": number, "tree": tree, "errors": [error]}
+//!
+//! tree is one of:
+//!   null
+//!     An Empty tree.
+//!   {"kind": "Token", "token_kind": string, "range": range, "value": string,
+//!    "leading_comments": [comment], "trailing_comments": [comment], "errors": [error]}
+//!     leading_comments, trailing_comments and errors are omitted when empty.
+//!     value is the raw text of the token.
+//!   {"kind": "List", "range": range, "start_delimiter": tree,
+//!    "elements": [tree], "separators": [tree], "end_delimiter": tree}
+//!     separators has the same length as elements; the last separator is
+//!     typically null.
+//!   {"kind": "Error", "error": error}
+//!   {"kind": string, "range": range, "fields": {string: tree, ...}}
+//!     All other kinds. kind is the ParseTree variant name, and fields has a
+//!     member for every field of the kind, in source order.
+//!
+//! range:   {"start": position, "end": position}
+//! position: {"line": number, "column": number}
+//!   Lines and columns are 0 based. Columns count chars, not bytes.
+//! comment: {"kind": "LineComment" | "DelimitedComment", "range": range, "value": string}
+//! error:   {"error_code": number, "messages": [{"range": range, "message": string}]}
+use super::parse_tree::{List, ParseTree};
+use crate::utils::{Json, SyntaxError};
+
+pub const SCHEMA_VERSION: i32 = 1;
+
+/// Serializes a parse tree and the errors from parsing it.
+pub fn parse_result_to_json(tree: &ParseTree, errors: &[SyntaxError]) -> Json {
+    Json::object(vec![
+        ("schema_version", SCHEMA_VERSION.into()),
+        ("tree", to_json(tree)),
+        ("errors", Json::Array(errors.iter().map(SyntaxError::to_json).collect())),
+    ])
+}
+
+fn list_to_json(tree: &ParseTree, list: &List) -> Json {
+    Json::object(vec![
+        ("kind", "List".into()),
+        ("range", tree.get_range().to_json()),
+        ("start_delimiter", to_json(&list.start_delimiter)),
+        (
+            "elements",
+            Json::Array(list.elements_and_separators.iter().map(|(element, _)| to_json(element)).collect()),
+        ),
+        (
+            "separators",
+            Json::Array(list.elements_and_separators.iter().map(|(_, separator)| to_json(separator)).collect()),
+        ),
+        ("end_delimiter", to_json(&list.end_delimiter)),
+    ])
+}
+
+fn tree_to_json(tree: &ParseTree, fields: Vec<(&str, Json)>) -> Json {
+    Json::object(vec![
+        ("kind", tree.kind_name().into()),
+        ("range", tree.get_range().to_json()),
+        ("fields", Json::object(fields)),
+    ])
+}
+
+"#;
+
+fn print_json(cs: &Vec<TreeConfig>) {
+    print!("{}", JSON_HEADER);
+    println!("/// Serializes a parse tree.");
+    println!("pub fn to_json(tree: &ParseTree) -> Json {{");
+    println!("    match tree {{");
+    println!("        ParseTree::Empty(_) => Json::Null,");
+    println!("        ParseTree::Token(token) => {{");
+    println!("            let mut result = Json::object(vec![(\"kind\", \"Token\".into())]);");
+    println!("            if let Json::Object(members) = token.token.to_json() {{");
+    println!("                for (name, value) in members {{");
+    println!("                    result.push(&name, value);");
+    println!("                }}");
+    println!("            }}");
+    println!("            result");
+    println!("        }}");
+    println!("        ParseTree::List(list) => list_to_json(tree, list),");
+    println!("        ParseTree::Error(error) => Json::object(vec![");
+    println!("            (\"kind\", \"Error\".into()),");
+    println!("            (\"error\", error.error.to_json()),");
+    println!("        ]),");
+    for config in cs {
+        let (class_name, ctor_name, fields) = get_config(config);
+        println!("        ParseTree::{}({}) => tree_to_json(", class_name, ctor_name);
+        println!("            tree,");
+        println!("            vec![");
+        for field_name in fields {
+            println!(
+                "                (\"{0}\", to_json(&{1}.{0})),",
+                field_name, ctor_name
+            );
+        }
+        println!("            ],");
+        println!("        ),");
+    }
+    println!("    }}");
+    print!("{}", END);
+    print!("{}", JSON_TESTS);
+}
+
+const JSON_TESTS: &str = r###"#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsing::parse_statement;
+
+    fn parse_json(text: &str) -> Json {
+        let (tree, errors) = parse_statement(text);
+        parse_result_to_json(&tree, &errors)
+    }
+
+    /// The member at path, following "fields" for trees.
+    fn member<'a>(json: &'a Json, path: &[&str]) -> &'a Json {
+        path.iter().fold(json, |json, name| {
+            json.get(name)
+                .or_else(|| json.get("fields").and_then(|fields| fields.get(name)))
+                .unwrap_or_else(|| panic!("no member {}", name))
+        })
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = parse_json("select a, 'x''y' -- c\nfrom t where b");
+        assert_eq!(Json::parse(&json.to_string()), Ok(json.clone()));
+        assert_eq!(Json::parse(&json.to_pretty_string()), Ok(json));
+    }
+
+    #[test]
+    fn schema() {
+        let json = parse_json("select 1 -- c");
+        assert_eq!(member(&json, &["schema_version"]), &Json::Number(1.0));
+        assert_eq!(member(&json, &["errors"]), &Json::Array(Vec::new()));
+        let query = member(&json, &["tree", "tree", "query_no_with", "query_term"]);
+        assert_eq!(query.get("kind"), Some(&"QuerySpecification".into()));
+        assert_eq!(member(query, &["from"]), &Json::Null);
+        let item = &member(query, &["select_items", "elements"]).as_array().unwrap()[0];
+        let token = member(item, &["expression", "value"]);
+        assert_eq!(token.get("token_kind"), Some(&"Integer".into()));
+        assert_eq!(token.get("value"), Some(&"1".into()));
+        assert_eq!(token.get("leading_comments"), None);
+        assert_eq!(
+            member(token, &["trailing_comments"]).to_string(),
+            concat!(
+                r##"[{"kind":"LineComment","range":{"start":{"line":0,"column":9},"##,
+                r##""end":{"line":0,"column":13}},"value":"-- c"}]"##
+            )
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let json = parse_json("select from");
+        let errors = member(&json, &["errors"]).as_array().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0].to_string(),
+            concat!(
+                r##"{"error_code":201,"messages":[{"range":{"start":{"line":0,"column":7},"##,
+                r##""end":{"line":0,"column":7}},"message":"Expected expression, found FROM."}]}"##
+            )
+        );
+        let query = member(&json, &["tree", "tree", "query_no_with", "query_term"]);
+        let item = &member(query, &["select_items", "elements"]).as_array().unwrap()[0];
+        let expression = member(item, &["expression"]);
+        assert_eq!(expression.get("kind"), Some(&"Error".into()));
+        assert_eq!(expression.get("error"), Some(&errors[0]));
+    }
+}
+"###;
+
+fn main() {
+    let cs = configs();
+    match env::args().nth(1).as_deref() {
+        None | Some("parse_tree") => print_parse_tree(&cs),
+        Some("visitor") => print_visitor(&cs),
+        Some("folder") => print_folder(&cs),
+        Some("json") => print_json(&cs),
+        Some(output) => panic!("Unknown output {}", output),
+    }
+}